@@ -0,0 +1,131 @@
+//! Heap allocation counting for hot-path systems
+//!
+//! `CountingAllocator` counts allocations made on a thread while `count_allocations` is running
+//! there. Binaries and test targets opt in by installing it as their global allocator; without it
+//! nothing is counted. Systems registered through `hot_path_system` are counted while
+//! `HotPathAllocations::enabled` is set (benchmarks with `assert_zero_alloc` set it). The engine
+//! swaps in wrapped copies of performance monitoring, input processing and render submission
+//! while the `HotPathAllocations` resource exists; other apps run the plain, parallel systems.
+
+use crate::PerformanceMonitor;
+use bevy::prelude::*;
+use std::alloc::{self, GlobalAlloc, Layout};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+/// System allocator that counts allocations on threads inside `count_allocations`
+///
+/// ```
+/// #[global_allocator]
+/// static ALLOCATOR: mindland_app::CountingAllocator = mindland_app::CountingAllocator;
+/// # fn main() {}
+/// ```
+pub struct CountingAllocator;
+
+thread_local! {
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Count an allocation if this thread is tracking (ignored while thread-locals are torn down)
+fn record_allocation() {
+    if TRACKING.try_with(Cell::get).unwrap_or(false) {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        alloc::System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        alloc::System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        alloc::System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        alloc::System.dealloc(ptr, layout)
+    }
+}
+
+/// Run `f`, returning its result and the heap allocations it made on this thread
+///
+/// Work `f` hands to other threads isn't counted. Always 0 unless `CountingAllocator` is installed.
+pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, u64) {
+    let outer = TRACKING.with(|tracking| tracking.replace(true));
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    let allocations = ALLOCATIONS.with(Cell::get) - before;
+    TRACKING.with(|tracking| tracking.set(outer));
+    (result, allocations)
+}
+
+/// Whether `CountingAllocator` is the global allocator (otherwise nothing can be counted)
+pub fn counting_allocator_installed() -> bool {
+    count_allocations(|| drop(std::hint::black_box(Box::new(0_u64)))).1 > 0
+}
+
+/// Allocations made by `hot_path_system`s while checking is enabled
+#[derive(Resource, Debug, Default)]
+pub struct HotPathAllocations {
+    pub enabled: bool, // Off = hot-path systems run untracked
+    by_system: HashMap<&'static str, u64>,
+}
+
+impl HotPathAllocations {
+    /// Add allocations made by a hot-path system
+    pub fn record(&mut self, name: &'static str, allocations: u64) {
+        *self.by_system.entry(name).or_default() += allocations;
+    }
+
+    /// Allocations across every hot-path system
+    pub fn total(&self) -> u64 {
+        self.by_system.values().sum()
+    }
+
+    /// Hot-path systems that allocated, most allocations first
+    pub fn by_system(&self) -> Vec<(String, u64)> {
+        let mut systems: Vec<_> = self.by_system.iter().map(|(name, count)| (name.to_string(), *count)).collect();
+        systems.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        systems
+    }
+}
+
+/// Wrap a per-frame system that must not heap-allocate, so `HotPathAllocations` can check it
+///
+/// The wrapper runs exclusively. Commands are applied outside the counted run.
+pub fn hot_path_system<M>(name: &'static str, system: impl IntoSystem<(), (), M>) -> impl FnMut(&mut World) {
+    let mut system = IntoSystem::into_system(system);
+    let mut initialized = false;
+
+    move |world: &mut World| {
+        if !initialized {
+            system.initialize(world);
+            initialized = true;
+        }
+
+        let tracked = world.get_resource::<HotPathAllocations>().is_some_and(|check| check.enabled);
+        if !tracked {
+            system.run((), world);
+            system.apply_deferred(world);
+            return;
+        }
+
+        let ((), allocations) = count_allocations(|| system.run((), world));
+        system.apply_deferred(world);
+
+        if allocations > 0 {
+            world.resource_mut::<HotPathAllocations>().record(name, allocations);
+            if let Some(mut monitor) = world.get_resource_mut::<PerformanceMonitor>() {
+                (0..allocations).for_each(|_| monitor.allocation_tracker.track_hot_path_allocation());
+            }
+        }
+    }
+}
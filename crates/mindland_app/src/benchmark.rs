@@ -0,0 +1,239 @@
+//! Benchmark runs
+//!
+//! Runs the app for a fixed number of frames, then writes a `BenchmarkReport` and (optionally) a
+//! screenshot of the final frame to one directory - a complete artifact for performance PRs.
+
+use crate::{counting_allocator_installed, EngineConfig, HotPathAllocations};
+use bevy::{app::AppExit, prelude::*, render::view::screenshot::ScreenshotManager, window::PrimaryWindow};
+use mindland_performance::{FrameTimeHistogram, DEFAULT_FRAME_TIME_BUCKETS};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+/// Report file written to `BenchmarkConfig::output_dir`
+pub const BENCHMARK_REPORT_FILE: &str = "benchmark-report.json";
+
+/// Final-frame screenshot written to `BenchmarkConfig::output_dir`
+pub const BENCHMARK_SCREENSHOT_FILE: &str = "benchmark-final.png";
+
+/// Frames to wait for the screenshot readback before exiting without it
+pub const SCREENSHOT_TIMEOUT_FRAMES: u32 = 120;
+
+/// Where and what a benchmark run writes when it finishes
+#[derive(Debug, Clone, Resource)]
+pub struct BenchmarkConfig {
+    pub capture_final_frame: bool, // Needs a window; headless runs write only the report
+    pub output_dir: PathBuf,
+    pub histogram_buckets: Vec<Duration>, // Frame-time histogram boundaries
+    pub assert_zero_alloc: bool,          // Fail if a `hot_path_system` allocates during measured frames
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            capture_final_frame: true,
+            output_dir: PathBuf::from("benchmark"),
+            histogram_buckets: DEFAULT_FRAME_TIME_BUCKETS.to_vec(),
+            assert_zero_alloc: false,
+        }
+    }
+}
+
+/// Frame time statistics for a benchmark run
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub engine_version: &'static str,
+    pub os: &'static str,
+    pub config: EngineConfig,
+    pub frames: usize,
+    pub total_time: Duration,
+    pub average_fps: f32,
+    pub average_frame_time: Duration,
+    pub min_frame_time: Duration,
+    pub max_frame_time: Duration,
+    pub p99_frame_time: Duration,
+    pub frame_time_histogram: FrameTimeHistogram, // Shows the slow-frame tail the averages hide
+    pub screenshot: Option<PathBuf>, // None if capture was off or failed
+    pub zero_alloc_passed: Option<bool>, // Some with `assert_zero_alloc`; false if a hot path allocated or nothing could be counted
+    pub hot_path_allocations: Vec<(String, u64)>, // Hot-path systems that allocated while measuring, most first
+}
+
+impl BenchmarkReport {
+    /// Summarize measured frame times, bucketed at `DEFAULT_FRAME_TIME_BUCKETS`
+    pub fn from_frame_times(config: EngineConfig, frame_times: &[Duration]) -> Self {
+        Self::with_histogram_buckets(config, frame_times, &DEFAULT_FRAME_TIME_BUCKETS)
+    }
+
+    /// Summarize measured frame times with custom histogram bucket boundaries
+    pub fn with_histogram_buckets(config: EngineConfig, frame_times: &[Duration], buckets: &[Duration]) -> Self {
+        let mut sorted = frame_times.to_vec();
+        sorted.sort_unstable();
+
+        let total_time: Duration = sorted.iter().sum();
+        let average_frame_time = total_time.checked_div(sorted.len() as u32).unwrap_or_default();
+        let p99_index = (sorted.len() * 99).div_ceil(100).saturating_sub(1);
+
+        Self {
+            engine_version: env!("CARGO_PKG_VERSION"),
+            os: std::env::consts::OS,
+            config,
+            frames: sorted.len(),
+            total_time,
+            average_fps: if total_time.is_zero() { 0.0 } else { sorted.len() as f32 / total_time.as_secs_f32() },
+            average_frame_time,
+            min_frame_time: sorted.first().copied().unwrap_or_default(),
+            max_frame_time: sorted.last().copied().unwrap_or_default(),
+            p99_frame_time: sorted.get(p99_index).copied().unwrap_or_default(),
+            frame_time_histogram: FrameTimeHistogram::from_frame_times(buckets, sorted.iter().copied()),
+            screenshot: None,
+            zero_alloc_passed: None,
+            hot_path_allocations: Vec::new(),
+        }
+    }
+
+    /// Whether the run met every check it was asked to make
+    pub fn passed(&self) -> bool {
+        self.zero_alloc_passed != Some(false)
+    }
+
+    /// Write the report as pretty-printed JSON
+    pub fn save_json(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Progress of the active benchmark
+#[derive(Resource)]
+pub(crate) struct BenchmarkRun {
+    frames: usize,
+    frame_times: Vec<Duration>,
+    phase: BenchmarkPhase,
+    pub(crate) failed: Arc<AtomicBool>, // Set when the report fails a check, for the exit code
+}
+
+enum BenchmarkPhase {
+    Measuring,
+    Capturing { saved: Arc<OnceLock<bool>>, waited: u32 }, // Set by the readback callback
+    Finished,
+}
+
+impl BenchmarkRun {
+    pub(crate) fn new(frames: u32) -> Self {
+        Self {
+            frames: frames as usize,
+            frame_times: Vec::with_capacity(frames as usize),
+            phase: BenchmarkPhase::Measuring,
+            failed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+/// Measure frames, capture the last one, then write the report and exit
+#[allow(clippy::too_many_arguments)] // One system per benchmark phase would need shared state anyway
+pub(crate) fn benchmark_system(
+    time: Res<Time<Real>>,
+    config: Res<EngineConfig>,
+    benchmark: Res<BenchmarkConfig>,
+    mut run: ResMut<BenchmarkRun>,
+    screenshots: Option<ResMut<ScreenshotManager>>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+    mut allocations: Option<ResMut<HotPathAllocations>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let run = &mut *run;
+    let screenshot_path = benchmark.output_dir.join(BENCHMARK_SCREENSHOT_FILE);
+
+    let screenshot = match &mut run.phase {
+        BenchmarkPhase::Finished => return,
+        BenchmarkPhase::Measuring => {
+            // The first update has no previous frame to measure
+            if !time.delta().is_zero() {
+                run.frame_times.push(time.delta());
+            }
+            // Count hot-path allocations from the first measured frame to the last
+            if let Some(allocations) = allocations.as_mut() {
+                allocations.enabled = run.frame_times.len() < run.frames;
+            }
+            if run.frame_times.len() < run.frames {
+                return;
+            }
+
+            if !benchmark.capture_final_frame {
+                None
+            } else if let (Some(mut screenshots), Ok(window)) = (screenshots, windows.get_single()) {
+                if let Err(error) = std::fs::create_dir_all(&benchmark.output_dir) {
+                    tracing::warn!("⚠️  Failed to create {}: {}", benchmark.output_dir.display(), error);
+                }
+                let saved = Arc::new(OnceLock::new());
+                let callback_saved = saved.clone();
+                let path = screenshot_path.clone();
+                let requested = screenshots.take_screenshot(window, move |image| {
+                    let result = match image.try_into_dynamic() {
+                        Ok(image) => image.to_rgb8().save(&path).map_err(|error| error.to_string()),
+                        Err(error) => Err(error.to_string()),
+                    };
+                    if let Err(error) = &result {
+                        tracing::warn!("⚠️  Failed to save benchmark screenshot: {}", error);
+                    }
+                    let _ = callback_saved.set(result.is_ok());
+                });
+                if requested.is_ok() {
+                    run.phase = BenchmarkPhase::Capturing { saved, waited: 0 };
+                    return;
+                }
+                tracing::warn!("⚠️  Screenshot already requested; benchmark report written without one");
+                None
+            } else {
+                tracing::warn!("⚠️  No window to capture; benchmark report written without a screenshot");
+                None
+            }
+        }
+        // Exiting before the readback resolves would lose the screenshot
+        BenchmarkPhase::Capturing { saved, waited } => match saved.get() {
+            Some(&ok) => ok.then_some(screenshot_path),
+            None if *waited < SCREENSHOT_TIMEOUT_FRAMES => {
+                *waited += 1;
+                return;
+            }
+            None => {
+                tracing::warn!("⚠️  Screenshot readback timed out; benchmark report written without one");
+                None
+            }
+        },
+    };
+
+    run.phase = BenchmarkPhase::Finished;
+    let mut report = BenchmarkReport::with_histogram_buckets(config.clone(), &run.frame_times, &benchmark.histogram_buckets);
+    report.screenshot = screenshot;
+    if benchmark.assert_zero_alloc {
+        let allocations = allocations.map(|allocations| allocations.by_system()).unwrap_or_default();
+        let counted = counting_allocator_installed();
+        if !counted {
+            tracing::error!("🚨 assert_zero_alloc needs `CountingAllocator` as the global allocator; no allocations were counted");
+        }
+        for (system, count) in &allocations {
+            tracing::error!("🚨 Hot-path system {} allocated {} times during the benchmark", system, count);
+        }
+        report.zero_alloc_passed = Some(counted && allocations.is_empty());
+        report.hot_path_allocations = allocations;
+    }
+    if !report.passed() {
+        run.failed.store(true, Ordering::Release);
+    }
+
+    let report_path = benchmark.output_dir.join(BENCHMARK_REPORT_FILE);
+    let written = std::fs::create_dir_all(&benchmark.output_dir).and_then(|()| report.save_json(&report_path));
+    match written {
+        Ok(()) => tracing::info!(
+            "🏁 Benchmark complete: {:.1} FPS average over {} frames, written to {}",
+            report.average_fps,
+            report.frames,
+            report_path.display()
+        ),
+        Err(error) => tracing::warn!("⚠️  Failed to write benchmark report: {}", error),
+    }
+    exit.send(AppExit);
+}
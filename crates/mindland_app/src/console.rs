@@ -0,0 +1,293 @@
+//! In-game debug console
+//!
+//! Toggled with `DEBUG_CONSOLE_KEY`; typed lines run registered commands against the world
+//! (`set render_distance 200`, `gc`, `stats`, ...). The workspace builds Bevy without its text
+//! renderer, so the overlay draws `DebugConsole::visible_lines` through `UltraRenderer::draw_text`
+//! and output is mirrored to the log.
+
+use crate::{EngineConfig, HardwareTier, PerformanceMode, PerformanceMonitor};
+use bevy::{ecs::event::ManualEventReader, prelude::*, window::ReceivedCharacter};
+use mindland_assets::SharedAssetManager;
+use mindland_performance::QualitySettings;
+use mindland_render::UltraRenderer;
+use std::collections::{BTreeMap, VecDeque};
+use thiserror::Error;
+
+/// Key that opens and closes the console
+pub const DEBUG_CONSOLE_KEY: KeyCode = KeyCode::Grave;
+
+/// Output lines kept for display
+pub const CONSOLE_HISTORY: usize = 100;
+
+/// Lines the overlay shows, including the prompt
+pub const CONSOLE_OVERLAY_ROWS: usize = 16;
+
+/// Overlay text height in pixels
+const CONSOLE_TEXT_SIZE: f32 = 16.0;
+
+/// A parsed console line: command name and its arguments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleCommand {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+/// Why a console line couldn't run
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConsoleError {
+    #[error("empty command")]
+    Empty,
+    #[error("unterminated quote")]
+    UnterminatedQuote,
+    #[error("unknown command '{0}' (try 'help')")]
+    UnknownCommand(String),
+    #[error("usage: {0}")]
+    Usage(&'static str),
+    #[error("invalid value {value:?} for {name}: {reason}")]
+    InvalidValue { name: String, value: String, reason: String },
+    #[error("{0} is not available in this app")]
+    MissingResource(&'static str),
+}
+
+/// Runs a command with its arguments, returning text to print
+pub type ConsoleHandler = Box<dyn Fn(&mut World, &[String]) -> Result<String, ConsoleError> + Send + Sync>;
+
+struct RegisteredCommand {
+    help: &'static str,
+    handler: ConsoleHandler,
+}
+
+/// Developer console state and command registry
+#[derive(Resource)]
+pub struct DebugConsole {
+    pub open: bool,
+    pub input: String, // Line being typed
+    output: VecDeque<String>,
+    commands: BTreeMap<String, RegisteredCommand>,
+    characters: ManualEventReader<ReceivedCharacter>,
+}
+
+impl ConsoleCommand {
+    /// Split a line into whitespace-separated words; double quotes group words into one argument
+    pub fn parse(line: &str) -> Result<Self, ConsoleError> {
+        let mut words = Vec::new();
+        let mut word: Option<String> = None;
+        let mut quoted = false;
+
+        for c in line.chars() {
+            match c {
+                '"' => {
+                    quoted = !quoted;
+                    word.get_or_insert_with(String::new); // `""` is an empty argument
+                }
+                c if c.is_whitespace() && !quoted => words.extend(word.take()),
+                c => word.get_or_insert_with(String::new).push(c),
+            }
+        }
+        if quoted {
+            return Err(ConsoleError::UnterminatedQuote);
+        }
+        words.extend(word);
+
+        let mut words = words.into_iter();
+        let name = words.next().ok_or(ConsoleError::Empty)?.to_ascii_lowercase();
+        Ok(Self { name, args: words.collect() })
+    }
+}
+
+impl Default for DebugConsole {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugConsole {
+    /// Create a closed console with the built-in commands (`help`, `set`, `gc`, `stats`)
+    pub fn new() -> Self {
+        let mut console = Self {
+            open: false,
+            input: String::new(),
+            output: VecDeque::with_capacity(CONSOLE_HISTORY),
+            commands: BTreeMap::new(),
+            characters: ManualEventReader::default(),
+        };
+        console.register("set", "set <render_distance|performance_mode|hardware_tier> <value>", set_command);
+        console.register("gc", "gc - free unused assets", gc_command);
+        console.register("stats", "stats - frame rate, entity and asset counts", stats_command);
+        console
+    }
+
+    /// Add or replace a command
+    pub fn register(
+        &mut self,
+        name: &str,
+        help: &'static str,
+        handler: impl Fn(&mut World, &[String]) -> Result<String, ConsoleError> + Send + Sync + 'static,
+    ) {
+        self.commands.insert(name.to_ascii_lowercase(), RegisteredCommand { help, handler: Box::new(handler) });
+    }
+
+    /// Whether a command is registered
+    pub fn has_command(&self, name: &str) -> bool {
+        self.commands.contains_key(&name.to_ascii_lowercase())
+    }
+
+    /// Parse and run a line, recording it and its result in the output
+    pub fn execute(&mut self, world: &mut World, line: &str) -> Result<String, ConsoleError> {
+        self.push_output(format!("> {}", line));
+        let result = ConsoleCommand::parse(line).and_then(|command| self.dispatch(world, &command));
+        match &result {
+            Ok(text) if !text.is_empty() => self.push_output(text.clone()),
+            Ok(_) => {}
+            Err(error) => self.push_output(format!("error: {}", error)),
+        }
+        result
+    }
+
+    fn dispatch(&self, world: &mut World, command: &ConsoleCommand) -> Result<String, ConsoleError> {
+        if command.name == "help" {
+            return Ok(self.commands.values().map(|command| command.help).collect::<Vec<_>>().join("\n"));
+        }
+        let registered = self
+            .commands
+            .get(&command.name)
+            .ok_or_else(|| ConsoleError::UnknownCommand(command.name.clone()))?;
+        (registered.handler)(world, &command.args)
+    }
+
+    fn push_output(&mut self, text: String) {
+        for line in text.lines() {
+            tracing::info!("🖥️  {}", line);
+            if self.output.len() == CONSOLE_HISTORY {
+                self.output.pop_front();
+            }
+            self.output.push_back(line.to_string());
+        }
+    }
+
+    /// Output history, oldest first
+    pub fn output(&self) -> impl Iterator<Item = &str> {
+        self.output.iter().map(String::as_str)
+    }
+
+    /// Lines the overlay draws: the last `rows - 1` output lines and the input prompt
+    pub fn visible_lines(&self, rows: usize) -> Vec<String> {
+        let history = rows.saturating_sub(1).min(self.output.len());
+        let mut lines: Vec<String> = self.output.iter().skip(self.output.len() - history).cloned().collect();
+        lines.push(format!("] {}_", self.input));
+        lines
+    }
+}
+
+/// Draw the open console as one text batch in the top-left corner
+pub(crate) fn debug_console_overlay_system(console: Res<DebugConsole>, mut renderer: ResMut<UltraRenderer>) {
+    if console.open {
+        let text = console.visible_lines(CONSOLE_OVERLAY_ROWS).join("\n");
+        renderer.draw_text(&text, Vec2::splat(CONSOLE_TEXT_SIZE * 0.5), CONSOLE_TEXT_SIZE, Color::WHITE);
+    }
+}
+
+/// Run a console line against the world's `DebugConsole`
+pub fn run_console_command(world: &mut World, line: &str) -> Result<String, ConsoleError> {
+    world.resource_scope(|world, mut console: Mut<DebugConsole>| console.execute(world, line))
+}
+
+/// Toggle the console, collect typed characters while it's open and run lines on Enter
+pub(crate) fn debug_console_system(world: &mut World) {
+    let Some(keys) = world.get_resource::<Input<KeyCode>>() else {
+        return;
+    };
+    let (toggled, submitted, erased) = (
+        keys.just_pressed(DEBUG_CONSOLE_KEY),
+        keys.just_pressed(KeyCode::Return),
+        keys.just_pressed(KeyCode::Back),
+    );
+
+    world.resource_scope(|world, mut console: Mut<DebugConsole>| {
+        let console = &mut *console;
+        let typed: String = match world.get_resource::<Events<ReceivedCharacter>>() {
+            Some(events) => console.characters.read(events).map(|event| event.char).collect(),
+            None => String::new(),
+        };
+
+        if toggled {
+            console.open = !console.open;
+            return; // The toggle key's own character isn't input
+        }
+        if !console.open {
+            return;
+        }
+
+        console.input.extend(typed.chars().filter(|c| !c.is_control()));
+        if erased {
+            console.input.pop();
+        }
+        if submitted {
+            let line = std::mem::take(&mut console.input);
+            if !line.trim().is_empty() {
+                let _ = console.execute(world, &line);
+            }
+        }
+    });
+}
+
+fn set_command(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    const USAGE: &str = "set <render_distance|performance_mode|hardware_tier> <value>";
+    let [name, value] = args else {
+        return Err(ConsoleError::Usage(USAGE));
+    };
+    let invalid = |reason: String| ConsoleError::InvalidValue { name: name.clone(), value: value.clone(), reason };
+
+    match name.as_str() {
+        "render_distance" => {
+            let distance: f32 = value.parse().map_err(|_| invalid("expected a number".to_string()))?;
+            if !(distance.is_finite() && distance > 0.0) {
+                return Err(invalid("must be positive".to_string()));
+            }
+            let mut quality = world
+                .get_resource_mut::<QualitySettings>()
+                .ok_or(ConsoleError::MissingResource("QualitySettings"))?;
+            quality.render_distance = distance;
+            Ok(format!("render_distance = {}", distance))
+        }
+        "performance_mode" => {
+            let mode: PerformanceMode = value.parse().map_err(|error: crate::ConfigParseError| invalid(error.to_string()))?;
+            world.resource_mut::<EngineConfig>().performance_mode = mode;
+            Ok(format!("performance_mode = {:?}", mode))
+        }
+        "hardware_tier" => {
+            let tier: HardwareTier = value.parse().map_err(|error: crate::ConfigParseError| invalid(error.to_string()))?;
+            world.resource_mut::<EngineConfig>().hardware_tier = tier;
+            Ok(format!("hardware_tier = {:?}", tier))
+        }
+        _ => Err(ConsoleError::Usage(USAGE)),
+    }
+}
+
+fn gc_command(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    if !args.is_empty() {
+        return Err(ConsoleError::Usage("gc"));
+    }
+    let assets = world
+        .get_resource::<SharedAssetManager>()
+        .ok_or(ConsoleError::MissingResource("SharedAssetManager"))?;
+    Ok(format!("freed {} assets", assets.collect_garbage()))
+}
+
+fn stats_command(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    if !args.is_empty() {
+        return Err(ConsoleError::Usage("stats"));
+    }
+    let mut lines = vec![format!("entities: {}", world.entities().len())];
+    if let Some(monitor) = world.get_resource::<PerformanceMonitor>() {
+        lines.push(format!("fps: {:.1} (target {})", monitor.current_fps, monitor.target_fps));
+    }
+    if let Some(assets) = world.get_resource::<SharedAssetManager>() {
+        let stats = assets.stats();
+        lines.push(format!(
+            "assets: {} textures, {} meshes, {} materials, {} pending loads",
+            stats.textures, stats.meshes, stats.materials, stats.pending_loads
+        ));
+    }
+    Ok(lines.join("\n"))
+}
@@ -0,0 +1,73 @@
+//! Safe-mode launch after a crash
+//!
+//! With `EngineConfig::crash_sentinel` set, startup writes a "launching" marker there once the
+//! fallible checks (config validation, backend probing) have passed, and a clean shutdown
+//! removes it. A marker still present at the next startup means the last session
+//! never exited cleanly, so the engine launches with `EngineConfig::safe_mode()` instead.
+
+use crate::EngineConfig;
+use bevy::prelude::*;
+use std::path::{Path, PathBuf};
+
+/// The crash marker for this session
+#[derive(Resource, Debug, Clone)]
+pub struct CrashSentinel {
+    pub path: PathBuf,
+    pub previous_launch_crashed: bool, // True when this session launched in safe mode
+}
+
+impl CrashSentinel {
+    /// Check for a stale marker at `path` without writing one
+    pub fn check(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let previous_launch_crashed = path.exists();
+        Self { path, previous_launch_crashed }
+    }
+
+    /// Write this session's marker; call once startup can no longer fail
+    pub fn arm(&self) {
+        if let Err(error) = std::fs::write(&self.path, format!("launching pid={}\n", std::process::id())) {
+            tracing::warn!("⚠️  Could not write crash marker {}: {}", self.path.display(), error);
+        }
+    }
+
+    /// Remove the marker; called on clean shutdown
+    pub fn clear(&self) {
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            if error.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("⚠️  Could not remove crash marker {}: {}", self.path.display(), error);
+            }
+        }
+    }
+}
+
+/// Check the configured sentinel and swap in the safe config if the last session crashed
+///
+/// The marker is only written when the engine is configured, so a launch that fails
+/// validation or backend probing leaves no marker behind. Paths and the world seed carry over into safe mode so the session still persists its state.
+pub(crate) fn launch_config(config: EngineConfig) -> (EngineConfig, Option<CrashSentinel>) {
+    let Some(sentinel) = config.crash_sentinel.as_ref().map(CrashSentinel::check) else {
+        return (config, None);
+    };
+    if !sentinel.previous_launch_crashed {
+        return (config, Some(sentinel));
+    }
+
+    tracing::warn!("🛟 The last session did not shut down cleanly; launching in safe mode");
+    tracing::warn!("   Low hardware tier, every graphics backend, minimal quality. Exit normally to restore your settings.");
+    let safe = EngineConfig {
+        world_seed: config.world_seed,
+        pool_size_cache: config.pool_size_cache,
+        crash_sentinel: config.crash_sentinel,
+        log_shutdown_summary: config.log_shutdown_summary,
+        ..EngineConfig::safe_mode()
+    };
+    (safe, Some(sentinel))
+}
+
+/// Shutdown hook removing the crash marker
+pub(crate) fn clear_crash_sentinel(world: &mut World) {
+    if let Some(sentinel) = world.get_resource::<CrashSentinel>() {
+        sentinel.clear();
+    }
+}
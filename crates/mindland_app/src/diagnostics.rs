@@ -0,0 +1,83 @@
+//! Diagnostics snapshots for bug reports
+//!
+//! Everything a maintainer needs to diagnose a performance issue, in one JSON file.
+
+use crate::{EngineConfig, PerformanceMonitor};
+use bevy::{prelude::*, render::renderer::RenderAdapterInfo};
+use mindland_performance::{HardwareDetector, PerformanceFrame};
+use mindland_render::RenderStats;
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+
+/// Key that writes `DIAGNOSTICS_FILE` to the working directory
+pub const DIAGNOSTICS_KEY: KeyCode = KeyCode::F12;
+
+/// File written by the diagnostics key
+pub const DIAGNOSTICS_FILE: &str = "mindland-diagnostics.json";
+
+/// Structured engine state for attaching to bug reports
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsSnapshot {
+    pub engine_version: &'static str,
+    pub os: &'static str,
+    pub config: EngineConfig,
+    pub hardware: HardwareDetector,
+    pub graphics_backend: String, // Active adapter backend, or the configured set before one exists
+    pub present_mode: String,
+    pub current_fps: f32,
+    pub system_timings: Vec<(String, Duration)>,
+    pub recent_frames: Vec<PerformanceFrame>,
+    pub render_stats: Option<RenderStats>,
+}
+
+impl DiagnosticsSnapshot {
+    /// Capture a snapshot from the engine's world
+    pub fn capture(world: &World) -> Self {
+        let config = world.resource::<EngineConfig>().clone();
+        let monitor = world.get_resource::<PerformanceMonitor>();
+        let adapter = world.get_resource::<RenderAdapterInfo>();
+
+        let mut hardware = HardwareDetector::detect();
+        if let Some(adapter) = adapter {
+            hardware.gpu_model = adapter.name.clone();
+        }
+
+        Self {
+            engine_version: env!("CARGO_PKG_VERSION"),
+            os: std::env::consts::OS,
+            graphics_backend: match adapter {
+                Some(adapter) => format!("{:?}", adapter.backend),
+                None => format!("{:?}", config.graphics_backends()),
+            },
+            present_mode: format!("{:?}", config.present_mode()),
+            current_fps: monitor.map_or(0.0, |monitor| monitor.current_fps),
+            system_timings: monitor.map(PerformanceMonitor::system_timings).unwrap_or_default(),
+            recent_frames: monitor.map(|monitor| monitor.recent_frames.iter().cloned().collect()).unwrap_or_default(),
+            render_stats: world.get_resource::<RenderStats>().copied(),
+            config,
+            hardware,
+        }
+    }
+
+    /// Write the snapshot as pretty-printed JSON
+    pub fn save_json(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+}
+
+/// Write a diagnostics snapshot when the diagnostics key is pressed
+pub(crate) fn diagnostics_hotkey_system(world: &World) {
+    let pressed = world
+        .get_resource::<Input<KeyCode>>()
+        .is_some_and(|keys| keys.just_pressed(DIAGNOSTICS_KEY));
+    if !pressed {
+        return;
+    }
+
+    match DiagnosticsSnapshot::capture(world).save_json(DIAGNOSTICS_FILE) {
+        Ok(()) => tracing::info!("📝 Diagnostics written to {}", DIAGNOSTICS_FILE),
+        Err(error) => tracing::warn!("⚠️  Failed to write diagnostics: {}", error),
+    }
+}
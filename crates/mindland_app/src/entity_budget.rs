@@ -0,0 +1,145 @@
+//! Entity spawn/despawn budget
+//!
+//! Loading a chunk can want thousands of entities at once, which hitches the frame. Spawns and
+//! despawns queued here are applied at most `spawns_per_frame` / `despawns_per_frame` at a time,
+//! nearest first, so population spreads across frames.
+
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+
+/// Spawns applied per frame unless configured otherwise
+pub const DEFAULT_SPAWNS_PER_FRAME: usize = 1000;
+
+/// Despawns applied per frame unless configured otherwise
+pub const DEFAULT_DESPAWNS_PER_FRAME: usize = 2000;
+
+/// Per-frame limits on entity creation and removal (insert the resource to enable)
+#[derive(Resource)]
+pub struct EntityBudget {
+    pub spawns_per_frame: usize,
+    pub despawns_per_frame: usize,
+    spawns: BinaryHeap<QueuedSpawn>,
+    despawns: VecDeque<Entity>,
+    spawned_this_frame: usize,
+    next_sequence: u64, // Keeps equal-distance spawns in request order
+}
+
+/// A deferred spawn, ordered nearest (then oldest) first
+struct QueuedSpawn {
+    distance: f32,
+    sequence: u64,
+    spawn: Box<dyn FnOnce(&mut World) + Send + Sync>,
+}
+
+impl PartialEq for QueuedSpawn {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedSpawn {}
+
+impl PartialOrd for QueuedSpawn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedSpawn {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap pops the greatest, so nearer and older compare greater
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl Default for EntityBudget {
+    fn default() -> Self {
+        Self::new(DEFAULT_SPAWNS_PER_FRAME, DEFAULT_DESPAWNS_PER_FRAME)
+    }
+}
+
+impl EntityBudget {
+    /// Create a budget with the given per-frame limits (each at least 1)
+    pub fn new(spawns_per_frame: usize, despawns_per_frame: usize) -> Self {
+        Self {
+            spawns_per_frame: spawns_per_frame.max(1),
+            despawns_per_frame: despawns_per_frame.max(1),
+            spawns: BinaryHeap::new(),
+            despawns: VecDeque::new(),
+            spawned_this_frame: 0,
+            next_sequence: 0,
+        }
+    }
+
+    /// Queue a bundle to spawn; `distance` (e.g. from the camera to its chunk) orders the queue
+    pub fn queue_spawn<B: Bundle>(&mut self, bundle: B, distance: f32) {
+        self.queue_spawn_with(distance, move |world| {
+            world.spawn(bundle);
+        });
+    }
+
+    /// Queue arbitrary spawning work that counts as one spawn against the budget
+    pub fn queue_spawn_with(&mut self, distance: f32, spawn: impl FnOnce(&mut World) + Send + Sync + 'static) {
+        self.spawns.push(QueuedSpawn {
+            distance,
+            sequence: self.next_sequence,
+            spawn: Box::new(spawn),
+        });
+        self.next_sequence += 1;
+    }
+
+    /// Queue an entity (and its children) for removal
+    pub fn queue_despawn(&mut self, entity: Entity) {
+        self.despawns.push_back(entity);
+    }
+
+    /// Spawns not yet applied
+    pub fn pending_spawns(&self) -> usize {
+        self.spawns.len()
+    }
+
+    /// Despawns not yet applied
+    pub fn pending_despawns(&self) -> usize {
+        self.despawns.len()
+    }
+
+    /// Spawns still allowed this frame after the queue was processed
+    pub fn remaining_spawn_budget(&self) -> usize {
+        self.spawns_per_frame.saturating_sub(self.spawned_this_frame)
+    }
+
+    /// Apply up to one frame's worth of queued despawns and spawns, returning how many of each
+    pub fn process(&mut self, world: &mut World) -> (usize, usize) {
+        let mut despawned = 0;
+        while despawned < self.despawns_per_frame {
+            let Some(entity) = self.despawns.pop_front() else {
+                break;
+            };
+            if let Some(entity) = world.get_entity_mut(entity) {
+                entity.despawn_recursive();
+            }
+            despawned += 1;
+        }
+
+        self.spawned_this_frame = 0;
+        while self.spawned_this_frame < self.spawns_per_frame {
+            let Some(queued) = self.spawns.pop() else {
+                break;
+            };
+            (queued.spawn)(world);
+            self.spawned_this_frame += 1;
+        }
+        (self.spawned_this_frame, despawned)
+    }
+}
+
+/// Apply this frame's share of queued spawns and despawns
+pub(crate) fn entity_budget_system(world: &mut World) {
+    world.resource_scope(|world, mut budget: Mut<EntityBudget>| {
+        budget.process(world);
+    });
+}
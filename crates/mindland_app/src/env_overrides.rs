@@ -0,0 +1,148 @@
+//! Environment overrides for hardware detection
+//!
+//! Auto-detection can guess wrong; these variables let users and CI force a known configuration
+//! without code changes. Invalid values are logged and ignored.
+
+use crate::{EngineConfig, HardwareTier, PerformanceMode};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// `low`, `medium`, `high` or `ultra`
+pub const HARDWARE_TIER_ENV: &str = "MINDLAND_HARDWARE_TIER";
+
+/// `ultra`, `balanced`, `quality`, `macbook` or `emergency`
+pub const PERFORMANCE_MODE_ENV: &str = "MINDLAND_PERFORMANCE_MODE";
+
+/// Forces the MacBook Pro 2014 preset during detection on macOS
+pub const FORCE_MACBOOK_2014_ENV: &str = "MINDLAND_FORCE_MACBOOK_2014";
+
+/// A config value that doesn't name any variant
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid {kind} {value:?}, expected one of: {expected}")]
+pub struct ConfigParseError {
+    pub kind: &'static str,
+    pub value: String,
+    pub expected: &'static str,
+}
+
+impl FromStr for HardwareTier {
+    type Err = ConfigParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "ultra" | "ultrahigh" | "ultra-high" => Ok(Self::UltraHigh),
+            _ => Err(ConfigParseError {
+                kind: "hardware tier",
+                value: value.to_string(),
+                expected: "low, medium, high, ultra",
+            }),
+        }
+    }
+}
+
+impl FromStr for PerformanceMode {
+    type Err = ConfigParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "ultra" | "ultraperformance" | "ultra-performance" => Ok(Self::UltraPerformance),
+            "balanced" => Ok(Self::Balanced),
+            "quality" => Ok(Self::Quality),
+            "macbook" | "macbookpro2014" | "macbook-pro-2014" => Ok(Self::MacBookPro2014),
+            "emergency" => Ok(Self::Emergency),
+            _ => Err(ConfigParseError {
+                kind: "performance mode",
+                value: value.to_string(),
+                expected: "ultra, balanced, quality, macbook, emergency",
+            }),
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Pick a preset for the detected hardware
+    pub fn detect() -> Self {
+        tracing::info!("🔍 Detecting hardware configuration...");
+
+        // TODO: Implement actual hardware tier detection (CPU, GPU/VRAM, RAM)
+        let hardware_tier = HardwareTier::Medium;
+
+        if detect_macbook_pro_2014() {
+            tracing::info!("🍎 MacBook Pro 2014 detected - applying thermal optimization");
+            Self::macbook_pro_2014()
+        } else if hardware_tier >= HardwareTier::High {
+            tracing::info!("🚀 High-end hardware detected - enabling ultra-performance mode");
+            Self::ultra_performance()
+        } else {
+            tracing::info!("⚖️  Standard hardware detected - using balanced configuration");
+            Self::default()
+        }
+    }
+
+    /// Detected configuration with `MINDLAND_HARDWARE_TIER` / `MINDLAND_PERFORMANCE_MODE` applied
+    pub fn from_env() -> Self {
+        Self::detect().with_overrides(|name| std::env::var(name).ok())
+    }
+
+    /// Apply tier and mode overrides looked up by variable name; unparseable values keep the current setting
+    pub fn with_overrides(mut self, lookup: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(tier) = parse_override::<HardwareTier>(HARDWARE_TIER_ENV, &lookup) {
+            tracing::info!("🔧 Hardware tier forced to {:?} by {}", tier, HARDWARE_TIER_ENV);
+            self.hardware_tier = tier;
+        }
+        if let Some(mode) = parse_override::<PerformanceMode>(PERFORMANCE_MODE_ENV, &lookup) {
+            tracing::info!("🔧 Performance mode forced to {:?} by {}", mode, PERFORMANCE_MODE_ENV);
+            self = self.with_performance_mode(mode);
+        }
+        self
+    }
+
+    /// Switch to `mode`, taking its preset's frame target, sync and load settings
+    ///
+    /// The hardware tier and per-session options (seed, pools cache, sentinel) are kept.
+    pub fn with_performance_mode(self, mode: PerformanceMode) -> Self {
+        let preset = Self::for_performance_mode(mode);
+        Self {
+            target_fps: preset.target_fps,
+            enable_vsync: preset.enable_vsync,
+            performance_mode: preset.performance_mode,
+            memory_pool_size: preset.memory_pool_size,
+            max_entities: preset.max_entities,
+            ..self
+        }
+    }
+
+    /// The preset each performance mode stands for
+    pub fn for_performance_mode(mode: PerformanceMode) -> Self {
+        match mode {
+            PerformanceMode::UltraPerformance => Self::ultra_performance(),
+            PerformanceMode::Balanced => Self::default(),
+            PerformanceMode::Quality => Self { performance_mode: PerformanceMode::Quality, ..Self::default() },
+            PerformanceMode::MacBookPro2014 => Self::macbook_pro_2014(),
+            PerformanceMode::Emergency => Self::safe_mode(),
+        }
+    }
+}
+
+fn parse_override<T: FromStr<Err = ConfigParseError>>(name: &str, lookup: &impl Fn(&str) -> Option<String>) -> Option<T> {
+    let value = lookup(name)?;
+    value
+        .parse()
+        .map_err(|error| tracing::warn!("⚠️  Ignoring {}: {}; using detected value", name, error))
+        .ok()
+}
+
+/// Detect if running on MacBook Pro 2014 (placeholder implementation)
+fn detect_macbook_pro_2014() -> bool {
+    // TODO: Check hardware identifiers (i5-4278U/i5-4308U, Iris 5100) via system_profiler
+    #[cfg(target_os = "macos")]
+    {
+        std::env::var(FORCE_MACBOOK_2014_ENV).is_ok()
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    false
+}
@@ -0,0 +1,37 @@
+//! Adapter feature probing
+//!
+//! Once the renderer has picked an adapter, `GpuCapabilities` records which optional features it
+//! supports. Headless apps (and anything before the adapter reports) keep the conservative default,
+//! so optional render paths stay off rather than failing on hardware that lacks them.
+
+use bevy::{prelude::*, render::renderer::RenderAdapter};
+use mindland_render::{GpuCapabilities, UltraRenderer};
+
+impl super::MindLandApp {
+    /// Optional GPU features supported by the chosen adapter
+    pub fn gpu_capabilities(&self) -> GpuCapabilities {
+        self.bevy_app.world.get_resource::<GpuCapabilities>().cloned().unwrap_or_default()
+    }
+}
+
+/// Probe the chosen adapter once the renderer exists
+pub(crate) fn gpu_capabilities_system(adapter: Option<Res<RenderAdapter>>, mut capabilities: ResMut<GpuCapabilities>) {
+    let Some(adapter) = adapter else { return };
+
+    *capabilities = GpuCapabilities::from_adapter(
+        adapter.get_info().name,
+        adapter.features(),
+        &adapter.limits(),
+        &adapter.get_downlevel_capabilities(),
+        |format| adapter.get_texture_format_features(format),
+    );
+    tracing::info!("🧩 GPU capabilities: {:?}", *capabilities);
+    if !capabilities.compute_shaders {
+        tracing::warn!("⚠️  No compute shaders - GPU culling and particles disabled");
+    }
+}
+
+/// Switch the renderer's optional paths to what the adapter supports
+pub(crate) fn render_capabilities_system(capabilities: Res<GpuCapabilities>, mut renderer: ResMut<UltraRenderer>) {
+    renderer.apply_capabilities(&capabilities);
+}
@@ -6,13 +6,66 @@
 use bevy::{
     prelude::*,
     diagnostic::{DiagnosticsPlugin, FrameTimeDiagnosticsPlugin},
+    ecs::schedule::SystemConfigs,
     render::{
-        settings::{WgpuSettings, Backends},
+        settings::{WgpuSettings, Backends, PowerPreference},
+        renderer::RenderAdapterInfo,
         RenderPlugin,
     },
-    window::{WindowPlugin, PresentMode},
+    window::{WindowPlugin, WindowResized, PresentMode, PrimaryWindow},
+    winit::WinitSettings,
 };
-use std::time::Duration;
+use mindland_assets::{asset_load_events_system, AssetLoadCompleted, AssetLoadFailed, AssetLoadStarted, SharedAssetManager};
+use mindland_camera::{camera_resize_system, floating_origin_system, FloatingOrigin};
+use mindland_input::{input_event_system, input_focus_system, InputEventSet, InputPlugin, InputPresentSet};
+use mindland_render::{gizmo_clear_system, render_quality_system, shadow_quality_system, text_batch_clear_system, GpuCapabilities, UltraRenderer};
+use mindland_performance::{
+    AdaptationStrategy, AutoOptimizer, BatterySaver, FrameLimiter, HardwareDetector, PerformanceFrame,
+    PresentCapabilities, QualitySettings, SyncMode, ThermalMonitor,
+};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+mod alloc_tracking;
+mod benchmark;
+mod console;
+mod crash_sentinel;
+mod diagnostics;
+mod entity_budget;
+mod env_overrides;
+mod gpu_capabilities;
+mod pool_sizing;
+mod render_thread;
+mod shutdown;
+mod surface;
+mod system_timing;
+mod validation;
+mod windows;
+pub use alloc_tracking::*;
+pub use benchmark::*;
+pub use console::*;
+pub use crash_sentinel::*;
+pub use diagnostics::*;
+pub use entity_budget::*;
+pub use env_overrides::*;
+pub use pool_sizing::*;
+pub use render_thread::*;
+pub use shutdown::*;
+pub use surface::*;
+pub use system_timing::*;
+pub use validation::*;
+pub use windows::*;
+use crash_sentinel::{clear_crash_sentinel, launch_config};
+use gpu_capabilities::{gpu_capabilities_system, render_capabilities_system};
+use pool_sizing::{log_pool_high_water_marks, save_pool_sizes};
+use render_thread::render_submit_system;
+use console::debug_console_overlay_system;
+use shutdown::{log_session_summary, shutdown_hooks_system};
 
 /// Main MindLand application with ultra-high performance architecture
 pub struct MindLandApp {
@@ -20,7 +73,7 @@ pub struct MindLandApp {
 }
 
 /// Engine configuration optimized for different hardware tiers
-#[derive(Debug, Clone, Resource)]
+#[derive(Debug, Clone, Resource, Serialize)]
 pub struct EngineConfig {
     pub target_fps: u32,
     pub enable_vsync: bool,
@@ -29,10 +82,28 @@ pub struct EngineConfig {
     pub enable_performance_monitoring: bool,
     pub memory_pool_size: usize,
     pub max_entities: u32,
+    pub max_render_commands: usize,
+    pub max_input_events: usize,
+    pub entity_pool_multiplier: f32,    // Entity pool capacity = max_entities * multiplier
+    pub transform_pool_multiplier: f32, // Transform pool capacity = max_entities * multiplier
+    pub world_seed: u64,
+    pub pool_size_cache: Option<PathBuf>, // Opt-in: start from and save suggested pool sizes here
+    pub log_shutdown_summary: bool,       // Log a `SessionSummary` when the app exits
+    pub crash_sentinel: Option<PathBuf>,  // Opt-in: launch in safe mode if the last session crashed
+    pub pipelined_rendering: bool,        // Submit render commands on a render thread, one frame behind
+}
+
+/// Engine startup errors
+#[derive(Error, Debug)]
+pub enum EngineError {
+    #[error("No usable graphics adapter; tried backends: {attempted:?}")]
+    BackendUnavailable { attempted: Vec<Backends> },
+    #[error("Invalid engine configuration: {problems:?}")]
+    InvalidConfig { problems: Vec<ConfigWarning> },
 }
 
 /// Performance mode presets for different use cases
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PerformanceMode {
     /// Maximum performance, minimal quality - for competitive gaming
     UltraPerformance,
@@ -47,7 +118,7 @@ pub enum PerformanceMode {
 }
 
 /// Hardware tier classification for automatic optimization
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub enum HardwareTier {
     Low,        // Integrated graphics, older hardware
     Medium,     // Mid-range discrete graphics
@@ -65,8 +136,14 @@ pub struct PerformanceMonitor {
     pub target_fps: f32,
     pub frame_time_budget: Duration,
     pub allocation_tracker: AllocationTracker,
+    pub system_timings: HashMap<&'static str, Duration>, // Last sampled run time of each timed system
+    pub system_timing_interval: u32,                     // Timed systems are sampled every N frames
+    pub recent_frames: VecDeque<PerformanceFrame>,       // Last RECENT_FRAME_CAPACITY frames, for diagnostics
 }
 
+/// Frames of history kept for diagnostics snapshots (~2 seconds at 60 FPS)
+pub const RECENT_FRAME_CAPACITY: usize = 120;
+
 /// Zero-allocation tracking for hot paths
 #[derive(Debug)]
 pub struct AllocationTracker {
@@ -85,10 +162,27 @@ pub struct MemoryPools {
     pub input_event_pool: InputEventPool,
 }
 
+/// Peak usage of one pool against its capacity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolHighWaterMark {
+    pub peak_used: usize,
+    pub capacity: usize,
+}
+
+/// Session peak usage of each memory pool, for right-sizing `EngineConfig` pool settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolHighWaterMarks {
+    pub entity_pool: PoolHighWaterMark,
+    pub transform_pool: PoolHighWaterMark,
+    pub render_command_pool: PoolHighWaterMark,
+    pub input_event_pool: PoolHighWaterMark,
+}
+
 /// Pre-allocated entity component pool
 pub struct EntityPool {
     pub capacity: usize,
     pub used: usize,
+    pub peak_used: usize, // Most ever in use at once this session
     // TODO: Add actual entity storage pools
 }
 
@@ -96,6 +190,7 @@ pub struct EntityPool {
 pub struct TransformPool {
     pub capacity: usize,
     pub used: usize,
+    pub peak_used: usize, // Most ever in use at once this session
     // TODO: Add SIMD-aligned transform matrices
 }
 
@@ -103,6 +198,7 @@ pub struct TransformPool {
 pub struct RenderCommandPool {
     pub capacity: usize,
     pub used: usize,
+    pub peak_used: usize, // Most ever in use at once this session
     // TODO: Add render command buffers
 }
 
@@ -110,9 +206,20 @@ pub struct RenderCommandPool {
 pub struct InputEventPool {
     pub capacity: usize,
     pub used: usize,
+    pub peak_used: usize, // Most ever in use at once this session
     // TODO: Add input event ring buffers
 }
 
+/// Deterministic world-generation RNG (xoshiro256** seeded via SplitMix64)
+///
+/// Reproducible for a given seed. Never share one instance across threads - use
+/// `split` to derive an independent stream per chunk/worker instead.
+#[derive(Resource, Debug, Clone)]
+pub struct WorldRng {
+    seed: u64,
+    state: [u64; 4],
+}
+
 /// Startup system for engine initialization
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct EngineStartupSet;
@@ -121,6 +228,64 @@ pub struct EngineStartupSet;
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct PerformanceUpdateSet;
 
+/// Gameplay systems (movement, physics, fixed-step logic) that stop while paused
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct SimulationSet;
+
+/// Whether gameplay simulation is advancing (rendering and monitoring always run)
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimulationState {
+    #[default]
+    Running,
+    Paused,
+}
+
+/// Top-level engine flow: a loading scene runs until preloading completes, then the game
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EngineState {
+    #[default]
+    Loading,
+    Running,
+}
+
+/// Preloading progress (0.0-1.0) driven by the loading scene; reaching 1.0 enters `EngineState::Running`
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct PreloadProgress(pub f32);
+
+impl Default for PreloadProgress {
+    fn default() -> Self {
+        Self(1.0) // Nothing to preload unless a loading scene is installed
+    }
+}
+
+/// Game-time multiplier: 0.0 freezes, 0.5 is slow-motion, 2.0 is fast-forward.
+/// Scales `Time` (movement, physics, fixed-step) but never real-time performance measurement.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TimeScale(pub f32);
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl PerformanceMonitor {
+    /// Record a sampled run time for a timed system
+    pub fn record_system_time(&mut self, name: &'static str, duration: Duration) {
+        self.system_timings.insert(name, duration);
+    }
+
+    /// Sampled system run times, most expensive first
+    pub fn system_timings(&self) -> Vec<(String, Duration)> {
+        let mut timings: Vec<_> = self.system_timings
+            .iter()
+            .map(|(name, duration)| (name.to_string(), *duration))
+            .collect();
+        timings.sort_by_key(|(_, duration)| std::cmp::Reverse(*duration));
+        timings
+    }
+}
+
 impl AllocationTracker {
     /// Track a hot path allocation (should be zero!)
     pub fn track_hot_path_allocation(&mut self) {
@@ -139,7 +304,117 @@ impl AllocationTracker {
     }
 }
 
+impl WorldRng {
+    /// Create a generator from a world seed
+    pub fn from_seed(seed: u64) -> Self {
+        let mut splitmix = seed;
+        let state = std::array::from_fn(|_| split_mix_64(&mut splitmix));
+        Self { seed, state }
+    }
+
+    /// Seed this generator was created from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Derive an independent generator for a stream (e.g. a chunk coordinate hash).
+    /// Depends only on the seed and stream id, not on how much this generator was used.
+    pub fn split(&self, stream: u64) -> Self {
+        let mut mixer = self.seed ^ stream.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        Self::from_seed(split_mix_64(&mut mixer))
+    }
+
+    /// Next 64 random bits
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// Next 32 random bits
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Uniform float in [0, 1)
+    pub fn next_f32_01(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// Uniform integer in `range` (unbiased). Returns `range.start` for an empty range.
+    pub fn gen_range(&mut self, range: Range<u32>) -> u32 {
+        let span = range.end.saturating_sub(range.start);
+        if span == 0 {
+            return range.start;
+        }
+
+        // Lemire's multiply-shift with rejection of the biased low zone
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let product = self.next_u32() as u64 * span as u64;
+            if (product as u32) >= threshold {
+                return range.start + (product >> 32) as u32;
+            }
+        }
+    }
+
+    /// Uniform float in `range`
+    pub fn gen_range_f32(&mut self, range: Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.next_f32_01()
+    }
+}
+
+/// SplitMix64 step, used to expand a single seed into generator state
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 impl MemoryPools {
+    /// Size all pools from the engine configuration
+    pub fn from_config(config: &EngineConfig) -> Self {
+        // Per-frame command/event pools shrink on low-end hardware
+        let tier_scale = match config.hardware_tier {
+            HardwareTier::Low => 0.5,
+            HardwareTier::Medium | HardwareTier::High | HardwareTier::UltraHigh => 1.0,
+        };
+        let scaled = |count: f32| (count.max(0.0) as usize).max(1);
+
+        Self {
+            entity_pool: EntityPool {
+                capacity: scaled(config.max_entities as f32 * config.entity_pool_multiplier),
+                used: 0,
+                peak_used: 0,
+            },
+            transform_pool: TransformPool {
+                capacity: scaled(config.max_entities as f32 * config.transform_pool_multiplier),
+                used: 0,
+                peak_used: 0,
+            },
+            render_command_pool: RenderCommandPool {
+                capacity: scaled(config.max_render_commands as f32 * tier_scale),
+                used: 0,
+                peak_used: 0,
+            },
+            input_event_pool: InputEventPool {
+                capacity: scaled(config.max_input_events as f32 * tier_scale),
+                used: 0,
+                peak_used: 0,
+            },
+        }
+    }
+
     /// Get available capacity in entity pool
     pub fn entity_pool_available(&self) -> usize {
         self.entity_pool.capacity - self.entity_pool.used
@@ -150,6 +425,22 @@ impl MemoryPools {
         self.transform_pool.capacity - self.transform_pool.used
     }
     
+    /// Peak usage of every pool so far, against its capacity
+    pub fn high_water_marks(&self) -> PoolHighWaterMarks {
+        PoolHighWaterMarks {
+            entity_pool: PoolHighWaterMark { peak_used: self.entity_pool.peak_used, capacity: self.entity_pool.capacity },
+            transform_pool: PoolHighWaterMark { peak_used: self.transform_pool.peak_used, capacity: self.transform_pool.capacity },
+            render_command_pool: PoolHighWaterMark {
+                peak_used: self.render_command_pool.peak_used,
+                capacity: self.render_command_pool.capacity,
+            },
+            input_event_pool: PoolHighWaterMark {
+                peak_used: self.input_event_pool.peak_used,
+                capacity: self.input_event_pool.capacity,
+            },
+        }
+    }
+
     /// Check if all pools have sufficient capacity
     pub fn has_sufficient_capacity(&self, entities: usize, transforms: usize, render_commands: usize, input_events: usize) -> bool {
         self.entity_pool_available() >= entities &&
@@ -165,6 +456,7 @@ impl EntityPool {
         if self.used + count <= self.capacity {
             let start_index = self.used;
             self.used += count;
+            self.peak_used = self.peak_used.max(self.used);
             Some(start_index)
         } else {
             None // Pool exhausted - would trigger allocation violation
@@ -183,6 +475,7 @@ impl TransformPool {
         if self.used + count <= self.capacity {
             let start_index = self.used;
             self.used += count;
+            self.peak_used = self.peak_used.max(self.used);
             Some(start_index)
         } else {
             None // Pool exhausted
@@ -201,6 +494,7 @@ impl RenderCommandPool {
         if self.used + count <= self.capacity {
             let start_index = self.used;
             self.used += count;
+            self.peak_used = self.peak_used.max(self.used);
             Some(start_index)
         } else {
             None
@@ -219,6 +513,7 @@ impl InputEventPool {
         if self.used + count <= self.capacity {
             let start_index = self.used;
             self.used += count;
+            self.peak_used = self.peak_used.max(self.used);
             Some(start_index)
         } else {
             None
@@ -241,6 +536,15 @@ impl Default for EngineConfig {
             enable_performance_monitoring: true,
             memory_pool_size: 1024 * 1024 * 64, // 64MB pre-allocated pool
             max_entities: 100_000, // Support up to 100k entities
+            max_render_commands: 10_000, // 10k render commands per frame
+            max_input_events: 1_000, // 1k input events per frame
+            entity_pool_multiplier: 1.0,
+            transform_pool_multiplier: 1.0,
+            world_seed: 0,
+            pool_size_cache: None,
+            log_shutdown_summary: true,
+            crash_sentinel: None,
+            pipelined_rendering: false, // Measure before enabling on 2 cores
         }
     }
 }
@@ -256,6 +560,15 @@ impl EngineConfig {
             enable_performance_monitoring: true,
             memory_pool_size: 1024 * 1024 * 32, // 32MB for thermal management
             max_entities: 50_000, // Reduced for thermal efficiency
+            max_render_commands: 10_000, // 10k render commands per frame
+            max_input_events: 1_000, // 1k input events per frame
+            entity_pool_multiplier: 1.0,
+            transform_pool_multiplier: 1.0,
+            world_seed: 0,
+            pool_size_cache: None,
+            log_shutdown_summary: true,
+            crash_sentinel: None,
+            pipelined_rendering: false, // Measure before enabling on 2 cores
         }
     }
 
@@ -269,6 +582,37 @@ impl EngineConfig {
             enable_performance_monitoring: true,
             memory_pool_size: 1024 * 1024 * 128, // 128MB for maximum performance
             max_entities: 200_000, // Maximum entity support
+            max_render_commands: 10_000, // 10k render commands per frame
+            max_input_events: 1_000, // 1k input events per frame
+            entity_pool_multiplier: 1.0,
+            transform_pool_multiplier: 1.0,
+            world_seed: 0,
+            pool_size_cache: None,
+            log_shutdown_summary: true,
+            crash_sentinel: None,
+            pipelined_rendering: false, // Measure before enabling on 2 cores
+        }
+    }
+
+    /// Create the safest configuration, used after a crash: lowest tier, every backend, minimal load
+    pub fn safe_mode() -> Self {
+        Self {
+            target_fps: 30,
+            enable_vsync: false, // Emergency mode paces with a `FrameLimiter` at `target_fps` instead
+            performance_mode: PerformanceMode::Emergency,
+            hardware_tier: HardwareTier::Low,
+            enable_performance_monitoring: true,
+            memory_pool_size: 1024 * 1024 * 16, // 16MB keeps pressure off older machines
+            max_entities: 25_000, // A quarter of the default world
+            max_render_commands: 10_000, // 10k render commands per frame
+            max_input_events: 1_000, // 1k input events per frame
+            entity_pool_multiplier: 1.0,
+            transform_pool_multiplier: 1.0,
+            world_seed: 0,
+            pool_size_cache: None,
+            log_shutdown_summary: true,
+            crash_sentinel: None,
+            pipelined_rendering: false, // Measure before enabling on 2 cores
         }
     }
 
@@ -290,6 +634,34 @@ impl EngineConfig {
             HardwareTier::Low => Backends::all(),
         }
     }
+
+    /// Backend sets to try in order: the configured tier, then the permissive low-tier set
+    pub fn backend_fallback_chain(&self) -> Vec<Backends> {
+        let mut chain = vec![self.graphics_backends()];
+        let permissive = Self { hardware_tier: HardwareTier::Low, ..self.clone() }.graphics_backends();
+        if !chain.contains(&permissive) {
+            chain.push(permissive);
+        }
+        chain
+    }
+
+    /// How quickly thermal protection lowers quality in this performance mode
+    pub fn adaptation_strategy(&self) -> AdaptationStrategy {
+        match self.performance_mode {
+            PerformanceMode::UltraPerformance => AdaptationStrategy::Aggressive,
+            PerformanceMode::Emergency => AdaptationStrategy::Emergency,
+            _ => AdaptationStrategy::Conservative,
+        }
+    }
+
+    /// Adapter power preference for the performance mode
+    pub fn power_preference(&self) -> PowerPreference {
+        match self.performance_mode {
+            PerformanceMode::UltraPerformance => PowerPreference::HighPerformance,
+            PerformanceMode::MacBookPro2014 => PowerPreference::LowPower,
+            _ => PowerPreference::default(),
+        }
+    }
 }
 
 impl MindLandApp {
@@ -310,6 +682,32 @@ impl MindLandApp {
 
     /// Create a new MindLand application with custom configuration
     pub fn with_config(config: EngineConfig) -> Self {
+        let (config, sentinel) = launch_config(config);
+        let backends = config.graphics_backends();
+        Self::with_backends(config, backends, sentinel)
+    }
+
+    /// Create an application after validating the config and probing for a usable GPU adapter,
+    /// falling back to more permissive backends instead of panicking inside the renderer
+    ///
+    /// Config errors are returned; config warnings are logged and startup continues.
+    pub fn try_with_config(config: EngineConfig) -> Result<Self, EngineError> {
+        let (config, sentinel) = launch_config(config);
+        if let Err(problems) = config.validate() {
+            if problems.iter().any(ConfigWarning::is_error) {
+                return Err(EngineError::InvalidConfig { problems });
+            }
+            for warning in &problems {
+                tracing::warn!("⚠️  Config {}", warning);
+            }
+        }
+
+        let power_preference = config.power_preference();
+        let backends = select_graphics_backends(&config, |backends| probe_adapter(backends, power_preference))?;
+        Ok(Self::with_backends(config, backends, sentinel))
+    }
+
+    fn with_backends(config: EngineConfig, backends: Backends, sentinel: Option<CrashSentinel>) -> Self {
         let mut bevy_app = App::new();
         
         // Configure Bevy with ultra-high performance settings
@@ -327,12 +725,8 @@ impl MindLandApp {
         // Configure rendering with optimal backends
         let render_plugin = RenderPlugin {
             render_creation: bevy::render::settings::RenderCreation::Automatic(WgpuSettings {
-                backends: Some(config.graphics_backends()),
-                power_preference: match config.performance_mode {
-                    PerformanceMode::UltraPerformance => bevy::render::settings::PowerPreference::HighPerformance,
-                    PerformanceMode::MacBookPro2014 => bevy::render::settings::PowerPreference::LowPower,
-                    _ => bevy::render::settings::PowerPreference::default(),
-                },
+                backends: Some(backends),
+                power_preference: config.power_preference(),
                 ..default()
             }),
         };
@@ -347,8 +741,65 @@ impl MindLandApp {
             FrameTimeDiagnosticsPlugin,
         ));
 
+        Self::configure_engine(&mut bevy_app, &config, sentinel);
+
+        Self { 
+            bevy_app,
+        }
+    }
+
+    /// Create a windowless MindLand application (no renderer) for tests and tooling
+    pub fn headless(config: EngineConfig) -> Self {
+        let (config, sentinel) = launch_config(config);
+        let mut bevy_app = App::new();
+
+        bevy_app.add_plugins((
+            MinimalPlugins,
+            DiagnosticsPlugin,
+            FrameTimeDiagnosticsPlugin,
+        ));
+
+        Self::configure_engine(&mut bevy_app, &config, sentinel);
+
+        Self { 
+            bevy_app,
+        }
+    }
+
+    /// Insert engine resources and systems shared by windowed and headless apps
+    fn configure_engine(bevy_app: &mut App, config: &EngineConfig, sentinel: Option<CrashSentinel>) {
         // Insert configuration and performance monitor as resources
         bevy_app.insert_resource(config.clone());
+        bevy_app.insert_resource(WorldRng::from_seed(config.world_seed));
+        bevy_app.init_resource::<SimulationState>();
+        bevy_app.init_resource::<TimeScale>();
+        bevy_app.init_resource::<PreloadProgress>();
+        bevy_app.init_resource::<FloatingOrigin>();
+        bevy_app.init_resource::<SurfaceState>();
+        bevy_app.init_resource::<GpuCapabilities>();
+        bevy_app.init_resource::<SessionStats>();
+        let mut shutdown_hooks = ShutdownHooks::default();
+        shutdown_hooks.add(log_pool_high_water_marks);
+        shutdown_hooks.add(save_pool_sizes);
+        shutdown_hooks.add(log_session_summary);
+        shutdown_hooks.add(clear_crash_sentinel);
+        bevy_app.insert_resource(shutdown_hooks);
+        if config.performance_mode == PerformanceMode::Emergency && !config.enable_vsync {
+            // Emergency mode presents without vsync, so the limiter is what caps the frame rate
+            let mut limiter = FrameLimiter::new(Some(config.target_fps as f32), 60.0);
+            limiter.set_present_mode(config.present_mode());
+            bevy_app.insert_resource(limiter);
+        }
+        if let Some(sentinel) = sentinel {
+            sentinel.arm();
+            bevy_app.insert_resource(sentinel);
+        }
+        bevy_app.add_event::<WindowResized>(); // Already registered by WindowPlugin except in headless apps
+        bevy_app.add_event::<AssetLoadStarted>();
+        bevy_app.add_event::<AssetLoadCompleted>();
+        bevy_app.add_event::<AssetLoadFailed>();
+        bevy_app.add_state::<EngineState>();
+        bevy_app.add_plugins(InputPlugin);
         
         if config.enable_performance_monitoring {
             let performance_monitor = PerformanceMonitor {
@@ -364,55 +815,175 @@ impl MindLandApp {
                     peak_allocations_per_frame: 0,
                     zero_allocation_violations: 0,
                 },
+                system_timings: HashMap::new(),
+                system_timing_interval: 30, // Twice a second at 60 FPS
+                recent_frames: VecDeque::with_capacity(RECENT_FRAME_CAPACITY),
             };
             bevy_app.insert_resource(performance_monitor);
             
             // Initialize memory pools for zero-allocation hot paths
-            let memory_pools = MemoryPools {
-                entity_pool: EntityPool {
-                    capacity: config.max_entities as usize,
-                    used: 0,
-                },
-                transform_pool: TransformPool {
-                    capacity: config.max_entities as usize,
-                    used: 0,
-                },
-                render_command_pool: RenderCommandPool {
-                    capacity: 10000, // Support 10k render commands per frame
-                    used: 0,
-                },
-                input_event_pool: InputEventPool {
-                    capacity: 1000, // Support 1k input events per frame
-                    used: 0,
-                },
+            let mut pools = MemoryPools::from_config(config);
+            if let Some(capacities) = config.pool_size_cache.as_ref().and_then(PoolCapacities::load_json) {
+                tracing::info!("💾 Using cached pool sizes: {:?}", capacities);
+                pools.set_capacities(capacities);
+            }
+            bevy_app.insert_resource(pools);
+
+            // Thermal protection throttles `QualitySettings` as the hardware heats up
+            let quality = match config.performance_mode {
+                PerformanceMode::Emergency => QualitySettings::safe_mode_preset(),
+                _ => QualitySettings::macbook_pro_2014_preset(),
             };
-            bevy_app.insert_resource(memory_pools);
+            bevy_app.init_resource::<ThermalMonitor>();
+            bevy_app.insert_resource(AutoOptimizer::new(
+                HardwareDetector::detect(),
+                quality.clone(),
+                config.adaptation_strategy(),
+            ));
+            bevy_app.insert_resource(quality);
         }
 
         // Add startup systems
         bevy_app.add_systems(Startup, (
             engine_startup_system,
             log_system_info,
+            gpu_capabilities_system,
         ).in_set(EngineStartupSet));
 
         // Add performance monitoring systems
         if config.enable_performance_monitoring {
+            // Thermal first, so each frame records this frame's sensor reading
             bevy_app.add_systems(Update, (
-                performance_monitoring_system,
-                thermal_protection_system,
-            ).in_set(PerformanceUpdateSet));
+                timed_system("thermal_protection_system", thermal_protection_system),
+                timed_hot_path_system("performance_monitoring_system", performance_monitoring_system),
+            ).chain().in_set(PerformanceUpdateSet));
         }
 
+        // Gameplay only advances while running; virtual time (and the fixed-step accumulator) follows
+        bevy_app.add_systems(First, sync_virtual_clock.before(bevy::time::TimeSystem));
+        bevy_app.add_systems(PostUpdate, finish_loading.run_if(in_state(EngineState::Loading)));
+        bevy_app.add_systems(Last, diagnostics_hotkey_system);
+        bevy_app.add_systems(Last, shutdown_hooks_system.run_if(on_event::<bevy::app::AppExit>()));
+        bevy_app.add_systems(PreUpdate, debug_console_system
+            .after(bevy::input::InputSystem)
+            .run_if(resource_exists::<DebugConsole>()));
+        bevy_app.add_systems(Last, (
+            sync_mode_system.run_if(resource_exists_and_changed::<SyncMode>()),
+            battery_saver_system.run_if(resource_exists::<BatterySaver>()),
+            frame_limiter_system.run_if(resource_exists::<FrameLimiter>()),
+        ).chain().before(InputPresentSet));
+        // Allocation-checked runs swap the plugin's input systems for `hot_path_system`-wrapped copies
+        bevy_app.configure_sets(PreUpdate, InputEventSet.run_if(not(resource_exists::<HotPathAllocations>())));
+        bevy_app.add_systems(PreUpdate, (
+            hot_path_system("input_focus_system", input_focus_system),
+            hot_path_system("input_event_system", input_event_system),
+        )
+            .chain()
+            .after(bevy::input::InputSystem)
+            .run_if(resource_exists::<HotPathAllocations>()));
+        bevy_app.add_systems(PreUpdate, asset_load_events_system.run_if(resource_exists::<SharedAssetManager>()));
+        bevy_app.add_systems(PreUpdate, (surface_state_system, camera_resize_system, window_camera_resize_system.after(camera_resize_system)));
+        bevy_app.add_systems(PostUpdate, (
+            render_capabilities_system.run_if(resource_changed::<GpuCapabilities>()),
+            window_surface_system,
+            window_render_system.run_if(surface_available),
+        )
+            .chain()
+            .after(bevy::transform::TransformSystem::TransformPropagate)
+            .run_if(resource_exists::<UltraRenderer>()));
+        bevy_app.add_systems(First, (text_batch_clear_system, gizmo_clear_system).run_if(resource_exists::<UltraRenderer>()));
+        bevy_app.add_systems(PostUpdate, debug_console_overlay_system
+            .run_if(resource_exists::<DebugConsole>().and_then(resource_exists::<UltraRenderer>()).and_then(surface_available)));
+        bevy_app.add_systems(PostUpdate, shadow_quality_system.run_if(resource_exists_and_changed::<QualitySettings>()));
+        bevy_app.add_systems(PostUpdate, render_quality_system
+            .before(window_render_system)
+            .run_if(resource_exists_and_changed::<QualitySettings>().and_then(resource_exists::<UltraRenderer>())));
+        bevy_app.add_systems(PostUpdate, timed_hot_path_system("render_submit_system", render_submit_system)
+            .after(window_render_system)
+            .after(debug_console_overlay_system)
+            .run_if(resource_exists::<RenderSubmitter>().and_then(resource_exists::<UltraRenderer>()).and_then(surface_available)));
+        bevy_app.add_systems(PreUpdate, entity_budget_system.run_if(resource_exists::<EntityBudget>()));
+        bevy_app.add_systems(PostUpdate, floating_origin_system.before(bevy::transform::TransformSystem::TransformPropagate));
+        bevy_app.configure_sets(Update, SimulationSet.run_if(simulation_running));
+        bevy_app.configure_sets(FixedUpdate, SimulationSet.run_if(simulation_running));
+
         // Configure system scheduling for optimal performance
         bevy_app.configure_sets(Update, (
             PerformanceUpdateSet.before(bevy::transform::TransformSystem::TransformPropagate),
         ));
+    }
 
-        Self { 
-            bevy_app,
+    /// Install a loading scene: `setup` adds splash systems (typically gated on
+    /// `in_state(EngineState::Loading)`) that drive `PreloadProgress` to 1.0
+    pub fn with_loading_scene(mut self, mut setup: impl FnMut(&mut App)) -> Self {
+        self.bevy_app.insert_resource(PreloadProgress(0.0));
+        setup(&mut self.bevy_app);
+        self
+    }
+
+    /// Measure `frames` frames, then write a `BenchmarkReport` (and final-frame screenshot) and exit
+    pub fn with_benchmark(mut self, frames: u32, config: BenchmarkConfig) -> Self {
+        if config.assert_zero_alloc {
+            self.bevy_app.init_resource::<HotPathAllocations>();
+        }
+        self.bevy_app.insert_resource(config);
+        self.bevy_app.insert_resource(BenchmarkRun::new(frames));
+        self.bevy_app.add_systems(Last, benchmark_system.before(shutdown_hooks_system));
+        // winit's event loop never returns by default, which would skip the exit status check
+        if let Some(mut winit) = self.bevy_app.world.get_resource_mut::<WinitSettings>() {
+            winit.return_from_run = true;
+        }
+        self
+    }
+
+    /// Run a benchmark of `frames` frames; returns once the report and screenshot are written
+    ///
+    /// Exits the process with status 1 if the report fails a check (see `BenchmarkReport::passed`).
+    /// Windowed apps return from the winit event loop for this (`WinitSettings::return_from_run`).
+    pub fn run_benchmark(self, frames: u32, config: BenchmarkConfig) {
+        tracing::info!("⏱️  Benchmarking {} frames", frames);
+        let app = self.with_benchmark(frames, config);
+        let failed = app.bevy_app.world.resource::<BenchmarkRun>().failed.clone();
+        app.run();
+        if failed.load(std::sync::atomic::Ordering::Acquire) {
+            tracing::error!("🚨 Benchmark failed its checks");
+            std::process::exit(1);
         }
     }
 
+    /// Freeze gameplay simulation while continuing to render
+    pub fn pause(&mut self) {
+        self.set_simulation_state(SimulationState::Paused);
+    }
+
+    /// Resume gameplay simulation
+    pub fn resume(&mut self) {
+        self.set_simulation_state(SimulationState::Running);
+    }
+
+    /// Toggle between paused and running
+    pub fn toggle_pause(&mut self) {
+        if self.is_paused() {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// Check if gameplay simulation is paused
+    pub fn is_paused(&self) -> bool {
+        self.bevy_app.world.get_resource::<SimulationState>() == Some(&SimulationState::Paused)
+    }
+
+    /// Capture config, hardware, backend and recent performance for a bug report
+    pub fn diagnostics_snapshot(&self) -> DiagnosticsSnapshot {
+        DiagnosticsSnapshot::capture(&self.bevy_app.world)
+    }
+
+    fn set_simulation_state(&mut self, state: SimulationState) {
+        self.bevy_app.world.insert_resource(state);
+    }
+
     /// Run the MindLand application
     pub fn run(mut self) {
         tracing::info!("🚀 Starting MindLand - Ultra-High Performance Engine");
@@ -513,18 +1084,186 @@ fn log_system_info() {
     // - MacBook Pro 2014 detection
 }
 
+/// Run condition for systems in `SimulationSet`
+pub fn simulation_running(state: Res<SimulationState>) -> bool {
+    *state == SimulationState::Running
+}
+
+/// Leave the loading scene once preloading reports completion
+fn finish_loading(progress: Res<PreloadProgress>, mut next_state: ResMut<NextState<EngineState>>) {
+    if progress.0 >= 1.0 {
+        next_state.set(EngineState::Running);
+    }
+}
+
+/// Drive virtual time from the simulation state and time scale, so fixed-step accumulation follows too
+fn sync_virtual_clock(
+    state: Res<SimulationState>,
+    time_scale: Res<TimeScale>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    match *state {
+        SimulationState::Running if virtual_time.is_paused() => virtual_time.unpause(),
+        SimulationState::Paused if !virtual_time.is_paused() => virtual_time.pause(),
+        _ => {}
+    }
+
+    let scale = if time_scale.0.is_finite() { time_scale.0.max(0.0) } else { 1.0 };
+    if virtual_time.relative_speed() != scale {
+        virtual_time.set_relative_speed(scale);
+    }
+}
+
+/// Apply the `SyncMode` resource (insert or change it to switch at runtime) to the window and limiter
+fn sync_mode_system(
+    sync_mode: Res<SyncMode>,
+    capabilities: Option<Res<PresentCapabilities>>,
+    adapter: Option<Res<RenderAdapterInfo>>,
+    limiter: Option<ResMut<FrameLimiter>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let capabilities = match (capabilities, adapter) {
+        (Some(capabilities), _) => capabilities.clone(),
+        (None, Some(adapter)) => present_capabilities(adapter.backend),
+        (None, None) => PresentCapabilities::default(),
+    };
+
+    let present_mode = match limiter {
+        Some(mut limiter) => limiter.set_sync_mode(*sync_mode, &capabilities),
+        None => sync_mode.resolve(&capabilities),
+    };
+    if *sync_mode == SyncMode::AdaptiveSync && present_mode != PresentMode::FifoRelaxed {
+        tracing::warn!("⚠️  Adaptive sync unsupported on this surface; falling back to vsync");
+    }
+    if let Ok(mut window) = windows.get_single_mut() {
+        window.present_mode = present_mode;
+    }
+}
+
+/// Present modes assumed for a backend's surfaces beyond the `Auto*` modes
+///
+/// Configuring an unsupported mode is a fatal wgpu error, so this stays conservative: only Vulkan
+/// (whose desktop drivers expose `FIFO_RELAXED`) offers adaptive sync. Insert a
+/// `PresentCapabilities` resource to override.
+pub fn present_capabilities(backend: wgpu::Backend) -> PresentCapabilities {
+    let present_modes = match backend {
+        wgpu::Backend::Vulkan => vec![PresentMode::Fifo, PresentMode::FifoRelaxed],
+        _ => vec![PresentMode::Fifo],
+    };
+    PresentCapabilities { present_modes }
+}
+
+/// Apply battery-saver caps to the frame limiter and quality settings, relaxing them on AC power
+fn battery_saver_system(
+    mut saver: ResMut<BatterySaver>,
+    limiter: Option<ResMut<FrameLimiter>>,
+    quality: Option<ResMut<QualitySettings>>,
+) {
+    if saver.poll(Instant::now()) {
+        if saver.is_active() {
+            tracing::info!("🔋 On battery: capping at {} FPS", saver.battery_fps);
+        } else {
+            tracing::info!("🔌 On AC power: battery saver relaxed");
+        }
+    }
+
+    if let Some(mut limiter) = limiter {
+        saver.apply_to_limiter(&mut limiter);
+    }
+    // Only flag QualitySettings as changed when the cap actually moves the update frequency
+    if let Some(mut quality) = quality {
+        let update_frequency = quality.update_frequency;
+        saver.apply_to_quality(quality.bypass_change_detection());
+        if quality.update_frequency != update_frequency {
+            quality.set_changed();
+        }
+    }
+}
+
+/// Pace frames with the `FrameLimiter` (insert the resource to enable), following runtime present-mode changes
+fn frame_limiter_system(
+    mut limiter: ResMut<FrameLimiter>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    if let Ok(window) = windows.get_single() {
+        limiter.set_present_mode(window.present_mode);
+    }
+    limiter.wait();
+}
+
+/// Timed `system`, swapped for a `hot_path_system` copy while `HotPathAllocations` exists
+///
+/// The allocation-counting wrapper is exclusive, so it is only scheduled in allocation-checked
+/// runs; other apps run the system in parallel. The two copies share resources but not `Local`s.
+fn timed_hot_path_system<M: 'static>(name: &'static str, system: impl IntoSystem<(), (), M> + Copy + 'static) -> SystemConfigs {
+    (
+        timed_system(name, system).run_if(not(resource_exists::<HotPathAllocations>())),
+        timed_system(name, hot_path_system(name, system)).run_if(resource_exists::<HotPathAllocations>()),
+    )
+        .into_configs()
+}
+
+/// Pick the first backend set in the config's fallback chain that `probe` accepts
+pub fn select_graphics_backends(
+    config: &EngineConfig,
+    mut probe: impl FnMut(Backends) -> bool,
+) -> Result<Backends, EngineError> {
+    let mut attempted = Vec::new();
+    for backends in config.backend_fallback_chain() {
+        tracing::info!("🔍 Probing graphics adapter with backends {:?}", backends);
+        if probe(backends) {
+            return Ok(backends);
+        }
+        tracing::warn!("⚠️  No adapter available for backends {:?}", backends);
+        attempted.push(backends);
+    }
+    Err(EngineError::BackendUnavailable { attempted })
+}
+
+/// Check whether wgpu can find an adapter for the given backends
+fn probe_adapter(backends: Backends, power_preference: PowerPreference) -> bool {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends,
+        ..default()
+    });
+    let options = wgpu::RequestAdapterOptions {
+        power_preference,
+        compatible_surface: None,
+        force_fallback_adapter: false,
+    };
+    bevy::tasks::block_on(instance.request_adapter(&options)).is_some()
+}
+
 /// Performance monitoring system - tracks FPS and frame times with zero-allocation tracking
 fn performance_monitoring_system(
-    time: Res<Time>,
+    time: Res<Time<Real>>, // Real time so FPS keeps updating while paused
     mut perf_monitor: ResMut<PerformanceMonitor>,
     _config: Res<EngineConfig>,
     mut memory_pools: ResMut<MemoryPools>,
+    mut session: ResMut<SessionStats>,
+    thermal: Option<Res<ThermalMonitor>>,
 ) {
     // Reset frame allocation counter
     perf_monitor.allocation_tracker.frame_allocations = 0;
     
     perf_monitor.frame_count += 1;
     perf_monitor.total_time += time.delta();
+
+    // Keep a short frame history; the buffer is pre-allocated so this never allocates
+    if perf_monitor.recent_frames.len() >= RECENT_FRAME_CAPACITY {
+        perf_monitor.recent_frames.pop_front();
+    }
+    let frame = PerformanceFrame {
+        timestamp: time.elapsed(),
+        frame_time: time.delta(),
+        cpu_usage: f32::NAN, // Not sampled at the app level
+        gpu_usage: f32::NAN,
+        memory_usage: 0,
+        temperature: thermal.map_or(f32::NAN, |thermal| thermal.cpu_temp), // NaN without a sensor
+        fps: if time.delta_seconds() > 0.0 { 1.0 / time.delta_seconds() } else { 0.0 },
+    };
+    session.record_frame(frame.frame_time, frame.temperature);
+    perf_monitor.recent_frames.push_back(frame);
     
     // Update FPS every second
     if perf_monitor.total_time - perf_monitor.last_fps_update >= Duration::from_secs(1) {
@@ -573,24 +1312,37 @@ fn performance_monitoring_system(
     memory_pools.input_event_pool.used = 0;
 }
 
-/// Thermal protection system - prevents overheating on MacBook Pro 2014
+/// Thermal protection system - samples sensors and throttles quality settings while hot
+///
+/// Lowered settings are restored once the hardware is Cool again. Quality changes made while
+/// unthrottled become the baseline that is restored. Each sensor sample also adapts the LOD bias
+/// to the thermal state and measured FPS.
 fn thermal_protection_system(
-    perf_monitor: Res<PerformanceMonitor>,
-    _config: Res<EngineConfig>,
+    mut thermal: ResMut<ThermalMonitor>,
+    mut optimizer: ResMut<AutoOptimizer>,
+    quality: Option<ResMut<QualitySettings>>,
+    perf_monitor: Option<Res<PerformanceMonitor>>,
 ) {
-    // Only active for MacBook Pro 2014 mode
-    if _config.performance_mode != PerformanceMode::MacBookPro2014 {
+    let sampled = thermal.poll(Instant::now());
+    let Some(mut quality) = quality else {
         return;
+    };
+
+    if !optimizer.is_throttled() {
+        optimizer.quality_settings.clone_from(&quality);
     }
-    
-    // TODO: Implement actual thermal monitoring
-    // - Read CPU/GPU temperatures
-    // - Monitor fan speeds
-    // - Trigger quality reduction if temperatures exceed thresholds
-    // - Ensure silent operation (< 2000 RPM fan speed)
-    
-    if perf_monitor.current_fps < _config.target_fps as f32 * 0.9 {
-        tracing::debug!("🌡️  Thermal protection: monitoring performance degradation");
-        // TODO: Implement automatic quality adjustment
+    if let Some(settings) = optimizer.step(thermal.thermal_state) {
+        tracing::info!("🌡️  Thermal state {:?}: render distance {:.0}", thermal.thermal_state, settings.render_distance);
+        *quality = settings;
+    }
+
+    // No FPS until the monitor's first one-second window closes
+    let measured_fps = perf_monitor.filter(|monitor| monitor.current_fps > 0.0);
+    if let (true, Some(monitor)) = (sampled, measured_fps) {
+        optimizer.adapt_lod_bias(thermal.thermal_state, monitor.current_fps, monitor.target_fps);
+        if optimizer.quality_settings.lod_bias != quality.lod_bias {
+            tracing::debug!("🔭 LOD bias {:.0} at {:.1} FPS", optimizer.quality_settings.lod_bias, monitor.current_fps);
+            quality.lod_bias = optimizer.quality_settings.lod_bias;
+        }
     }
 }
\ No newline at end of file
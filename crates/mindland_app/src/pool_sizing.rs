@@ -0,0 +1,116 @@
+//! Right-sizing memory pools between sessions
+//!
+//! `MemoryPools::suggest_capacities` turns a session's high-water marks into capacities with some
+//! headroom. With `EngineConfig::pool_size_cache` set, the suggestion is written on exit and the
+//! next launch starts from it instead of the config-derived sizes.
+
+use crate::{EngineConfig, MemoryPools, PoolHighWaterMark};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Headroom over the session peak used when persisting suggestions
+pub const POOL_SIZE_HEADROOM: f32 = 0.1;
+
+/// Capacity of each memory pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolCapacities {
+    pub entity_pool: usize,
+    pub transform_pool: usize,
+    pub render_command_pool: usize,
+    pub input_event_pool: usize,
+}
+
+impl PoolCapacities {
+    /// Read capacities saved by `save_json`; None if the file is missing or unreadable
+    pub fn load_json(path: impl AsRef<Path>) -> Option<Self> {
+        let json = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Write the capacities as pretty-printed JSON
+    pub fn save_json(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+}
+
+impl PoolHighWaterMark {
+    /// Capacity covering the peak plus `headroom` (0.1 = 10%)
+    ///
+    /// An unused pool keeps its capacity (the session may just not have exercised it), and an
+    /// exhausted one doubles, since its real demand was never observed.
+    pub fn suggested_capacity(&self, headroom: f32) -> usize {
+        if self.peak_used == 0 {
+            self.capacity
+        } else if self.peak_used >= self.capacity {
+            self.capacity.saturating_mul(2)
+        } else {
+            (self.peak_used as f32 * (1.0 + headroom.max(0.0))).ceil() as usize
+        }
+    }
+}
+
+impl MemoryPools {
+    /// Capacities fitting this session's peak usage plus `headroom` (0.1 = 10%)
+    pub fn suggest_capacities(&self, headroom: f32) -> PoolCapacities {
+        let marks = self.high_water_marks();
+        PoolCapacities {
+            entity_pool: marks.entity_pool.suggested_capacity(headroom),
+            transform_pool: marks.transform_pool.suggested_capacity(headroom),
+            render_command_pool: marks.render_command_pool.suggested_capacity(headroom),
+            input_event_pool: marks.input_event_pool.suggested_capacity(headroom),
+        }
+    }
+
+    /// Resize every pool (at least one slot each), resetting usage and peaks
+    pub fn set_capacities(&mut self, capacities: PoolCapacities) {
+        self.entity_pool.capacity = capacities.entity_pool.max(1);
+        self.transform_pool.capacity = capacities.transform_pool.max(1);
+        self.render_command_pool.capacity = capacities.render_command_pool.max(1);
+        self.input_event_pool.capacity = capacities.input_event_pool.max(1);
+
+        self.entity_pool.reset();
+        self.transform_pool.reset();
+        self.render_command_pool.reset();
+        self.input_event_pool.reset();
+        self.entity_pool.peak_used = 0;
+        self.transform_pool.peak_used = 0;
+        self.render_command_pool.peak_used = 0;
+        self.input_event_pool.peak_used = 0;
+    }
+}
+
+/// Shutdown hook: log pool high-water marks, so pool sizes can be tuned for the next run
+pub(crate) fn log_pool_high_water_marks(world: &mut World) {
+    let Some(pools) = world.get_resource::<MemoryPools>() else {
+        return;
+    };
+
+    let marks = pools.high_water_marks();
+    tracing::info!("💾 Memory pool high-water marks:");
+    for (name, mark) in [
+        ("Entities", marks.entity_pool),
+        ("Transforms", marks.transform_pool),
+        ("Render commands", marks.render_command_pool),
+        ("Input events", marks.input_event_pool),
+    ] {
+        let percent = mark.peak_used as f32 / mark.capacity.max(1) as f32 * 100.0;
+        tracing::info!("   {}: peak {} of {} ({:.0}%)", name, mark.peak_used, mark.capacity, percent);
+    }
+}
+
+/// Shutdown hook: save suggested pool sizes when `EngineConfig::pool_size_cache` is set
+pub(crate) fn save_pool_sizes(world: &mut World) {
+    let (Some(config), Some(pools)) = (world.get_resource::<EngineConfig>(), world.get_resource::<MemoryPools>()) else {
+        return;
+    };
+    let Some(path) = &config.pool_size_cache else {
+        return;
+    };
+
+    match pools.suggest_capacities(POOL_SIZE_HEADROOM).save_json(path) {
+        Ok(()) => tracing::info!("💾 Saved suggested pool sizes to {}", path.display()),
+        Err(error) => tracing::warn!("⚠️  Could not save pool sizes to {}: {}", path.display(), error),
+    }
+}
@@ -0,0 +1,168 @@
+//! Render command submission, optionally pipelined onto a render thread
+//!
+//! With `EngineConfig::pipelined_rendering` the main thread records frame N+1 while a dedicated
+//! thread submits frame N. Two command buffers trade places through channels, so each buffer is
+//! owned by exactly one thread at a time and the main thread can get at most one frame ahead.
+
+use crate::{EngineConfig, MemoryPools};
+use bevy::{prelude::*, utils::synccell::SyncCell};
+use mindland_render::{RenderCommandBuffer, UltraRenderer};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// Command buffers in rotation: one recording, one submitting
+pub const RENDER_COMMAND_BUFFERS: usize = 2;
+
+/// Backend hook that submits a recorded frame (runs on the render thread when pipelined)
+pub type SubmitFn = Box<dyn FnMut(&RenderCommandBuffer) + Send>;
+
+/// Hands recorded command buffers to the backend, inline or on the render thread
+#[derive(Resource)]
+pub struct RenderSubmitter {
+    recording: RenderCommandBuffer,
+    mode: SyncCell<SubmitMode>, // Only touched through `&mut self`, so the resource can be shared
+    pipelined: bool,
+    next_frame: u64,
+    submitted: Arc<AtomicU64>, // Frames the backend has finished submitting
+}
+
+enum SubmitMode {
+    Inline(SubmitFn),
+    Pipelined {
+        to_render: Option<SyncSender<RenderCommandBuffer>>, // Dropped on shutdown to stop the thread
+        returned: Receiver<RenderCommandBuffer>,             // Buffers the render thread is done with
+        spare: Option<RenderCommandBuffer>,                  // Free buffer when nothing is in flight
+        thread: Option<JoinHandle<()>>,
+    },
+}
+
+impl RenderSubmitter {
+    /// Submit inline on the calling thread, or on a render thread when `pipelined`
+    pub fn new(pipelined: bool, command_capacity: usize, mut submit: SubmitFn) -> Self {
+        let submitted = Arc::new(AtomicU64::new(0));
+        let recording = RenderCommandBuffer::with_capacity(command_capacity);
+        if !pipelined {
+            return Self { recording, mode: SyncCell::new(SubmitMode::Inline(submit)), pipelined, next_frame: 0, submitted };
+        }
+
+        let (to_render, incoming) = mpsc::sync_channel::<RenderCommandBuffer>(RENDER_COMMAND_BUFFERS);
+        let (to_main, returned) = mpsc::sync_channel(RENDER_COMMAND_BUFFERS);
+        let counter = Arc::clone(&submitted);
+        let thread = std::thread::Builder::new()
+            .name("mindland-render".into())
+            .spawn(move || {
+                for buffer in incoming {
+                    submit(&buffer);
+                    counter.fetch_add(1, Ordering::Release);
+                    if to_main.send(buffer).is_err() {
+                        break; // Main thread has shut down
+                    }
+                }
+            })
+            .expect("failed to spawn render thread");
+        tracing::info!("🧵 Pipelined rendering: submitting on the render thread");
+
+        Self {
+            recording,
+            mode: SyncCell::new(SubmitMode::Pipelined {
+                to_render: Some(to_render),
+                returned,
+                spare: Some(RenderCommandBuffer::with_capacity(command_capacity)),
+                thread: Some(thread),
+            }),
+            pipelined,
+            next_frame: 0,
+            submitted,
+        }
+    }
+
+    /// Whether submission runs on the render thread
+    pub fn is_pipelined(&self) -> bool {
+        self.pipelined
+    }
+
+    /// Buffer for the frame being recorded
+    pub fn recording(&mut self) -> &mut RenderCommandBuffer {
+        &mut self.recording
+    }
+
+    /// Frames the backend has finished submitting
+    pub fn submitted_frames(&self) -> u64 {
+        self.submitted.load(Ordering::Acquire)
+    }
+
+    /// Submit the recorded frame and start recording the next one
+    ///
+    /// When pipelined this returns once the render thread hands back the previous frame's buffer,
+    /// so recording never overwrites a buffer that is still being submitted.
+    pub fn submit(&mut self) {
+        self.recording.frame = self.next_frame;
+        self.next_frame += 1;
+        match self.mode.get() {
+            SubmitMode::Inline(submit) => {
+                submit(&self.recording);
+                self.submitted.fetch_add(1, Ordering::Release);
+            }
+            SubmitMode::Pipelined { to_render, returned, spare, .. } => {
+                let next = match spare.take() {
+                    Some(buffer) => buffer,
+                    None => returned.recv().expect("render thread exited"),
+                };
+                let sender = to_render.as_ref().expect("render thread stopped");
+                sender.send(std::mem::replace(&mut self.recording, next)).expect("render thread exited");
+            }
+        }
+        self.recording.clear();
+    }
+
+    /// Block until every submitted frame has been submitted by the backend
+    pub fn flush(&mut self) {
+        if let SubmitMode::Pipelined { returned, spare, .. } = self.mode.get() {
+            if spare.is_none() {
+                *spare = Some(returned.recv().expect("render thread exited"));
+            }
+        }
+    }
+}
+
+impl Drop for RenderSubmitter {
+    fn drop(&mut self) {
+        if let SubmitMode::Pipelined { to_render, thread, .. } = self.mode.get() {
+            to_render.take(); // Ends the render thread's loop after its current frame
+            if let Some(Err(_)) = thread.take().map(JoinHandle::join) {
+                tracing::error!("🧵 Render thread panicked");
+            }
+        }
+    }
+}
+
+impl crate::MindLandApp {
+    /// Submit each frame's recorded commands through `submit`, on a render thread if
+    /// `EngineConfig::pipelined_rendering` is set
+    pub fn with_render_submitter(mut self, submit: impl FnMut(&RenderCommandBuffer) + Send + 'static) -> Self {
+        let world = &mut self.app_mut().world;
+        world.init_resource::<UltraRenderer>();
+        let config = world.resource::<EngineConfig>();
+        let submitter = RenderSubmitter::new(config.pipelined_rendering, config.max_render_commands, Box::new(submit));
+        world.insert_resource(submitter);
+        self
+    }
+}
+
+/// Record the renderer's frame and hand it off for submission
+pub(crate) fn render_submit_system(
+    renderer: Res<UltraRenderer>,
+    mut submitter: ResMut<RenderSubmitter>,
+    pools: Option<ResMut<MemoryPools>>,
+) {
+    renderer.record_commands(submitter.recording());
+    if let Some(mut pools) = pools {
+        let commands = submitter.recording().commands.len();
+        if pools.render_command_pool.allocate(commands).is_none() {
+            tracing::warn!("🎨 Render command pool exhausted: {} commands this frame", commands);
+        }
+    }
+    submitter.submit();
+}
@@ -0,0 +1,187 @@
+//! Shutdown hooks and the end-of-session performance summary
+//!
+//! Hooks registered with `MindLandApp::on_shutdown` run once, at the end of the frame in which
+//! an `AppExit` event is sent. The engine registers its own (saving pool sizes, logging the
+//! `SessionSummary`) the same way.
+
+use crate::{EngineConfig, MemoryPools, MindLandApp, PoolHighWaterMarks};
+use bevy::prelude::*;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Resolution of the session frame-time histogram
+const FRAME_TIME_RESOLUTION: Duration = Duration::from_micros(100);
+
+/// Frame times at or above this share the overflow bucket
+const MAX_BUCKETED_FRAME_TIME: Duration = Duration::from_millis(100);
+
+type ShutdownHook = Box<dyn FnMut(&mut World) + Send + Sync>;
+
+/// Callbacks run once when the app exits
+#[derive(Resource, Default)]
+pub struct ShutdownHooks {
+    hooks: Vec<ShutdownHook>,
+}
+
+impl ShutdownHooks {
+    /// Run `hook` with the world when the app exits
+    pub fn add(&mut self, hook: impl FnMut(&mut World) + Send + Sync + 'static) {
+        self.hooks.push(Box::new(hook));
+    }
+}
+
+/// Whole-session frame statistics, updated by performance monitoring
+///
+/// Frame times go into fixed 0.1ms buckets rather than a growing list, so tracking a long
+/// session costs the same ~4KB as a short one.
+#[derive(Resource, Debug, Clone)]
+pub struct SessionStats {
+    pub frames: u64,
+    pub total_time: Duration,
+    pub longest_frame: Duration,
+    pub peak_temperature: Option<f32>, // Celsius; None until a sensor reports
+    frame_time_counts: Vec<u32>,       // One bucket per FRAME_TIME_RESOLUTION, plus overflow
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        let buckets = (MAX_BUCKETED_FRAME_TIME.as_nanos() / FRAME_TIME_RESOLUTION.as_nanos()) as usize;
+        Self {
+            frames: 0,
+            total_time: Duration::ZERO,
+            longest_frame: Duration::ZERO,
+            peak_temperature: None,
+            frame_time_counts: vec![0; buckets + 1],
+        }
+    }
+}
+
+impl SessionStats {
+    /// Count one frame (zero-length frames, like the first, are skipped)
+    pub fn record_frame(&mut self, frame_time: Duration, temperature: f32) {
+        if temperature.is_finite() {
+            self.peak_temperature = Some(self.peak_temperature.map_or(temperature, |peak| peak.max(temperature)));
+        }
+        if frame_time.is_zero() {
+            return;
+        }
+
+        self.frames += 1;
+        self.total_time += frame_time;
+        self.longest_frame = self.longest_frame.max(frame_time);
+        let bucket = (frame_time.as_nanos() / FRAME_TIME_RESOLUTION.as_nanos()) as usize;
+        let last = self.frame_time_counts.len() - 1;
+        self.frame_time_counts[bucket.min(last)] += 1;
+    }
+
+    /// Mean FPS over the session
+    pub fn average_fps(&self) -> f32 {
+        if self.total_time.is_zero() {
+            return 0.0;
+        }
+        self.frames as f32 / self.total_time.as_secs_f32()
+    }
+
+    /// FPS at the frame time only `fraction` of frames exceed (0.01 gives the "1% low")
+    ///
+    /// Accurate to the 0.1ms bucket width; frames over 100ms report the session's longest frame.
+    pub fn low_fps(&self, fraction: f64) -> f32 {
+        if self.frames == 0 {
+            return 0.0;
+        }
+
+        let slow_frames = ((self.frames as f64 * fraction.clamp(0.0, 1.0)).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, &count) in self.frame_time_counts.iter().enumerate().rev() {
+            seen += count as u64;
+            if seen >= slow_frames {
+                let frame_time = if bucket == self.frame_time_counts.len() - 1 {
+                    self.longest_frame
+                } else {
+                    FRAME_TIME_RESOLUTION * (bucket as u32 + 1) // Bucket upper bound, conservatively slow
+                };
+                return 1.0 / frame_time.as_secs_f32();
+            }
+        }
+        0.0
+    }
+}
+
+/// End-of-session performance verdict, logged at shutdown when `EngineConfig::log_shutdown_summary` is on
+#[derive(Resource, Debug, Clone, Serialize)]
+pub struct SessionSummary {
+    pub frames: u64,
+    pub average_fps: f32,
+    pub one_percent_low_fps: f32,
+    pub point_one_percent_low_fps: f32,
+    pub peak_memory_bytes: Option<u64>, // Process peak resident set, where the OS reports it
+    pub peak_temperature: Option<f32>,
+    pub pool_high_water_marks: Option<PoolHighWaterMarks>, // None without performance monitoring
+}
+
+impl SessionSummary {
+    /// Summarize the session so far
+    pub fn capture(world: &World) -> Self {
+        let stats = world.get_resource::<SessionStats>().cloned().unwrap_or_default();
+        Self {
+            frames: stats.frames,
+            average_fps: stats.average_fps(),
+            one_percent_low_fps: stats.low_fps(0.01),
+            point_one_percent_low_fps: stats.low_fps(0.001),
+            peak_memory_bytes: peak_resident_memory(),
+            peak_temperature: stats.peak_temperature,
+            pool_high_water_marks: world.get_resource::<MemoryPools>().map(MemoryPools::high_water_marks),
+        }
+    }
+
+    /// Write the summary to the log (pool high-water marks are logged by their own shutdown hook)
+    pub fn log(&self) {
+        tracing::info!("🏁 Session summary: {} frames", self.frames);
+        tracing::info!(
+            "   FPS: {:.1} average, {:.1} 1% low, {:.1} 0.1% low",
+            self.average_fps, self.one_percent_low_fps, self.point_one_percent_low_fps
+        );
+        match self.peak_memory_bytes {
+            Some(bytes) => tracing::info!("   Peak memory: {}MB", bytes / (1024 * 1024)),
+            None => tracing::info!("   Peak memory: unavailable"),
+        }
+        match self.peak_temperature {
+            Some(temperature) => tracing::info!("   Peak temperature: {:.1}°C", temperature),
+            None => tracing::info!("   Peak temperature: no sensor"),
+        }
+    }
+}
+
+impl MindLandApp {
+    /// Run `hook` once when the app exits
+    pub fn on_shutdown(mut self, hook: impl FnMut(&mut World) + Send + Sync + 'static) -> Self {
+        self.bevy_app.world.resource_mut::<ShutdownHooks>().add(hook);
+        self
+    }
+}
+
+/// Log the session summary and keep it as a resource for anything running after shutdown
+pub(crate) fn log_session_summary(world: &mut World) {
+    let enabled = world.get_resource::<EngineConfig>().is_some_and(|config| config.log_shutdown_summary);
+    if enabled {
+        let summary = SessionSummary::capture(world);
+        summary.log();
+        world.insert_resource(summary);
+    }
+}
+
+/// Run every shutdown hook once, in registration order
+pub(crate) fn shutdown_hooks_system(world: &mut World) {
+    let hooks = std::mem::take(&mut world.resource_mut::<ShutdownHooks>().hooks);
+    for mut hook in hooks {
+        hook(world);
+    }
+}
+
+/// Peak resident memory of this process (Linux `VmHWM`; None elsewhere)
+fn peak_resident_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
@@ -0,0 +1,58 @@
+//! Window surface availability
+//!
+//! A minimized window reports a zero-area size, and configuring a wgpu surface that size fails.
+//! `SurfaceState` tracks whether there is anything to draw to; render-path systems gate on
+//! `surface_available` while the rest of the frame (simulation, performance monitoring) keeps running.
+
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowResized},
+};
+
+/// Size of the primary window's drawable surface
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceState {
+    pub size: Vec2,      // Logical size from the last resize event
+    pub minimized: bool, // Zero area; rendering and surface reconfiguration are skipped
+}
+
+impl Default for SurfaceState {
+    fn default() -> Self {
+        Self {
+            size: Vec2::new(1920.0, 1080.0), // Matches the primary window's initial resolution
+            minimized: false,
+        }
+    }
+}
+
+impl SurfaceState {
+    /// Record a new window size
+    pub fn resize(&mut self, size: Vec2) {
+        let minimized = !(size.x > 0.0 && size.y > 0.0);
+        if minimized != self.minimized {
+            if minimized {
+                tracing::info!("🗕  Window minimized - skipping rendering");
+            } else {
+                tracing::info!("🗖  Window restored at {}x{} - resuming rendering", size.x, size.y);
+            }
+        }
+        self.size = size;
+        self.minimized = minimized;
+    }
+}
+
+/// Run condition: the window has a surface worth rendering to
+pub fn surface_available(surface: Res<SurfaceState>) -> bool {
+    !surface.minimized
+}
+
+/// Track primary window resizes in `SurfaceState`
+pub(crate) fn surface_state_system(
+    mut resized: EventReader<WindowResized>,
+    primary: Query<(), With<PrimaryWindow>>,
+    mut surface: ResMut<SurfaceState>,
+) {
+    if let Some(event) = resized.read().filter(|event| primary.contains(event.window)).last() {
+        surface.resize(Vec2::new(event.width, event.height));
+    }
+}
@@ -0,0 +1,149 @@
+//! Sampled per-system timing
+//!
+//! `timed_system` wraps a system in `TimedSystem`, which reads the clock around the inner run
+//! only on frames the chained start system marks as sampled; the chained stop system records the
+//! result in `PerformanceMonitor::system_timings`. The wrapper reports the inner system's own data
+//! access, so a timed system still runs in parallel with the rest of the schedule.
+
+use crate::PerformanceMonitor;
+use bevy::core::FrameCount;
+use bevy::ecs::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    query::Access,
+    schedule::{InternedSystemSet, SystemConfigs},
+    world::unsafe_world_cell::UnsafeWorldCell,
+};
+use bevy::prelude::*;
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Timing state handed from the start system through the wrapper to the stop system
+#[derive(Debug, Default)]
+enum Sample {
+    #[default]
+    Skipped,
+    Due,
+    Measured(Duration),
+}
+
+type SampleSlot = Arc<Mutex<Sample>>;
+
+fn lock(slot: &SampleSlot) -> MutexGuard<'_, Sample> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Wrap a system so its run time is sampled into `PerformanceMonitor::system_timings`
+///
+/// Returns the system chained between a start and a stop system. The clock is only read on
+/// frames that are due a sample (every `system_timing_interval` frames). The wrapped system keeps
+/// its system set, so `.before(system)` / `.after(system)` ordering still applies to it.
+pub fn timed_system<M>(name: &'static str, system: impl IntoSystem<(), (), M>) -> SystemConfigs {
+    let slot = SampleSlot::default();
+    let start_slot = slot.clone();
+    let stop_slot = slot.clone();
+
+    let start = move |monitor: Option<Res<PerformanceMonitor>>, frame: Option<Res<FrameCount>>| {
+        let due = match (monitor, frame) {
+            (Some(monitor), Some(frame)) => frame.0 % monitor.system_timing_interval.max(1) == 0,
+            _ => false,
+        };
+        *lock(&start_slot) = if due { Sample::Due } else { Sample::Skipped };
+    };
+    let stop = move |monitor: Option<ResMut<PerformanceMonitor>>| {
+        let sample = std::mem::take(&mut *lock(&stop_slot));
+        if let (Sample::Measured(duration), Some(mut monitor)) = (sample, monitor) {
+            monitor.record_system_time(name, duration);
+        }
+    };
+    let timed = TimedSystem { system: IntoSystem::into_system(system), slot };
+
+    (start, timed, stop).chain()
+}
+
+/// A system that times its inner system's runs when the start system asked for a sample
+struct TimedSystem<S> {
+    system: S,
+    slot: SampleSlot,
+}
+
+impl<S> TimedSystem<S> {
+    fn timed(&mut self, run: impl FnOnce(&mut Self)) {
+        let due = matches!(*lock(&self.slot), Sample::Due);
+        let start = due.then(Instant::now);
+        run(self);
+        if let Some(start) = start {
+            *lock(&self.slot) = Sample::Measured(start.elapsed());
+        }
+    }
+}
+
+impl<S: System<In = (), Out = ()>> System for TimedSystem<S> {
+    type In = ();
+    type Out = ();
+
+    fn name(&self) -> Cow<'static, str> {
+        self.system.name()
+    }
+
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Self>()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.system.component_access()
+    }
+
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        self.system.archetype_component_access()
+    }
+
+    fn is_send(&self) -> bool {
+        self.system.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.system.is_exclusive()
+    }
+
+    unsafe fn run_unsafe(&mut self, input: (), world: UnsafeWorldCell) {
+        // SAFETY: forwarded unchanged; the caller upholds the inner system's requirements,
+        // since this wrapper reports exactly the inner system's access
+        self.timed(|timed| unsafe { timed.system.run_unsafe(input, world) });
+    }
+
+    fn run(&mut self, input: (), world: &mut World) {
+        // Exclusive systems only support `run`
+        self.timed(|timed| timed.system.run(input, world));
+    }
+
+    fn apply_deferred(&mut self, world: &mut World) {
+        self.system.apply_deferred(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        self.system.initialize(world);
+    }
+
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        self.system.update_archetype_component_access(world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.system.check_change_tick(change_tick);
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        self.system.default_system_sets()
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.system.get_last_run()
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system.set_last_run(last_run);
+    }
+}
@@ -0,0 +1,120 @@
+//! EngineConfig sanity checks
+//!
+//! Catches combinations that would crash (errors) or quietly misbehave (warnings) before the
+//! engine starts.
+
+use crate::{EngineConfig, PerformanceMode};
+use std::fmt;
+
+/// Pools above this size are almost certainly a units mistake (bytes vs MB)
+const LARGE_MEMORY_POOL: usize = 1024 * 1024 * 1024;
+
+/// Frame rates above this are beyond any display and timer resolution
+const MAX_SENSIBLE_FPS: u32 = 1000;
+
+/// Whether a config problem stops the engine from starting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSeverity {
+    Warning,
+    Error,
+}
+
+/// One problem found by `EngineConfig::validate`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    pub severity: ConfigSeverity,
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl ConfigWarning {
+    fn error(field: &'static str, message: impl Into<String>) -> Self {
+        Self { severity: ConfigSeverity::Error, field, message: message.into() }
+    }
+
+    fn warning(field: &'static str, message: impl Into<String>) -> Self {
+        Self { severity: ConfigSeverity::Warning, field, message: message.into() }
+    }
+
+    /// Whether this problem prevents startup
+    pub fn is_error(&self) -> bool {
+        self.severity == ConfigSeverity::Error
+    }
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            ConfigSeverity::Warning => "warning",
+            ConfigSeverity::Error => "error",
+        };
+        write!(f, "{}: {}: {}", severity, self.field, self.message)
+    }
+}
+
+impl EngineConfig {
+    /// Check for nonsensical settings, returning every problem found (errors and warnings)
+    pub fn validate(&self) -> Result<(), Vec<ConfigWarning>> {
+        let mut problems = Vec::new();
+
+        if self.target_fps == 0 {
+            problems.push(ConfigWarning::error("target_fps", "must be above 0 (the frame time budget is 1 / target_fps)"));
+        } else if self.target_fps > MAX_SENSIBLE_FPS {
+            problems.push(ConfigWarning::warning(
+                "target_fps",
+                format!("{} FPS is above {}; frame budgets this small are dominated by timer jitter", self.target_fps, MAX_SENSIBLE_FPS),
+            ));
+        }
+
+        if self.max_entities == 0 {
+            problems.push(ConfigWarning::error("max_entities", "must be above 0; the world could not hold the player"));
+        }
+
+        for (field, multiplier) in [
+            ("entity_pool_multiplier", self.entity_pool_multiplier),
+            ("transform_pool_multiplier", self.transform_pool_multiplier),
+        ] {
+            if !multiplier.is_finite() || multiplier <= 0.0 {
+                problems.push(ConfigWarning::error(field, format!("must be a positive number, got {}", multiplier)));
+            }
+        }
+
+        for (field, count) in [
+            ("max_render_commands", self.max_render_commands),
+            ("max_input_events", self.max_input_events),
+        ] {
+            if count == 0 {
+                problems.push(ConfigWarning::warning(field, "is 0; the pool will be clamped to a single slot"));
+            }
+        }
+
+        if self.memory_pool_size == 0 {
+            problems.push(ConfigWarning::warning("memory_pool_size", "is 0; hot paths will allocate every frame"));
+        } else if self.memory_pool_size > LARGE_MEMORY_POOL {
+            problems.push(ConfigWarning::warning(
+                "memory_pool_size",
+                format!("{}MB is unusually large (the value is in bytes)", self.memory_pool_size / (1024 * 1024)),
+            ));
+        }
+
+        if self.enable_vsync {
+            match self.performance_mode {
+                PerformanceMode::Emergency => problems.push(ConfigWarning::warning(
+                    "enable_vsync",
+                    "vsync in Emergency mode halves the frame rate on every missed vblank; use the frame limiter instead",
+                )),
+                PerformanceMode::UltraPerformance => problems.push(ConfigWarning::warning(
+                    "enable_vsync",
+                    "is ignored in UltraPerformance mode, which always presents without vsync",
+                )),
+                _ => {}
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Extra windows for tools (inspectors, debug views)
+//!
+//! Each extra window gets its own surface in `UltraRenderer` and draws the `CameraController`
+//! named by its `WindowCamera`.
+
+use crate::MindLandApp;
+use bevy::{prelude::*, window::WindowResized};
+use mindland_camera::CameraController;
+use mindland_render::UltraRenderer;
+use mindland_window::DisplaySettings;
+
+/// Camera entity a window draws
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowCamera(pub Entity);
+
+impl MindLandApp {
+    /// Open an additional window, returning its entity (assign a camera with `set_window_camera`)
+    pub fn add_window(&mut self, settings: DisplaySettings) -> Entity {
+        let world = &mut self.app_mut().world;
+        world.init_resource::<UltraRenderer>();
+        let (width, height) = settings.resolution;
+        let window = world.spawn(settings.to_window("MindLand - Tools")).id();
+        tracing::info!("🪟 Added window {:?} ({}x{})", window, width, height);
+        window
+    }
+
+    /// Draw `camera` (an entity with a `CameraController`) into `window`
+    pub fn set_window_camera(&mut self, window: Entity, camera: Entity) {
+        self.app_mut().world.entity_mut(window).insert(WindowCamera(camera));
+    }
+}
+
+/// Keep each window's surface in step with its size and present mode
+pub(crate) fn window_surface_system(
+    windows: Query<(Entity, &Window), Changed<Window>>,
+    mut closed: RemovedComponents<Window>,
+    mut renderer: ResMut<UltraRenderer>,
+) {
+    for window in closed.read() {
+        renderer.remove_surface(window);
+    }
+    for (entity, window) in &windows {
+        let size = UVec2::new(window.resolution.physical_width(), window.resolution.physical_height());
+        if renderer.configure_surface(entity, size, window.present_mode) {
+            tracing::debug!("🪟 Configured surface for {:?}: {}x{}", entity, size.x, size.y);
+        }
+    }
+}
+
+/// Fit each window's camera to its own window, after `camera_resize_system` fit every camera to the primary
+pub(crate) fn window_camera_resize_system(
+    mut resized: EventReader<WindowResized>,
+    windows: Query<(&Window, &WindowCamera)>,
+    mut cameras: Query<&mut CameraController>,
+) {
+    if resized.read().last().is_none() {
+        return;
+    }
+    for (window, camera) in &windows {
+        if let Ok(mut camera) = cameras.get_mut(camera.0) {
+            camera.set_viewport_size(Vec2::new(window.width(), window.height()));
+        }
+    }
+}
+
+/// Draw each window's camera into its surface
+pub(crate) fn window_render_system(
+    windows: Query<(Entity, &WindowCamera)>,
+    cameras: Query<&CameraController>,
+    mut renderer: ResMut<UltraRenderer>,
+) {
+    renderer.viewport_passes.retain(|pass| pass.window.is_none()); // Last frame's window passes
+    for (window, camera) in &windows {
+        if let Ok(camera) = cameras.get(camera.0) {
+            renderer.render_window(window, camera);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Tests for asset-load telemetry events
+//!
+//! **Feature: asset-load-events, Property 1: Every Queued Load Starts Then Completes Or Fails**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_app::{EngineConfig, MindLandApp};
+use mindland_assets::{
+    AssetId, AssetLoadCompleted, AssetLoadFailed, AssetLoadStarted, AssetManager, AssetPath, AssetType, LoadPriority,
+    SharedAssetManager,
+};
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Events seen, in arrival order
+#[derive(Resource, Default)]
+struct LoadLog(Vec<String>);
+
+/// Completed-load durations
+#[derive(Resource, Default)]
+struct Durations(Vec<(AssetId, Duration)>);
+
+/// Encode a solid PNG of the given size
+fn png_bytes(size: u32) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+    Image::new(
+        Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![120; (size * size * 4) as usize],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+    .try_into_dynamic()
+    .unwrap()
+    .write_to(&mut bytes, bevy::render::texture::ImageFormat::Png.as_image_crate_format().unwrap())
+    .unwrap();
+    bytes.into_inner()
+}
+
+fn drive_loads(assets: Res<SharedAssetManager>) {
+    let _ = assets.process_loading_queue();
+}
+
+fn record_events(
+    mut started: EventReader<AssetLoadStarted>,
+    mut completed: EventReader<AssetLoadCompleted>,
+    mut failed: EventReader<AssetLoadFailed>,
+    mut log: ResMut<LoadLog>,
+    mut durations: ResMut<Durations>,
+) {
+    for event in started.read() {
+        log.0.push(format!("started {}", event.path.display()));
+    }
+    for event in completed.read() {
+        log.0.push("completed".to_string());
+        durations.0.push((event.id.clone(), event.duration));
+    }
+    for event in failed.read() {
+        log.0.push(format!("failed {}", event.path.display()));
+    }
+}
+
+/// Headless app whose asset manager reads from a fresh directory holding `files`
+fn app_with_assets(name: &str, files: &[(&str, Vec<u8>)]) -> (MindLandApp, SharedAssetManager) {
+    let root = std::env::temp_dir().join(format!("mindland-load-events-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&root).unwrap();
+    for (file, bytes) in files {
+        std::fs::write(root.join(file), bytes).unwrap();
+    }
+
+    let mut manager = AssetManager::new();
+    manager.set_root(root);
+    let assets = SharedAssetManager::new(manager);
+
+    let mut app = MindLandApp::headless(EngineConfig::default());
+    app.app_mut()
+        .insert_resource(assets.clone())
+        .init_resource::<LoadLog>()
+        .init_resource::<Durations>()
+        .add_systems(Update, drive_loads)
+        .add_systems(Last, record_events);
+    (app, assets)
+}
+
+/// Update until `done` holds or a second passes
+fn update_until(app: &mut MindLandApp, done: impl Fn(&LoadLog) -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(1);
+    while !done(app.app_mut().world.resource::<LoadLog>()) && Instant::now() < deadline {
+        app.app_mut().update();
+    }
+}
+
+fn texture(path: &str) -> AssetPath {
+    AssetPath { path: PathBuf::from(path), asset_type: AssetType::Texture }
+}
+
+#[cfg(test)]
+mod asset_load_events_tests {
+    use super::*;
+
+    #[test]
+    fn test_load_emits_started_then_completed() {
+        // **Feature: asset-load-events, Property 1: Every Queued Load Starts Then Completes Or Fails**
+
+        let (mut app, assets) = app_with_assets("complete", &[("stone.png", png_bytes(64))]);
+        assets.queue_load(texture("stone.png"), LoadPriority::Normal);
+
+        update_until(&mut app, |log| log.0.len() >= 2);
+
+        let world = &app.app_mut().world;
+        assert_eq!(world.resource::<LoadLog>().0, vec!["started stone.png", "completed"]);
+        let (id, duration) = world.resource::<Durations>().0[0].clone();
+        assert_eq!(assets.read().find_by_path(&PathBuf::from("stone.png")), Some(id));
+        assert!(duration > Duration::ZERO && duration < Duration::from_secs(1), "{duration:?}");
+    }
+
+    #[test]
+    fn test_undecodable_asset_emits_failed() {
+        // **Feature: asset-load-events, Property 1: Every Queued Load Starts Then Completes Or Fails**
+
+        let (mut app, assets) = app_with_assets("fail", &[("broken.png", b"not a png".to_vec())]);
+        assets.queue_load(texture("broken.png"), LoadPriority::Normal);
+
+        update_until(&mut app, |log| log.0.len() >= 2);
+
+        assert_eq!(app.app_mut().world.resource::<LoadLog>().0, vec!["started broken.png", "failed broken.png"]);
+    }
+
+    #[test]
+    fn test_cached_load_still_reports() {
+        let (mut app, assets) = app_with_assets("cached", &[("dirt.png", png_bytes(8))]);
+        assets.load_texture(PathBuf::from("dirt.png")).unwrap();
+        assets.queue_load(texture("dirt.png"), LoadPriority::Normal);
+
+        update_until(&mut app, |log| log.0.len() >= 2);
+
+        assert_eq!(app.app_mut().world.resource::<LoadLog>().0, vec!["started dirt.png", "completed"]);
+    }
+}
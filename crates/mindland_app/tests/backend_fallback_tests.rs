@@ -0,0 +1,60 @@
+//! Tests for graphics backend pre-flight fallback
+//!
+//! **Feature: backend-fallback, Property 1: Progressive Backend Fallback**
+
+use mindland_app::{select_graphics_backends, EngineConfig, EngineError, HardwareTier};
+use bevy::render::settings::Backends;
+
+#[cfg(test)]
+mod backend_fallback_tests {
+    use super::*;
+
+    fn high_tier_config() -> EngineConfig {
+        EngineConfig {
+            hardware_tier: HardwareTier::High,
+            ..EngineConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_falls_back_to_low_tier_backends() {
+        // **Feature: backend-fallback, Property 1: Progressive Backend Fallback**
+
+        let config = high_tier_config();
+        let mut probed = Vec::new();
+        let selected = select_graphics_backends(&config, |backends| {
+            probed.push(backends);
+            backends == Backends::all()
+        });
+
+        assert_eq!(selected.unwrap(), Backends::all());
+        assert_eq!(probed, vec![config.graphics_backends(), Backends::all()]);
+    }
+
+    #[test]
+    fn test_reports_every_attempted_backend() {
+        // **Feature: backend-fallback, Property 1: Progressive Backend Fallback**
+
+        let config = high_tier_config();
+        let result = select_graphics_backends(&config, |_| false);
+
+        match result {
+            Err(EngineError::BackendUnavailable { attempted }) => {
+                assert_eq!(attempted, config.backend_fallback_chain());
+                assert_eq!(attempted.len(), 2);
+            }
+            Ok(backends) => panic!("expected failure, selected {backends:?}"),
+            Err(other) => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_low_tier_does_not_retry_same_backends() {
+        let config = EngineConfig {
+            hardware_tier: HardwareTier::Low,
+            ..EngineConfig::default()
+        };
+
+        assert_eq!(config.backend_fallback_chain(), vec![Backends::all()]);
+    }
+}
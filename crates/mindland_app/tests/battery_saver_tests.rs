@@ -0,0 +1,67 @@
+//! Tests for the battery saver in the running app
+//!
+//! **Feature: battery-saver, Property 1: Caps Apply On Battery And Relax On AC**
+
+use bevy::prelude::*;
+use mindland_app::{EngineConfig, MindLandApp};
+use mindland_performance::{BatterySaver, PowerSource, PowerSourceProvider, QualitySettings};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Power source the test can unplug
+#[derive(Clone)]
+struct MockPower(Arc<Mutex<PowerSource>>);
+
+impl PowerSourceProvider for MockPower {
+    fn power_source(&self) -> PowerSource {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// Frames on which `QualitySettings` was seen as changed
+#[derive(Resource, Default)]
+struct QualityChanges(u32);
+
+fn count_quality_changes(quality: Res<QualitySettings>, mut changes: ResMut<QualityChanges>) {
+    if quality.is_changed() {
+        changes.0 += 1;
+    }
+}
+
+#[cfg(test)]
+mod battery_saver_tests {
+    use super::*;
+
+    #[test]
+    fn test_quality_settings_only_change_when_the_cap_moves() {
+        // **Feature: battery-saver, Property 1: Caps Apply On Battery And Relax On AC**
+
+        let power = MockPower(Arc::new(Mutex::new(PowerSource::Ac)));
+        let mut saver = BatterySaver::new(Box::new(power.clone()));
+        saver.poll_interval = Duration::ZERO; // Poll every frame
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut()
+            .insert_resource(saver)
+            .insert_resource(QualitySettings::macbook_pro_2014_preset())
+            .init_resource::<QualityChanges>()
+            .add_systems(First, count_quality_changes);
+        let changes = |app: &mut MindLandApp| app.app_mut().world.resource::<QualityChanges>().0;
+
+        app.app_mut().update();
+        assert_eq!(changes(&mut app), 1, "inserting the settings is the only change so far");
+        for _ in 0..3 {
+            app.app_mut().update();
+        }
+        assert_eq!(changes(&mut app), 1, "on AC the saver leaves the settings untouched");
+
+        *power.0.lock().unwrap() = PowerSource::Battery;
+        for _ in 0..4 {
+            app.app_mut().update();
+        }
+        assert_eq!(changes(&mut app), 2, "unplugging changes the settings once");
+        let saver = app.app_mut().world.resource::<BatterySaver>();
+        let capped = saver.battery_update_frequency;
+        assert!(app.app_mut().world.resource::<QualitySettings>().update_frequency <= capped);
+    }
+}
@@ -0,0 +1,104 @@
+//! Tests for benchmark runs and their output artifacts
+//!
+//! **Feature: benchmark, Property 1: Finished Benchmarks Write A Report And Exit**
+
+use bevy::{app::AppExit, prelude::*, time::TimeUpdateStrategy, winit::WinitSettings};
+use mindland_app::{
+    BenchmarkConfig, BenchmarkReport, EngineConfig, MindLandApp, BENCHMARK_REPORT_FILE, BENCHMARK_SCREENSHOT_FILE,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Fresh output directory per test
+fn output_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("mindland-benchmark-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn exit_requested(app: &mut MindLandApp) -> bool {
+    !app.app_mut().world.resource::<Events<AppExit>>().is_empty()
+}
+
+#[cfg(test)]
+mod benchmark_tests {
+    use super::*;
+
+    #[test]
+    fn test_headless_benchmark_writes_report_and_exits() {
+        // **Feature: benchmark, Property 1: Finished Benchmarks Write A Report And Exit**
+
+        let dir = output_dir("headless");
+        let config = BenchmarkConfig { capture_final_frame: true, output_dir: dir.clone(), ..Default::default() };
+        let mut app = MindLandApp::headless(EngineConfig::default()).with_benchmark(10, config);
+        app.app_mut().insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(20)));
+
+        let mut updates = 0;
+        while !exit_requested(&mut app) {
+            app.app_mut().update();
+            updates += 1;
+            assert!(updates < 50, "benchmark never finished");
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.join(BENCHMARK_REPORT_FILE)).unwrap()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(json["frames"], 10);
+        assert!((json["average_fps"].as_f64().unwrap() - 50.0).abs() < 0.01);
+        assert!(json["screenshot"].is_null()); // No window to capture
+        assert!(!dir.join(BENCHMARK_SCREENSHOT_FILE).exists());
+    }
+
+    #[test]
+    fn test_windowed_benchmark_returns_from_the_event_loop() {
+        // **Feature: benchmark, Property 1: Finished Benchmarks Write A Report And Exit**
+        // `run_benchmark` checks the result after `run`, which winit only returns from on request
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(WinitSettings::default());
+        assert!(!app.app_mut().world.resource::<WinitSettings>().return_from_run);
+
+        let config = BenchmarkConfig { output_dir: output_dir("windowed"), ..Default::default() };
+        let mut app = app.with_benchmark(10, config);
+        assert!(app.app_mut().world.resource::<WinitSettings>().return_from_run);
+    }
+
+    #[test]
+    fn test_report_statistics() {
+        // **Feature: benchmark, Property 1: Finished Benchmarks Write A Report And Exit**
+
+        let mut frame_times: Vec<_> = (1..=100).map(Duration::from_millis).collect();
+        frame_times.reverse();
+        let report = BenchmarkReport::from_frame_times(EngineConfig::default(), &frame_times);
+
+        assert_eq!(report.frames, 100);
+        assert_eq!(report.total_time, Duration::from_millis(5050));
+        assert_eq!(report.min_frame_time, Duration::from_millis(1));
+        assert_eq!(report.max_frame_time, Duration::from_millis(100));
+        assert_eq!(report.p99_frame_time, Duration::from_millis(99));
+        assert_eq!(report.average_frame_time, Duration::from_micros(50_500));
+    }
+
+    #[test]
+    fn test_report_histogram_uses_configured_buckets() {
+        let frame_times: Vec<_> = [5, 9, 14, 14, 40].into_iter().map(Duration::from_millis).collect();
+
+        let report = BenchmarkReport::from_frame_times(EngineConfig::default(), &frame_times);
+        let counts: Vec<_> = report.frame_time_histogram.buckets.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(counts, vec![1, 1, 2, 0, 1]);
+
+        let buckets = [Duration::from_millis(10), Duration::from_millis(30)];
+        let report = BenchmarkReport::with_histogram_buckets(EngineConfig::default(), &frame_times, &buckets);
+        let counts: Vec<_> = report.frame_time_histogram.buckets.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(counts, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn test_empty_report_is_all_zero() {
+        let report = BenchmarkReport::from_frame_times(EngineConfig::default(), &[]);
+        assert_eq!(report.frames, 0);
+        assert_eq!(report.average_fps, 0.0);
+        assert_eq!(report.p99_frame_time, Duration::ZERO);
+    }
+}
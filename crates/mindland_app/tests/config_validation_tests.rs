@@ -0,0 +1,120 @@
+//! Tests for EngineConfig validation
+//!
+//! **Feature: config-validation, Property 1: Nonsensical Configs Are Reported**
+
+use mindland_app::{ConfigSeverity, ConfigWarning, EngineConfig, EngineError, MindLandApp, PerformanceMode};
+
+/// Problems reported for one field, or none if the config validates
+fn problems_for(config: &EngineConfig, field: &str) -> Vec<ConfigWarning> {
+    config
+        .validate()
+        .err()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|problem| problem.field == field)
+        .collect()
+}
+
+fn severity_of(config: &EngineConfig, field: &str) -> Option<ConfigSeverity> {
+    let problems = problems_for(config, field);
+    assert!(problems.len() <= 1, "{problems:?}");
+    problems.first().map(|problem| problem.severity)
+}
+
+#[cfg(test)]
+mod config_validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_valid() {
+        // **Feature: config-validation, Property 1: Nonsensical Configs Are Reported**
+
+        assert_eq!(EngineConfig::default().validate(), Ok(()));
+        assert_eq!(EngineConfig::macbook_pro_2014().validate(), Ok(()));
+        assert_eq!(EngineConfig::ultra_performance().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_zero_target_fps_is_error() {
+        // **Feature: config-validation, Property 1: Nonsensical Configs Are Reported**
+
+        let config = EngineConfig { target_fps: 0, ..EngineConfig::default() };
+        assert_eq!(severity_of(&config, "target_fps"), Some(ConfigSeverity::Error));
+    }
+
+    #[test]
+    fn test_extreme_target_fps_is_warning() {
+        let config = EngineConfig { target_fps: 5000, ..EngineConfig::default() };
+        assert_eq!(severity_of(&config, "target_fps"), Some(ConfigSeverity::Warning));
+    }
+
+    #[test]
+    fn test_zero_max_entities_is_error() {
+        let config = EngineConfig { max_entities: 0, ..EngineConfig::default() };
+        assert_eq!(severity_of(&config, "max_entities"), Some(ConfigSeverity::Error));
+    }
+
+    #[test]
+    fn test_non_positive_pool_multipliers_are_errors() {
+        let config = EngineConfig {
+            entity_pool_multiplier: 0.0,
+            transform_pool_multiplier: f32::NAN,
+            ..EngineConfig::default()
+        };
+        assert_eq!(severity_of(&config, "entity_pool_multiplier"), Some(ConfigSeverity::Error));
+        assert_eq!(severity_of(&config, "transform_pool_multiplier"), Some(ConfigSeverity::Error));
+    }
+
+    #[test]
+    fn test_zero_per_frame_pools_are_warnings() {
+        let config = EngineConfig { max_render_commands: 0, max_input_events: 0, ..EngineConfig::default() };
+        assert_eq!(severity_of(&config, "max_render_commands"), Some(ConfigSeverity::Warning));
+        assert_eq!(severity_of(&config, "max_input_events"), Some(ConfigSeverity::Warning));
+    }
+
+    #[test]
+    fn test_memory_pool_size_bounds_are_warnings() {
+        let huge = EngineConfig { memory_pool_size: 4 * 1024 * 1024 * 1024, ..EngineConfig::default() };
+        assert_eq!(severity_of(&huge, "memory_pool_size"), Some(ConfigSeverity::Warning));
+
+        let empty = EngineConfig { memory_pool_size: 0, ..EngineConfig::default() };
+        assert_eq!(severity_of(&empty, "memory_pool_size"), Some(ConfigSeverity::Warning));
+    }
+
+    #[test]
+    fn test_vsync_mode_conflicts_are_warnings() {
+        let emergency = EngineConfig {
+            performance_mode: PerformanceMode::Emergency,
+            enable_vsync: true,
+            ..EngineConfig::default()
+        };
+        assert_eq!(severity_of(&emergency, "enable_vsync"), Some(ConfigSeverity::Warning));
+
+        let ultra = EngineConfig { enable_vsync: true, ..EngineConfig::ultra_performance() };
+        assert_eq!(severity_of(&ultra, "enable_vsync"), Some(ConfigSeverity::Warning));
+
+        let emergency_no_vsync = EngineConfig { enable_vsync: false, ..emergency };
+        assert_eq!(severity_of(&emergency_no_vsync, "enable_vsync"), None);
+    }
+
+    #[test]
+    fn test_all_problems_reported_together() {
+        let config = EngineConfig { target_fps: 0, max_entities: 0, memory_pool_size: 0, ..EngineConfig::default() };
+        let problems = config.validate().unwrap_err();
+        assert_eq!(problems.len(), 3);
+        assert_eq!(problems.iter().filter(|problem| problem.is_error()).count(), 2);
+        assert!(problems[0].to_string().starts_with("error: target_fps:"));
+    }
+
+    #[test]
+    fn test_try_with_config_rejects_errors_before_touching_the_gpu() {
+        // **Feature: config-validation, Property 1: Nonsensical Configs Are Reported**
+
+        let config = EngineConfig { target_fps: 0, ..EngineConfig::default() };
+        match MindLandApp::try_with_config(config) {
+            Err(EngineError::InvalidConfig { problems }) => assert!(problems.iter().any(|p| p.field == "target_fps")),
+            Err(other) => panic!("unexpected error: {other}"),
+            Ok(_) => panic!("invalid config was accepted"),
+        }
+    }
+}
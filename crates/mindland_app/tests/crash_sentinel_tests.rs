@@ -0,0 +1,114 @@
+//! Tests for the safe-mode launch after a crash
+//!
+//! **Feature: crash-sentinel, Property 1: A Stale Launch Marker Selects The Safe Config**
+
+use bevy::app::AppExit;
+use bevy::render::settings::Backends;
+use mindland_app::{CrashSentinel, EngineConfig, HardwareTier, MindLandApp};
+use mindland_performance::{FrameLimiter, QualitySettings};
+use std::path::{Path, PathBuf};
+
+fn marker_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("mindland-launching-{}-{}", name, std::process::id()))
+}
+
+fn high_tier_config(marker: &Path) -> EngineConfig {
+    EngineConfig {
+        hardware_tier: HardwareTier::High,
+        world_seed: 42,
+        crash_sentinel: Some(marker.to_path_buf()),
+        ..EngineConfig::ultra_performance()
+    }
+}
+
+#[cfg(test)]
+mod crash_sentinel_tests {
+    use super::*;
+
+    #[test]
+    fn test_stale_marker_selects_safe_config() {
+        // **Feature: crash-sentinel, Property 1: A Stale Launch Marker Selects The Safe Config**
+
+        let marker = marker_path("stale");
+        std::fs::write(&marker, "launching\n").unwrap(); // Left behind by a crashed session
+
+        let mut app = MindLandApp::headless(high_tier_config(&marker));
+        let world = &app.app_mut().world;
+        let config = world.resource::<EngineConfig>();
+
+        assert_eq!(config.hardware_tier, HardwareTier::Low);
+        assert_eq!(config.graphics_backends(), Backends::all());
+        assert_eq!(config.performance_mode, EngineConfig::safe_mode().performance_mode);
+        assert_eq!(config.max_entities, EngineConfig::safe_mode().max_entities);
+        assert_eq!(config.world_seed, 42, "the world seed carries over");
+        assert!(world.resource::<CrashSentinel>().previous_launch_crashed);
+
+        // Minimal quality, and capped even though vsync is off
+        assert_eq!(*world.resource::<QualitySettings>(), QualitySettings::safe_mode_preset());
+        let safe_fps = EngineConfig::safe_mode().target_fps as f32;
+        assert_eq!(world.resource::<FrameLimiter>().effective_cap(), Some(safe_fps));
+
+        let _ = std::fs::remove_file(&marker);
+    }
+
+    #[test]
+    fn test_clean_launch_keeps_config_and_writes_marker() {
+        let marker = marker_path("clean");
+        let _ = std::fs::remove_file(&marker);
+
+        let mut app = MindLandApp::headless(high_tier_config(&marker));
+
+        assert!(marker.exists(), "marker written at startup");
+        let world = &app.app_mut().world;
+        assert_eq!(world.resource::<EngineConfig>().hardware_tier, HardwareTier::High);
+        assert!(!world.resource::<CrashSentinel>().previous_launch_crashed);
+        assert!(world.get_resource::<FrameLimiter>().is_none(), "normal launches stay uncapped");
+
+        let _ = std::fs::remove_file(&marker);
+    }
+
+    #[test]
+    fn test_clean_shutdown_clears_marker() {
+        let marker = marker_path("shutdown");
+        let _ = std::fs::remove_file(&marker);
+
+        let mut app = MindLandApp::headless(high_tier_config(&marker));
+        app.app_mut().world.send_event(AppExit);
+        app.app_mut().update();
+
+        assert!(!marker.exists());
+        let mut next = MindLandApp::headless(high_tier_config(&marker));
+        assert!(!next.app_mut().world.resource::<CrashSentinel>().previous_launch_crashed);
+
+        let _ = std::fs::remove_file(&marker);
+    }
+
+    #[test]
+    fn test_failed_startup_leaves_no_marker() {
+        // **Feature: crash-sentinel, Property 1: A Stale Launch Marker Selects The Safe Config**
+        // A launch rejected before the engine starts is not a crash
+
+        let marker = marker_path("rejected");
+        let _ = std::fs::remove_file(&marker);
+
+        let invalid = EngineConfig { target_fps: 0, ..high_tier_config(&marker) };
+        assert!(MindLandApp::try_with_config(invalid).is_err());
+        assert!(!marker.exists(), "no marker after a rejected launch");
+
+        let mut next = MindLandApp::headless(high_tier_config(&marker));
+        assert!(!next.app_mut().world.resource::<CrashSentinel>().previous_launch_crashed);
+
+        let _ = std::fs::remove_file(&marker);
+    }
+
+    #[test]
+    fn test_safe_mode_config_is_valid() {
+        assert_eq!(EngineConfig::safe_mode().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_no_marker_without_opt_in() {
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        assert!(app.app_mut().world.get_resource::<CrashSentinel>().is_none());
+    }
+}
@@ -0,0 +1,122 @@
+//! Tests for the debug console command parser and dispatch
+//!
+//! **Feature: debug-console, Property 1: Well-Formed Lines Parse, Malformed Lines Are Rejected**
+
+use bevy::prelude::*;
+use mindland_app::{
+    run_console_command, ConsoleCommand, ConsoleError, DebugConsole, EngineConfig, MindLandApp, PerformanceMode,
+};
+use mindland_performance::QualitySettings;
+use mindland_render::UltraRenderer;
+
+fn command(name: &str, args: &[&str]) -> ConsoleCommand {
+    ConsoleCommand {
+        name: name.to_string(),
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+    }
+}
+
+fn console_app() -> MindLandApp {
+    let mut app = MindLandApp::headless(EngineConfig::default());
+    app.app_mut().insert_resource(DebugConsole::new());
+    app.app_mut().insert_resource(QualitySettings::macbook_pro_2014_preset());
+    app
+}
+
+#[cfg(test)]
+mod debug_console_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_valid_lines() {
+        // **Feature: debug-console, Property 1: Well-Formed Lines Parse, Malformed Lines Are Rejected**
+
+        assert_eq!(ConsoleCommand::parse("gc"), Ok(command("gc", &[])));
+        assert_eq!(ConsoleCommand::parse("set render_distance 200"), Ok(command("set", &["render_distance", "200"])));
+        assert_eq!(ConsoleCommand::parse("  SET   performance_mode\tultra  "), Ok(command("set", &["performance_mode", "ultra"])));
+        assert_eq!(ConsoleCommand::parse(r#"say "hello world" """#), Ok(command("say", &["hello world", ""])));
+    }
+
+    #[test]
+    fn test_parse_malformed_lines() {
+        // **Feature: debug-console, Property 1: Well-Formed Lines Parse, Malformed Lines Are Rejected**
+
+        assert_eq!(ConsoleCommand::parse(""), Err(ConsoleError::Empty));
+        assert_eq!(ConsoleCommand::parse("   \t "), Err(ConsoleError::Empty));
+        assert_eq!(ConsoleCommand::parse(r#"say "unterminated"#), Err(ConsoleError::UnterminatedQuote));
+    }
+
+    #[test]
+    fn test_set_dispatches_to_resources() {
+        let mut app = console_app();
+        let world = &mut app.app_mut().world;
+
+        assert!(run_console_command(world, "set render_distance 200").is_ok());
+        assert_eq!(world.resource::<QualitySettings>().render_distance, 200.0);
+
+        assert!(run_console_command(world, "set performance_mode ultra").is_ok());
+        assert_eq!(world.resource::<EngineConfig>().performance_mode, PerformanceMode::UltraPerformance);
+    }
+
+    #[test]
+    fn test_malformed_commands_leave_state_unchanged() {
+        let mut app = console_app();
+        let world = &mut app.app_mut().world;
+        let distance = world.resource::<QualitySettings>().render_distance;
+
+        assert!(matches!(run_console_command(world, "set render_distance far"), Err(ConsoleError::InvalidValue { .. })));
+        assert!(matches!(run_console_command(world, "set render_distance -5"), Err(ConsoleError::InvalidValue { .. })));
+        assert!(matches!(run_console_command(world, "set render_distance"), Err(ConsoleError::Usage(_))));
+        assert!(matches!(run_console_command(world, "set gravity 3"), Err(ConsoleError::Usage(_))));
+        assert!(matches!(run_console_command(world, "set performance_mode turbo"), Err(ConsoleError::InvalidValue { .. })));
+        assert_eq!(run_console_command(world, "teleport 0 0"), Err(ConsoleError::UnknownCommand("teleport".to_string())));
+        assert_eq!(run_console_command(world, "gc"), Err(ConsoleError::MissingResource("SharedAssetManager")));
+
+        assert_eq!(world.resource::<QualitySettings>().render_distance, distance);
+        assert_eq!(world.resource::<EngineConfig>().performance_mode, EngineConfig::default().performance_mode);
+
+        let output: Vec<&str> = world.resource::<DebugConsole>().output().collect();
+        assert!(output.contains(&"> set render_distance far"));
+        assert!(output.iter().any(|line| line.starts_with("error: ")));
+    }
+
+    #[test]
+    fn test_registered_commands_are_dispatched() {
+        let mut app = console_app();
+        let world = &mut app.app_mut().world;
+
+        world.resource_mut::<DebugConsole>().register("spawn", "spawn <count>", |world, args| {
+            let count: usize = args.first().and_then(|arg| arg.parse().ok()).ok_or(ConsoleError::Usage("spawn <count>"))?;
+            world.spawn_batch((0..count).map(|_| Transform::default()));
+            Ok(format!("spawned {count}"))
+        });
+        assert!(world.resource::<DebugConsole>().has_command("SPAWN"));
+
+        let before = world.entities().len();
+        assert_eq!(run_console_command(world, "spawn 3"), Ok("spawned 3".to_string()));
+        assert_eq!(world.entities().len(), before + 3);
+
+        let help = run_console_command(world, "help").unwrap();
+        assert!(help.contains("spawn <count>") && help.contains("gc"));
+        assert!(run_console_command(world, "stats").unwrap().contains("entities:"));
+    }
+
+    #[test]
+    fn test_open_console_draws_overlay_text() {
+        let mut app = console_app();
+        app.app_mut().insert_resource(UltraRenderer::new());
+        run_console_command(&mut app.app_mut().world, "stats").unwrap();
+
+        app.app_mut().update();
+        assert!(app.app_mut().world.resource::<UltraRenderer>().text.glyphs.is_empty(), "closed console draws nothing");
+
+        app.app_mut().world.resource_mut::<DebugConsole>().open = true;
+        app.app_mut().update();
+        let glyphs = app.app_mut().world.resource::<UltraRenderer>().text.glyphs.len();
+        assert!(glyphs > 0);
+        for _ in 0..3 {
+            app.app_mut().update();
+            assert_eq!(app.app_mut().world.resource::<UltraRenderer>().text.glyphs.len(), glyphs, "glyphs pile up across frames");
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Tests for bug-report diagnostics snapshots
+//!
+//! **Feature: diagnostics, Property 1: Snapshot Round-Trips Through JSON**
+
+use mindland_app::{EngineConfig, MindLandApp, RECENT_FRAME_CAPACITY};
+use mindland_render::RenderStats;
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use std::time::Duration;
+
+#[cfg(test)]
+mod diagnostics_tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_contains_config_frames_and_render_stats() {
+        // **Feature: diagnostics, Property 1: Snapshot Round-Trips Through JSON**
+
+        let mut app = MindLandApp::headless(EngineConfig::macbook_pro_2014());
+        app.app_mut().insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(16)));
+        app.app_mut().insert_resource(RenderStats { visible_instances: 42, ..default() });
+        for _ in 0..(RECENT_FRAME_CAPACITY + 10) {
+            app.app_mut().update();
+        }
+
+        let snapshot = app.diagnostics_snapshot();
+        assert_eq!(snapshot.config.target_fps, EngineConfig::macbook_pro_2014().target_fps);
+        assert_eq!(snapshot.recent_frames.len(), RECENT_FRAME_CAPACITY);
+        assert_eq!(snapshot.render_stats.map(|stats| stats.visible_instances), Some(42));
+        assert!(!snapshot.graphics_backend.is_empty());
+
+        let path = std::env::temp_dir().join(format!("mindland-diagnostics-{}.json", std::process::id()));
+        snapshot.save_json(&path).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(json["config"]["performance_mode"], "MacBookPro2014");
+        assert_eq!(json["recent_frames"].as_array().unwrap().len(), RECENT_FRAME_CAPACITY);
+        assert_eq!(json["render_stats"]["visible_instances"], 42);
+        assert!(json["hardware"]["cpu_model"].is_string());
+    }
+}
@@ -28,10 +28,10 @@ mod engine_initialization_performance_tests {
             use mindland_app::{MemoryPools, EntityPool, TransformPool, RenderCommandPool, InputEventPool};
             
             let mut memory_pools = MemoryPools {
-                entity_pool: EntityPool { capacity: 10000, used: 0 },
-                transform_pool: TransformPool { capacity: 10000, used: 0 },
-                render_command_pool: RenderCommandPool { capacity: 10000, used: 0 },
-                input_event_pool: InputEventPool { capacity: 10000, used: 0 },
+                entity_pool: EntityPool { capacity: 10000, used: 0, peak_used: 0 },
+                transform_pool: TransformPool { capacity: 10000, used: 0, peak_used: 0 },
+                render_command_pool: RenderCommandPool { capacity: 10000, used: 0, peak_used: 0 },
+                input_event_pool: InputEventPool { capacity: 10000, used: 0, peak_used: 0 },
             };
             
             let mut total_entities = 0;
@@ -156,10 +156,10 @@ mod engine_initialization_performance_tests {
         use mindland_app::{MemoryPools, EntityPool, TransformPool, RenderCommandPool, InputEventPool};
         
         let mut memory_pools = MemoryPools {
-            entity_pool: EntityPool { capacity: 1000, used: 0 },
-            transform_pool: TransformPool { capacity: 1000, used: 0 },
-            render_command_pool: RenderCommandPool { capacity: 1000, used: 0 },
-            input_event_pool: InputEventPool { capacity: 1000, used: 0 },
+            entity_pool: EntityPool { capacity: 1000, used: 0, peak_used: 0 },
+            transform_pool: TransformPool { capacity: 1000, used: 0, peak_used: 0 },
+            render_command_pool: RenderCommandPool { capacity: 1000, used: 0, peak_used: 0 },
+            input_event_pool: InputEventPool { capacity: 1000, used: 0, peak_used: 0 },
         };
         
         // Test entity pool allocation
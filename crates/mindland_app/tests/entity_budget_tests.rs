@@ -0,0 +1,84 @@
+//! Tests for the per-frame entity spawn/despawn budget
+//!
+//! **Feature: entity-budget, Property 1: Spawns Are Spread Across Frames Within Budget**
+
+use bevy::prelude::*;
+use mindland_app::{EngineConfig, EntityBudget, MindLandApp};
+
+/// Marks entities spawned through the budget, with the distance they were queued at
+#[derive(Component)]
+struct Spawned(f32);
+
+fn spawned_count(app: &mut MindLandApp) -> usize {
+    let world = &mut app.app_mut().world;
+    world.query::<&Spawned>().iter(world).count()
+}
+
+#[cfg(test)]
+mod entity_budget_tests {
+    use super::*;
+
+    #[test]
+    fn test_10k_spawns_take_10_frames_at_1k_budget() {
+        // **Feature: entity-budget, Property 1: Spawns Are Spread Across Frames Within Budget**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let mut budget = EntityBudget::new(1000, 1000);
+        for i in 0..10_000 {
+            budget.queue_spawn(Spawned(i as f32), i as f32);
+        }
+        app.app_mut().insert_resource(budget);
+
+        let mut frames = 0;
+        while app.app_mut().world.resource::<EntityBudget>().pending_spawns() > 0 {
+            let before = spawned_count(&mut app);
+            app.app_mut().update();
+            frames += 1;
+
+            assert!(spawned_count(&mut app) - before <= 1000, "frame {frames} exceeded the budget");
+            assert!(frames <= 20, "queue never drained");
+        }
+
+        assert_eq!(frames, 10);
+        assert_eq!(spawned_count(&mut app), 10_000);
+    }
+
+    #[test]
+    fn test_near_spawns_populate_first() {
+        // **Feature: entity-budget, Property 1: Spawns Are Spread Across Frames Within Budget**
+
+        let mut world = World::new();
+        let mut budget = EntityBudget::new(3, 1);
+        for distance in [50.0, 5.0, 120.0, 1.0, 30.0, 5.0] {
+            budget.queue_spawn(Spawned(distance), distance);
+        }
+
+        assert_eq!(budget.process(&mut world), (3, 0));
+        assert_eq!(budget.remaining_spawn_budget(), 0);
+
+        let mut distances: Vec<f32> = world.query::<&Spawned>().iter(&world).map(|spawned| spawned.0).collect();
+        distances.sort_by(f32::total_cmp);
+        assert_eq!(distances, vec![1.0, 5.0, 5.0]);
+        assert_eq!(budget.pending_spawns(), 3);
+    }
+
+    #[test]
+    fn test_remaining_budget_and_despawns() {
+        let mut world = World::new();
+        let mut budget = EntityBudget::new(10, 2);
+        budget.queue_spawn(Spawned(0.0), 0.0);
+        assert_eq!(budget.process(&mut world), (1, 0));
+        assert_eq!(budget.remaining_spawn_budget(), 9);
+
+        let entities: Vec<Entity> = (0..3).map(|_| world.spawn(Spawned(0.0)).id()).collect();
+        for &entity in &entities {
+            budget.queue_despawn(entity);
+        }
+        assert_eq!(budget.process(&mut world), (0, 2));
+        assert_eq!(budget.pending_despawns(), 1);
+        assert_eq!(budget.remaining_spawn_budget(), 10);
+
+        assert_eq!(budget.process(&mut world), (0, 1));
+        assert!(entities.iter().all(|&entity| world.get_entity(entity).is_none()));
+    }
+}
@@ -0,0 +1,93 @@
+//! Tests for hardware tier and performance mode overrides
+//!
+//! **Feature: env-overrides, Property 1: Valid Overrides Win, Invalid Ones Fall Back**
+
+use mindland_app::{EngineConfig, HardwareTier, PerformanceMode, HARDWARE_TIER_ENV, PERFORMANCE_MODE_ENV};
+use std::collections::HashMap;
+
+/// Lookup backed by a map instead of the process environment (tests run in parallel)
+fn vars(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let map: HashMap<String, String> = pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |name| map.get(name).cloned()
+}
+
+#[cfg(test)]
+mod env_override_tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_overrides_replace_detected_values() {
+        // **Feature: env-overrides, Property 1: Valid Overrides Win, Invalid Ones Fall Back**
+
+        let config = EngineConfig::default()
+            .with_overrides(vars(&[(HARDWARE_TIER_ENV, "ultra"), (PERFORMANCE_MODE_ENV, "MacBook")]));
+        assert_eq!(config.hardware_tier, HardwareTier::UltraHigh);
+        assert_eq!(config.performance_mode, PerformanceMode::MacBookPro2014);
+        assert_eq!(config.target_fps, EngineConfig::default().target_fps); // Other fields untouched
+    }
+
+    #[test]
+    fn test_mode_override_applies_the_mode_preset() {
+        // **Feature: env-overrides, Property 1: Valid Overrides Win, Invalid Ones Fall Back**
+        // Forcing a mode must not leave the detected preset's frame target and vsync behind
+
+        let detected = EngineConfig { world_seed: 7, ..EngineConfig::macbook_pro_2014() };
+        let config = detected.with_overrides(vars(&[(PERFORMANCE_MODE_ENV, "ultra")]));
+        let ultra = EngineConfig::ultra_performance();
+        assert_eq!(config.performance_mode, PerformanceMode::UltraPerformance);
+        assert_eq!(config.target_fps, ultra.target_fps);
+        assert_eq!(config.enable_vsync, ultra.enable_vsync);
+        assert_eq!(config.max_entities, ultra.max_entities);
+        assert_eq!(config.memory_pool_size, ultra.memory_pool_size);
+        assert_eq!(config.hardware_tier, HardwareTier::Medium, "the detected tier is kept");
+        assert_eq!(config.world_seed, 7);
+        assert!(config.validate().is_ok());
+
+        let emergency = EngineConfig::ultra_performance().with_overrides(vars(&[(PERFORMANCE_MODE_ENV, "emergency")]));
+        assert_eq!(emergency.target_fps, EngineConfig::safe_mode().target_fps);
+        assert!(!emergency.enable_vsync);
+    }
+
+    #[test]
+    fn test_invalid_overrides_fall_back_without_panicking() {
+        // **Feature: env-overrides, Property 1: Valid Overrides Win, Invalid Ones Fall Back**
+
+        let detected = EngineConfig::macbook_pro_2014();
+        let config = detected
+            .clone()
+            .with_overrides(vars(&[(HARDWARE_TIER_ENV, "potato"), (PERFORMANCE_MODE_ENV, "")]));
+        assert_eq!(config.hardware_tier, detected.hardware_tier);
+        assert_eq!(config.performance_mode, detected.performance_mode);
+    }
+
+    #[test]
+    fn test_unset_variables_keep_detection() {
+        let config = EngineConfig::ultra_performance().with_overrides(vars(&[]));
+        assert_eq!(config.hardware_tier, HardwareTier::High);
+        assert_eq!(config.performance_mode, PerformanceMode::UltraPerformance);
+    }
+
+    #[test]
+    fn test_parsing_accepts_every_documented_value() {
+        for (value, tier) in [
+            ("low", HardwareTier::Low),
+            ("Medium", HardwareTier::Medium),
+            (" HIGH ", HardwareTier::High),
+            ("ultra", HardwareTier::UltraHigh),
+        ] {
+            assert_eq!(value.parse::<HardwareTier>(), Ok(tier));
+        }
+        for (value, mode) in [
+            ("ultra", PerformanceMode::UltraPerformance),
+            ("balanced", PerformanceMode::Balanced),
+            ("quality", PerformanceMode::Quality),
+            ("macbook-pro-2014", PerformanceMode::MacBookPro2014),
+            ("emergency", PerformanceMode::Emergency),
+        ] {
+            assert_eq!(value.parse::<PerformanceMode>(), Ok(mode));
+        }
+
+        let error = "fast".parse::<PerformanceMode>().unwrap_err();
+        assert!(error.to_string().contains("\"fast\""));
+    }
+}
@@ -0,0 +1,28 @@
+//! Tests for per-frame gizmo clearing in the running app
+//!
+//! **Feature: debug-gizmos, Property 1: Gizmo Lines Last One Frame**
+
+use bevy::prelude::*;
+use mindland_app::{EngineConfig, MindLandApp};
+use mindland_render::UltraRenderer;
+
+#[cfg(test)]
+mod gizmo_frame_tests {
+    use super::*;
+
+    #[test]
+    fn test_gizmo_lines_do_not_pile_up_across_frames() {
+        // **Feature: debug-gizmos, Property 1: Gizmo Lines Last One Frame**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(UltraRenderer::new());
+        app.app_mut().add_systems(Update, |mut renderer: ResMut<UltraRenderer>| {
+            renderer.gizmos.draw_line(Vec3::ZERO, Vec3::X, Color::RED, 2.0);
+        });
+
+        for _ in 0..3 {
+            app.app_mut().update();
+            assert_eq!(app.app_mut().world.resource::<UltraRenderer>().gizmos.lines.len(), 1);
+        }
+    }
+}
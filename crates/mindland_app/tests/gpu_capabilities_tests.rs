@@ -0,0 +1,96 @@
+//! Tests for adapter feature probing
+//!
+//! **Feature: gpu-capabilities, Property 1: Unsupported Features Disable Their Render Paths**
+
+use bevy::render::render_resource::{TextureFormat, TextureUsages};
+use mindland_app::{EngineConfig, MindLandApp};
+use mindland_render::{GpuCapabilities, RenderFeatures, UltraRenderer};
+use wgpu::{DownlevelCapabilities, DownlevelFlags, Features, Limits, TextureFormatFeatureFlags as Flags, TextureFormatFeatures};
+
+/// A desktop-class adapter: compute, indirect, BC, HDR and up to 8x MSAA
+fn desktop_capabilities() -> GpuCapabilities {
+    let downlevel = DownlevelCapabilities {
+        flags: DownlevelFlags::COMPUTE_SHADERS | DownlevelFlags::INDIRECT_EXECUTION,
+        ..Default::default()
+    };
+    GpuCapabilities::from_adapter(
+        "Desktop GPU",
+        Features::TIMESTAMP_QUERY | Features::TEXTURE_COMPRESSION_BC,
+        &Limits::default(),
+        &downlevel,
+        |_: TextureFormat| TextureFormatFeatures {
+            allowed_usages: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+            flags: Flags::BLENDABLE | Flags::MULTISAMPLE_X2 | Flags::MULTISAMPLE_X4 | Flags::MULTISAMPLE_X8,
+        },
+    )
+}
+
+#[cfg(test)]
+mod gpu_capabilities_tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_populated_after_init() {
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().update();
+
+        // No adapter in a headless app: the conservative baseline is reported
+        let capabilities = app.gpu_capabilities();
+        assert_eq!(capabilities, GpuCapabilities::default());
+        assert!(!capabilities.compute_shaders);
+        assert!(capabilities.max_texture_size >= 2048);
+        assert_eq!(capabilities.msaa_sample_counts, vec![1]);
+
+        app.app_mut().world.insert_resource(desktop_capabilities());
+        assert_eq!(app.gpu_capabilities().adapter_name, "Desktop GPU");
+    }
+
+    #[test]
+    fn test_report_reflects_adapter_features() {
+        let capabilities = desktop_capabilities();
+
+        assert!(capabilities.compute_shaders);
+        assert!(capabilities.timestamp_queries);
+        assert!(capabilities.indirect_draw);
+        assert!(capabilities.bc_compression);
+        assert!(!capabilities.etc2_compression && !capabilities.astc_compression);
+        assert!(capabilities.hdr_render_target);
+        assert_eq!(capabilities.max_texture_size, Limits::default().max_texture_dimension_2d);
+        assert_eq!(capabilities.msaa_sample_counts, vec![1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn test_unsupported_features_disable_render_paths() {
+        // **Feature: gpu-capabilities, Property 1: Unsupported Features Disable Their Render Paths**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().init_resource::<UltraRenderer>();
+        app.app_mut().update();
+        let renderer = app.app_mut().world.resource::<UltraRenderer>();
+        assert_eq!(renderer.active_features(), RenderFeatures { gpu_culling: false, gpu_particles: false, indirect_draw: false, hdr: false });
+
+        app.app_mut().world.insert_resource(desktop_capabilities());
+        app.app_mut().update();
+        let renderer = app.app_mut().world.resource::<UltraRenderer>();
+        assert_eq!(renderer.active_features(), RenderFeatures::default());
+
+        // An adapter without compute (e.g. a WebGL2-class GPU) loses the compute paths only
+        let no_compute = GpuCapabilities { compute_shaders: false, ..desktop_capabilities() };
+        app.app_mut().world.insert_resource(no_compute);
+        app.app_mut().update();
+        let active = app.app_mut().world.resource::<UltraRenderer>().active_features();
+        assert!(!active.gpu_culling && !active.gpu_particles);
+        assert!(active.indirect_draw && active.hdr);
+    }
+
+    #[test]
+    fn test_paths_turned_off_by_request_stay_off() {
+        let mut renderer = UltraRenderer::new();
+        renderer.features.hdr = false;
+
+        let active = renderer.apply_capabilities(&desktop_capabilities());
+
+        assert!(!active.hdr);
+        assert!(active.gpu_culling && active.indirect_draw);
+    }
+}
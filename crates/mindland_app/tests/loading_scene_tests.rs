@@ -0,0 +1,63 @@
+//! Tests for the loading scene to gameplay transition
+//!
+//! **Feature: loading-scene, Property 1: Gameplay Starts Only After Preloading**
+
+use mindland_app::{EngineConfig, EngineState, MindLandApp, PreloadProgress};
+use bevy::prelude::*;
+
+/// Progress observed when the main state was entered
+#[derive(Resource, Default)]
+struct EnteredAtProgress(Option<f32>);
+
+/// Stand-in asset preloading: a quarter of the work per frame
+fn fake_preload(mut progress: ResMut<PreloadProgress>) {
+    progress.0 = (progress.0 + 0.25).min(1.0);
+}
+
+fn record_enter(progress: Res<PreloadProgress>, mut entered: ResMut<EnteredAtProgress>) {
+    entered.0 = Some(progress.0);
+}
+
+fn engine_state(app: &mut MindLandApp) -> EngineState {
+    *app.app_mut().world.resource::<State<EngineState>>().get()
+}
+
+#[cfg(test)]
+mod loading_scene_tests {
+    use super::*;
+
+    #[test]
+    fn test_main_state_entered_only_after_preload_completes() {
+        // **Feature: loading-scene, Property 1: Gameplay Starts Only After Preloading**
+
+        let mut app = MindLandApp::headless(EngineConfig::default()).with_loading_scene(|app| {
+            app.init_resource::<EnteredAtProgress>();
+            app.add_systems(Update, fake_preload.run_if(in_state(EngineState::Loading)));
+            app.add_systems(OnEnter(EngineState::Running), record_enter);
+        });
+
+        for _ in 0..3 {
+            app.app_mut().update();
+            assert_eq!(engine_state(&mut app), EngineState::Loading);
+            assert!(app.app_mut().world.resource::<PreloadProgress>().0 < 1.0);
+        }
+
+        for _ in 0..3 {
+            app.app_mut().update();
+        }
+
+        assert_eq!(engine_state(&mut app), EngineState::Running);
+        assert_eq!(app.app_mut().world.resource::<EnteredAtProgress>().0, Some(1.0));
+    }
+
+    #[test]
+    fn test_without_loading_scene_enters_main_state_immediately() {
+        // **Feature: loading-scene, Property 1: Gameplay Starts Only After Preloading**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().update();
+        app.app_mut().update();
+
+        assert_eq!(engine_state(&mut app), EngineState::Running);
+    }
+}
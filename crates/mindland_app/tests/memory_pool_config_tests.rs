@@ -0,0 +1,55 @@
+//! Tests for configurable memory pool sizing
+//!
+//! **Feature: engine-boot, Property 4: Configurable Pool Sizing**
+
+use mindland_app::{EngineConfig, HardwareTier, MemoryPools, MindLandApp};
+
+#[cfg(test)]
+mod memory_pool_config_tests {
+    use super::*;
+
+    #[test]
+    fn test_headless_app_uses_configured_pool_sizes() {
+        // **Feature: engine-boot, Property 4: Configurable Pool Sizing**
+
+        let config = EngineConfig {
+            max_entities: 500,
+            max_render_commands: 64,
+            max_input_events: 16,
+            entity_pool_multiplier: 2.0,
+            transform_pool_multiplier: 0.5,
+            ..Default::default()
+        };
+
+        let mut app = MindLandApp::headless(config);
+        let pools = app.app_mut().world.resource::<MemoryPools>();
+
+        assert_eq!(pools.entity_pool.capacity, 1000);
+        assert_eq!(pools.transform_pool.capacity, 250);
+        assert_eq!(pools.render_command_pool.capacity, 64);
+        assert_eq!(pools.input_event_pool.capacity, 16);
+    }
+
+    #[test]
+    fn test_low_tier_scales_down_per_frame_pools() {
+        let medium = MemoryPools::from_config(&EngineConfig::default());
+        let low = MemoryPools::from_config(&EngineConfig {
+            hardware_tier: HardwareTier::Low,
+            ..Default::default()
+        });
+
+        assert!(low.render_command_pool.capacity < medium.render_command_pool.capacity);
+        assert!(low.input_event_pool.capacity < medium.input_event_pool.capacity);
+        assert_eq!(low.entity_pool.capacity, medium.entity_pool.capacity);
+    }
+
+    #[test]
+    fn test_default_config_matches_previous_pool_sizes() {
+        let pools = MemoryPools::from_config(&EngineConfig::default());
+
+        assert_eq!(pools.entity_pool.capacity, 100_000);
+        assert_eq!(pools.transform_pool.capacity, 100_000);
+        assert_eq!(pools.render_command_pool.capacity, 10_000);
+        assert_eq!(pools.input_event_pool.capacity, 1_000);
+    }
+}
@@ -0,0 +1,100 @@
+//! Tests for extra tool windows
+//!
+//! **Feature: multi-window, Property 1: Each Window Gets Its Own Surface And Camera**
+
+use bevy::{
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, WindowResized},
+};
+use mindland_app::{EngineConfig, MindLandApp, SurfaceState};
+use mindland_camera::CameraController;
+use mindland_render::UltraRenderer;
+use mindland_window::DisplaySettings;
+
+fn settings(width: u32, height: u32, vsync: bool) -> DisplaySettings {
+    DisplaySettings { resolution: (width, height), vsync, ..Default::default() }
+}
+
+#[cfg(test)]
+mod multi_window_tests {
+    use super::*;
+
+    #[test]
+    fn test_two_windows_get_distinct_surfaces() {
+        // **Feature: multi-window, Property 1: Each Window Gets Its Own Surface And Camera**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let game = app.add_window(settings(1920, 1080, true));
+        let inspector = app.add_window(settings(800, 600, false));
+        assert_ne!(game, inspector);
+        app.app_mut().update();
+
+        let renderer = app.app_mut().world.resource::<UltraRenderer>();
+        let game_surface = renderer.surface(game).expect("game window surface");
+        let inspector_surface = renderer.surface(inspector).expect("inspector window surface");
+        assert_eq!(game_surface.size, UVec2::new(1920, 1080));
+        assert_eq!(game_surface.present_mode, PresentMode::AutoVsync);
+        assert_eq!(inspector_surface.size, UVec2::new(800, 600));
+        assert_eq!(inspector_surface.present_mode, PresentMode::AutoNoVsync);
+
+        // Resizing one window reconfigures only its own surface
+        app.app_mut().world.get_mut::<Window>(inspector).unwrap().resolution.set(640.0, 480.0);
+        app.app_mut().update();
+        let renderer = app.app_mut().world.resource::<UltraRenderer>();
+        assert_eq!(renderer.surface(inspector).unwrap().size, UVec2::new(640, 480));
+        assert_eq!(renderer.surface(game).unwrap().size, UVec2::new(1920, 1080));
+
+        app.app_mut().world.despawn(inspector);
+        app.app_mut().update();
+        assert!(app.app_mut().world.resource::<UltraRenderer>().surface(inspector).is_none());
+    }
+
+    #[test]
+    fn test_each_window_draws_its_own_camera() {
+        // **Feature: multi-window, Property 1: Each Window Gets Its Own Surface And Camera**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let game = app.add_window(settings(1920, 1080, true));
+        let inspector = app.add_window(settings(800, 600, true));
+
+        let mut overhead = CameraController::new();
+        overhead.transform.translation = Vec3::new(0.0, 100.0, 0.0);
+        let game_camera = app.app_mut().world.spawn(CameraController::new()).id();
+        let overhead_camera = app.app_mut().world.spawn(overhead).id();
+        app.set_window_camera(game, game_camera);
+        app.set_window_camera(inspector, overhead_camera);
+        app.app_mut().update();
+
+        let renderer = app.app_mut().world.resource::<UltraRenderer>();
+        let pass = |window| renderer.viewport_passes.iter().find(|pass| pass.window == Some(window)).unwrap();
+        assert_eq!(pass(game).viewport.physical_size, UVec2::new(1920, 1080));
+        assert_eq!(pass(inspector).viewport.physical_size, UVec2::new(800, 600));
+        assert_eq!(pass(inspector).camera_position, Vec3::new(0.0, 100.0, 0.0));
+
+        app.app_mut().update();
+        assert_eq!(app.app_mut().world.resource::<UltraRenderer>().viewport_passes.len(), 2, "passes shouldn't pile up");
+    }
+
+    #[test]
+    fn test_secondary_resize_leaves_primary_camera_and_surface() {
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let primary = app.app_mut().world.spawn((Window::default(), PrimaryWindow)).id();
+        let inspector = app.add_window(settings(800, 600, true));
+        let primary_camera = app.app_mut().world.spawn(CameraController::new()).id();
+        let inspector_camera = app.app_mut().world.spawn(CameraController::new()).id();
+        app.set_window_camera(inspector, inspector_camera);
+
+        app.app_mut().world.send_event(WindowResized { window: primary, width: 1600.0, height: 900.0 });
+        app.app_mut().update();
+        let aspect = |app: &mut MindLandApp, camera| app.app_mut().world.get::<CameraController>(camera).unwrap().projection.aspect_ratio;
+        assert!((aspect(&mut app, primary_camera) - 16.0 / 9.0).abs() < 1e-6);
+        assert!((aspect(&mut app, inspector_camera) - 4.0 / 3.0).abs() < 1e-6, "window cameras follow their own window");
+
+        app.app_mut().world.get_mut::<Window>(inspector).unwrap().resolution.set(500.0, 500.0);
+        app.app_mut().world.send_event(WindowResized { window: inspector, width: 500.0, height: 500.0 });
+        app.app_mut().update();
+        assert!((aspect(&mut app, primary_camera) - 16.0 / 9.0).abs() < 1e-6);
+        assert_eq!(aspect(&mut app, inspector_camera), 1.0);
+        assert_eq!(app.app_mut().world.resource::<SurfaceState>().size, Vec2::new(1600.0, 900.0));
+    }
+}
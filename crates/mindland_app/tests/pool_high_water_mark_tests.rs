@@ -0,0 +1,56 @@
+//! Tests for memory pool high-water-mark tracking
+//!
+//! **Feature: pool-high-water-marks, Property 1: Peak Reflects The Maximum, Not The Last, Usage**
+
+use bevy::app::AppExit;
+use mindland_app::{EngineConfig, MemoryPools, MindLandApp, PoolHighWaterMark};
+
+#[cfg(test)]
+mod pool_high_water_mark_tests {
+    use super::*;
+
+    #[test]
+    fn test_peak_tracks_maximum_across_frames() {
+        // **Feature: pool-high-water-marks, Property 1: Peak Reflects The Maximum, Not The Last, Usage**
+
+        let mut pools = MemoryPools::from_config(&EngineConfig::default());
+
+        for (entities, commands) in [(1_200, 300), (30_000, 4_500), (800, 9_000), (5, 10)] {
+            pools.entity_pool.reset();
+            pools.render_command_pool.reset();
+            assert!(pools.entity_pool.allocate(entities).is_some());
+            assert!(pools.render_command_pool.allocate(commands).is_some());
+        }
+
+        let marks = pools.high_water_marks();
+        assert_eq!(marks.entity_pool, PoolHighWaterMark { peak_used: 30_000, capacity: 100_000 });
+        assert_eq!(marks.render_command_pool.peak_used, 9_000);
+        assert_eq!(pools.entity_pool.used, 5, "current usage is the last frame's");
+        assert_eq!(marks.transform_pool.peak_used, 0);
+        assert_eq!(marks.input_event_pool.peak_used, 0);
+    }
+
+    #[test]
+    fn test_peak_accumulates_within_a_frame_and_ignores_failed_allocations() {
+        // **Feature: pool-high-water-marks, Property 1: Peak Reflects The Maximum, Not The Last, Usage**
+
+        let mut pools = MemoryPools::from_config(&EngineConfig { max_input_events: 100, ..Default::default() });
+
+        pools.input_event_pool.allocate(40);
+        pools.input_event_pool.allocate(35);
+        assert!(pools.input_event_pool.allocate(50).is_none());
+
+        assert_eq!(pools.high_water_marks().input_event_pool.peak_used, 75);
+    }
+
+    #[test]
+    fn test_peaks_survive_exit_report() {
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().world.resource_mut::<MemoryPools>().transform_pool.allocate(64);
+        app.app_mut().world.send_event(AppExit);
+        app.app_mut().update();
+
+        let marks = app.app_mut().world.resource::<MemoryPools>().high_water_marks();
+        assert_eq!(marks.transform_pool.peak_used, 64);
+    }
+}
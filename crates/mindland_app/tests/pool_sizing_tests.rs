@@ -0,0 +1,79 @@
+//! Tests for suggesting and persisting memory pool sizes
+//!
+//! **Feature: pool-sizing, Property 1: Suggestions Cover The Session Peak Plus Headroom**
+
+use bevy::app::AppExit;
+use mindland_app::{EngineConfig, MemoryPools, MindLandApp, PoolCapacities};
+use std::path::PathBuf;
+
+fn cache_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("mindland-pool-sizes-{}-{}.json", name, std::process::id()))
+}
+
+#[cfg(test)]
+mod pool_sizing_tests {
+    use super::*;
+
+    #[test]
+    fn test_peak_of_30k_entities_suggests_33k() {
+        // **Feature: pool-sizing, Property 1: Suggestions Cover The Session Peak Plus Headroom**
+
+        let mut pools = MemoryPools::from_config(&EngineConfig::default());
+        for entities in [12_000, 30_000, 18_000] {
+            pools.entity_pool.reset();
+            pools.entity_pool.allocate(entities);
+        }
+        pools.render_command_pool.allocate(2_000);
+
+        let suggested = pools.suggest_capacities(0.1);
+
+        assert_eq!(suggested.entity_pool, 33_000);
+        assert_eq!(suggested.render_command_pool, 2_200);
+        assert_eq!(suggested.transform_pool, 100_000, "an unused pool keeps its size");
+    }
+
+    #[test]
+    fn test_exhausted_pool_grows() {
+        // **Feature: pool-sizing, Property 1: Suggestions Cover The Session Peak Plus Headroom**
+
+        let mut pools = MemoryPools::from_config(&EngineConfig { max_input_events: 100, ..Default::default() });
+        pools.input_event_pool.allocate(100);
+        assert!(pools.input_event_pool.allocate(1).is_none());
+
+        assert_eq!(pools.suggest_capacities(0.1).input_event_pool, 200);
+    }
+
+    #[test]
+    fn test_cached_sizes_persist_to_next_launch() {
+        let path = cache_path("persist");
+        let _ = std::fs::remove_file(&path);
+        let config = EngineConfig { pool_size_cache: Some(path.clone()), ..Default::default() };
+
+        // First session: peaks at 30k entities, saves suggestions on exit
+        let mut first = MindLandApp::headless(config.clone());
+        first.app_mut().world.resource_mut::<MemoryPools>().entity_pool.allocate(30_000);
+        first.app_mut().world.send_event(AppExit);
+        first.app_mut().update();
+        let saved = PoolCapacities::load_json(&path).expect("suggestions written on exit");
+        assert_eq!(saved.entity_pool, 33_000);
+
+        // Next launch starts right-sized
+        let mut second = MindLandApp::headless(config);
+        let pools = second.app_mut().world.resource::<MemoryPools>();
+        assert_eq!(pools.entity_pool.capacity, 33_000);
+        assert_eq!(pools.entity_pool.peak_used, 0);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_no_cache_without_opt_in() {
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().world.resource_mut::<MemoryPools>().entity_pool.allocate(10);
+        app.app_mut().world.send_event(AppExit);
+        app.app_mut().update();
+
+        assert_eq!(app.app_mut().world.resource::<MemoryPools>().entity_pool.capacity, 100_000);
+        assert!(PoolCapacities::load_json(cache_path("missing")).is_none());
+    }
+}
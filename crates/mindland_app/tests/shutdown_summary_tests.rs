@@ -0,0 +1,93 @@
+//! Tests for shutdown hooks and the session performance summary
+//!
+//! **Feature: shutdown-summary, Property 1: Exiting Produces A Summary Of The Whole Session**
+
+use bevy::app::AppExit;
+use mindland_app::{EngineConfig, MemoryPools, MindLandApp, SessionStats, SessionSummary};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Headless app with a session of 980 frames at ~16.7ms and 20 hitches of 50ms
+fn played_session(config: EngineConfig) -> MindLandApp {
+    let mut app = MindLandApp::headless(config);
+    let world = &mut app.app_mut().world;
+    let mut stats = world.resource_mut::<SessionStats>();
+    for frame in 0..1000 {
+        let frame_time = if frame % 50 == 0 { Duration::from_millis(50) } else { Duration::from_micros(16_650) };
+        stats.record_frame(frame_time, 60.0 + (frame % 7) as f32);
+    }
+    world.resource_mut::<MemoryPools>().entity_pool.allocate(4_000);
+    app
+}
+
+#[cfg(test)]
+mod shutdown_summary_tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_produces_session_summary() {
+        // **Feature: shutdown-summary, Property 1: Exiting Produces A Summary Of The Whole Session**
+
+        let mut app = played_session(EngineConfig::default());
+        app.app_mut().update();
+        assert!(app.app_mut().world.get_resource::<SessionSummary>().is_none(), "no summary before exit");
+
+        app.app_mut().world.send_event(AppExit);
+        app.app_mut().update();
+
+        let summary = app.app_mut().world.resource::<SessionSummary>();
+        assert!(summary.frames >= 1000);
+        assert!((summary.average_fps - 57.7).abs() < 1.0, "average {}", summary.average_fps);
+        assert!((summary.one_percent_low_fps - 20.0).abs() < 0.1, "1% low {}", summary.one_percent_low_fps);
+        assert!((summary.point_one_percent_low_fps - 20.0).abs() < 0.1);
+        assert_eq!(summary.peak_temperature, Some(66.0));
+        assert_eq!(summary.pool_high_water_marks.unwrap().entity_pool.peak_used, 4_000);
+        #[cfg(target_os = "linux")]
+        assert!(summary.peak_memory_bytes.is_some_and(|bytes| bytes > 0));
+    }
+
+    #[test]
+    fn test_summary_can_be_turned_off() {
+        // **Feature: shutdown-summary, Property 1: Exiting Produces A Summary Of The Whole Session**
+
+        let mut app = played_session(EngineConfig { log_shutdown_summary: false, ..Default::default() });
+        app.app_mut().world.send_event(AppExit);
+        app.app_mut().update();
+
+        assert!(app.app_mut().world.get_resource::<SessionSummary>().is_none());
+    }
+
+    #[test]
+    fn test_on_shutdown_hooks_run_once() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let hook_calls = calls.clone();
+        let mut app = MindLandApp::headless(EngineConfig::default()).on_shutdown(move |world| {
+            assert!(world.contains_resource::<SessionStats>());
+            hook_calls.fetch_add(1, Ordering::SeqCst);
+        });
+
+        app.app_mut().update();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        app.app_mut().world.send_event(AppExit);
+        app.app_mut().update();
+        app.app_mut().update(); // The event is still buffered this frame
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_low_fps_from_bucketed_frame_times() {
+        let mut stats = SessionStats::default();
+        assert_eq!(stats.low_fps(0.01), 0.0);
+
+        for _ in 0..99 {
+            stats.record_frame(Duration::from_millis(10), f32::NAN);
+        }
+        stats.record_frame(Duration::from_millis(250), f32::NAN); // Beyond the bucketed range
+
+        assert!((stats.low_fps(0.01) - 4.0).abs() < 1e-3, "the worst frame sets the 1% low");
+        assert!((stats.low_fps(0.5) - 1.0 / 0.0101).abs() < 0.1);
+        assert_eq!(stats.peak_temperature, None);
+    }
+}
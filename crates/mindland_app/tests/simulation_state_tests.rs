@@ -0,0 +1,78 @@
+//! Tests for pausing gameplay simulation while rendering continues
+//!
+//! **Feature: pause-menu, Property 1: Paused Simulation Is Frozen**
+
+use mindland_app::{EngineConfig, MindLandApp, PerformanceMonitor, SimulationSet};
+use mindland_camera::CameraController;
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use std::time::Duration;
+
+/// Stand-in gameplay system: always pushes the camera forward
+fn drive_camera_forward(time: Res<Time>, mut cameras: Query<&mut CameraController>) {
+    for mut camera in &mut cameras {
+        camera.update_movement(Vec3::new(0.0, 0.0, 1.0), false, false, time.delta_seconds());
+    }
+}
+
+fn test_app() -> MindLandApp {
+    let mut app = MindLandApp::headless(EngineConfig::default());
+    let bevy_app = app.app_mut();
+    bevy_app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(16)));
+    bevy_app.add_systems(Update, drive_camera_forward.in_set(SimulationSet));
+    bevy_app.world.spawn(CameraController::new());
+    app
+}
+
+fn camera_position(app: &mut MindLandApp) -> Vec3 {
+    let world = &mut app.app_mut().world;
+    world.query::<&CameraController>().single(world).transform.translation
+}
+
+#[cfg(test)]
+mod simulation_state_tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_does_not_move_while_paused() {
+        // **Feature: pause-menu, Property 1: Paused Simulation Is Frozen**
+
+        let mut app = test_app();
+        for _ in 0..3 {
+            app.app_mut().update();
+        }
+        let before_pause = camera_position(&mut app);
+        assert_ne!(before_pause, CameraController::new().transform.translation, "camera should move while running");
+
+        app.pause();
+        assert!(app.is_paused());
+        for _ in 0..10 {
+            app.app_mut().update();
+        }
+        assert_eq!(camera_position(&mut app), before_pause);
+
+        // Performance monitoring keeps counting frames while paused
+        assert!(app.app_mut().world.resource::<PerformanceMonitor>().frame_count >= 10);
+
+        app.resume();
+        app.app_mut().update();
+        app.app_mut().update();
+        assert_ne!(camera_position(&mut app), before_pause);
+    }
+
+    #[test]
+    fn test_pause_freezes_virtual_time() {
+        let mut app = test_app();
+        app.app_mut().update();
+        app.toggle_pause();
+        app.app_mut().update();
+
+        let elapsed = app.app_mut().world.resource::<Time<Virtual>>().elapsed();
+        for _ in 0..5 {
+            app.app_mut().update();
+        }
+        assert_eq!(app.app_mut().world.resource::<Time<Virtual>>().elapsed(), elapsed);
+
+        app.toggle_pause();
+        assert!(!app.is_paused());
+    }
+}
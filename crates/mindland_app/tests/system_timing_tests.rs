@@ -0,0 +1,94 @@
+//! Tests for per-system timing breakdown
+//!
+//! **Feature: system-profiling, Property 1: Sampled Timings Sorted By Cost**
+
+use mindland_app::{timed_system, EngineConfig, MindLandApp, PerformanceMonitor};
+use bevy::prelude::*;
+use std::time::Duration;
+
+fn slow_system() {
+    std::thread::sleep(Duration::from_millis(3));
+}
+
+fn fast_system(mut counter: Local<u32>) {
+    *counter += 1;
+}
+
+#[cfg(test)]
+mod system_timing_tests {
+    use super::*;
+
+    #[test]
+    fn test_timed_systems_reported_most_expensive_first() {
+        // **Feature: system-profiling, Property 1: Sampled Timings Sorted By Cost**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let bevy_app = app.app_mut();
+        bevy_app.world.resource_mut::<PerformanceMonitor>().system_timing_interval = 1;
+        bevy_app.add_systems(Update, (
+            timed_system("fast", fast_system),
+            timed_system("slow", slow_system),
+        ));
+
+        for _ in 0..3 {
+            bevy_app.update();
+        }
+
+        let timings: Vec<_> = bevy_app.world.resource::<PerformanceMonitor>()
+            .system_timings()
+            .into_iter()
+            .filter(|(name, _)| name == "fast" || name == "slow")
+            .collect();
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings[0].0, "slow");
+        assert!(timings[0].1 >= Duration::from_millis(3));
+        assert!(timings[0].1 > timings[1].1);
+    }
+
+    #[test]
+    fn test_timings_only_recorded_on_sampled_frames() {
+        // **Feature: system-profiling, Property 1: Sampled Timings Sorted By Cost**
+        // With a long interval, only the first frame (frame 0) is sampled
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let bevy_app = app.app_mut();
+        bevy_app.world.resource_mut::<PerformanceMonitor>().system_timing_interval = 1000;
+        bevy_app.add_systems(Update, timed_system("fast", fast_system));
+
+        bevy_app.update();
+        bevy_app.world.resource_mut::<PerformanceMonitor>().system_timings.clear();
+        for _ in 0..5 {
+            bevy_app.update();
+        }
+
+        assert!(bevy_app.world.resource::<PerformanceMonitor>().system_timings().is_empty());
+    }
+
+    #[test]
+    fn test_engine_systems_are_timed() {
+        // **Feature: system-profiling, Property 1: Sampled Timings Sorted By Cost**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        let bevy_app = app.app_mut();
+        bevy_app.world.resource_mut::<PerformanceMonitor>().system_timing_interval = 1;
+        bevy_app.update();
+
+        let timings = bevy_app.world.resource::<PerformanceMonitor>().system_timings();
+        for name in ["thermal_protection_system", "performance_monitoring_system"] {
+            assert!(timings.iter().any(|(timed, _)| timed == name), "{name} missing from {timings:?}");
+        }
+    }
+
+    #[test]
+    fn test_timed_systems_are_not_exclusive() {
+        // **Feature: system-profiling, Property 1: Sampled Timings Sorted By Cost**
+        // Timing must not serialize the schedule: the clock systems and the wrapper stay parallel
+
+        let mut app = App::new();
+        app.add_systems(Update, timed_system("fast", fast_system));
+
+        let systems: Vec<_> = app.get_schedule(Update).unwrap().graph().systems().collect();
+        assert_eq!(systems.len(), 3, "clock start, the timed system and clock stop");
+        assert!(systems.iter().all(|(_, system, _)| !system.is_exclusive()));
+    }
+}
@@ -0,0 +1,154 @@
+//! Tests for thermal protection in the running app
+//!
+//! **Feature: thermal-protection, Property 1: Hot Hardware Lowers The App's Quality Until It Cools**
+
+use mindland_app::{EngineConfig, MindLandApp, PerformanceMonitor, SessionStats};
+use mindland_performance::{QualitySettings, ThermalMonitor};
+use mindland_render::UltraRenderer;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// A fake thermal zone directory with one CPU zone
+fn thermal_zones(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("mindland-thermal-protection-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(root.join("thermal_zone0")).unwrap();
+    std::fs::write(root.join("thermal_zone0/type"), "x86_pkg_temp\n").unwrap();
+    root
+}
+
+fn set_temperature(root: &Path, celsius: u32) {
+    std::fs::write(root.join("thermal_zone0/temp"), format!("{}\n", celsius * 1000)).unwrap();
+}
+
+fn render_distance(app: &mut MindLandApp) -> f32 {
+    app.app_mut().world.resource::<QualitySettings>().render_distance
+}
+
+#[cfg(test)]
+mod thermal_protection_tests {
+    use super::*;
+
+    #[test]
+    fn test_render_distance_drops_when_hot_and_recovers() {
+        // **Feature: thermal-protection, Property 1: Hot Hardware Lowers The App's Quality Until It Cools**
+
+        let root = thermal_zones("hot");
+        set_temperature(&root, 50);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample_interval = Duration::ZERO; // Read every frame
+        thermal.smoothing_time_constant = Duration::ZERO;
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(QualitySettings::macbook_pro_2014_preset());
+        app.app_mut().insert_resource(thermal);
+        let baseline = render_distance(&mut app);
+
+        app.app_mut().update();
+        assert_eq!(render_distance(&mut app), baseline, "cool hardware leaves quality alone");
+
+        set_temperature(&root, 80);
+        app.app_mut().update();
+        assert!(render_distance(&mut app) < baseline, "Hot lowers the render distance");
+
+        set_temperature(&root, 45);
+        app.app_mut().update();
+        assert_eq!(render_distance(&mut app), baseline, "cooling restores it");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_fan_over_target_lowers_quality() {
+        // **Feature: thermal-protection, Property 1: Hot Hardware Lowers The App's Quality Until It Cools**
+
+        let root = thermal_zones("fan");
+        set_temperature(&root, 70);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample_interval = Duration::ZERO; // Read every frame
+        thermal.smoothing_time_constant = Duration::ZERO;
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(QualitySettings::macbook_pro_2014_preset());
+        app.app_mut().insert_resource(thermal);
+
+        app.app_mut().update();
+        let quiet = render_distance(&mut app);
+
+        let max_fan_speed = app.app_mut().world.resource::<ThermalMonitor>().max_fan_speed;
+        app.app_mut().world.resource_mut::<ThermalMonitor>().fan_speed = max_fan_speed + 500;
+        app.app_mut().update();
+        assert!(render_distance(&mut app) < quiet, "a fan over target escalates Warm to Hot");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_lod_bias_follows_fps_and_reaches_the_renderer() {
+        let root = thermal_zones("lod");
+        set_temperature(&root, 50);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample_interval = Duration::ZERO;
+        thermal.smoothing_time_constant = Duration::ZERO;
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(QualitySettings::macbook_pro_2014_preset());
+        app.app_mut().insert_resource(thermal);
+        app.app_mut().insert_resource(UltraRenderer::new());
+        app.app_mut().update();
+        assert_eq!(app.app_mut().world.resource::<UltraRenderer>().lod_bias, 0.0, "no FPS measured yet");
+
+        let target_fps = app.app_mut().world.resource::<PerformanceMonitor>().target_fps;
+        app.app_mut().world.resource_mut::<PerformanceMonitor>().current_fps = target_fps * 0.5;
+        app.app_mut().update();
+        let lod_bias = app.app_mut().world.resource::<QualitySettings>().lod_bias;
+        assert!(lod_bias > 0.0, "missing the FPS target raises the LOD bias");
+        assert_eq!(app.app_mut().world.resource::<UltraRenderer>().lod_bias, lod_bias);
+
+        app.app_mut().world.resource_mut::<PerformanceMonitor>().current_fps = target_fps;
+        app.app_mut().update();
+        assert_eq!(app.app_mut().world.resource::<UltraRenderer>().lod_bias, 0.0, "recovered once on target");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_frames_record_the_sensor_temperature() {
+        let root = thermal_zones("frames");
+        set_temperature(&root, 71);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample_interval = Duration::ZERO;
+        thermal.smoothing_time_constant = Duration::ZERO;
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(thermal);
+        app.app_mut().update();
+
+        let frame = app.app_mut().world.resource::<PerformanceMonitor>().recent_frames.back().cloned().unwrap();
+        assert_eq!(frame.temperature, 71.0);
+        assert_eq!(app.app_mut().world.resource::<SessionStats>().peak_temperature, Some(71.0));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_default_app_culls_at_the_throttled_render_distance() {
+        // **Feature: thermal-protection, Property 1: Hot Hardware Lowers The App's Quality Until It Cools**
+        // The engine inserts `QualitySettings` itself, and its render distance drives culling
+
+        let root = thermal_zones("culling");
+        set_temperature(&root, 50);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample_interval = Duration::ZERO;
+        thermal.smoothing_time_constant = Duration::ZERO;
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().insert_resource(thermal);
+        app.app_mut().insert_resource(UltraRenderer::new());
+        app.app_mut().update();
+        let baseline = render_distance(&mut app);
+        let culled_at = |app: &mut MindLandApp| app.app_mut().world.resource::<UltraRenderer>().culling_system.max_render_distance;
+        assert_eq!(culled_at(&mut app), baseline);
+
+        set_temperature(&root, 80);
+        app.app_mut().update();
+        assert!(render_distance(&mut app) < baseline, "Hot lowers the render distance");
+        assert_eq!(culled_at(&mut app), render_distance(&mut app));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
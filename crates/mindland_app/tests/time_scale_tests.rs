@@ -0,0 +1,64 @@
+//! Tests for game-time scaling
+//!
+//! **Feature: time-scale, Property 1: Scaled Game Time**
+
+use mindland_app::{EngineConfig, MindLandApp, SimulationSet, TimeScale};
+use mindland_camera::CameraController;
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use std::time::Duration;
+
+const CAMERA_SPEED: f32 = 5.0;
+
+/// Stand-in gameplay system: moves the camera forward at constant speed in game time
+fn move_camera(time: Res<Time>, mut cameras: Query<&mut CameraController>) {
+    for mut camera in &mut cameras {
+        camera.transform.translation.z -= CAMERA_SPEED * time.delta_seconds();
+    }
+}
+
+/// Distance the camera travels over a fixed amount of real time at the given scale
+fn distance_travelled(scale: f32) -> f32 {
+    let mut app = MindLandApp::headless(EngineConfig::default());
+    let bevy_app = app.app_mut();
+    bevy_app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(16)));
+    bevy_app.insert_resource(TimeScale(scale));
+    bevy_app.add_systems(Update, move_camera.in_set(SimulationSet));
+    let camera = bevy_app.world.spawn(CameraController::new()).id();
+    let start = bevy_app.world.get::<CameraController>(camera).unwrap().transform.translation;
+
+    for _ in 0..60 {
+        bevy_app.update();
+    }
+
+    let end = bevy_app.world.get::<CameraController>(camera).unwrap().transform.translation;
+    start.distance(end)
+}
+
+#[cfg(test)]
+mod time_scale_tests {
+    use super::*;
+
+    #[test]
+    fn test_half_scale_covers_half_distance() {
+        // **Feature: time-scale, Property 1: Scaled Game Time**
+
+        let normal = distance_travelled(1.0);
+        let slow_motion = distance_travelled(0.5);
+
+        assert!(normal > 0.0);
+        assert!(
+            (slow_motion - normal * 0.5).abs() < normal * 0.01,
+            "slow-motion distance {:.3} should be half of {:.3}",
+            slow_motion,
+            normal
+        );
+    }
+
+    #[test]
+    fn test_zero_scale_freezes_and_fast_forward_doubles() {
+        let normal = distance_travelled(1.0);
+
+        assert_eq!(distance_travelled(0.0), 0.0);
+        assert!((distance_travelled(2.0) - normal * 2.0).abs() < normal * 0.01);
+    }
+}
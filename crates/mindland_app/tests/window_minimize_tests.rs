@@ -0,0 +1,100 @@
+//! Tests for surviving a minimized (zero-size) window
+//!
+//! **Feature: window-minimize, Property 1: Zero-Size Frames Are Skipped And Rendering Resumes On Restore**
+
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowResized},
+};
+use mindland_app::{surface_available, EngineConfig, MindLandApp, PerformanceMonitor, RenderSubmitter, SurfaceState};
+use mindland_camera::CameraController;
+use mindland_render::UltraRenderer;
+use std::time::Instant;
+
+/// Frames the render path actually ran
+#[derive(Resource, Default)]
+struct RenderedFrames(u32);
+
+fn render_stand_in(mut rendered: ResMut<RenderedFrames>) {
+    rendered.0 += 1;
+}
+
+fn resize(app: &mut MindLandApp, width: f32, height: f32) {
+    let world = &mut app.app_mut().world;
+    let window = world.query_filtered::<Entity, With<PrimaryWindow>>().single(world);
+    world.send_event(WindowResized { window, width, height });
+    app.app_mut().update();
+}
+
+fn recorded_frames(app: &mut MindLandApp) -> usize {
+    app.app_mut().world.resource::<PerformanceMonitor>().recent_frames.len()
+}
+
+#[cfg(test)]
+mod window_minimize_tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize_and_restore() {
+        // **Feature: window-minimize, Property 1: Zero-Size Frames Are Skipped And Rendering Resumes On Restore**
+
+        let mut app = MindLandApp::headless(EngineConfig::default());
+        app.app_mut().world.spawn((Window::default(), PrimaryWindow));
+        app.app_mut().init_resource::<RenderedFrames>();
+        app.app_mut().add_systems(Update, render_stand_in.run_if(surface_available));
+        let camera = app.app_mut().world.spawn(CameraController::new()).id();
+
+        resize(&mut app, 1600.0, 900.0);
+        assert_eq!(app.app_mut().world.resource::<RenderedFrames>().0, 1);
+        let aspect = app.app_mut().world.get::<CameraController>(camera).unwrap().projection.aspect_ratio;
+        assert!((aspect - 16.0 / 9.0).abs() < 1e-6);
+
+        // Minimized: the loop and performance monitor keep going, rendering doesn't
+        let frames = recorded_frames(&mut app);
+        resize(&mut app, 0.0, 0.0);
+        app.app_mut().update();
+        assert!(app.app_mut().world.resource::<SurfaceState>().minimized);
+        assert_eq!(app.app_mut().world.resource::<RenderedFrames>().0, 1);
+        assert_eq!(recorded_frames(&mut app), frames + 2);
+        let camera_state = app.app_mut().world.get::<CameraController>(camera).unwrap();
+        assert_eq!(camera_state.projection.aspect_ratio, aspect, "aspect ratio must survive a zero-size window");
+
+        // Restored at a new size
+        resize(&mut app, 1000.0, 1000.0);
+        assert!(!app.app_mut().world.resource::<SurfaceState>().minimized);
+        assert_eq!(app.app_mut().world.resource::<RenderedFrames>().0, 2);
+        assert_eq!(app.app_mut().world.get::<CameraController>(camera).unwrap().projection.aspect_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_engine_submits_nothing_while_minimized() {
+        // **Feature: window-minimize, Property 1: Zero-Size Frames Are Skipped And Rendering Resumes On Restore**
+
+        let mut app = MindLandApp::headless(EngineConfig::default()).with_render_submitter(|_| {});
+        app.app_mut().world.spawn((Window::default(), PrimaryWindow));
+        let submitted = |app: &mut MindLandApp| app.app_mut().world.resource::<RenderSubmitter>().submitted_frames();
+
+        resize(&mut app, 1600.0, 900.0);
+        assert_eq!(submitted(&mut app), 1);
+
+        resize(&mut app, 0.0, 0.0);
+        app.app_mut().update();
+        assert_eq!(submitted(&mut app), 1, "no frames submitted while minimized");
+
+        resize(&mut app, 1600.0, 900.0);
+        assert_eq!(submitted(&mut app), 2);
+    }
+
+    #[test]
+    fn test_renderer_skips_zero_size_frames() {
+        let mut renderer = UltraRenderer::new();
+        let now = Instant::now();
+        let size = UVec2::new(1280, 720);
+
+        assert_eq!(renderer.begin_frame(size, now).map(|target| target.output_size), Some(size));
+        assert!(renderer.begin_frame(UVec2::new(0, 0), now).is_none());
+        assert!(renderer.begin_frame(UVec2::new(1280, 0), now).is_none());
+        assert_eq!(renderer.render_targets.settled_size(), Some(size), "minimizing shouldn't resize targets");
+        assert_eq!(renderer.begin_frame(size, now).map(|target| target.output_size), Some(size));
+    }
+}
@@ -0,0 +1,151 @@
+//! Tests for zero-allocation validation in benchmark runs
+//!
+//! **Feature: zero-alloc-benchmark, Property 1: A Hot-Path Allocation Fails The Benchmark**
+
+use bevy::{app::AppExit, prelude::*, time::TimeUpdateStrategy};
+use mindland_app::{
+    count_allocations, counting_allocator_installed, hot_path_system, BenchmarkConfig, CountingAllocator, EngineConfig,
+    HotPathAllocations, MindLandApp, BENCHMARK_REPORT_FILE,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Resource, Default)]
+struct Counter(u64);
+
+#[derive(Resource, Default)]
+struct Log(Vec<String>);
+
+fn steady_system(mut counter: ResMut<Counter>) {
+    counter.0 += 1;
+}
+
+fn allocating_system(counter: Res<Counter>, mut log: ResMut<Log>) {
+    log.0.push(format!("frame {}", counter.0)); // Deliberately allocates every frame
+}
+
+/// Fresh output directory per test
+fn output_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("mindland-zero-alloc-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Run a 10-frame zero-allocation benchmark with the systems `add_systems` registers, returning the report JSON
+fn run_checked_benchmark(name: &str, add_systems: impl FnOnce(&mut App)) -> serde_json::Value {
+    run_checked_app(name, MindLandApp::headless(EngineConfig::default()), add_systems)
+}
+
+/// Run a 10-frame zero-allocation benchmark of `app`, returning the report JSON
+fn run_checked_app(name: &str, app: MindLandApp, add_systems: impl FnOnce(&mut App)) -> serde_json::Value {
+    let dir = output_dir(name);
+    let config = BenchmarkConfig {
+        capture_final_frame: false,
+        output_dir: dir.clone(),
+        assert_zero_alloc: true,
+        ..Default::default()
+    };
+    let mut app = app.with_benchmark(10, config);
+    app.app_mut()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(16)))
+        .init_resource::<Counter>()
+        .init_resource::<Log>();
+    add_systems(app.app_mut());
+
+    let mut updates = 0;
+    while app.app_mut().world.resource::<Events<AppExit>>().is_empty() {
+        app.app_mut().update();
+        updates += 1;
+        assert!(updates < 50, "benchmark never finished");
+    }
+
+    let json = serde_json::from_str(&std::fs::read_to_string(dir.join(BENCHMARK_REPORT_FILE)).unwrap()).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    json
+}
+
+#[cfg(test)]
+mod zero_alloc_benchmark_tests {
+    use super::*;
+
+    #[test]
+    fn test_allocating_hot_path_fails_benchmark() {
+        // **Feature: zero-alloc-benchmark, Property 1: A Hot-Path Allocation Fails The Benchmark**
+
+        let json = run_checked_benchmark("allocating", |app| {
+            app.add_systems(Update, (
+                hot_path_system("steady", steady_system),
+                hot_path_system("allocating", allocating_system),
+            ).chain());
+        });
+
+        assert_eq!(json["zero_alloc_passed"], false);
+        let flagged = json["hot_path_allocations"].as_array().unwrap();
+        assert_eq!(flagged.len(), 1, "{flagged:?}");
+        assert_eq!(flagged[0][0], "allocating");
+        assert!(flagged[0][1].as_u64().unwrap() >= 10, "every measured frame allocated");
+    }
+
+    #[test]
+    fn test_allocation_free_hot_path_passes() {
+        // **Feature: zero-alloc-benchmark, Property 1: A Hot-Path Allocation Fails The Benchmark**
+
+        let json = run_checked_benchmark("steady", |app| {
+            app.add_systems(Update, hot_path_system("steady", steady_system));
+        });
+
+        assert_eq!(json["zero_alloc_passed"], true);
+        assert!(json["hot_path_allocations"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_count_allocations_is_per_thread_and_nests() {
+        assert!(counting_allocator_installed());
+
+        let ((_, inner), outer) = count_allocations(|| {
+            let first = vec![1_u8; 64];
+            let nested = count_allocations(|| vec![2_u8; 64]);
+            std::thread::spawn(|| vec![3_u8; 64]).join().unwrap(); // Other threads aren't counted here
+            drop(first);
+            nested
+        });
+        assert_eq!(inner, 1);
+        assert!(outer >= 2);
+
+        let (_, none) = count_allocations(|| 40 + 2);
+        assert_eq!(none, 0);
+    }
+
+    #[test]
+    fn test_hot_path_systems_untracked_unless_enabled() {
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .init_resource::<Log>()
+            .init_resource::<HotPathAllocations>()
+            .add_systems(Update, hot_path_system("allocating", allocating_system));
+
+        app.update();
+        assert_eq!(app.world.resource::<HotPathAllocations>().total(), 0);
+
+        app.world.resource_mut::<HotPathAllocations>().enabled = true;
+        app.update();
+        assert!(app.world.resource::<HotPathAllocations>().total() > 0);
+    }
+
+    #[test]
+    fn test_allocating_engine_system_fails_benchmark() {
+        // The render backend runs inside `render_submit_system` when rendering isn't pipelined
+        let app = MindLandApp::headless(EngineConfig::default()).with_render_submitter(|buffer| {
+            std::hint::black_box(format!("{} commands", buffer.commands.len()));
+        });
+        let json = run_checked_app("engine", app, |_| {});
+
+        assert_eq!(json["zero_alloc_passed"], false);
+        let flagged = json["hot_path_allocations"].as_array().unwrap();
+        assert!(flagged.iter().any(|entry| entry[0] == "render_submit_system"), "{flagged:?}");
+        assert!(flagged.iter().all(|entry| entry[0] == "render_submit_system"), "other engine hot paths allocated: {flagged:?}");
+    }
+}
@@ -0,0 +1,235 @@
+//! Archive asset roots
+//!
+//! `.zip`/`.pak` resource packs mounted alongside loose-file roots, plus the background reader that
+//! decompresses queued loads off the main thread.
+
+use crate::{AssetError, AssetPath, RetryPolicy};
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use std::io::{Cursor, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use zip::{result::ZipError, ZipArchive};
+
+/// A zip-format asset archive (`.zip` or `.pak`), held in memory once opened
+#[derive(Debug, Clone)]
+pub struct AssetArchive {
+    path: PathBuf, // Source file, or a display name for in-memory archives
+    zip: ZipArchive<Cursor<Arc<[u8]>>>,
+}
+
+/// One place assets are searched for
+#[derive(Debug, Clone)]
+pub enum AssetRoot {
+    Directory(PathBuf),
+    Archive(Arc<AssetArchive>),
+}
+
+/// Bytes read for a queued load (`None` when no root contains the path)
+pub type AssetReadResult = Option<Result<Vec<u8>, AssetError>>;
+
+/// A queued read: the asset, a snapshot of the roots, and how to retry transient failures
+type ReadJob = (AssetPath, Arc<[AssetRoot]>, RetryPolicy);
+
+/// Single worker thread that reads and decompresses queued assets
+pub struct AssetReader {
+    job_sender: Option<Sender<ReadJob>>,
+    result_receiver: Receiver<(AssetPath, AssetReadResult, Duration)>,
+    worker: Option<JoinHandle<()>>,
+    pending_reads: usize,
+}
+
+impl AssetArchive {
+    /// Open an archive file
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, AssetError> {
+        let path = path.into();
+        let bytes = std::fs::read(&path).map_err(|_| AssetError::NotFound { path: path.clone() })?;
+        Self::from_bytes(path, bytes)
+    }
+
+    /// Mount an archive already in memory (`name` is only used for display and `resolve`)
+    pub fn from_bytes(name: impl Into<PathBuf>, bytes: impl Into<Arc<[u8]>>) -> Result<Self, AssetError> {
+        let zip = ZipArchive::new(Cursor::new(bytes.into()))
+            .map_err(|error| AssetError::LoadingFailed { reason: error.to_string() })?;
+        Ok(Self { path: name.into(), zip })
+    }
+
+    /// Source path of the archive
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the archive has an entry for a relative asset path
+    pub fn contains(&self, path: &Path) -> bool {
+        entry_name(path).is_some_and(|name| self.zip.clone().by_name(&name).is_ok())
+    }
+
+    /// Decompress an entry (`None` if the archive doesn't contain it)
+    pub fn read(&self, path: &Path) -> AssetReadResult {
+        let name = entry_name(path)?;
+        let mut zip = self.zip.clone(); // Shares the parsed directory; only the cursor is copied
+        let mut entry = match zip.by_name(&name) {
+            Ok(entry) => entry,
+            Err(ZipError::FileNotFound) => return None,
+            Err(error) => return Some(Err(AssetError::LoadingFailed { reason: error.to_string() })),
+        };
+
+        let mut bytes = Vec::with_capacity(entry.size() as usize);
+        Some(
+            entry
+                .read_to_end(&mut bytes)
+                .map(|_| bytes)
+                .map_err(|error| AssetError::LoadingFailed { reason: error.to_string() }),
+        )
+    }
+}
+
+impl AssetRoot {
+    /// Directory or archive file backing this root
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Directory(directory) => directory,
+            Self::Archive(archive) => archive.path(),
+        }
+    }
+
+    /// Where a relative path lives in this root (archive entries resolve to `<archive>/<entry>`)
+    pub fn locate(&self, path: &Path) -> Option<PathBuf> {
+        match self {
+            Self::Directory(directory) => Some(directory.join(path)).filter(|candidate| candidate.is_file()),
+            Self::Archive(archive) => archive.contains(path).then(|| archive.path().join(path)),
+        }
+    }
+
+    /// Read a relative path from this root (`None` if it isn't here)
+    pub fn read(&self, path: &Path) -> AssetReadResult {
+        match self {
+            Self::Directory(_) => {
+                let file = self.locate(path)?;
+                Some(read_file(&file))
+            }
+            Self::Archive(archive) => archive.read(path),
+        }
+    }
+}
+
+impl From<PathBuf> for AssetRoot {
+    fn from(directory: PathBuf) -> Self {
+        Self::Directory(directory)
+    }
+}
+
+impl From<&PathBuf> for AssetRoot {
+    fn from(directory: &PathBuf) -> Self {
+        Self::Directory(directory.clone())
+    }
+}
+
+impl From<&Path> for AssetRoot {
+    fn from(directory: &Path) -> Self {
+        Self::Directory(directory.to_path_buf())
+    }
+}
+
+impl From<AssetArchive> for AssetRoot {
+    fn from(archive: AssetArchive) -> Self {
+        Self::Archive(Arc::new(archive))
+    }
+}
+
+/// Read a path from the first root that has it (absolute paths bypass the roots)
+pub fn read_from_roots(roots: &[AssetRoot], path: &Path) -> AssetReadResult {
+    if path.is_absolute() {
+        return path.is_file().then(|| read_file(path));
+    }
+    roots.iter().find_map(|root| root.read(path))
+}
+
+/// Read a file; errors other than a missing file (locks, network drives) are `LoadingFailed` so they're retried
+fn read_file(path: &Path) -> Result<Vec<u8>, AssetError> {
+    std::fs::read(path).map_err(|error| match error.kind() {
+        std::io::ErrorKind::NotFound => AssetError::NotFound { path: path.to_path_buf() },
+        _ => AssetError::LoadingFailed { reason: format!("{}: {}", path.display(), error) },
+    })
+}
+
+/// Zip entry name for a relative path (always `/`-separated; `..` is rejected)
+fn entry_name(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    Some(parts.join("/"))
+}
+
+impl Default for AssetReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssetReader {
+    /// Start the reader thread
+    pub fn new() -> Self {
+        let (job_sender, job_receiver) = unbounded::<ReadJob>();
+        let (result_sender, result_receiver) = unbounded();
+
+        let worker = std::thread::Builder::new()
+            .name("mindland-asset-reader".to_string())
+            .spawn(move || {
+                // Exits once the reader drops its sender
+                for (asset_path, roots, retry) in job_receiver {
+                    let started = Instant::now();
+                    let bytes = retry.retry(|| read_from_roots(&roots, &asset_path.path).transpose()).transpose();
+                    if result_sender.send((asset_path, bytes, started.elapsed())).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn asset reader thread");
+
+        Self {
+            job_sender: Some(job_sender),
+            result_receiver,
+            worker: Some(worker),
+            pending_reads: 0,
+        }
+    }
+
+    /// Queue a read against a snapshot of the roots (requests are served in order)
+    ///
+    /// Transient failures are retried per `retry`; the worker backs off before reading anything else.
+    pub fn request(&mut self, asset_path: AssetPath, roots: Arc<[AssetRoot]>, retry: RetryPolicy) {
+        if let Some(sender) = &self.job_sender {
+            // The worker only exits after the sender is dropped, so this cannot fail
+            let _ = sender.send((asset_path, roots, retry));
+            self.pending_reads += 1;
+        }
+    }
+
+    /// Take the next finished read, if any, with how long reading it took (retries included)
+    pub fn poll_finished(&mut self) -> Option<(AssetPath, AssetReadResult, Duration)> {
+        let finished = self.result_receiver.try_recv().ok()?;
+        self.pending_reads -= 1;
+        Some(finished)
+    }
+
+    /// Number of requested reads not yet collected
+    pub fn pending_reads(&self) -> usize {
+        self.pending_reads
+    }
+}
+
+impl Drop for AssetReader {
+    fn drop(&mut self) {
+        self.job_sender = None; // Closes the queue so the worker exits
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -4,23 +4,66 @@
 
 use bevy::{
     prelude::*,
-    render::render_resource::TextureFormat,
+    render::{
+        render_resource::{AddressMode, FilterMode, SamplerDescriptor, TextureFormat, WgpuLimits},
+        texture::{CompressedImageFormats, ImageSampler, ImageType},
+    },
 };
 use slotmap::{SlotMap, DefaultKey};
 use lru::LruCache;
 use std::collections::VecDeque;
-use std::path::PathBuf;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
 use anyhow::Result;
+use mesh_formats::{check_mesh_extension, decode_mesh};
 use thiserror::Error;
 
+mod archive;
+mod mesh_formats;
+mod retry;
+mod shared;
+mod telemetry;
+pub use archive::*;
+pub use mesh_formats::MESH_EXTENSIONS;
+pub use retry::*;
+pub use shared::*;
+pub use telemetry::*;
+
 /// High-performance asset manager with LRU caching
 pub struct AssetManager {
     pub textures: SlotMap<TextureId, ManagedTexture>,
     pub meshes: SlotMap<MeshId, ManagedMesh>,
     pub materials: SlotMap<MaterialId, ManagedMaterial>,
-    pub asset_cache: LruCache<AssetPath, AssetId>,
+    pub texture_cache: LruCache<PathBuf, TextureId>, // Per-type caches so one type can't evict another
+    pub mesh_cache: LruCache<PathBuf, MeshId>,
+    pub material_cache: LruCache<PathBuf, MaterialId>,
     pub loading_queue: VecDeque<AssetLoadRequest>,
+    pub max_texture_dimension: u32, // Larger textures are downscaled on load
+    pub asset_roots: Vec<AssetRoot>, // Search order for relative paths, highest priority (mods) first
+    pub retry_policy: RetryPolicy,   // For queued loads that don't set their own
+    pub deterministic: bool,         // Load queued assets of equal priority in path order (reproducible ids)
+    reader: AssetReader,             // Background reads for queued loads
+    resident_gpu_bytes: u64,
+    gpu_pressure: Option<GpuPressureHandler>,
+    uploads: VecDeque<PendingUpload>, // Loaded assets whose GPU data isn't fully submitted yet
+    upload_budget: u64,               // Bytes handed out per `schedule_uploads` call
+    telemetry: LoadTelemetry,         // Queued-load records for `asset_load_events_system`
+}
+
+/// Asset data not yet fully submitted to the GPU
+struct PendingUpload {
+    asset: AssetId,
+    total: u64,
+    uploaded: u64,
+}
+
+/// Callback fired when estimated VRAM use crosses a threshold
+struct GpuPressureHandler {
+    threshold: u64,
+    callback: Box<dyn FnMut(u64) + Send + Sync>,
+    fired: bool, // Re-armed once usage drops back to the threshold
 }
 
 /// Unique identifiers for different asset types
@@ -59,6 +102,8 @@ pub struct ManagedTexture {
     pub mip_levels: u32,
     pub usage_count: AtomicU32,
     pub path: PathBuf,
+    pub image: Option<Image>, // Decoded pixels awaiting upload (None for placeholders)
+    pub sampler: SamplerConfig,
 }
 
 /// Managed mesh with bounding information
@@ -69,14 +114,33 @@ pub struct ManagedMesh {
     pub bounding_box: BoundingBox,
     pub usage_count: AtomicU32,
     pub path: PathBuf,
+    pub gpu_bytes: u64, // Vertex + index buffer size
 }
 
+/// Render queue for skyboxes and other backdrops (drawn first)
+pub const QUEUE_BACKGROUND: i32 = -1000;
+
+/// Render queue for ordinary opaque geometry (the default)
+pub const QUEUE_OPAQUE: i32 = 0;
+
+/// Render queue for blended geometry; this queue and above sort back to front
+pub const QUEUE_TRANSPARENT: i32 = 1000;
+
+/// Render queue for UI and debug overlays (drawn last)
+pub const QUEUE_OVERLAY: i32 = 2000;
+
 /// Managed material with shader information
 pub struct ManagedMaterial {
     pub handle: Handle<StandardMaterial>,
     pub shader_type: ShaderType,
     pub usage_count: AtomicU32,
     pub path: PathBuf,
+    pub render_queue: i32, // Draw order hint, lower first (see the `QUEUE_*` constants)
+}
+
+/// Whether draws in `render_queue` are blended, and so sorted back to front
+pub fn is_transparent_queue(render_queue: i32) -> bool {
+    render_queue >= QUEUE_TRANSPARENT
 }
 
 /// Shader type for material optimization
@@ -95,11 +159,19 @@ pub struct BoundingBox {
     pub max: Vec3,
 }
 
+/// Bounding sphere for screen-space size and LOD estimation
+#[derive(Debug, Clone, Copy)]
+pub struct BoundingSphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
 /// Asset loading request for async processing
 #[derive(Debug, Clone)]
 pub struct AssetLoadRequest {
     pub path: AssetPath,
     pub priority: LoadPriority,
+    pub retry: Option<RetryPolicy>, // None uses `AssetManager::retry_policy`
 }
 
 /// Loading priority for asset queue management
@@ -111,8 +183,49 @@ pub enum LoadPriority {
     Critical = 3,
 }
 
+/// How a texture is sampled (wrap modes and filtering)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SamplerConfig {
+    pub address_u: AddressMode,
+    pub address_v: AddressMode,
+    pub filter: FilterMode,        // Magnification and minification
+    pub mipmap_filter: FilterMode, // Between mip levels
+}
+
+/// Path cache capacity per asset type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetCacheSizes {
+    pub textures: usize,
+    pub meshes: usize,
+    pub materials: usize,
+}
+
+/// Snapshot of asset manager occupancy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AssetStats {
+    pub textures: usize,
+    pub meshes: usize,
+    pub materials: usize,
+    pub cached_paths: usize, // Sum of the per-type caches below
+    pub cached_textures: usize,
+    pub cached_meshes: usize,
+    pub cached_materials: usize,
+    pub pending_loads: usize,
+    pub texture_bytes: usize, // Decoded pixels still held on the CPU
+    pub pending_uploads: usize, // Loaded but not yet fully submitted to the GPU
+}
+
+/// Byte range of an asset's GPU data to submit this frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadChunk {
+    pub asset: AssetId,
+    pub offset: u64, // Into the texture's pixel data or the mesh's vertex + index bytes
+    pub len: u64,
+    pub total: u64,
+}
+
 /// Asset loading errors
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum AssetError {
     #[error("Asset not found: {path}")]
     NotFound { path: PathBuf },
@@ -124,6 +237,16 @@ pub enum AssetError {
     CacheFull,
 }
 
+impl Default for AssetCacheSizes {
+    fn default() -> Self {
+        Self {
+            textures: 1000, // Block/item textures are numerous and small
+            meshes: 256,
+            materials: 256,
+        }
+    }
+}
+
 impl Default for AssetManager {
     fn default() -> Self {
         Self::new()
@@ -131,91 +254,440 @@ impl Default for AssetManager {
 }
 
 impl AssetManager {
-    /// Create a new asset manager with default cache size
+    /// Create a new asset manager with default cache sizes
     pub fn new() -> Self {
-        Self::with_cache_size(1000) // Default 1000 asset cache
+        Self::with_cache_sizes(AssetCacheSizes::default())
     }
 
-    /// Create asset manager with custom cache size
+    /// Create asset manager with the same cache size for every asset type
     pub fn with_cache_size(cache_size: usize) -> Self {
+        Self::with_cache_sizes(AssetCacheSizes {
+            textures: cache_size,
+            meshes: cache_size,
+            materials: cache_size,
+        })
+    }
+
+    /// Create asset manager with independent per-type cache sizes
+    pub fn with_cache_sizes(sizes: AssetCacheSizes) -> Self {
+        let capacity = |size: usize| NonZeroUsize::new(size).expect("asset cache size must be non-zero");
         Self {
             textures: SlotMap::new(),
             meshes: SlotMap::new(),
             materials: SlotMap::new(),
-            asset_cache: LruCache::new(cache_size.try_into().unwrap()),
+            texture_cache: LruCache::new(capacity(sizes.textures)),
+            mesh_cache: LruCache::new(capacity(sizes.meshes)),
+            material_cache: LruCache::new(capacity(sizes.materials)),
             loading_queue: VecDeque::new(),
+            max_texture_dimension: WgpuLimits::default().max_texture_dimension_2d,
+            asset_roots: vec![AssetRoot::Directory(default_asset_root())],
+            retry_policy: RetryPolicy::default(),
+            deterministic: false,
+            reader: AssetReader::new(),
+            resident_gpu_bytes: 0,
+            gpu_pressure: None,
+            uploads: VecDeque::new(),
+            upload_budget: u64::MAX,
+            telemetry: LoadTelemetry::default(),
+        }
+    }
+
+    /// Replace all roots with a single base root (a directory or an `AssetArchive`)
+    pub fn set_root(&mut self, root: impl Into<AssetRoot>) {
+        self.asset_roots = vec![root.into()];
+    }
+
+    /// Add a root that takes priority over all existing roots (mods, resource packs)
+    pub fn add_root(&mut self, root: impl Into<AssetRoot>) {
+        self.asset_roots.insert(0, root.into());
+    }
+
+    /// Base root, searched last
+    pub fn asset_root(&self) -> Option<&Path> {
+        self.asset_roots.last().map(AssetRoot::path)
+    }
+
+    /// Resolve a load path against the roots in priority order (absolute paths are used as-is)
+    ///
+    /// Paths found in an archive resolve to `<archive>/<entry>`, which is not a loose file.
+    pub fn resolve(&self, path: &Path) -> Option<PathBuf> {
+        if path.is_absolute() {
+            return path.exists().then(|| path.to_path_buf());
         }
+        self.asset_roots.iter().find_map(|root| root.locate(path))
     }
 
-    /// Load a texture asset (returns cached version if available)
+    /// Read an asset's bytes from the highest-priority root containing it
+    pub fn read_asset(&self, path: &Path) -> Option<Result<Vec<u8>, AssetError>> {
+        read_from_roots(&self.asset_roots, path)
+    }
+
+    /// Clamp texture sizes to what the adapter supports (pass `RenderDevice::limits()`)
+    pub fn set_texture_limits(&mut self, limits: &WgpuLimits) {
+        self.max_texture_dimension = limits.max_texture_dimension_2d;
+    }
+
+    /// Load a texture resolved against the asset roots (returns cached version if available)
     pub fn load_texture(&mut self, path: PathBuf) -> Result<TextureId, AssetError> {
+        self.load_texture_with(path, SamplerConfig::default())
+    }
+
+    /// Load a texture with explicit sampling (a cached texture keeps the sampler it was first loaded with)
+    pub fn load_texture_with(&mut self, path: PathBuf, sampler: SamplerConfig) -> Result<TextureId, AssetError> {
         let asset_path = AssetPath {
             path: path.clone(),
             asset_type: AssetType::Texture,
         };
 
         // Check cache first
-        if let Some(AssetId::Texture(texture_id)) = self.asset_cache.get(&asset_path).cloned() {
-            if let Some(texture) = self.textures.get(texture_id) {
-                texture.usage_count.fetch_add(1, Ordering::Relaxed);
-                return Ok(texture_id);
-            }
+        if let Some(texture_id) = self.cached_texture(&asset_path) {
+            return Ok(texture_id);
         }
 
-        // Load new texture (placeholder implementation)
+        // Decode from the roots when available; missing files fall back to a placeholder
+        match self.read_asset(&path) {
+            Some(bytes) => self.decode_texture(path, &bytes?, sampler),
+            None => Ok(self.insert_placeholder_texture(asset_path, sampler)),
+        }
+    }
+
+    /// Bump and return a cached texture
+    fn cached_texture(&mut self, asset_path: &AssetPath) -> Option<TextureId> {
+        let texture_id = *self.texture_cache.get(&asset_path.path)?;
+        let texture = self.textures.get(texture_id)?;
+        texture.usage_count.fetch_add(1, Ordering::Relaxed);
+        Some(texture_id)
+    }
+
+    /// Bump and return a cached texture without touching LRU order (needs only shared access)
+    pub(crate) fn peek_cached_texture(&self, asset_path: &AssetPath) -> Option<TextureId> {
+        let texture_id = *self.texture_cache.peek(&asset_path.path)?;
+        let texture = self.textures.get(texture_id)?;
+        texture.usage_count.fetch_add(1, Ordering::Relaxed);
+        Some(texture_id)
+    }
+
+    /// Register a placeholder for a texture no root contains
+    pub(crate) fn insert_placeholder_texture(&mut self, asset_path: AssetPath, sampler: SamplerConfig) -> TextureId {
         let texture_id = self.textures.insert(ManagedTexture {
             handle: Handle::default(), // Would load actual texture in full implementation
             size: (256, 256), // Placeholder
             format: TextureFormat::Rgba8UnormSrgb,
             mip_levels: 1,
             usage_count: AtomicU32::new(1),
+            path: asset_path.path.clone(),
+            image: None,
+            sampler,
+        });
+        self.texture_cache.put(asset_path.path, texture_id);
+        texture_id
+    }
+
+    /// Decode encoded image bytes, picking the format from the path's extension
+    fn decode_texture(&mut self, path: PathBuf, bytes: &[u8], sampler: SamplerConfig) -> Result<TextureId, AssetError> {
+        let image = decode_image(&path, bytes)?;
+        self.load_texture_image_with(path, image, sampler)
+    }
+
+    /// Register an already-decoded texture, downscaling it if it exceeds `max_texture_dimension`
+    pub fn load_texture_image(&mut self, path: PathBuf, image: Image) -> Result<TextureId, AssetError> {
+        self.load_texture_image_with(path, image, SamplerConfig::default())
+    }
+
+    /// Register an already-decoded texture with explicit sampling
+    pub fn load_texture_image_with(
+        &mut self,
+        path: PathBuf,
+        image: Image,
+        sampler: SamplerConfig,
+    ) -> Result<TextureId, AssetError> {
+        let mut image = self.fit_to_texture_limit(&path, image)?;
+        image.sampler = sampler.image_sampler();
+        let upload_bytes = image.data.len() as u64;
+
+        let texture_id = self.textures.insert(ManagedTexture {
+            handle: Handle::default(),
+            size: (image.width(), image.height()),
+            format: image.texture_descriptor.format,
+            mip_levels: image.texture_descriptor.mip_level_count,
+            usage_count: AtomicU32::new(1),
             path: path.clone(),
+            image: Some(image),
+            sampler,
         });
+        self.track_gpu_bytes(self.textures[texture_id].gpu_bytes());
+        self.queue_upload(AssetId::Texture(texture_id), upload_bytes);
 
         // Cache the loaded asset
-        self.asset_cache.put(asset_path, AssetId::Texture(texture_id));
+        self.texture_cache.put(path, texture_id);
 
         Ok(texture_id)
     }
 
+    /// Load an OBJ or glTF mesh resolved against the asset roots (returns cached version if available)
+    ///
+    /// Unlike textures, missing meshes are an error rather than a placeholder.
+    pub fn load_mesh(&mut self, path: PathBuf) -> Result<MeshId, AssetError> {
+        if let Some(mesh_id) = self.cached_mesh(&path) {
+            return Ok(mesh_id);
+        }
+
+        check_mesh_extension(&path)?;
+        let bytes = self.read_asset(&path).ok_or_else(|| AssetError::NotFound { path: path.clone() })??;
+        self.decode_mesh(path, &bytes)
+    }
+
+    /// Parse mesh bytes and register the result (glTF buffers are resolved against the asset roots)
+    fn decode_mesh(&mut self, path: PathBuf, bytes: &[u8]) -> Result<MeshId, AssetError> {
+        let mesh = decode_mesh(&path, bytes, |buffer| self.read_asset(buffer))?;
+        Ok(self.load_mesh_data(path, mesh))
+    }
+
+    /// Bump and return a cached mesh
+    fn cached_mesh(&mut self, path: &Path) -> Option<MeshId> {
+        let mesh_id = *self.mesh_cache.get(path)?;
+        let mesh = self.meshes.get(mesh_id)?;
+        mesh.usage_count.fetch_add(1, Ordering::Relaxed);
+        Some(mesh_id)
+    }
+
+    /// Register an already-built mesh under a path
+    pub fn load_mesh_data(&mut self, path: PathBuf, mesh: Mesh) -> MeshId {
+        let bounding_box = mesh
+            .compute_aabb()
+            .map(|aabb| BoundingBox::new(aabb.min().into(), aabb.max().into()))
+            .unwrap_or(BoundingBox::new(Vec3::ZERO, Vec3::ZERO));
+
+        let gpu_bytes = mesh.get_vertex_buffer_data().len() + mesh.get_index_buffer_bytes().map_or(0, <[u8]>::len);
+        let mesh_id = self.meshes.insert(ManagedMesh {
+            handle: Handle::default(),
+            vertex_count: mesh.count_vertices() as u32,
+            index_count: mesh.indices().map_or(0, |indices| indices.len() as u32),
+            bounding_box,
+            usage_count: AtomicU32::new(1),
+            path: path.clone(),
+            gpu_bytes: gpu_bytes as u64,
+        });
+        self.track_gpu_bytes(gpu_bytes as u64);
+        self.queue_upload(AssetId::Mesh(mesh_id), gpu_bytes as u64);
+        self.mesh_cache.put(path, mesh_id);
+        mesh_id
+    }
+
+    /// Estimated VRAM held by loaded textures and meshes
+    pub fn gpu_memory_usage(&self) -> u64 {
+        self.resident_gpu_bytes
+    }
+
+    /// Call `f` with the current usage when estimated VRAM use rises above `threshold` bytes
+    ///
+    /// Fires once per crossing and re-arms after usage falls back (e.g. via `collect_garbage`),
+    /// so the game can lower quality or stream out assets before the OS starts paging GPU memory.
+    /// Fires immediately if usage is already above the threshold.
+    pub fn set_gpu_pressure_handler(&mut self, threshold: u64, f: Box<dyn FnMut(u64) + Send + Sync>) {
+        self.gpu_pressure = Some(GpuPressureHandler {
+            threshold,
+            callback: f,
+            fired: false,
+        });
+        self.check_gpu_pressure();
+    }
+
+    /// Remove the GPU pressure handler
+    pub fn clear_gpu_pressure_handler(&mut self) {
+        self.gpu_pressure = None;
+    }
+
+    fn track_gpu_bytes(&mut self, bytes: u64) {
+        self.resident_gpu_bytes += bytes;
+        self.check_gpu_pressure();
+    }
+
+    fn check_gpu_pressure(&mut self) {
+        let usage = self.resident_gpu_bytes;
+        let Some(handler) = &mut self.gpu_pressure else {
+            return;
+        };
+
+        if usage <= handler.threshold {
+            handler.fired = false;
+        } else if !handler.fired {
+            handler.fired = true;
+            (handler.callback)(usage);
+        }
+    }
+
+    /// Limit how many bytes of new GPU data `schedule_uploads` hands out per frame (unlimited by default)
+    ///
+    /// Large assets are split across frames. A budget of 0 is treated as 1 byte so uploads still progress.
+    pub fn set_upload_budget(&mut self, bytes_per_frame: u64) {
+        self.upload_budget = bytes_per_frame.max(1);
+    }
+
+    /// Bytes of new GPU data submitted per frame
+    pub fn upload_budget(&self) -> u64 {
+        self.upload_budget
+    }
+
+    fn queue_upload(&mut self, asset: AssetId, total: u64) {
+        self.uploads.push_back(PendingUpload { asset, total, uploaded: 0 });
+    }
+
+    /// Hand out this frame's uploads, oldest assets first, within the upload budget (call once per frame)
+    ///
+    /// Chunks of one asset arrive in order; the asset stops being pending with its last chunk.
+    pub fn schedule_uploads(&mut self) -> Vec<UploadChunk> {
+        let mut remaining = self.upload_budget;
+        let mut chunks = Vec::new();
+
+        while remaining > 0 {
+            let Some(upload) = self.uploads.front_mut() else {
+                break;
+            };
+            let len = (upload.total - upload.uploaded).min(remaining);
+            chunks.push(UploadChunk {
+                asset: upload.asset.clone(),
+                offset: upload.uploaded,
+                len,
+                total: upload.total,
+            });
+            upload.uploaded += len;
+            remaining -= len;
+
+            if upload.uploaded == upload.total {
+                self.uploads.pop_front();
+            }
+        }
+        chunks
+    }
+
+    /// Whether an asset is loaded but its GPU data isn't fully submitted (draw a placeholder)
+    pub fn is_upload_pending(&self, asset: &AssetId) -> bool {
+        self.uploads.iter().any(|upload| upload.asset == *asset)
+    }
+
+    /// Bytes waiting for `schedule_uploads`
+    pub fn pending_upload_bytes(&self) -> u64 {
+        self.uploads.iter().map(|upload| upload.total - upload.uploaded).sum()
+    }
+
+    /// Downscale an image so neither side exceeds `max_texture_dimension` (aspect preserved)
+    fn fit_to_texture_limit(&self, path: &Path, image: Image) -> Result<Image, AssetError> {
+        fit_image_to_limit(path, image, self.max_texture_dimension)
+    }
+
     /// Queue an asset for async loading
     pub fn queue_load(&mut self, path: AssetPath, priority: LoadPriority) {
-        let request = AssetLoadRequest { path, priority };
-        
-        // Insert based on priority (higher priority first)
+        self.queue_request(AssetLoadRequest { path, priority, retry: None });
+    }
+
+    /// Queue an asset for async loading with its own retry policy
+    pub fn queue_load_with_retry(&mut self, path: AssetPath, priority: LoadPriority, retry: RetryPolicy) {
+        self.queue_request(AssetLoadRequest { path, priority, retry: Some(retry) });
+    }
+
+    /// Queue every asset in a manifest, returning how many were queued
+    ///
+    /// In `deterministic` mode the order the manifest lists them in doesn't matter: see `set_deterministic`.
+    pub fn preload_manifest(&mut self, manifest: impl IntoIterator<Item = AssetPath>, priority: LoadPriority) -> usize {
+        let mut queued = 0;
+        for path in manifest {
+            self.queue_load(path, priority);
+            queued += 1;
+        }
+        queued
+    }
+
+    /// Make id assignment reproducible across runs (for frame-hash and golden tests)
+    ///
+    /// Queued loads are then processed highest priority first and, within a priority, sorted by
+    /// path (then asset type) instead of in the order they were queued, so callers that queue from
+    /// hash maps or ECS queries still get the same ids every run. Slot-map ids and the LRU caches
+    /// are otherwise deterministic for a given load order. Off by default, since comparing paths
+    /// makes queueing slower.
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    fn queue_request(&mut self, request: AssetLoadRequest) {
+        let priority = request.priority;
+
+        // Insert based on priority (higher priority first), then path order in deterministic mode
         let insert_pos = self.loading_queue
             .iter()
-            .position(|req| req.priority < priority)
+            .position(|req| {
+                req.priority < priority
+                    || (self.deterministic && req.priority == priority && load_order(&request.path) < load_order(&req.path))
+            })
             .unwrap_or(self.loading_queue.len());
         
         self.loading_queue.insert(insert_pos, request);
     }
 
-    /// Process next item in loading queue
+    /// Process the loading queue, returning the next finished load (call once per frame)
+    ///
+    /// Queued textures and meshes are read and decompressed on the background reader thread, so
+    /// this returns `None` until one is ready; only decoding happens on the calling thread.
     pub fn process_loading_queue(&mut self) -> Option<Result<AssetId, AssetError>> {
-        let request = self.loading_queue.pop_front()?;
-        
-        // Process based on asset type
-        match request.path.asset_type {
-            AssetType::Texture => {
-                match self.load_texture(request.path.path) {
-                    Ok(texture_id) => Some(Ok(AssetId::Texture(texture_id))),
-                    Err(e) => Some(Err(e)),
+        // Hand queued reads to the background reader in priority order
+        while let Some(request) = self.loading_queue.pop_front() {
+            let path = request.path.path.clone();
+            let start = Instant::now();
+            self.telemetry.started(path.clone());
+            match request.path.asset_type {
+                AssetType::Texture => match self.cached_texture(&request.path) {
+                    Some(texture_id) => return Some(self.finish_load(path, Ok(AssetId::Texture(texture_id)), start.elapsed())),
+                    None => {
+                        let retry = request.retry.unwrap_or(self.retry_policy);
+                        self.reader.request(request.path, self.asset_roots.as_slice().into(), retry);
+                    }
+                },
+                AssetType::Mesh => match (self.cached_mesh(&path), check_mesh_extension(&path)) {
+                    (Some(mesh_id), _) => return Some(self.finish_load(path, Ok(AssetId::Mesh(mesh_id)), start.elapsed())),
+                    (None, Err(error)) => return Some(self.finish_load(path, Err(error), start.elapsed())),
+                    (None, Ok(())) => {
+                        let retry = request.retry.unwrap_or(self.retry_policy);
+                        self.reader.request(request.path, self.asset_roots.as_slice().into(), retry);
+                    }
+                },
+                AssetType::Material => {
+                    // TODO: Implement material loading
+                    let error = AssetError::UnsupportedFormat {
+                        format: "Material loading not yet implemented".to_string(),
+                    };
+                    return Some(self.finish_load(path, Err(error), start.elapsed()));
                 }
             }
-            AssetType::Mesh => {
-                // TODO: Implement mesh loading
-                Some(Err(AssetError::UnsupportedFormat { 
-                    format: "Mesh loading not yet implemented".to_string() 
-                }))
-            }
-            AssetType::Material => {
-                // TODO: Implement material loading
-                Some(Err(AssetError::UnsupportedFormat { 
-                    format: "Material loading not yet implemented".to_string() 
-                }))
-            }
         }
+
+        let (asset_path, bytes, read_time) = self.reader.poll_finished()?;
+        let path = asset_path.path.clone();
+        let decode_start = Instant::now();
+        let asset_id = match (asset_path.asset_type, bytes) {
+            (AssetType::Mesh, Some(bytes)) => bytes.and_then(|bytes| self.decode_mesh(asset_path.path, &bytes)).map(AssetId::Mesh),
+            (AssetType::Mesh, None) => Err(AssetError::NotFound { path: asset_path.path }), // Missing meshes are an error, not a placeholder
+            (_, Some(bytes)) => bytes
+                .and_then(|bytes| self.decode_texture(asset_path.path, &bytes, SamplerConfig::default()))
+                .map(AssetId::Texture),
+            (_, None) => Ok(AssetId::Texture(self.insert_placeholder_texture(asset_path, SamplerConfig::default()))),
+        };
+        Some(self.finish_load(path, asset_id, read_time + decode_start.elapsed()))
+    }
+
+    /// Record a queued load's outcome for telemetry and pass it on
+    fn finish_load(&mut self, path: PathBuf, result: Result<AssetId, AssetError>, duration: Duration) -> Result<AssetId, AssetError> {
+        self.telemetry.finished(path, &result, duration);
+        result
+    }
+
+    /// Take the load records not yet sent as events (see `asset_load_events_system`)
+    pub fn take_load_events(&mut self) -> Vec<AssetLoadEvent> {
+        self.telemetry.take()
+    }
+
+    /// Number of queued loads not yet returned by `process_loading_queue`
+    pub fn pending_loads(&self) -> usize {
+        self.loading_queue.len() + self.reader.pending_reads()
     }
 
     /// Get texture by ID
@@ -223,8 +695,85 @@ impl AssetManager {
         self.textures.get(texture_id)
     }
 
-    /// Release an asset (decrements usage count)
-    pub fn release_texture(&mut self, texture_id: TextureId) {
+    /// Iterate loaded textures
+    pub fn iter_textures(&self) -> impl Iterator<Item = (TextureId, &ManagedTexture)> {
+        self.textures.iter()
+    }
+
+    /// Iterate loaded meshes
+    pub fn iter_meshes(&self) -> impl Iterator<Item = (MeshId, &ManagedMesh)> {
+        self.meshes.iter()
+    }
+
+    /// Iterate loaded materials
+    pub fn iter_materials(&self) -> impl Iterator<Item = (MaterialId, &ManagedMaterial)> {
+        self.materials.iter()
+    }
+
+    /// Find a loaded asset by source path (searches textures, then meshes, then materials)
+    pub fn find_by_path(&self, path: &Path) -> Option<AssetId> {
+        let texture = self.iter_textures()
+            .find(|(_, texture)| texture.path == path)
+            .map(|(id, _)| AssetId::Texture(id));
+        let mesh = || self.iter_meshes()
+            .find(|(_, mesh)| mesh.path == path)
+            .map(|(id, _)| AssetId::Mesh(id));
+        let material = || self.iter_materials()
+            .find(|(_, material)| material.path == path)
+            .map(|(id, _)| AssetId::Material(id));
+
+        texture.or_else(mesh).or_else(material)
+    }
+
+    /// Counts of loaded assets and pending work
+    pub fn stats(&self) -> AssetStats {
+        AssetStats {
+            textures: self.textures.len(),
+            meshes: self.meshes.len(),
+            materials: self.materials.len(),
+            cached_paths: self.texture_cache.len() + self.mesh_cache.len() + self.material_cache.len(),
+            cached_textures: self.texture_cache.len(),
+            cached_meshes: self.mesh_cache.len(),
+            cached_materials: self.material_cache.len(),
+            pending_loads: self.pending_loads(),
+            texture_bytes: self.textures.values()
+                .filter_map(|texture| texture.image.as_ref())
+                .map(|image| image.data.len())
+                .sum(),
+            pending_uploads: self.uploads.len(),
+        }
+    }
+
+    /// Remove assets whose usage count has dropped to zero, returning how many were freed
+    pub fn collect_garbage(&mut self) -> usize {
+        let in_use = |usage_count: &AtomicU32| usage_count.load(Ordering::Relaxed) > 0;
+        let before = self.textures.len() + self.meshes.len() + self.materials.len();
+
+        self.textures.retain(|_, texture| in_use(&texture.usage_count));
+        self.meshes.retain(|_, mesh| in_use(&mesh.usage_count));
+        self.materials.retain(|_, material| in_use(&material.usage_count));
+
+        // Cache entries for freed assets would hand out dead ids
+        drop_stale_entries(&mut self.texture_cache, &self.textures);
+        drop_stale_entries(&mut self.mesh_cache, &self.meshes);
+        drop_stale_entries(&mut self.material_cache, &self.materials);
+
+        let (textures, meshes, materials) = (&self.textures, &self.meshes, &self.materials);
+        self.uploads.retain(|upload| match upload.asset {
+            AssetId::Texture(id) => textures.contains_key(id),
+            AssetId::Mesh(id) => meshes.contains_key(id),
+            AssetId::Material(id) => materials.contains_key(id),
+        });
+
+        self.resident_gpu_bytes = self.textures.values().map(ManagedTexture::gpu_bytes).sum::<u64>()
+            + self.meshes.values().map(|mesh| mesh.gpu_bytes).sum::<u64>();
+        self.check_gpu_pressure();
+
+        before - (self.textures.len() + self.meshes.len() + self.materials.len())
+    }
+
+    /// Release an asset (decrements usage count; freed by `collect_garbage` once unused)
+    pub fn release_texture(&self, texture_id: TextureId) {
         if let Some(texture) = self.textures.get(texture_id) {
             let usage = texture.usage_count.fetch_sub(1, Ordering::Relaxed);
             
@@ -236,6 +785,163 @@ impl AssetManager {
     }
 }
 
+impl Default for SamplerConfig {
+    /// Tiling, linearly filtered - suits world textures
+    fn default() -> Self {
+        Self::repeat()
+    }
+}
+
+impl SamplerConfig {
+    /// Tile in both directions (terrain, walls)
+    pub fn repeat() -> Self {
+        Self::with_address_mode(AddressMode::Repeat)
+    }
+
+    /// Clamp to the edge texel (UI sprites, atlases)
+    pub fn clamp() -> Self {
+        Self::with_address_mode(AddressMode::ClampToEdge)
+    }
+
+    /// Tile, flipping every other repeat
+    pub fn mirror() -> Self {
+        Self::with_address_mode(AddressMode::MirrorRepeat)
+    }
+
+    fn with_address_mode(address_mode: AddressMode) -> Self {
+        Self {
+            address_u: address_mode,
+            address_v: address_mode,
+            filter: FilterMode::Linear,
+            mipmap_filter: FilterMode::Linear,
+        }
+    }
+
+    /// Use nearest-neighbour filtering (pixel art)
+    pub fn nearest(self) -> Self {
+        Self {
+            filter: FilterMode::Nearest,
+            mipmap_filter: FilterMode::Nearest,
+            ..self
+        }
+    }
+
+    /// Descriptor the renderer creates the sampler from
+    pub fn descriptor(&self) -> SamplerDescriptor<'static> {
+        SamplerDescriptor {
+            address_mode_u: self.address_u,
+            address_mode_v: self.address_v,
+            mag_filter: self.filter,
+            min_filter: self.filter,
+            mipmap_filter: self.mipmap_filter,
+            ..default()
+        }
+    }
+
+    /// Sampler for a Bevy `Image`
+    pub fn image_sampler(&self) -> ImageSampler {
+        ImageSampler::Descriptor(self.descriptor().into())
+    }
+}
+
+impl ManagedTexture {
+    /// Estimated VRAM including the mip chain (placeholders share one texture and count as zero)
+    pub fn gpu_bytes(&self) -> u64 {
+        if self.image.is_none() {
+            return 0;
+        }
+        texture_gpu_bytes(self.size, self.format, self.mip_levels)
+    }
+}
+
+/// Bytes a 2D texture occupies on the GPU, handling block-compressed formats
+pub fn texture_gpu_bytes(size: (u32, u32), format: TextureFormat, mip_levels: u32) -> u64 {
+    let (block_width, block_height) = format.block_dimensions();
+    let block_bytes = format.block_size(None).unwrap_or(4) as u64; // Depth-stencil has no single size
+    (0..mip_levels.max(1))
+        .map(|level| {
+            let width = (size.0 >> level).max(1);
+            let height = (size.1 >> level).max(1);
+            width.div_ceil(block_width) as u64 * height.div_ceil(block_height) as u64 * block_bytes
+        })
+        .sum()
+}
+
+/// Sort key for deterministic loading
+fn load_order(path: &AssetPath) -> (&Path, u8) {
+    (&path.path, path.asset_type as u8)
+}
+
+/// Remove cache entries whose asset no longer exists
+fn drop_stale_entries<T>(cache: &mut LruCache<PathBuf, DefaultKey>, assets: &SlotMap<DefaultKey, T>) {
+    let stale: Vec<PathBuf> = cache
+        .iter()
+        .filter(|(_, id)| !assets.contains_key(**id))
+        .map(|(path, _)| path.clone())
+        .collect();
+    for path in stale {
+        cache.pop(&path);
+    }
+}
+
+/// Decode encoded image bytes, picking the format from the path's extension
+pub(crate) fn decode_image(path: &Path, bytes: &[u8]) -> Result<Image, AssetError> {
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+    Image::from_buffer(
+        bytes,
+        ImageType::Extension(extension),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+    )
+    .map_err(|error| AssetError::LoadingFailed { reason: error.to_string() })
+}
+
+/// Downscale an image so neither side exceeds `max_dimension` (aspect preserved)
+pub(crate) fn fit_image_to_limit(path: &Path, image: Image, max_dimension: u32) -> Result<Image, AssetError> {
+    let (width, height) = (image.width(), image.height());
+    let (fitted_width, fitted_height) = fit_texture_size((width, height), max_dimension);
+    if (fitted_width, fitted_height) == (width, height) {
+        return Ok(image);
+    }
+
+    tracing::warn!(
+        "Texture {} is {}x{}, above the {}px limit; downscaling to {}x{}",
+        path.display(), width, height, max_dimension, fitted_width, fitted_height
+    );
+
+    let is_srgb = image.texture_descriptor.format.is_srgb();
+    let dynamic = image
+        .try_into_dynamic()
+        .map_err(|error| AssetError::LoadingFailed { reason: error.to_string() })?;
+    Ok(Image::from_dynamic(dynamic.thumbnail_exact(fitted_width, fitted_height), is_srgb))
+}
+
+/// `MINDLAND_ASSET_ROOT`, else `assets/` next to the executable, else `assets/` in the working directory
+fn default_asset_root() -> PathBuf {
+    if let Some(root) = std::env::var_os("MINDLAND_ASSET_ROOT") {
+        return PathBuf::from(root);
+    }
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join("assets")))
+        .filter(|root| root.is_dir())
+        .unwrap_or_else(|| PathBuf::from("assets"))
+}
+
+/// Largest size with the same aspect ratio where neither side exceeds `max_dimension`
+pub fn fit_texture_size(size: (u32, u32), max_dimension: u32) -> (u32, u32) {
+    let (width, height) = size;
+    let largest = width.max(height);
+    if largest <= max_dimension || max_dimension == 0 {
+        return size;
+    }
+
+    let scale = max_dimension as f64 / largest as f64;
+    let fit = |side: u32| ((side as f64 * scale).round() as u32).clamp(1, max_dimension);
+    (fit(width), fit(height))
+}
+
 impl BoundingBox {
     /// Create a new bounding box
     pub fn new(min: Vec3, max: Vec3) -> Self {
@@ -258,4 +964,19 @@ impl BoundingBox {
     pub fn size(&self) -> Vec3 {
         self.max - self.min
     }
-}
\ No newline at end of file
+}
+
+impl BoundingSphere {
+    /// Create a new bounding sphere
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Smallest sphere enclosing a bounding box
+    pub fn from_bounding_box(bounding_box: &BoundingBox) -> Self {
+        Self {
+            center: bounding_box.center(),
+            radius: bounding_box.size().length() * 0.5,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Mesh file decoding
+//!
+//! OBJ (`.obj`) and glTF (`.gltf`, `.glb`) files decode into a single triangle-list `Mesh`: every
+//! OBJ object and every glTF triangle primitive is merged, in file order. glTF node transforms,
+//! materials and skins are ignored; positions stay in mesh space.
+
+use crate::AssetError;
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+use std::path::{Path, PathBuf};
+
+/// File extensions `decode_mesh` understands
+pub const MESH_EXTENSIONS: [&str; 3] = ["obj", "gltf", "glb"];
+
+/// Vertex streams gathered from the parts of a mesh file
+#[derive(Default)]
+struct MeshParts {
+    positions: Vec<[f32; 3]>,
+    normals: Option<Vec<[f32; 3]>>, // Kept only if every part has normals
+    uvs: Option<Vec<[f32; 2]>>,     // Kept only if every part has texture coordinates
+    indices: Vec<u32>,
+    parts: usize,
+}
+
+impl MeshParts {
+    /// Append one part, offsetting its indices past the vertices already added
+    fn push(&mut self, positions: Vec<[f32; 3]>, normals: Option<Vec<[f32; 3]>>, uvs: Option<Vec<[f32; 2]>>, indices: Vec<u32>) {
+        let first = self.parts == 0;
+        let offset = self.positions.len() as u32;
+        self.normals = match (self.normals.take(), normals) {
+            (Some(mut all), Some(part)) => Some({ all.extend(part); all }),
+            (None, Some(part)) if first => Some(part),
+            _ => None,
+        };
+        self.uvs = match (self.uvs.take(), uvs) {
+            (Some(mut all), Some(part)) => Some({ all.extend(part); all }),
+            (None, Some(part)) if first => Some(part),
+            _ => None,
+        };
+        self.positions.extend(positions);
+        self.indices.extend(indices.into_iter().map(|index| index + offset));
+        self.parts += 1;
+    }
+
+    fn into_mesh(self) -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.positions);
+        if let Some(normals) = self.normals {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        }
+        if let Some(uvs) = self.uvs {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        }
+        mesh.set_indices(Some(Indices::U32(self.indices)));
+        mesh
+    }
+}
+
+/// Lower-case extension of a mesh path ("" if it has none)
+pub(crate) fn mesh_extension(path: &Path) -> String {
+    path.extension().and_then(|extension| extension.to_str()).unwrap_or_default().to_ascii_lowercase()
+}
+
+/// Reject paths whose extension isn't one of `MESH_EXTENSIONS`
+pub(crate) fn check_mesh_extension(path: &Path) -> Result<(), AssetError> {
+    let extension = mesh_extension(path);
+    if !MESH_EXTENSIONS.contains(&extension.as_str()) {
+        return Err(AssetError::UnsupportedFormat { format: extension });
+    }
+    Ok(())
+}
+
+/// Decode a mesh file, picking the format from the path's extension
+///
+/// `read_buffer` reads files a glTF references (resolved next to `path`).
+pub(crate) fn decode_mesh(
+    path: &Path,
+    bytes: &[u8],
+    read_buffer: impl Fn(&Path) -> Option<Result<Vec<u8>, AssetError>>,
+) -> Result<Mesh, AssetError> {
+    match mesh_extension(path).as_str() {
+        "obj" => decode_obj(bytes),
+        "gltf" | "glb" => decode_gltf(path, bytes, read_buffer),
+        format => Err(AssetError::UnsupportedFormat { format: format.to_string() }),
+    }
+}
+
+fn loading_failed(path: &Path, reason: impl std::fmt::Display) -> AssetError {
+    AssetError::LoadingFailed { reason: format!("{}: {}", path.display(), reason) }
+}
+
+fn decode_obj(bytes: &[u8]) -> Result<Mesh, AssetError> {
+    // Materials aren't used, so `mtllib` references are never opened
+    let (models, _) = tobj::load_obj_buf(&mut &*bytes, &tobj::GPU_LOAD_OPTIONS, |_| Err(tobj::LoadError::OpenFileFailed))
+        .map_err(|error| AssetError::LoadingFailed { reason: format!("OBJ: {error}") })?;
+
+    let mut parts = MeshParts::default();
+    for model in models {
+        let mesh = model.mesh;
+        let vertex_count = mesh.positions.len() / 3;
+        let positions = mesh.positions.chunks_exact(3).map(|p| [p[0], p[1], p[2]]).collect();
+        let normals = (mesh.normals.len() == vertex_count * 3).then(|| mesh.normals.chunks_exact(3).map(|n| [n[0], n[1], n[2]]).collect());
+        let uvs = (mesh.texcoords.len() == vertex_count * 2).then(|| mesh.texcoords.chunks_exact(2).map(|t| [t[0], 1.0 - t[1]]).collect()); // OBJ's v points up
+        parts.push(positions, normals, uvs, mesh.indices);
+    }
+    if parts.indices.is_empty() {
+        return Err(AssetError::LoadingFailed { reason: "OBJ: no faces".to_string() });
+    }
+    Ok(parts.into_mesh())
+}
+
+fn decode_gltf(
+    path: &Path,
+    bytes: &[u8],
+    read_buffer: impl Fn(&Path) -> Option<Result<Vec<u8>, AssetError>>,
+) -> Result<Mesh, AssetError> {
+    let gltf = gltf::Gltf::from_slice(bytes).map_err(|error| loading_failed(path, error))?;
+    let buffers = gltf
+        .buffers()
+        .map(|buffer| match buffer.source() {
+            gltf::buffer::Source::Bin => gltf.blob.clone().ok_or_else(|| loading_failed(path, "missing GLB binary chunk")),
+            gltf::buffer::Source::Uri(uri) if uri.starts_with("data:") => Err(loading_failed(path, "embedded data URIs aren't supported")),
+            gltf::buffer::Source::Uri(uri) => {
+                let buffer_path = path.parent().map_or_else(|| PathBuf::from(uri), |parent| parent.join(uri));
+                read_buffer(&buffer_path).unwrap_or(Err(AssetError::NotFound { path: buffer_path }))
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut parts = MeshParts::default();
+    for primitive in gltf.meshes().flat_map(|mesh| mesh.primitives()) {
+        if primitive.mode() != gltf::mesh::Mode::Triangles {
+            continue;
+        }
+        let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(Vec::as_slice));
+        let Some(positions) = reader.read_positions() else { continue };
+        let positions: Vec<[f32; 3]> = positions.collect();
+        let indices = match reader.read_indices() {
+            Some(indices) => indices.into_u32().collect(),
+            None => (0..positions.len() as u32).collect(),
+        };
+        let normals = reader.read_normals().map(Iterator::collect);
+        let uvs = reader.read_tex_coords(0).map(|uvs| uvs.into_f32().collect());
+        parts.push(positions, normals, uvs, indices);
+    }
+    if parts.parts == 0 {
+        return Err(loading_failed(path, "no triangle primitives"));
+    }
+    Ok(parts.into_mesh())
+}
@@ -0,0 +1,65 @@
+//! Load retries
+//!
+//! Network drives and files locked mid-save during hot-reload fail transiently. Reads that fail
+//! with `AssetError::LoadingFailed` are retried with exponential backoff before the error is
+//! surfaced; `NotFound` and format errors are permanent and returned immediately.
+
+use crate::AssetError;
+use std::time::Duration;
+
+/// How often and how patiently a failed load is retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,          // Attempts after the first; 0 disables retrying
+    pub initial_backoff: Duration, // Wait before the first retry, doubled for each one after
+    pub max_backoff: Duration,     // Cap on a single wait
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Fail on the first error
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Wait before retry number `retry` (0-based)
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry).unwrap_or(u32::MAX);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+
+    /// Whether an error may go away on its own
+    pub fn is_retryable(error: &AssetError) -> bool {
+        matches!(error, AssetError::LoadingFailed { .. })
+    }
+
+    /// Run `load`, sleeping and retrying on retryable errors until it succeeds or the budget runs out
+    ///
+    /// Blocks the calling thread while backing off; the background reader calls this off the main thread.
+    pub fn retry<T>(&self, mut load: impl FnMut() -> Result<T, AssetError>) -> Result<T, AssetError> {
+        let mut retry = 0;
+        loop {
+            match load() {
+                Err(error) if retry < self.max_retries && Self::is_retryable(&error) => {
+                    let backoff = self.backoff(retry);
+                    tracing::warn!("{}; retrying in {:?} ({}/{})", error, backoff, retry + 1, self.max_retries);
+                    std::thread::sleep(backoff);
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
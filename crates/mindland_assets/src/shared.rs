@@ -0,0 +1,146 @@
+//! Thread-safe asset manager handle
+//!
+//! Shares one `AssetManager` between loader threads and gameplay systems.
+//!
+//! # Locking discipline
+//!
+//! - Every method takes the lock for its own duration only; none calls back into another method
+//!   while holding it. `load_texture` does its reading and decoding with no lock held and takes the
+//!   write lock only to insert the result.
+//! - Guards from `read`/`write` must not be held across calls to other `SharedAssetManager` methods.
+//!   The lock is not reentrant, so a `read()` guard held while `collect_garbage` (or a load that
+//!   inserts) waits for the write lock deadlocks the thread.
+//! - Usage counts are atomic, so handing out and releasing ids needs only the read lock.
+//!   `collect_garbage` takes the write lock, so it cannot free a texture while a load is returning it.
+
+use crate::{
+    decode_image, fit_image_to_limit, read_from_roots, AssetError, AssetId, AssetLoadEvent, AssetManager, AssetPath,
+    AssetRoot, AssetStats, AssetType, LoadPriority, ManagedTexture, RetryPolicy, SamplerConfig, TextureId, UploadChunk,
+};
+use bevy::prelude::*;
+use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Cloneable, `Sync` handle to a shared `AssetManager`
+#[derive(Clone, Default, Resource)]
+pub struct SharedAssetManager {
+    inner: Arc<RwLock<AssetManager>>,
+}
+
+impl From<AssetManager> for SharedAssetManager {
+    fn from(manager: AssetManager) -> Self {
+        Self::new(manager)
+    }
+}
+
+impl SharedAssetManager {
+    /// Share an existing manager
+    pub fn new(manager: AssetManager) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(manager)),
+        }
+    }
+
+    /// Shared access (see the module docs before holding this across other calls)
+    pub fn read(&self) -> RwLockReadGuard<'_, AssetManager> {
+        self.inner.read()
+    }
+
+    /// Exclusive access (see the module docs before holding this across other calls)
+    pub fn write(&self) -> RwLockWriteGuard<'_, AssetManager> {
+        self.inner.write()
+    }
+
+    /// Load a texture, reading and decoding it without blocking other users of the manager
+    pub fn load_texture(&self, path: PathBuf) -> Result<TextureId, AssetError> {
+        self.load_texture_with(path, SamplerConfig::default())
+    }
+
+    /// Load a texture with explicit sampling, without blocking other users of the manager
+    pub fn load_texture_with(&self, path: PathBuf, sampler: SamplerConfig) -> Result<TextureId, AssetError> {
+        let asset_path = AssetPath {
+            path: path.clone(),
+            asset_type: AssetType::Texture,
+        };
+
+        let (roots, max_dimension): (Arc<[AssetRoot]>, u32) = {
+            let manager = self.inner.read();
+            if let Some(texture_id) = manager.peek_cached_texture(&asset_path) {
+                return Ok(texture_id);
+            }
+            (manager.asset_roots.as_slice().into(), manager.max_texture_dimension)
+        };
+
+        // Unlocked: file/archive reads, decoding and downscaling
+        let image = match read_from_roots(&roots, &path) {
+            Some(bytes) => Some(fit_image_to_limit(&path, decode_image(&path, &bytes?)?, max_dimension)?),
+            None => None,
+        };
+
+        let mut manager = self.inner.write();
+        // Another thread may have finished the same texture while this one was decoding
+        if let Some(texture_id) = manager.cached_texture(&asset_path) {
+            return Ok(texture_id);
+        }
+        match image {
+            Some(image) => manager.load_texture_image_with(path, image, sampler),
+            None => Ok(manager.insert_placeholder_texture(asset_path, sampler)),
+        }
+    }
+
+    /// Queue an asset for the background reader
+    pub fn queue_load(&self, path: AssetPath, priority: LoadPriority) {
+        self.inner.write().queue_load(path, priority);
+    }
+
+    /// Queue an asset for the background reader with its own retry policy
+    pub fn queue_load_with_retry(&self, path: AssetPath, priority: LoadPriority, retry: RetryPolicy) {
+        self.inner.write().queue_load_with_retry(path, priority, retry);
+    }
+
+    /// Queue every asset in a manifest, returning how many were queued
+    pub fn preload_manifest(&self, manifest: impl IntoIterator<Item = AssetPath>, priority: LoadPriority) -> usize {
+        self.inner.write().preload_manifest(manifest, priority)
+    }
+
+    /// Dispatch queued loads and return the next finished one
+    pub fn process_loading_queue(&self) -> Option<Result<AssetId, AssetError>> {
+        self.inner.write().process_loading_queue()
+    }
+
+    /// Take the load records not yet sent as events
+    pub fn take_load_events(&self) -> Vec<AssetLoadEvent> {
+        self.inner.write().take_load_events()
+    }
+
+    /// Hand out this frame's uploads within the upload budget
+    pub fn schedule_uploads(&self) -> Vec<UploadChunk> {
+        self.inner.write().schedule_uploads()
+    }
+
+    /// Whether an asset's GPU data isn't fully submitted yet
+    pub fn is_upload_pending(&self, asset: &AssetId) -> bool {
+        self.inner.read().is_upload_pending(asset)
+    }
+
+    /// Run a closure against a loaded texture under the read lock
+    pub fn with_texture<R>(&self, texture_id: TextureId, f: impl FnOnce(&ManagedTexture) -> R) -> Option<R> {
+        self.inner.read().get_texture(texture_id).map(f)
+    }
+
+    /// Release a texture (freed by the next `collect_garbage` once unused)
+    pub fn release_texture(&self, texture_id: TextureId) {
+        self.inner.read().release_texture(texture_id);
+    }
+
+    /// Occupancy counts
+    pub fn stats(&self) -> AssetStats {
+        self.inner.read().stats()
+    }
+
+    /// Free unused assets, waiting for in-flight readers to finish
+    pub fn collect_garbage(&self) -> usize {
+        self.inner.write().collect_garbage()
+    }
+}
@@ -0,0 +1,88 @@
+//! Asset load telemetry
+//!
+//! `AssetManager` records when each queued load starts and how it ends, and
+//! `asset_load_events_system` forwards the records as Bevy events, for loading screens and logs.
+
+use crate::{AssetError, AssetId, SharedAssetManager};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Records kept while nothing drains them; the oldest are dropped beyond this
+pub const MAX_PENDING_LOAD_EVENTS: usize = 1024;
+
+/// A queued load was picked up (sent to the background reader, or served from cache)
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AssetLoadStarted {
+    pub path: PathBuf,
+}
+
+/// A queued load finished
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AssetLoadCompleted {
+    pub id: AssetId,
+    pub duration: Duration, // Reading (with any retries) plus decoding; not time spent waiting in the queue
+}
+
+/// A queued load gave up
+#[derive(Event, Debug, Clone)]
+pub struct AssetLoadFailed {
+    pub path: PathBuf,
+    pub error: AssetError,
+}
+
+/// A load record waiting to be sent as an event
+#[derive(Debug, Clone)]
+pub enum AssetLoadEvent {
+    Started(AssetLoadStarted),
+    Completed(AssetLoadCompleted),
+    Failed(AssetLoadFailed),
+}
+
+/// Bounded buffer of load records
+#[derive(Debug, Default)]
+pub(crate) struct LoadTelemetry {
+    events: VecDeque<AssetLoadEvent>,
+}
+
+impl LoadTelemetry {
+    pub(crate) fn started(&mut self, path: PathBuf) {
+        self.push(AssetLoadEvent::Started(AssetLoadStarted { path }));
+    }
+
+    /// Record how a load ended
+    pub(crate) fn finished(&mut self, path: PathBuf, result: &Result<AssetId, AssetError>, duration: Duration) {
+        self.push(match result {
+            Ok(id) => AssetLoadEvent::Completed(AssetLoadCompleted { id: id.clone(), duration }),
+            Err(error) => AssetLoadEvent::Failed(AssetLoadFailed { path, error: error.clone() }),
+        });
+    }
+
+    pub(crate) fn take(&mut self) -> Vec<AssetLoadEvent> {
+        self.events.drain(..).collect()
+    }
+
+    fn push(&mut self, event: AssetLoadEvent) {
+        if self.events.len() == MAX_PENDING_LOAD_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+}
+
+/// Send load records from the shared asset manager as events
+pub fn asset_load_events_system(
+    assets: Res<SharedAssetManager>,
+    mut started: EventWriter<AssetLoadStarted>,
+    mut completed: EventWriter<AssetLoadCompleted>,
+    mut failed: EventWriter<AssetLoadFailed>,
+) {
+    for event in assets.take_load_events() {
+        match event {
+            AssetLoadEvent::Started(event) => started.send(event),
+            AssetLoadEvent::Completed(event) => completed.send(event),
+            AssetLoadEvent::Failed(event) => failed.send(event),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Tests for loading assets from zip/pak archive roots
+//!
+//! **Feature: asset-archives, Property 1: Archive Roots Load Transparently By Priority**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::{AssetArchive, AssetId, AssetManager, AssetPath, AssetType, LoadPriority};
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use zip::{write::FileOptions, ZipWriter};
+
+/// Encode a solid PNG of the given size
+fn png_bytes(size: u32) -> Vec<u8> {
+    let mut bytes = Cursor::new(Vec::new());
+    Image::new(
+        Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![120; (size * size * 4) as usize],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+    .try_into_dynamic()
+    .unwrap()
+    .write_to(&mut bytes, bevy::render::texture::ImageFormat::Png.as_image_crate_format().unwrap())
+    .unwrap();
+    bytes.into_inner()
+}
+
+/// Build a deflated zip archive in memory
+fn zip_bytes(entries: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, bytes) in entries {
+        zip.start_file(*name, FileOptions::default()).unwrap();
+        zip.write_all(bytes).unwrap();
+    }
+    zip.finish().unwrap().into_inner()
+}
+
+fn texture_size(assets: &AssetManager, path: &str) -> (u32, u32) {
+    match assets.find_by_path(Path::new(path)) {
+        Some(AssetId::Texture(id)) => assets.get_texture(id).unwrap().size,
+        other => panic!("expected texture for {path}, got {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod archive_tests {
+    use super::*;
+
+    #[test]
+    fn test_load_texture_from_in_memory_zip() {
+        // **Feature: asset-archives, Property 1: Archive Roots Load Transparently By Priority**
+
+        let archive = AssetArchive::from_bytes("base.pak", zip_bytes(&[("blocks/stone.png", png_bytes(16))])).unwrap();
+        assert!(archive.contains(Path::new("blocks/stone.png")));
+        assert!(!archive.contains(Path::new("blocks/dirt.png")));
+
+        let mut assets = AssetManager::new();
+        assets.set_root(archive);
+
+        assert_eq!(assets.asset_root(), Some(Path::new("base.pak")));
+        assert_eq!(
+            assets.resolve(Path::new("blocks/stone.png")),
+            Some(PathBuf::from("base.pak/blocks/stone.png"))
+        );
+
+        let stone = assets.load_texture(PathBuf::from("blocks/stone.png")).unwrap();
+        let texture = assets.get_texture(stone).unwrap();
+        assert_eq!(texture.size, (16, 16));
+        assert!(texture.image.is_some());
+    }
+
+    #[test]
+    fn test_archive_and_directory_roots_respect_priority() {
+        // **Feature: asset-archives, Property 1: Archive Roots Load Transparently By Priority**
+
+        let base = std::env::temp_dir().join(format!("mindland-archive-base-{}", std::process::id()));
+        std::fs::create_dir_all(base.join("blocks")).unwrap();
+        std::fs::write(base.join("blocks/stone.png"), png_bytes(8)).unwrap();
+        std::fs::write(base.join("blocks/dirt.png"), png_bytes(8)).unwrap();
+
+        let pack = zip_bytes(&[("blocks/stone.png", png_bytes(32))]);
+        let mut assets = AssetManager::new();
+        assets.set_root(&base);
+        assets.add_root(AssetArchive::from_bytes("pack.zip", pack).unwrap());
+
+        assets.load_texture(PathBuf::from("blocks/stone.png")).unwrap();
+        assets.load_texture(PathBuf::from("blocks/dirt.png")).unwrap();
+        assert_eq!(texture_size(&assets, "blocks/stone.png"), (32, 32)); // Archive shadows the base
+        assert_eq!(texture_size(&assets, "blocks/dirt.png"), (8, 8)); // Falls through to the directory
+
+        std::fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn test_queued_load_reads_archive_in_background() {
+        // **Feature: asset-archives, Property 1: Archive Roots Load Transparently By Priority**
+
+        let mut assets = AssetManager::new();
+        assets.set_root(AssetArchive::from_bytes("base.pak", zip_bytes(&[("blocks/stone.png", png_bytes(16))])).unwrap());
+        assets.queue_load(
+            AssetPath { path: PathBuf::from("blocks/stone.png"), asset_type: AssetType::Texture },
+            LoadPriority::High,
+        );
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let loaded = loop {
+            if let Some(result) = assets.process_loading_queue() {
+                break result.unwrap();
+            }
+            assert!(Instant::now() < deadline, "background read never finished");
+            std::thread::sleep(Duration::from_millis(1));
+        };
+
+        assert_eq!(assets.pending_loads(), 0);
+        assert_eq!(assets.find_by_path(Path::new("blocks/stone.png")), Some(loaded));
+        assert_eq!(texture_size(&assets, "blocks/stone.png"), (16, 16));
+    }
+
+    #[test]
+    fn test_invalid_archive_is_rejected() {
+        assert!(AssetArchive::from_bytes("broken.pak", b"not a zip".to_vec()).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Tests for the per-type asset caches
+//!
+//! **Feature: asset-cache, Property 1: Asset Types Don't Evict Each Other**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::{AssetCacheSizes, AssetManager};
+use std::path::PathBuf;
+
+fn tiny_image() -> Image {
+    Image::new(
+        Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![255; 4],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+#[cfg(test)]
+mod asset_cache_tests {
+    use super::*;
+
+    #[test]
+    fn test_texture_flood_does_not_evict_mesh() {
+        // **Feature: asset-cache, Property 1: Asset Types Don't Evict Each Other**
+
+        let mut assets = AssetManager::with_cache_sizes(AssetCacheSizes { textures: 8, meshes: 2, materials: 2 });
+        let cube = assets.load_mesh_data(PathBuf::from("models/cube.mesh"), Mesh::from(shape::Cube::new(1.0)));
+        assert_eq!(assets.load_mesh(PathBuf::from("models/cube.mesh")).unwrap(), cube);
+
+        for i in 0..64 {
+            assets.load_texture_image(PathBuf::from(format!("blocks/{i}.png")), tiny_image()).unwrap();
+        }
+
+        assert_eq!(assets.load_mesh(PathBuf::from("models/cube.mesh")).unwrap(), cube);
+        let stats = assets.stats();
+        assert_eq!(stats.cached_textures, 8); // Texture cache is full, oldest entries evicted
+        assert_eq!(stats.cached_meshes, 1);
+        assert_eq!(stats.cached_paths, 9);
+    }
+
+    #[test]
+    fn test_each_cache_evicts_its_own_least_recently_used() {
+        // **Feature: asset-cache, Property 1: Asset Types Don't Evict Each Other**
+
+        let mut assets = AssetManager::with_cache_sizes(AssetCacheSizes { textures: 4, meshes: 2, materials: 2 });
+        let mesh = || Mesh::from(shape::Cube::new(1.0));
+        assets.load_mesh_data(PathBuf::from("a.mesh"), mesh());
+        assets.load_mesh_data(PathBuf::from("b.mesh"), mesh());
+        assets.load_mesh(PathBuf::from("a.mesh")).unwrap(); // a is now most recent
+        assets.load_mesh_data(PathBuf::from("c.mesh"), mesh());
+
+        assert!(assets.load_mesh(PathBuf::from("a.mesh")).is_ok());
+        assert!(assets.load_mesh(PathBuf::from("b.mesh")).is_err());
+        assert!(assets.load_mesh(PathBuf::from("c.mesh")).is_ok());
+    }
+
+    #[test]
+    fn test_mesh_data_records_geometry() {
+        let mut assets = AssetManager::new();
+        let cube = assets.load_mesh_data(PathBuf::from("models/cube.mesh"), Mesh::from(shape::Cube::new(2.0)));
+        let managed = &assets.meshes[cube];
+
+        assert_eq!(managed.vertex_count, 24);
+        assert_eq!(managed.index_count, 36);
+        assert_eq!(managed.bounding_box.min, Vec3::splat(-1.0));
+        assert_eq!(managed.bounding_box.max, Vec3::splat(1.0));
+    }
+}
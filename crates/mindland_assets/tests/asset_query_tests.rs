@@ -0,0 +1,57 @@
+//! Tests for read-only asset enumeration
+//!
+//! **Feature: asset-browser, Property 1: Loaded Assets Are Enumerable**
+
+use bevy::prelude::*;
+use mindland_assets::{AssetId, AssetManager, BoundingBox, ManagedMesh};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[cfg(test)]
+mod asset_query_tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_textures_exposes_paths_and_usage() {
+        // **Feature: asset-browser, Property 1: Loaded Assets Are Enumerable**
+
+        let mut assets = AssetManager::new();
+        let grass = assets.load_texture(PathBuf::from("textures/grass.png")).unwrap();
+        assets.load_texture(PathBuf::from("textures/grass.png")).unwrap(); // Cached, bumps usage
+        let stone = assets.load_texture(PathBuf::from("textures/stone.png")).unwrap();
+
+        let mut listed: Vec<_> = assets
+            .iter_textures()
+            .map(|(id, texture)| (id, texture.path.clone(), texture.usage_count.load(Ordering::Relaxed)))
+            .collect();
+        listed.sort_by(|a, b| a.1.cmp(&b.1));
+
+        assert_eq!(listed, vec![
+            (grass, PathBuf::from("textures/grass.png"), 2),
+            (stone, PathBuf::from("textures/stone.png"), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_find_by_path_across_asset_types() {
+        // **Feature: asset-browser, Property 1: Loaded Assets Are Enumerable**
+
+        let mut assets = AssetManager::new();
+        let texture = assets.load_texture(PathBuf::from("textures/grass.png")).unwrap();
+        let mesh = assets.meshes.insert(ManagedMesh {
+            handle: Handle::default(),
+            vertex_count: 24,
+            index_count: 36,
+            bounding_box: BoundingBox::new(Vec3::ZERO, Vec3::ONE),
+            usage_count: AtomicU32::new(1),
+            path: PathBuf::from("meshes/cube.obj"),
+            gpu_bytes: 0,
+        });
+
+        assert_eq!(assets.find_by_path(Path::new("textures/grass.png")), Some(AssetId::Texture(texture)));
+        assert_eq!(assets.find_by_path(Path::new("meshes/cube.obj")), Some(AssetId::Mesh(mesh)));
+        assert_eq!(assets.find_by_path(Path::new("missing.png")), None);
+        assert_eq!(assets.iter_meshes().count(), 1);
+        assert_eq!(assets.iter_materials().count(), 0);
+    }
+}
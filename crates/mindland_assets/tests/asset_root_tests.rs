@@ -0,0 +1,72 @@
+//! Tests for resolving asset paths against prioritized roots
+//!
+//! **Feature: asset-roots, Property 1: Higher-Priority Roots Shadow Lower Ones**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::AssetManager;
+use std::path::{Path, PathBuf};
+
+/// Write a solid PNG of the given size
+fn write_png(path: &Path, size: u32) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    Image::new(
+        Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![200; (size * size * 4) as usize],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+    .try_into_dynamic()
+    .unwrap()
+    .save(path)
+    .unwrap();
+}
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("mindland-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    root
+}
+
+#[cfg(test)]
+mod asset_root_tests {
+    use super::*;
+
+    #[test]
+    fn test_higher_priority_root_shadows_lower_root() {
+        // **Feature: asset-roots, Property 1: Higher-Priority Roots Shadow Lower Ones**
+
+        let base = temp_root("base-assets");
+        let resource_pack = temp_root("resource-pack");
+        write_png(&base.join("blocks/stone.png"), 16);
+        write_png(&base.join("blocks/dirt.png"), 8);
+        write_png(&resource_pack.join("blocks/stone.png"), 32);
+
+        let mut assets = AssetManager::new();
+        assets.set_root(&base);
+        assets.add_root(&resource_pack);
+
+        assert_eq!(assets.asset_root(), Some(base.as_path()));
+        assert_eq!(
+            assets.resolve(Path::new("blocks/stone.png")),
+            Some(resource_pack.join("blocks/stone.png"))
+        );
+
+        let stone = assets.load_texture(PathBuf::from("blocks/stone.png")).unwrap();
+        let dirt = assets.load_texture(PathBuf::from("blocks/dirt.png")).unwrap();
+        assert_eq!(assets.get_texture(stone).unwrap().size, (32, 32));
+        assert_eq!(assets.get_texture(dirt).unwrap().size, (8, 8)); // Falls through to the base root
+
+        std::fs::remove_dir_all(base).unwrap();
+        std::fs::remove_dir_all(resource_pack).unwrap();
+    }
+
+    #[test]
+    fn test_unresolved_path_is_none() {
+        let mut assets = AssetManager::new();
+        assets.set_root(temp_root("empty-assets"));
+        assert_eq!(assets.resolve(Path::new("blocks/missing.png")), None);
+    }
+}
@@ -0,0 +1,77 @@
+//! Tests for reproducible asset id assignment
+//!
+//! **Feature: deterministic-loading, Property 1: The Same Manifest Yields The Same Ids**
+
+use mindland_assets::{AssetId, AssetManager, AssetPath, AssetType, LoadPriority};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+fn texture(path: &str) -> AssetPath {
+    AssetPath { path: PathBuf::from(path), asset_type: AssetType::Texture }
+}
+
+/// Load a manifest to completion, returning (path, id) in the order ids were assigned
+fn load_manifest(manifest: &[&str], deterministic: bool) -> Vec<(PathBuf, AssetId)> {
+    let mut manager = AssetManager::new();
+    manager.set_root(std::env::temp_dir().join("mindland-deterministic-load-tests")); // Missing files load as placeholders
+    manager.set_deterministic(deterministic);
+    assert_eq!(manager.preload_manifest(manifest.iter().map(|path| texture(path)), LoadPriority::Normal), manifest.len());
+
+    let mut loaded = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while manager.pending_loads() > 0 {
+        assert!(Instant::now() < deadline, "loads never finished");
+        match manager.process_loading_queue() {
+            Some(result) => {
+                let id = result.expect("placeholder load failed");
+                let AssetId::Texture(texture_id) = id else { unreachable!() };
+                loaded.push((manager.get_texture(texture_id).unwrap().path.clone(), id));
+            }
+            None => std::thread::sleep(Duration::from_millis(1)),
+        }
+    }
+    loaded
+}
+
+#[cfg(test)]
+mod deterministic_load_tests {
+    use super::*;
+
+    #[test]
+    fn test_same_manifest_assigns_same_ids() {
+        // **Feature: deterministic-loading, Property 1: The Same Manifest Yields The Same Ids**
+
+        let manifest = ["stone.png", "dirt.png", "grass.png", "blocks/ore.png", "water.png"];
+        let mut shuffled = manifest;
+        shuffled.reverse(); // Same manifest, listed in a different order
+
+        let first = load_manifest(&manifest, true);
+        let second = load_manifest(&shuffled, true);
+        assert_eq!(first, second);
+
+        let paths: Vec<&PathBuf> = first.iter().map(|(path, _)| path).collect();
+        let mut sorted = paths.clone();
+        sorted.sort();
+        assert_eq!(paths, sorted, "ids should be assigned in path order");
+    }
+
+    #[test]
+    fn test_default_mode_keeps_queue_order() {
+        let manifest = ["stone.png", "dirt.png", "grass.png"];
+        let loaded: Vec<PathBuf> = load_manifest(&manifest, false).into_iter().map(|(path, _)| path).collect();
+        assert_eq!(loaded, manifest.map(PathBuf::from));
+    }
+
+    #[test]
+    fn test_priority_still_wins_over_path_order() {
+        let mut manager = AssetManager::new();
+        manager.set_deterministic(true);
+        manager.queue_load(texture("b.png"), LoadPriority::Normal);
+        manager.queue_load(texture("a.png"), LoadPriority::Low);
+        manager.queue_load(texture("c.png"), LoadPriority::High);
+        manager.queue_load(texture("a.png"), LoadPriority::Normal);
+
+        let queued: Vec<&str> = manager.loading_queue.iter().map(|request| request.path.path.to_str().unwrap()).collect();
+        assert_eq!(queued, ["c.png", "a.png", "b.png", "a.png"]);
+    }
+}
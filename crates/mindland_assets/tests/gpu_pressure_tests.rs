@@ -0,0 +1,81 @@
+//! Tests for GPU memory estimation and pressure callbacks
+//!
+//! **Feature: gpu-pressure, Property 1: Pressure Callback Fires Once Per Crossing**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::{texture_gpu_bytes, AssetManager};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// 64x64 RGBA8 texture: 16 KiB
+fn texture_64() -> Image {
+    Image::new(
+        Extent3d { width: 64, height: 64, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![0; 64 * 64 * 4],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+/// Manager with a pressure handler recording every call
+fn watched_manager(threshold: u64) -> (AssetManager, Arc<Mutex<Vec<u64>>>) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut assets = AssetManager::new();
+    let recorded = calls.clone();
+    assets.set_gpu_pressure_handler(threshold, Box::new(move |usage| recorded.lock().unwrap().push(usage)));
+    (assets, calls)
+}
+
+#[cfg(test)]
+mod gpu_pressure_tests {
+    use super::*;
+
+    #[test]
+    fn test_callback_fires_once_when_loads_cross_threshold() {
+        // **Feature: gpu-pressure, Property 1: Pressure Callback Fires Once Per Crossing**
+
+        let (mut assets, calls) = watched_manager(40 * 1024);
+        for i in 0..8 {
+            assets.load_texture_image(PathBuf::from(format!("blocks/{i}.png")), texture_64()).unwrap();
+        }
+
+        assert_eq!(assets.gpu_memory_usage(), 8 * 16 * 1024);
+        assert_eq!(*calls.lock().unwrap(), vec![48 * 1024]); // Third texture crossed 40 KiB
+    }
+
+    #[test]
+    fn test_handler_rearms_after_usage_drops() {
+        // **Feature: gpu-pressure, Property 1: Pressure Callback Fires Once Per Crossing**
+
+        let (mut assets, calls) = watched_manager(20 * 1024);
+        let first = assets.load_texture_image(PathBuf::from("a.png"), texture_64()).unwrap();
+        let second = assets.load_texture_image(PathBuf::from("b.png"), texture_64()).unwrap();
+        assert_eq!(calls.lock().unwrap().len(), 1);
+
+        assets.release_texture(first);
+        assets.release_texture(second);
+        assets.collect_garbage();
+        assert_eq!(assets.gpu_memory_usage(), 0);
+
+        assets.load_texture_image(PathBuf::from("c.png"), texture_64()).unwrap();
+        assets.load_texture_image(PathBuf::from("d.png"), texture_64()).unwrap();
+        assert_eq!(calls.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_meshes_count_toward_usage() {
+        let mut assets = AssetManager::new();
+        assets.load_mesh_data(PathBuf::from("cube.mesh"), Mesh::from(shape::Cube::new(1.0)));
+        assert!(assets.gpu_memory_usage() > 0);
+    }
+
+    #[test]
+    fn test_texture_bytes_include_mips_and_compression() {
+        assert_eq!(texture_gpu_bytes((64, 64), TextureFormat::Rgba8UnormSrgb, 1), 16384);
+        assert_eq!(texture_gpu_bytes((4, 4), TextureFormat::Rgba8Unorm, 3), (16 + 4 + 1) * 4);
+        assert_eq!(texture_gpu_bytes((64, 64), TextureFormat::Bc1RgbaUnorm, 1), 16 * 16 * 8);
+    }
+}
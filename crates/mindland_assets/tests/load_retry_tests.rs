@@ -0,0 +1,134 @@
+//! Tests for retrying transient asset load failures
+//!
+//! **Feature: load-retry, Property 1: Transient Failures Are Retried Within The Budget**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::{AssetError, AssetManager, AssetPath, AssetType, LoadPriority, RetryPolicy};
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Policy with short waits so tests stay fast
+fn fast_policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(4),
+    }
+}
+
+/// Loader that fails `failures` times with `error` before succeeding, counting calls
+fn flaky_loader(
+    failures: u32,
+    error: fn() -> AssetError,
+    attempts: &Cell<u32>,
+) -> impl FnMut() -> Result<Vec<u8>, AssetError> + '_ {
+    move || {
+        attempts.set(attempts.get() + 1);
+        if attempts.get() <= failures {
+            Err(error())
+        } else {
+            Ok(vec![1, 2, 3])
+        }
+    }
+}
+
+fn transient() -> AssetError {
+    AssetError::LoadingFailed { reason: "file is locked".to_string() }
+}
+
+#[cfg(test)]
+mod load_retry_tests {
+    use super::*;
+
+    #[test]
+    fn test_flaky_load_succeeds_within_budget() {
+        // **Feature: load-retry, Property 1: Transient Failures Are Retried Within The Budget**
+
+        let attempts = Cell::new(0);
+        let result = fast_policy(3).retry(flaky_loader(2, transient, &attempts));
+
+        assert_eq!(result.unwrap(), vec![1, 2, 3]);
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn test_exhausted_budget_surfaces_error() {
+        // **Feature: load-retry, Property 1: Transient Failures Are Retried Within The Budget**
+
+        let attempts = Cell::new(0);
+        let result = fast_policy(1).retry(flaky_loader(2, transient, &attempts));
+
+        assert!(matches!(result, Err(AssetError::LoadingFailed { .. })));
+        assert_eq!(attempts.get(), 2, "one attempt plus one retry");
+
+        let attempts = Cell::new(0);
+        assert!(RetryPolicy::none().retry(flaky_loader(1, transient, &attempts)).is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn test_not_found_is_not_retried() {
+        let attempts = Cell::new(0);
+        let missing = || AssetError::NotFound { path: PathBuf::from("blocks/missing.png") };
+        let result = fast_policy(5).retry(flaky_loader(1, missing, &attempts));
+
+        assert!(matches!(result, Err(AssetError::NotFound { .. })));
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(300),
+        };
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(50));
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff(40), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_queued_load_with_retry_policy() {
+        let root = std::env::temp_dir().join(format!("mindland-load-retry-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        Image::new(
+            Extent3d { width: 4, height: 4, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            vec![90; 64],
+            TextureFormat::Rgba8UnormSrgb,
+        )
+        .try_into_dynamic()
+        .unwrap()
+        .save(root.join("grass.png"))
+        .unwrap();
+
+        let mut assets = AssetManager::new();
+        assets.set_root(root.clone());
+        assets.queue_load_with_retry(
+            AssetPath { path: PathBuf::from("grass.png"), asset_type: AssetType::Texture },
+            LoadPriority::Normal,
+            fast_policy(2),
+        );
+        assert_eq!(assets.loading_queue[0].retry, Some(fast_policy(2)));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let loaded = loop {
+            if let Some(result) = assets.process_loading_queue() {
+                break result.unwrap();
+            }
+            assert!(Instant::now() < deadline, "background read never finished");
+            std::thread::sleep(Duration::from_millis(1));
+        };
+        assert_eq!(assets.find_by_path(Path::new("grass.png")), Some(loaded));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
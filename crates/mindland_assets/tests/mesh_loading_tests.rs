@@ -0,0 +1,168 @@
+//! Tests for loading OBJ and glTF meshes
+//!
+//! **Feature: mesh-loading, Property 1: Loaded Bounds Match The Vertex Extents**
+
+use bevy::prelude::*;
+use mindland_assets::{AssetError, AssetId, AssetManager, AssetPath, AssetType, LoadPriority};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// A box from (-1, -2, -3) to (4, 5, 6) as two objects: the bottom and top faces
+const BOX_OBJ: &str = "\
+# Tiny fixture
+o bottom
+v -1 -2 -3
+v 4 -2 -3
+v 4 -2 6
+v -1 -2 6
+vn 0 -1 0
+f 1//1 2//1 3//1 4//1
+o top
+v -1 5 -3
+v 4 5 -3
+v 4 5 6
+v -1 5 6
+vn 0 1 0
+f 5//2 6//2 7//2 8//2
+";
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("mindland-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("models")).unwrap();
+    root
+}
+
+fn assets_in(root: &Path) -> AssetManager {
+    let mut assets = AssetManager::new();
+    assets.set_root(root.to_path_buf());
+    assets
+}
+
+/// One-triangle glTF with its vertex data in a separate `.bin` file
+/// Process the queue until the background read of a queued load finishes
+fn next_load(assets: &mut AssetManager) -> Result<AssetId, AssetError> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if let Some(result) = assets.process_loading_queue() {
+            return result;
+        }
+        assert!(Instant::now() < deadline, "background read never finished");
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+fn write_triangle_gltf(dir: &Path) {
+    let positions: [[f32; 3]; 3] = [[0.0, 0.0, 0.0], [2.0, 0.0, -1.0], [0.0, 3.0, 0.5]];
+    let bytes: Vec<u8> = positions.iter().flatten().flat_map(|value| value.to_le_bytes()).collect();
+    std::fs::write(dir.join("triangle.bin"), &bytes).unwrap();
+    let json = format!(
+        r#"{{
+            "asset": {{ "version": "2.0" }},
+            "buffers": [{{ "uri": "triangle.bin", "byteLength": {len} }}],
+            "bufferViews": [{{ "buffer": 0, "byteLength": {len} }}],
+            "accessors": [{{ "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                             "min": [0, 0, -1], "max": [2, 3, 0.5] }}],
+            "meshes": [{{ "primitives": [{{ "attributes": {{ "POSITION": 0 }} }}] }}]
+        }}"#,
+        len = bytes.len()
+    );
+    std::fs::write(dir.join("triangle.gltf"), json).unwrap();
+}
+
+#[cfg(test)]
+mod mesh_loading_tests {
+    use super::*;
+
+    #[test]
+    fn test_obj_bounds_match_vertex_extents() {
+        // **Feature: mesh-loading, Property 1: Loaded Bounds Match The Vertex Extents**
+
+        let root = temp_root("mesh-obj");
+        std::fs::write(root.join("models/box.obj"), BOX_OBJ).unwrap();
+        let mut assets = assets_in(&root);
+
+        let mesh_id = assets.load_mesh(PathBuf::from("models/box.obj")).unwrap();
+        let mesh = &assets.meshes[mesh_id];
+        assert_eq!(mesh.bounding_box.min, Vec3::new(-1.0, -2.0, -3.0));
+        assert_eq!(mesh.bounding_box.max, Vec3::new(4.0, 5.0, 6.0));
+        assert_eq!(mesh.vertex_count, 8);
+        assert_eq!(mesh.index_count, 12, "two quads, triangulated");
+        assert!(mesh.gpu_bytes > 0);
+
+        assert_eq!(assets.load_mesh(PathBuf::from("models/box.obj")).unwrap(), mesh_id, "second load hits the cache");
+        assert_eq!(assets.stats().cached_meshes, 1);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_gltf_with_external_buffer() {
+        // **Feature: mesh-loading, Property 1: Loaded Bounds Match The Vertex Extents**
+
+        let root = temp_root("mesh-gltf");
+        write_triangle_gltf(&root.join("models"));
+        let mut assets = assets_in(&root);
+
+        let mesh_id = assets.load_mesh(PathBuf::from("models/triangle.gltf")).unwrap();
+        let mesh = &assets.meshes[mesh_id];
+        assert_eq!(mesh.bounding_box.min, Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(mesh.bounding_box.max, Vec3::new(2.0, 3.0, 0.5));
+        assert_eq!((mesh.vertex_count, mesh.index_count), (3, 3), "unindexed primitives get sequential indices");
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_unsupported_and_missing_meshes() {
+        let root = temp_root("mesh-errors");
+        std::fs::write(root.join("models/box.fbx"), "not really").unwrap();
+        std::fs::write(root.join("models/broken.obj"), "v 1 2\nf 1 2 3\n").unwrap();
+        let mut assets = assets_in(&root);
+
+        match assets.load_mesh(PathBuf::from("models/box.FBX")) {
+            Err(AssetError::UnsupportedFormat { format }) => assert_eq!(format, "fbx"),
+            other => panic!("expected UnsupportedFormat, got {:?}", other.map(|_| ())),
+        }
+        assert!(matches!(assets.load_mesh(PathBuf::from("models/missing.obj")), Err(AssetError::NotFound { .. })));
+        assert!(matches!(assets.load_mesh(PathBuf::from("models/broken.obj")), Err(AssetError::LoadingFailed { .. })));
+        assert_eq!(assets.stats().cached_meshes, 0);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_loading_queue_loads_meshes() {
+        let root = temp_root("mesh-queue");
+        std::fs::write(root.join("models/box.obj"), BOX_OBJ).unwrap();
+        let mut assets = assets_in(&root);
+
+        let path = AssetPath { path: PathBuf::from("models/box.obj"), asset_type: AssetType::Mesh };
+        assets.queue_load(path.clone(), LoadPriority::Normal);
+        let Ok(AssetId::Mesh(mesh_id)) = next_load(&mut assets) else {
+            panic!("queued mesh should load");
+        };
+        assert_eq!(assets.meshes[mesh_id].vertex_count, 8);
+
+        // A second request is served from the cache without another read
+        assets.queue_load(path, LoadPriority::Normal);
+        assert!(matches!(assets.process_loading_queue(), Some(Ok(AssetId::Mesh(id))) if id == mesh_id));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_queued_mesh_errors_come_back_from_the_reader() {
+        let root = temp_root("mesh-queue-errors");
+        let mut assets = assets_in(&root);
+
+        let missing = AssetPath { path: PathBuf::from("models/missing.obj"), asset_type: AssetType::Mesh };
+        assets.queue_load(missing, LoadPriority::Normal);
+        assert!(matches!(next_load(&mut assets), Err(AssetError::NotFound { .. })), "missing meshes don't get a placeholder");
+
+        let unsupported = AssetPath { path: PathBuf::from("models/box.fbx"), asset_type: AssetType::Mesh };
+        assets.queue_load(unsupported, LoadPriority::Normal);
+        assert!(
+            matches!(assets.process_loading_queue(), Some(Err(AssetError::UnsupportedFormat { .. }))),
+            "unsupported formats are rejected without a read"
+        );
+        assert_eq!(assets.pending_loads(), 0);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
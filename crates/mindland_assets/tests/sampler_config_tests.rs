@@ -0,0 +1,86 @@
+//! Tests for per-texture sampler configuration
+//!
+//! **Feature: texture-sampler, Property 1: Sampler Config Reaches The Descriptor**
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_resource::{AddressMode, Extent3d, FilterMode, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+};
+use mindland_assets::{AssetManager, SamplerConfig};
+use std::path::PathBuf;
+
+fn texture_4() -> Image {
+    Image::new(
+        Extent3d { width: 4, height: 4, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![0; 4 * 4 * 4],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+#[cfg(test)]
+mod sampler_config_tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_and_repeat_textures_have_distinct_descriptors() {
+        // **Feature: texture-sampler, Property 1: Sampler Config Reaches The Descriptor**
+
+        let mut assets = AssetManager::new();
+        let tiles = assets.load_texture_image_with(PathBuf::from("grass.png"), texture_4(), SamplerConfig::repeat()).unwrap();
+        let sprite = assets.load_texture_image_with(PathBuf::from("button.png"), texture_4(), SamplerConfig::clamp()).unwrap();
+
+        let tiles = assets.get_texture(tiles).unwrap();
+        let sprite = assets.get_texture(sprite).unwrap();
+        let (tiles_descriptor, sprite_descriptor) = (tiles.sampler.descriptor(), sprite.sampler.descriptor());
+
+        assert_ne!(tiles_descriptor, sprite_descriptor);
+        assert_eq!(tiles_descriptor.address_mode_u, AddressMode::Repeat);
+        assert_eq!(tiles_descriptor.address_mode_v, AddressMode::Repeat);
+        assert_eq!(sprite_descriptor.address_mode_u, AddressMode::ClampToEdge);
+        assert_eq!(sprite_descriptor.address_mode_v, AddressMode::ClampToEdge);
+
+        // The decoded image carries the sampler Bevy binds on upload
+        let ImageSampler::Descriptor(image_sampler) = &sprite.image.as_ref().unwrap().sampler else {
+            panic!("image sampler not set");
+        };
+        assert_eq!(image_sampler.as_wgpu(), sprite_descriptor);
+    }
+
+    #[test]
+    fn test_mirror_and_filter_modes() {
+        // **Feature: texture-sampler, Property 1: Sampler Config Reaches The Descriptor**
+
+        let mirror = SamplerConfig::mirror().nearest().descriptor();
+        assert_eq!(mirror.address_mode_u, AddressMode::MirrorRepeat);
+        assert_eq!(mirror.mag_filter, FilterMode::Nearest);
+        assert_eq!(mirror.min_filter, FilterMode::Nearest);
+        assert_eq!(mirror.mipmap_filter, FilterMode::Nearest);
+
+        let mixed = SamplerConfig {
+            address_u: AddressMode::Repeat,
+            address_v: AddressMode::ClampToEdge,
+            filter: FilterMode::Linear,
+            mipmap_filter: FilterMode::Nearest,
+        }
+        .descriptor();
+        assert_eq!(mixed.address_mode_u, AddressMode::Repeat);
+        assert_eq!(mixed.address_mode_v, AddressMode::ClampToEdge);
+        assert_eq!(mixed.mipmap_filter, FilterMode::Nearest);
+    }
+
+    #[test]
+    fn test_default_sampler_and_placeholder() {
+        let mut assets = AssetManager::new();
+        assets.set_root(PathBuf::from("/nonexistent"));
+
+        let default = assets.load_texture(PathBuf::from("missing.png")).unwrap();
+        assert_eq!(assets.get_texture(default).unwrap().sampler, SamplerConfig::repeat());
+
+        let clamped = assets.load_texture_with(PathBuf::from("ui/missing.png"), SamplerConfig::clamp()).unwrap();
+        assert_eq!(assets.get_texture(clamped).unwrap().sampler, SamplerConfig::clamp());
+    }
+}
@@ -0,0 +1,145 @@
+//! Tests for sharing the asset manager across threads
+//!
+//! **Feature: shared-assets, Property 1: Loads Run Concurrently With Reads**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::{AssetArchive, AssetManager, SharedAssetManager};
+use std::io::{Cursor, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Barrier};
+use zip::{write::FileOptions, ZipWriter};
+
+const TEXTURE_COUNT: usize = 32;
+
+/// Archive holding `TEXTURE_COUNT` small PNGs named `blocks/<i>.png`
+fn block_archive() -> AssetArchive {
+    let mut png = Cursor::new(Vec::new());
+    Image::new(
+        Extent3d { width: 8, height: 8, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![90; 8 * 8 * 4],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+    .try_into_dynamic()
+    .unwrap()
+    .write_to(&mut png, bevy::render::texture::ImageFormat::Png.as_image_crate_format().unwrap())
+    .unwrap();
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for index in 0..TEXTURE_COUNT {
+        zip.start_file(format!("blocks/{index}.png"), FileOptions::default()).unwrap();
+        zip.write_all(png.get_ref()).unwrap();
+    }
+    AssetArchive::from_bytes("blocks.pak", zip.finish().unwrap().into_inner()).unwrap()
+}
+
+fn shared_manager() -> SharedAssetManager {
+    let mut manager = AssetManager::new();
+    manager.set_root(block_archive());
+    SharedAssetManager::new(manager)
+}
+
+#[cfg(test)]
+mod shared_asset_tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_manager_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedAssetManager>();
+    }
+
+    #[test]
+    fn test_load_on_one_thread_while_querying_stats_on_another() {
+        // **Feature: shared-assets, Property 1: Loads Run Concurrently With Reads**
+
+        let assets = shared_manager();
+        let loading_done = Arc::new(AtomicBool::new(false));
+        let start = Arc::new(Barrier::new(2));
+
+        let loader = {
+            let assets = assets.clone();
+            let loading_done = loading_done.clone();
+            let start = start.clone();
+            std::thread::spawn(move || {
+                start.wait();
+                for index in 0..TEXTURE_COUNT {
+                    assets.load_texture(PathBuf::from(format!("blocks/{index}.png"))).unwrap();
+                }
+                loading_done.store(true, Ordering::Release);
+            })
+        };
+
+        let reader = {
+            let assets = assets.clone();
+            let loading_done = loading_done.clone();
+            let start = start.clone();
+            std::thread::spawn(move || {
+                start.wait();
+                let mut last_count = 0;
+                let mut queries = 0;
+                loop {
+                    let done = loading_done.load(Ordering::Acquire);
+                    let stats = assets.stats();
+                    assert!(stats.textures >= last_count, "texture count went backwards");
+                    last_count = stats.textures;
+                    queries += 1;
+                    if done {
+                        break queries;
+                    }
+                }
+            })
+        };
+
+        loader.join().unwrap();
+        assert!(reader.join().unwrap() > 0);
+
+        let stats = assets.stats();
+        assert_eq!(stats.textures, TEXTURE_COUNT);
+        assert_eq!(stats.texture_bytes, TEXTURE_COUNT * 8 * 8 * 4);
+    }
+
+    #[test]
+    fn test_concurrent_loads_of_same_texture_share_one_entry() {
+        // **Feature: shared-assets, Property 1: Loads Run Concurrently With Reads**
+
+        let assets = shared_manager();
+        let ids: Vec<_> = (0..4)
+            .map(|_| {
+                let assets = assets.clone();
+                std::thread::spawn(move || assets.load_texture(PathBuf::from("blocks/0.png")).unwrap())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        assert!(ids.windows(2).all(|pair| pair[0] == pair[1]));
+        assert_eq!(assets.stats().textures, 1);
+        assert_eq!(assets.with_texture(ids[0], |texture| texture.usage_count.load(Ordering::Relaxed)), Some(4));
+    }
+
+    #[test]
+    fn test_collect_garbage_frees_released_textures() {
+        let assets = shared_manager();
+        let kept = assets.load_texture(PathBuf::from("blocks/0.png")).unwrap();
+        let released = assets.load_texture(PathBuf::from("blocks/1.png")).unwrap();
+
+        assets.release_texture(released);
+        assert_eq!(assets.collect_garbage(), 1);
+
+        let stats = assets.stats();
+        assert_eq!(stats.textures, 1);
+        assert_eq!(stats.cached_paths, 1);
+        assert!(assets.with_texture(kept, |_| ()).is_some());
+        assert!(assets.with_texture(released, |_| ()).is_none());
+
+        // Reloading after collection decodes a fresh copy
+        assets.load_texture(PathBuf::from("blocks/1.png")).unwrap();
+        assert_eq!(assets.stats().textures, 2);
+    }
+}
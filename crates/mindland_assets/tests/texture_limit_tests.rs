@@ -0,0 +1,68 @@
+//! Tests for clamping oversized textures to the adapter limit
+//!
+//! **Feature: texture-limits, Property 1: Oversized Textures Are Downscaled**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat, WgpuLimits},
+};
+use mindland_assets::{fit_texture_size, AssetManager};
+use std::path::PathBuf;
+
+fn synthetic_image(width: u32, height: u32) -> Image {
+    Image::new(
+        Extent3d { width, height, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![128; (width * height * 4) as usize],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+#[cfg(test)]
+mod texture_limit_tests {
+    use super::*;
+
+    #[test]
+    fn test_over_limit_texture_is_clamped() {
+        // **Feature: texture-limits, Property 1: Oversized Textures Are Downscaled**
+
+        let mut assets = AssetManager::new();
+        assets.set_texture_limits(&WgpuLimits {
+            max_texture_dimension_2d: 256,
+            ..WgpuLimits::default()
+        });
+
+        let id = assets
+            .load_texture_image(PathBuf::from("textures/huge.png"), synthetic_image(1024, 512))
+            .unwrap();
+
+        let texture = assets.get_texture(id).unwrap();
+        assert_eq!(texture.size, (256, 128));
+        let image = texture.image.as_ref().unwrap();
+        assert_eq!((image.width(), image.height()), (256, 128));
+        assert_eq!(image.data.len(), 256 * 128 * 4);
+    }
+
+    #[test]
+    fn test_within_limit_texture_is_untouched() {
+        // **Feature: texture-limits, Property 1: Oversized Textures Are Downscaled**
+
+        let mut assets = AssetManager::new();
+        assets.max_texture_dimension = 4096;
+
+        let id = assets
+            .load_texture_image(PathBuf::from("textures/small.png"), synthetic_image(64, 32))
+            .unwrap();
+
+        assert_eq!(assets.get_texture(id).unwrap().size, (64, 32));
+    }
+
+    #[test]
+    fn test_fit_texture_size_preserves_aspect() {
+        assert_eq!(fit_texture_size((8192, 8192), 4096), (4096, 4096));
+        assert_eq!(fit_texture_size((8192, 2048), 4096), (4096, 1024));
+        assert_eq!(fit_texture_size((100, 9000), 4096), (46, 4096));
+        assert_eq!(fit_texture_size((8192, 1), 4096), (4096, 1));
+        assert_eq!(fit_texture_size((512, 512), 4096), (512, 512));
+    }
+}
@@ -0,0 +1,111 @@
+//! Tests for the per-frame GPU upload budget
+//!
+//! **Feature: upload-budget, Property 1: Uploads Spread Across Frames Within Budget**
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use mindland_assets::{AssetId, AssetManager};
+use std::path::PathBuf;
+
+const MIB: u64 = 1024 * 1024;
+
+/// RGBA8 texture of the given size
+fn texture(width: u32, height: u32) -> Image {
+    Image::new(
+        Extent3d { width, height, depth_or_array_layers: 1 },
+        TextureDimension::D2,
+        vec![0; (width * height * 4) as usize],
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+#[cfg(test)]
+mod upload_budget_tests {
+    use super::*;
+
+    #[test]
+    fn test_large_upload_spans_frames_within_budget() {
+        // **Feature: upload-budget, Property 1: Uploads Spread Across Frames Within Budget**
+
+        let mut assets = AssetManager::new();
+        assets.set_upload_budget(2 * MIB);
+        let id = AssetId::Texture(assets.load_texture_image(PathBuf::from("big.png"), texture(1280, 2048)).unwrap());
+        assert_eq!(assets.pending_upload_bytes(), 10 * MIB);
+
+        let mut frames = 0;
+        let mut next_offset = 0;
+        while assets.is_upload_pending(&id) {
+            let chunks = assets.schedule_uploads();
+            assert!(chunks.iter().map(|chunk| chunk.len).sum::<u64>() <= 2 * MIB);
+            for chunk in chunks {
+                assert_eq!(chunk.offset, next_offset);
+                next_offset += chunk.len;
+            }
+            frames += 1;
+            assert!(frames <= 100, "upload never finished");
+        }
+
+        assert!(frames >= 5, "10MB under a 2MB budget finished in {frames} frames");
+        assert_eq!(next_offset, 10 * MIB);
+        assert_eq!(assets.stats().pending_uploads, 0);
+        assert!(assets.schedule_uploads().is_empty());
+    }
+
+    #[test]
+    fn test_unlimited_by_default() {
+        // **Feature: upload-budget, Property 1: Uploads Spread Across Frames Within Budget**
+
+        let mut assets = AssetManager::new();
+        for name in ["a.png", "b.png", "c.png"] {
+            assets.load_texture_image(PathBuf::from(name), texture(512, 512)).unwrap();
+        }
+        assert_eq!(assets.stats().pending_uploads, 3);
+        assert_eq!(assets.schedule_uploads().len(), 3);
+        assert_eq!(assets.stats().pending_uploads, 0);
+    }
+
+    #[test]
+    fn test_small_assets_share_a_frame_in_load_order() {
+        let mut assets = AssetManager::new();
+        assets.set_upload_budget(MIB);
+        let ids: Vec<_> = ["a.png", "b.png", "c.png"]
+            .into_iter()
+            .map(|name| AssetId::Texture(assets.load_texture_image(PathBuf::from(name), texture(256, 512)).unwrap()))
+            .collect(); // 512 KiB each
+
+        let first = assets.schedule_uploads();
+        assert_eq!(first.iter().map(|chunk| chunk.asset.clone()).collect::<Vec<_>>(), ids[..2]);
+        assert!(!assets.is_upload_pending(&ids[0]));
+        assert!(!assets.is_upload_pending(&ids[1]));
+        assert!(assets.is_upload_pending(&ids[2]));
+
+        let second = assets.schedule_uploads();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].len, second[0].total);
+    }
+
+    #[test]
+    fn test_placeholders_are_never_pending() {
+        let mut assets = AssetManager::new();
+        assets.set_root(PathBuf::from("/nonexistent"));
+        let id = AssetId::Texture(assets.load_texture(PathBuf::from("missing.png")).unwrap());
+        assert!(!assets.is_upload_pending(&id));
+        assert!(assets.schedule_uploads().is_empty());
+    }
+
+    #[test]
+    fn test_freed_assets_stop_uploading() {
+        let mut assets = AssetManager::new();
+        assets.set_upload_budget(MIB);
+        let texture_id = assets.load_texture_image(PathBuf::from("big.png"), texture(1024, 1024)).unwrap();
+        assets.schedule_uploads();
+
+        assets.release_texture(texture_id);
+        assets.collect_garbage();
+
+        assert!(!assets.is_upload_pending(&AssetId::Texture(texture_id)));
+        assert_eq!(assets.pending_upload_bytes(), 0);
+    }
+}
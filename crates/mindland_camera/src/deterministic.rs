@@ -0,0 +1,154 @@
+//! Bit-exact movement for server-side validation
+//!
+//! `DeterministicMovement` reproduces `CameraController::update_movement` in 48.16 fixed point,
+//! stepped at a fixed tick rate, so the same `MovementInput` sequence gives identical positions
+//! on every machine. What is deterministic:
+//!
+//! - Position and velocity are `i64` fixed point; every add, multiply and shift is integer.
+//! - Tuning (`MovementState` speeds, friction) is converted to fixed point once, by a
+//!   power-of-two multiply and round, which IEEE 754 makes exact on all platforms.
+//! - Yaw arrives pre-quantized (`MovementInput::yaw`), and its sine and cosine come from an
+//!   integer polynomial instead of the platform's libm.
+//! - The time step is `1 / tick_rate`, never a measured frame time.
+//!
+//! Not covered: the float render transform derived from it, mouse look, and the velocity
+//! smoothing `update_movement` applies, which is presentation only.
+
+use crate::MovementState;
+use bevy::math::{I64Vec3, Vec3};
+
+/// Fractional bits of the fixed-point format
+pub const FIXED_FRACTION_BITS: u32 = 16;
+
+const ONE: i64 = 1 << FIXED_FRACTION_BITS;
+
+/// One tick of player input, already quantized for the wire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MovementInput {
+    pub forward: i8, // -1, 0 or 1 (values beyond are clamped)
+    pub strafe: i8,  // Positive moves right
+    pub vertical: i8,
+    pub yaw: u16, // Heading, 65536 units per turn, counter-clockwise from -z like `Quat::from_rotation_y`
+    pub sprint: bool,
+    pub precision: bool,
+}
+
+/// Fixed-point movement state stepped at a fixed tick rate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicMovement {
+    pub tick_rate: u32,
+    pub position: I64Vec3, // Blocks in 48.16 fixed point
+    pub velocity: I64Vec3, // Local (strafe, vertical, forward) blocks per second in 48.16 fixed point
+    max_speed: i64,
+    sprint_multiplier: i64,
+    precision_multiplier: i64,
+    friction: i64,
+}
+
+impl MovementInput {
+    /// Quantize a yaw angle in radians for `yaw` (done once, on the client)
+    pub fn quantize_yaw(radians: f32) -> u16 {
+        (radians as f64 / std::f64::consts::TAU * 65536.0).round().rem_euclid(65536.0) as u16
+    }
+}
+
+impl DeterministicMovement {
+    /// Start at `position` with the tuning from `movement` (tick rates below 1 are raised to 1)
+    pub fn new(position: Vec3, movement: &MovementState, tick_rate: u32) -> Self {
+        Self {
+            tick_rate: tick_rate.max(1),
+            position: I64Vec3::new(to_fixed(position.x), to_fixed(position.y), to_fixed(position.z)),
+            velocity: I64Vec3::ZERO,
+            max_speed: to_fixed(movement.max_speed),
+            sprint_multiplier: to_fixed(movement.sprint_multiplier),
+            precision_multiplier: to_fixed(movement.precision_multiplier),
+            friction: to_fixed(movement.friction),
+        }
+    }
+
+    /// Advance one tick, mirroring `CameraController::update_movement` without its smoothing
+    pub fn step(&mut self, input: &MovementInput) {
+        let tick_rate = self.tick_rate as i64;
+        let axes = I64Vec3::new(
+            input.strafe.clamp(-1, 1) as i64,
+            input.vertical.clamp(-1, 1) as i64,
+            input.forward.clamp(-1, 1) as i64,
+        );
+        let multiplier = if input.sprint {
+            self.sprint_multiplier
+        } else if input.precision {
+            self.precision_multiplier
+        } else {
+            ONE
+        };
+
+        let target = axes * mul(self.max_speed, multiplier);
+        let acceleration = (target - self.velocity) * 10;
+        self.velocity += div_round(acceleration, tick_rate);
+        if axes == I64Vec3::ZERO {
+            self.velocity = I64Vec3::new(
+                mul(self.velocity.x, self.friction),
+                mul(self.velocity.y, self.friction),
+                mul(self.velocity.z, self.friction),
+            );
+        }
+
+        // Local -> world: forward is -z rotated by yaw, right is +x rotated by yaw, up stays +y
+        let (sin, cos) = (fixed_sin(input.yaw), fixed_sin(input.yaw.wrapping_add(16384)));
+        let forward = I64Vec3::new(-sin, 0, -cos);
+        let right = I64Vec3::new(cos, 0, -sin);
+        let world = right * self.velocity.x + forward * self.velocity.z;
+        let world = I64Vec3::new(world.x >> FIXED_FRACTION_BITS, self.velocity.y, world.z >> FIXED_FRACTION_BITS);
+
+        self.position += div_round(world, tick_rate);
+    }
+
+    /// Position as floats for rendering
+    pub fn position_f32(&self) -> Vec3 {
+        self.position.as_vec3() / ONE as f32
+    }
+
+    /// Stable hash of the exact position (FNV-1a over little-endian bytes), for comparing runs
+    pub fn position_hash(&self) -> u64 {
+        self.position
+            .to_array()
+            .iter()
+            .flat_map(|component| component.to_le_bytes())
+            .fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+    }
+}
+
+fn to_fixed(value: f32) -> i64 {
+    (value as f64 * ONE as f64).round() as i64
+}
+
+fn mul(a: i64, b: i64) -> i64 {
+    (a * b) >> FIXED_FRACTION_BITS
+}
+
+/// Integer division rounding half away from zero (plain `/` would bias toward zero)
+fn div_round(value: I64Vec3, divisor: i64) -> I64Vec3 {
+    let round = |v: i64| if v >= 0 { (v + divisor / 2) / divisor } else { (v - divisor / 2) / divisor };
+    I64Vec3::new(round(value.x), round(value.y), round(value.z))
+}
+
+/// Sine of a 65536-per-turn angle in 16.16 fixed point (max error ~1e-4)
+fn fixed_sin(angle: u16) -> i64 {
+    let quadrant = angle >> 14;
+    let mut t = (angle & 0x3FFF) as i64 * 4; // Position within the quadrant, 16.16 in [0, 1)
+    if quadrant & 1 == 1 {
+        t = ONE - t;
+    }
+
+    // sin(πt/2) ≈ t(a1 + t²(a3 + a5 t²)), coefficients in 16.16
+    const A1: i64 = 102_944; // 1.5707963
+    const A3: i64 = -42_334; // -0.6459640
+    const A5: i64 = 5_210; // 0.0794931
+    let t2 = mul(t, t);
+    let sine = mul(t, A1 + mul(t2, A3 + mul(t2, A5)));
+    if quadrant >= 2 {
+        -sine
+    } else {
+        sine
+    }
+}
@@ -3,10 +3,31 @@
 //! First-person camera with sub-millisecond response time and quaternion-based rotation.
 
 use bevy::{
+    math::{DVec3, I64Vec3},
     prelude::*,
     render::camera::CameraProjection,
+    window::{PrimaryWindow, WindowResized},
 };
 use glam::Quat;
+use mindland_assets::BoundingSphere;
+use mindland_performance::TimeSource;
+use std::time::Instant;
+
+mod deterministic;
+mod plugin;
+mod shake;
+pub use deterministic::*;
+pub use plugin::*;
+pub use shake::*;
+
+/// Pitch limit in radians (~86 degrees), so the view never flips over the top
+const MAX_PITCH: f32 = 1.5;
+
+/// Distance (blocks) and angle (radians) at which `move_toward` snaps onto its target
+const MOVE_TARGET_SNAP: f32 = 1.0e-3;
+
+/// Default `ExponentialSmoothing` alpha: keeps ~43% of the previous value per 60 FPS frame (~20ms lag)
+pub const DEFAULT_SMOOTHING_ALPHA: f32 = 0.95;
 
 /// High-performance first-person camera controller
 #[derive(Component)]
@@ -15,8 +36,52 @@ pub struct CameraController {
     pub projection: PerspectiveProjection,
     pub movement_state: MovementState,
     pub sensitivity: f32,
+    pub base_fov: f32,         // Unzoomed FOV in radians that `sensitivity` is tuned for
+    pub zoom_sensitivity: f32, // 0 = same sensitivity when zoomed, 1 = scaled fully by fov / base_fov
     pub smoothing: ExponentialSmoothing,
     pub update_rate: u32, // Target 1000Hz internal updates
+    pub recoil_offset: Vec2,       // Transient (pitch, yaw) kick in radians on top of the aim rotation
+    pub recoil_recovery_time: f32, // Seconds for 95% of a kick to decay back to aim
+    pub shake: CameraShake,        // Impact shake, applied only by `view_matrix_with_shake`
+    last_update: Option<Instant>,  // Previous `update` reading, for its delta time
+    pub deterministic: Option<DeterministicMovement>, // Some = movement runs through `step_deterministic`
+    pub move_target: Option<Transform>, // Set by `move_toward`; input is ignored until it's reached
+    pub fov_mode: FovMode,              // Which FOV axis stays fixed when the aspect ratio changes
+}
+
+/// How the FOV adapts to the aspect ratio (`projection.fov` is always the vertical FOV)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FovMode {
+    /// Vert-: horizontal FOV stays fixed, so wider screens see less vertically
+    Vertical,
+    /// Hor+: vertical FOV stays fixed and wider screens see more horizontally (the modern standard)
+    #[default]
+    HorizontalPlus,
+}
+
+/// Keep camera aspect ratios in step with primary window resizes (minimizing keeps the last valid ratio)
+///
+/// Other windows' resizes are ignored; cameras drawn into extra windows follow their own window.
+pub fn camera_resize_system(
+    mut resized: EventReader<WindowResized>,
+    primary: Query<(), With<PrimaryWindow>>,
+    mut cameras: Query<&mut CameraController>,
+) {
+    if let Some(event) = resized.read().filter(|event| primary.contains(event.window)).last() {
+        for mut camera in &mut cameras {
+            camera.set_viewport_size(Vec2::new(event.width, event.height));
+        }
+    }
+}
+
+/// Large-world origin rebasing: keeps rendered positions near zero where f32 is precise
+///
+/// World position (in blocks) = `offset` + local `Transform` position.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct FloatingOrigin {
+    pub offset: I64Vec3,       // Accumulated shift, always a whole number of chunks
+    pub rebase_distance: f32,  // Camera distance from the origin that triggers a rebase
+    pub chunk_size: u32,       // Shifts snap to this grid so chunk boundaries stay aligned
 }
 
 /// Movement state with acceleration curves
@@ -31,11 +96,143 @@ pub struct MovementState {
 }
 
 /// Exponential smoothing for micro-stutter elimination
+///
+/// `alpha` is the fraction of the previous value kept per internal tick (1 / `update_rate`
+/// seconds), so a frame of `dt` seconds keeps `alpha^(dt * update_rate)` of it. 0.0 disables
+/// smoothing; values near 1.0 respond slowly. Independent of frame rate.
+///
+/// This is a retention factor: earlier versions used `alpha` as the new-sample weight, so
+/// callers porting an old value `w` should pass `1.0 - w`.
+///
+/// Smoothing makes the rotation trail a moving input. `prediction_strength` extrapolates the
+/// smoothed rotation along the recent angular velocity to win some of that lag back.
 #[derive(Debug, Clone)]
 pub struct ExponentialSmoothing {
-    pub alpha: f32,
+    alpha: f32,
+    prediction_strength: f32,
     pub previous_value: Vec3,
     pub previous_rotation: Quat,
+    pub predicted_rotation: Quat, // `previous_rotation` plus look-ahead; what the view shows
+    pub target_rotation: Quat,    // Input rotation as of the last update, which `previous_rotation` chases
+    pub angular_velocity: f32,    // Radians per second the input rotation moved last update
+}
+
+impl Default for FloatingOrigin {
+    fn default() -> Self {
+        Self {
+            offset: I64Vec3::ZERO,
+            rebase_distance: 4096.0, // f32 still has ~0.5mm precision here
+            chunk_size: 32,
+        }
+    }
+}
+
+impl FloatingOrigin {
+    /// Whole-chunk shift that brings `camera_position` back near the origin, if one is needed
+    pub fn rebase_shift(&self, camera_position: Vec3) -> Option<IVec3> {
+        if camera_position.length() <= self.rebase_distance {
+            return None;
+        }
+        let chunk_size = self.chunk_size.max(1) as f32;
+        let chunks = (camera_position / chunk_size).floor().as_ivec3();
+        Some(chunks * self.chunk_size.max(1) as i32)
+    }
+
+    /// Absolute world position of a local (rebased) position
+    pub fn to_world(&self, local: Vec3) -> DVec3 {
+        self.offset.as_dvec3() + local.as_dvec3()
+    }
+
+    /// Local position of an absolute world position
+    pub fn to_local(&self, world: DVec3) -> Vec3 {
+        (world - self.offset.as_dvec3()).as_vec3()
+    }
+}
+
+/// Shift the world back toward the origin when the camera strays too far
+///
+/// Only root transforms move; children stay relative to their parents.
+pub fn floating_origin_system(
+    mut origin: ResMut<FloatingOrigin>,
+    mut cameras: Query<&mut CameraController>,
+    mut transforms: Query<&mut Transform, Without<Parent>>,
+) {
+    let Some(camera_position) = cameras.iter().next().map(|camera| camera.transform.translation) else {
+        return;
+    };
+    let Some(shift) = origin.rebase_shift(camera_position) else {
+        return;
+    };
+
+    // Integer shift values are exact in f32 up to 2^24, so relative positions are preserved
+    let shift_f32 = shift.as_vec3();
+    for mut camera in &mut cameras {
+        camera.transform.translation -= shift_f32;
+    }
+    for mut transform in &mut transforms {
+        transform.translation -= shift_f32;
+    }
+    origin.offset += shift.as_i64vec3();
+}
+
+impl ExponentialSmoothing {
+    /// Create smoothing with the given alpha (clamped to [0, 1])
+    pub fn new(alpha: f32) -> Self {
+        let mut smoothing = Self {
+            alpha: 0.0,
+            prediction_strength: 0.0,
+            previous_value: Vec3::ZERO,
+            previous_rotation: Quat::IDENTITY,
+            predicted_rotation: Quat::IDENTITY,
+            target_rotation: Quat::IDENTITY,
+            angular_velocity: 0.0,
+        };
+        smoothing.set_alpha(alpha);
+        smoothing
+    }
+
+    /// Fraction of the previous value kept per internal tick
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Set alpha, clamped to [0, 1] (NaN disables smoothing)
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = if alpha.is_nan() { 0.0 } else { alpha.clamp(0.0, 1.0) };
+    }
+
+    /// Weight of the new value for a frame of `delta_time` seconds, always within [0, 1]
+    pub fn blend_factor(&self, delta_time: f32, update_rate: u32) -> f32 {
+        1.0 - self.alpha.powf(delta_time.max(0.0) * update_rate as f32)
+    }
+
+    /// Fraction of the smoothing lag the look-ahead compensates (0 = off, 1 = all of it)
+    pub fn prediction_strength(&self) -> f32 {
+        self.prediction_strength
+    }
+
+    /// Set the look-ahead strength, clamped to [0, 1] (NaN disables prediction)
+    pub fn set_prediction_strength(&mut self, strength: f32) {
+        self.prediction_strength = if strength.is_nan() { 0.0 } else { strength.clamp(0.0, 1.0) };
+    }
+
+    /// Seconds a constantly moving input trails behind its smoothed value
+    pub fn lag_time(&self, update_rate: u32) -> f32 {
+        if self.alpha <= 0.0 || self.alpha >= 1.0 || update_rate == 0 {
+            return 0.0;
+        }
+        -1.0 / (update_rate as f32 * self.alpha.ln())
+    }
+
+    /// Smoothed rotation pushed toward `target` by the predicted lag, never past it
+    pub fn predict_rotation(&self, target: Quat, update_rate: u32) -> Quat {
+        let remaining = self.previous_rotation.angle_between(target);
+        if self.prediction_strength == 0.0 || remaining <= f32::EPSILON {
+            return self.previous_rotation;
+        }
+        let look_ahead = self.prediction_strength * self.angular_velocity * self.lag_time(update_rate);
+        self.previous_rotation.slerp(target, (look_ahead / remaining).min(1.0))
+    }
 }
 
 impl Default for CameraController {
@@ -64,46 +261,248 @@ impl CameraController {
                 friction: 0.9,
             },
             sensitivity: 0.002, // Optimized mouse sensitivity
-            smoothing: ExponentialSmoothing {
-                alpha: 0.8,
-                previous_value: Vec3::ZERO,
-                previous_rotation: Quat::IDENTITY,
-            },
+            base_fov: 70.0_f32.to_radians(),
+            zoom_sensitivity: 0.0, // Off by default
+            smoothing: ExponentialSmoothing::new(DEFAULT_SMOOTHING_ALPHA),
             update_rate: 1000, // 1000Hz internal update rate
+            recoil_offset: Vec2::ZERO,
+            recoil_recovery_time: 0.25,
+            shake: CameraShake::default(),
+            last_update: None,
+            deterministic: None,
+            move_target: None,
+            fov_mode: FovMode::default(),
+        }
+    }
+
+    /// Match the projection's aspect ratio to a viewport, returning false (and changing nothing)
+    /// for a zero-area or non-finite size such as a minimized window
+    ///
+    /// Under `FovMode::Vertical` the vertical FOV (and `base_fov`) is refit to keep the horizontal FOV.
+    pub fn set_viewport_size(&mut self, size: Vec2) -> bool {
+        if !(size.x > 0.0 && size.y > 0.0 && size.is_finite()) {
+            return false;
+        }
+        let aspect_ratio = size.x / size.y;
+        if self.fov_mode == FovMode::Vertical {
+            let old_aspect_ratio = self.projection.aspect_ratio;
+            self.base_fov = vertical_fov(horizontal_fov(self.base_fov, old_aspect_ratio), aspect_ratio);
+        }
+        self.projection = self.projection_for_aspect(aspect_ratio);
+        true
+    }
+
+    /// The projection refit to another aspect ratio according to `fov_mode` (split-screen viewports)
+    pub fn projection_for_aspect(&self, aspect_ratio: f32) -> PerspectiveProjection {
+        let fov = match self.fov_mode {
+            FovMode::Vertical => vertical_fov(self.horizontal_fov(), aspect_ratio),
+            FovMode::HorizontalPlus => self.projection.fov,
+        };
+        PerspectiveProjection { fov, aspect_ratio, ..self.projection.clone() }
+    }
+
+    /// Current horizontal FOV in radians
+    pub fn horizontal_fov(&self) -> f32 {
+        horizontal_fov(self.projection.fov, self.projection.aspect_ratio)
+    }
+
+    /// Current vertical FOV in radians
+    pub fn vertical_fov(&self) -> f32 {
+        self.projection.fov
+    }
+
+    /// Mouse sensitivity after zoom scaling, so a magnified view doesn't turn faster on screen
+    pub fn effective_sensitivity(&self) -> f32 {
+        if self.base_fov <= 0.0 || self.zoom_sensitivity == 0.0 {
+            return self.sensitivity;
         }
+        let fov_ratio = self.projection.fov / self.base_fov;
+        self.sensitivity * fov_ratio.powf(self.zoom_sensitivity.clamp(0.0, 1.0))
     }
 
     /// Update camera rotation using quaternions (prevents gimbal lock)
+    ///
+    /// Call every frame, even without mouse motion, so the smoothed view settles on the aim.
     pub fn update_rotation(&mut self, mouse_delta: Vec2, delta_time: f32) {
-        if mouse_delta.length_squared() < f32::EPSILON {
+        if self.move_target.is_some() {
             return;
         }
 
-        // Calculate rotation deltas
-        let yaw_delta = -mouse_delta.x * self.sensitivity;
-        let pitch_delta = -mouse_delta.y * self.sensitivity;
+        let previous_target = self.transform.rotation;
+        // Any nonzero delta counts: thresholding would drop slow sub-pixel aim entirely
+        if mouse_delta != Vec2::ZERO {
+            // Calculate rotation deltas
+            let sensitivity = self.effective_sensitivity();
+            let yaw_delta = -mouse_delta.x * sensitivity;
+            let pitch_delta = -mouse_delta.y * sensitivity;
 
-        // Create rotation quaternions
-        let yaw_rotation = Quat::from_rotation_y(yaw_delta);
-        let pitch_rotation = Quat::from_rotation_x(pitch_delta);
+            // Create rotation quaternions
+            let yaw_rotation = Quat::from_rotation_y(yaw_delta);
+            let pitch_rotation = Quat::from_rotation_x(pitch_delta);
 
-        // Apply rotations (yaw around world Y, pitch around local X)
-        self.transform.rotation = yaw_rotation * self.transform.rotation * pitch_rotation;
+            // Apply rotations (yaw around world Y, pitch around local X)
+            self.transform.rotation = yaw_rotation * self.transform.rotation * pitch_rotation;
 
-        // Clamp pitch to prevent over-rotation
-        let (yaw, pitch, _roll) = self.transform.rotation.to_euler(EulerRot::YXZ);
-        let clamped_pitch = pitch.clamp(-1.5, 1.5); // ~86 degrees
-        self.transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, clamped_pitch, 0.0);
+            // Clamp pitch to prevent over-rotation
+            let (yaw, pitch, _roll) = self.transform.rotation.to_euler(EulerRot::YXZ);
+            let clamped_pitch = pitch.clamp(-MAX_PITCH, MAX_PITCH);
+            self.transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, clamped_pitch, 0.0);
+        }
+
+        // A rotation set directly (teleport, scripted move) is taken as-is rather than eased into
+        if self.smoothing.target_rotation != previous_target {
+            self.smoothing.previous_rotation = previous_target;
+        }
 
         // Apply exponential smoothing
         self.smoothing.previous_rotation = self.smoothing.previous_rotation.slerp(
             self.transform.rotation,
-            self.smoothing.alpha * delta_time * self.update_rate as f32
+            self.smoothing.blend_factor(delta_time, self.update_rate)
         );
+
+        // Look ahead along the input's angular velocity to offset the smoothing lag
+        if delta_time > 0.0 {
+            self.smoothing.angular_velocity = previous_target.angle_between(self.transform.rotation) / delta_time;
+        }
+        self.smoothing.predicted_rotation = self.smoothing.predict_rotation(self.transform.rotation, self.update_rate);
+        self.smoothing.target_rotation = self.transform.rotation;
+    }
+
+    /// Kick the view by `pitch`/`yaw` radians (positive pitch looks up); decays back via `update_recoil`
+    ///
+    /// The aim rotation (`transform.rotation`) is left untouched, so the view returns to where
+    /// the player was aiming.
+    pub fn apply_recoil(&mut self, pitch: f32, yaw: f32) {
+        self.recoil_offset += Vec2::new(pitch, yaw);
+    }
+
+    /// Decay the recoil offset toward zero
+    pub fn update_recoil(&mut self, delta_time: f32) {
+        if self.recoil_recovery_time <= 0.0 {
+            self.recoil_offset = Vec2::ZERO;
+            return;
+        }
+        // exp(-3) leaves 5% after one recovery time
+        self.recoil_offset *= (-3.0 * delta_time.max(0.0) / self.recoil_recovery_time).exp();
+    }
+
+    /// Rotation the view renders with: smoothed (and predicted) aim plus recoil, pitch clamped
+    ///
+    /// The smoothed aim only applies while it's chasing the current `transform.rotation`; a
+    /// rotation set directly since the last `update_rotation` shows unsmoothed.
+    pub fn view_rotation(&self) -> Quat {
+        let aim = if self.smoothing.target_rotation == self.transform.rotation {
+            self.smoothing.predicted_rotation
+        } else {
+            self.transform.rotation
+        };
+        if self.recoil_offset == Vec2::ZERO {
+            return aim;
+        }
+        let (yaw, pitch, _roll) = aim.to_euler(EulerRot::YXZ);
+        Quat::from_euler(
+            EulerRot::YXZ,
+            yaw + self.recoil_offset.y,
+            (pitch + self.recoil_offset.x).clamp(-MAX_PITCH, MAX_PITCH),
+            0.0,
+        )
+    }
+
+    /// Shake the view for an impact or explosion (trauma accumulates up to `shake.max_trauma`)
+    pub fn add_shake(&mut self, trauma: f32) {
+        self.shake.add_trauma(trauma);
+    }
+
+    /// Decay shake trauma toward zero
+    pub fn update_shake(&mut self, delta_time: f32) {
+        self.shake.update(delta_time);
+    }
+
+    /// Run one camera update with the delta time read from `clock`, returning that delta in seconds
+    ///
+    /// Applies mouse look, movement, recoil and shake decay. The first call only starts the clock.
+    pub fn update(
+        &mut self,
+        clock: &dyn TimeSource,
+        mouse_delta: Vec2,
+        movement_input: Vec3,
+        sprint: bool,
+        precision: bool,
+    ) -> f32 {
+        let now = clock.now();
+        let delta_time = self.last_update.map_or(0.0, |last| now.saturating_duration_since(last).as_secs_f32());
+        self.last_update = Some(now);
+
+        self.update_rotation(mouse_delta, delta_time);
+        self.update_movement(movement_input, sprint, precision, delta_time);
+        self.update_recoil(delta_time);
+        self.update_shake(delta_time);
+        delta_time
+    }
+
+    /// Move toward `target` (cutscenes, death cams), overriding input until it arrives
+    ///
+    /// Each call closes `1 - e^(-speed * dt)` of the remaining gap, lerping position and
+    /// slerping rotation, so the approach eases in at any frame rate. Once within a small
+    /// tolerance the camera snaps onto the target and input takes over again; call
+    /// `release_move_target` to hand control back earlier.
+    pub fn move_toward(&mut self, target: Transform, speed: f32, dt: f32) {
+        if self.move_target.is_none() {
+            // Don't let input-driven velocity carry on after the scripted move
+            self.movement_state.velocity = Vec3::ZERO;
+            self.smoothing.previous_value = Vec3::ZERO;
+        }
+        self.move_target = Some(target);
+
+        let blend = 1.0 - (-speed.max(0.0) * dt.max(0.0)).exp();
+        self.transform.translation = self.transform.translation.lerp(target.translation, blend);
+        self.transform.rotation = self.transform.rotation.slerp(target.rotation, blend).normalize();
+
+        if self.is_at_target(MOVE_TARGET_SNAP) {
+            self.transform.translation = target.translation;
+            self.transform.rotation = target.rotation;
+            self.smoothing.previous_rotation = target.rotation;
+            self.smoothing.predicted_rotation = target.rotation;
+            self.smoothing.target_rotation = target.rotation;
+            self.move_target = None;
+        }
+    }
+
+    /// Whether the camera is within `tolerance` (blocks and radians) of its `move_toward` target;
+    /// true when there is no target
+    pub fn is_at_target(&self, tolerance: f32) -> bool {
+        self.move_target.is_none_or(|target| {
+            self.transform.translation.distance(target.translation) <= tolerance
+                && self.transform.rotation.angle_between(target.rotation) <= tolerance
+        })
+    }
+
+    /// Stop a `move_toward` where it is and give control back to input
+    pub fn release_move_target(&mut self) {
+        self.move_target = None;
+    }
+
+    /// Switch movement to bit-exact fixed-point integration at `tick_rate` ticks per second
+    pub fn enable_deterministic_movement(&mut self, tick_rate: u32) {
+        self.deterministic = Some(DeterministicMovement::new(self.transform.translation, &self.movement_state, tick_rate));
+    }
+
+    /// Advance deterministic movement one fixed tick and move the camera to the result
+    ///
+    /// Does nothing unless `enable_deterministic_movement` was called.
+    pub fn step_deterministic(&mut self, input: &MovementInput) {
+        if let Some(movement) = &mut self.deterministic {
+            movement.step(input);
+            self.transform.translation = movement.position_f32();
+        }
     }
 
     /// Update camera movement with acceleration curves
     pub fn update_movement(&mut self, movement_input: Vec3, sprint: bool, precision: bool, delta_time: f32) {
+        if self.move_target.is_some() {
+            return; // A scripted move owns the camera
+        }
+
         // Calculate target velocity based on input
         let speed_multiplier = if sprint {
             self.movement_state.sprint_multiplier
@@ -140,7 +539,7 @@ impl CameraController {
         // Apply smoothing to eliminate micro-stutters
         let smoothed_velocity = self.smoothing.previous_value.lerp(
             world_velocity,
-            self.smoothing.alpha * delta_time * self.update_rate as f32
+            self.smoothing.blend_factor(delta_time, self.update_rate)
         );
 
         // Update position
@@ -150,11 +549,86 @@ impl CameraController {
 
     /// Get the view matrix for rendering (SIMD-optimized)
     pub fn view_matrix(&self) -> Mat4 {
-        self.transform.compute_matrix().inverse()
+        self.transform.with_rotation(self.view_rotation()).compute_matrix().inverse()
+    }
+
+    /// View matrix with screen shake at `time` seconds; the true transform is left untouched
+    pub fn view_matrix_with_shake(&self, time: f32) -> Mat4 {
+        let (offset, shake_rotation) = self.shake.offsets(time);
+        if offset == Vec3::ZERO && shake_rotation == Quat::IDENTITY {
+            return self.view_matrix();
+        }
+        let rotation = self.view_rotation();
+        Transform {
+            translation: self.transform.translation + rotation * offset,
+            rotation: rotation * shake_rotation,
+            scale: self.transform.scale,
+        }
+        .compute_matrix()
+        .inverse()
     }
 
     /// Get the projection matrix
     pub fn projection_matrix(&self) -> Mat4 {
         self.projection.get_projection_matrix()
     }
-}
\ No newline at end of file
+
+    /// View-projection matrix with the aspect ratio taken from `viewport` (pixels)
+    fn view_projection(&self, viewport: Vec2) -> Mat4 {
+        let projection = if viewport.x > 0.0 && viewport.y > 0.0 {
+            self.projection_for_aspect(viewport.x / viewport.y)
+        } else {
+            self.projection.clone()
+        };
+        projection.get_projection_matrix() * self.view_matrix()
+    }
+
+    /// Pixel position of a world point (origin top-left, y down), or None if it's behind the camera
+    ///
+    /// Points off the sides of the screen still return a position (outside the viewport), which
+    /// edge-of-screen indicators can clamp.
+    pub fn world_to_screen(&self, world: Vec3, viewport: Vec2) -> Option<Vec2> {
+        let clip = self.view_projection(viewport) * world.extend(1.0);
+        if clip.w <= f32::EPSILON {
+            return None;
+        }
+        let ndc = clip.truncate() / clip.w;
+        Some(Vec2::new(ndc.x + 1.0, 1.0 - ndc.y) * 0.5 * viewport)
+    }
+
+    /// World-space ray through a pixel, as (origin on the near plane, unit direction), or None
+    /// for an empty viewport (a minimized window)
+    pub fn screen_to_world_ray(&self, screen: Vec2, viewport: Vec2) -> Option<(Vec3, Vec3)> {
+        if viewport.x <= 0.0 || viewport.y <= 0.0 {
+            return None;
+        }
+        let ndc = Vec2::new(screen.x / viewport.x * 2.0 - 1.0, 1.0 - screen.y / viewport.y * 2.0);
+        let inverse = self.view_projection(viewport).inverse();
+
+        // Reverse-Z: depth 1 is the near plane, smaller depths are farther away
+        let near = inverse.project_point3(ndc.extend(1.0));
+        let far = inverse.project_point3(ndc.extend(0.5));
+        Some((near, (far - near).normalize()))
+    }
+
+    /// Approximate on-screen height in pixels of a bounding sphere (resolution and FOV aware)
+    pub fn projected_size(&self, bounds: &BoundingSphere, viewport_height: f32) -> f32 {
+        let distance = bounds.center.distance(self.transform.translation);
+        if distance <= bounds.radius {
+            return viewport_height; // Camera is inside the bounds - fills the screen
+        }
+
+        let half_fov_tan = (self.projection.fov * 0.5).tan();
+        bounds.radius * viewport_height / (distance * half_fov_tan)
+    }
+}
+
+/// Horizontal FOV for a vertical FOV at an aspect ratio
+fn horizontal_fov(vertical_fov: f32, aspect_ratio: f32) -> f32 {
+    2.0 * ((vertical_fov * 0.5).tan() * aspect_ratio).atan()
+}
+
+/// Vertical FOV for a horizontal FOV at an aspect ratio
+fn vertical_fov(horizontal_fov: f32, aspect_ratio: f32) -> f32 {
+    2.0 * ((horizontal_fov * 0.5).tan() / aspect_ratio).atan()
+}
@@ -0,0 +1,80 @@
+//! Bevy integration
+//!
+//! `CameraControllerPlugin` drives every entity holding both a `CameraController` and a
+//! `Camera3d` from the `InputManager`: mouse look, then WASD movement (Space/C for up/down,
+//! left Shift to sprint, left Ctrl for precision). The controller stays the source of truth;
+//! its transform and projection are copied onto the Bevy camera each frame.
+
+use crate::CameraController;
+use bevy::prelude::*;
+use mindland_input::{InputManager, InputPlugin};
+
+/// Keys moving the camera along its local axes (x = right, y = up, z = forward)
+const MOVEMENT_KEYS: [(KeyCode, Vec3); 6] = [
+    (KeyCode::W, Vec3::Z),
+    (KeyCode::S, Vec3::NEG_Z),
+    (KeyCode::D, Vec3::X),
+    (KeyCode::A, Vec3::NEG_X),
+    (KeyCode::Space, Vec3::Y),
+    (KeyCode::C, Vec3::NEG_Y),
+];
+
+/// Spawns a controlled camera and keeps it in sync with input
+pub struct CameraControllerPlugin;
+
+impl Plugin for CameraControllerPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputPlugin>() {
+            app.add_plugins(InputPlugin);
+        }
+        app.add_systems(Startup, spawn_controlled_camera)
+            .add_systems(Update, camera_controller_system);
+    }
+}
+
+/// Spawn a `Camera3d` driven by a default `CameraController`
+pub fn spawn_controlled_camera(mut commands: Commands) {
+    let controller = CameraController::new();
+    commands.spawn((
+        Camera3dBundle {
+            transform: controller.transform,
+            projection: Projection::Perspective(controller.projection.clone()),
+            ..default()
+        },
+        controller,
+    ));
+}
+
+/// Movement input from the held movement keys, normalized so diagonals aren't faster
+fn movement_input(input: &InputManager) -> Vec3 {
+    MOVEMENT_KEYS
+        .iter()
+        .filter(|(key, _)| input.is_key_pressed(*key))
+        .map(|(_, direction)| *direction)
+        .sum::<Vec3>()
+        .normalize_or_zero()
+}
+
+/// Apply this frame's mouse look and movement, then copy the result onto the Bevy camera
+pub fn camera_controller_system(
+    time: Res<Time>,
+    input: Res<InputManager>,
+    mut cameras: Query<(&mut CameraController, &mut Transform, Option<&mut Projection>), With<Camera3d>>,
+) {
+    let delta_time = time.delta_seconds();
+    let movement = movement_input(&input);
+    let sprint = input.is_key_pressed(KeyCode::ShiftLeft);
+    let precision = input.is_key_pressed(KeyCode::ControlLeft);
+
+    for (mut controller, mut transform, projection) in &mut cameras {
+        controller.update_rotation(input.mouse_delta(), delta_time);
+        controller.update_movement(movement, sprint, precision, delta_time);
+        controller.update_recoil(delta_time);
+        controller.update_shake(delta_time);
+
+        *transform = controller.transform.with_rotation(controller.view_rotation());
+        if let Some(mut projection) = projection {
+            *projection = Projection::Perspective(controller.projection.clone());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Trauma-based screen shake
+//!
+//! Impacts add trauma (0-1), which decays linearly. The shake amplitude is trauma², so small
+//! bumps barely register while big hits are violent, and smooth noise (rather than random jumps)
+//! drives the offsets so the shake reads as motion instead of flicker.
+
+use bevy::prelude::*;
+
+/// Screen shake state and tuning
+#[derive(Debug, Clone, PartialEq)]
+pub struct CameraShake {
+    pub trauma: f32,
+    pub max_trauma: f32,  // Cap on accumulated trauma, so repeated impacts can't shake forever
+    pub decay_time: f32,  // Seconds for full trauma to decay to zero
+    pub max_offset: f32,  // Positional shake at full intensity, in blocks
+    pub max_angle: f32,   // Yaw/pitch/roll shake at full intensity, in radians
+    pub frequency: f32,   // Noise samples per second; higher is more violent
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            max_trauma: 1.0,
+            decay_time: 1.0,
+            max_offset: 0.15,
+            max_angle: 0.05, // ~3 degrees
+            frequency: 20.0,
+        }
+    }
+}
+
+impl CameraShake {
+    /// Add trauma from an impact (negative values are ignored), capped at `max_trauma`
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma.max(0.0)).min(self.max_trauma);
+    }
+
+    /// Decay trauma toward zero
+    pub fn update(&mut self, delta_time: f32) {
+        if self.decay_time <= 0.0 {
+            self.trauma = 0.0;
+            return;
+        }
+        self.trauma = (self.trauma - delta_time.max(0.0) / self.decay_time).max(0.0);
+    }
+
+    /// Shake amplitude, 0-1
+    pub fn intensity(&self) -> f32 {
+        self.trauma.clamp(0.0, 1.0).powi(2)
+    }
+
+    /// (Local positional offset, local rotation offset) at `time` seconds
+    pub fn offsets(&self, time: f32) -> (Vec3, Quat) {
+        let intensity = self.intensity();
+        if intensity == 0.0 {
+            return (Vec3::ZERO, Quat::IDENTITY);
+        }
+
+        // Each channel samples the same noise at a different seed, so they move independently
+        let t = time * self.frequency;
+        let channel = |seed: u32| gradient_noise(t, seed) * intensity;
+        let offset = Vec3::new(channel(0), channel(1), channel(2)) * self.max_offset;
+        let rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            channel(3) * self.max_angle,
+            channel(4) * self.max_angle,
+            channel(5) * self.max_angle,
+        );
+        (offset, rotation)
+    }
+}
+
+/// 1D Perlin gradient noise in roughly [-1, 1], zero at integer `x`
+fn gradient_noise(x: f32, seed: u32) -> f32 {
+    let cell = x.floor();
+    let f = x - cell;
+    let gradient = |i: i32| {
+        // Integer hash to a gradient slope in [-1, 1]
+        let mut h = (i as u32).wrapping_mul(0x9E37_79B1) ^ seed.wrapping_mul(0x85EB_CA6B);
+        h ^= h >> 15;
+        h = h.wrapping_mul(0x2C1B_3C6D);
+        h ^= h >> 12;
+        (h & 0xFFFF) as f32 / 32767.5 - 1.0
+    };
+    let fade = f * f * f * (f * (f * 6.0 - 15.0) + 10.0);
+    let (left, right) = (gradient(cell as i32) * f, gradient(cell as i32 + 1) * (f - 1.0));
+    (left + (right - left) * fade) * 2.0 // Raw range is about ±0.5
+}
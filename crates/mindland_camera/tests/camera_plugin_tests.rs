@@ -0,0 +1,55 @@
+//! Tests for driving a Bevy camera entity from the CameraController
+//!
+//! **Feature: camera-plugin, Property 1: Mouse Input Turns The Spawned Camera**
+
+use bevy::{input::mouse::MouseMotion, prelude::*};
+use mindland_camera::{CameraController, CameraControllerPlugin};
+use mindland_input::InputManager;
+
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, CameraControllerPlugin));
+    app.update(); // Startup spawns the camera
+    app
+}
+
+fn camera_transform(app: &mut App) -> Transform {
+    *app.world.query_filtered::<&Transform, With<CameraController>>().single(&app.world)
+}
+
+fn yaw(transform: &Transform) -> f32 {
+    transform.rotation.to_euler(EulerRot::YXZ).0
+}
+
+#[cfg(test)]
+mod camera_plugin_tests {
+    use super::*;
+
+    #[test]
+    fn test_mouse_delta_turns_camera_entity() {
+        // **Feature: camera-plugin, Property 1: Mouse Input Turns The Spawned Camera**
+
+        let mut app = headless_app();
+        let before = camera_transform(&mut app);
+        assert_eq!(before.translation, CameraController::new().transform.translation);
+
+        app.world.send_event(MouseMotion { delta: Vec2::new(100.0, 0.0) });
+        app.update();
+        let controller = app.world.query::<&CameraController>().single(&app.world);
+        let (aim, view) = (yaw(&controller.transform), controller.view_rotation());
+        let expected = -100.0 * CameraController::new().sensitivity; // Moving right turns clockwise
+        assert!((aim - yaw(&before) - expected).abs() < 1e-4, "yaw {} -> {}", yaw(&before), aim);
+
+        // The entity shows the smoothed view of that aim
+        assert_eq!(camera_transform(&mut app).rotation, view);
+    }
+
+    #[test]
+    fn test_camera_entity_follows_controller_without_input() {
+        let mut app = headless_app();
+        let before = camera_transform(&mut app);
+        app.update();
+        assert_eq!(camera_transform(&mut app), before);
+        assert_eq!(app.world.resource::<InputManager>().mouse_delta(), Vec2::ZERO);
+    }
+}
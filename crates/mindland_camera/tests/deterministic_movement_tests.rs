@@ -0,0 +1,80 @@
+//! Tests for fixed-point deterministic movement
+//!
+//! **Feature: deterministic-movement, Property 1: A Recorded Input Sequence Replays To The Same Bits**
+
+use bevy::prelude::*;
+use mindland_camera::{CameraController, DeterministicMovement, MovementInput};
+
+/// Hash of `replay()`'s final position. It is pinned because any change here means a client
+/// and server would disagree.
+const RECORDED_POSITION_HASH: u64 = 10_517_863_104_029_566_129;
+
+/// A scripted few seconds of play: walk, turn, sprint, strafe, fly up and coast to a stop
+fn recorded_inputs() -> Vec<MovementInput> {
+    let mut inputs = Vec::new();
+    for tick in 0..600u32 {
+        inputs.push(MovementInput {
+            forward: if tick < 400 { 1 } else { 0 },
+            strafe: if (150..250).contains(&tick) { -1 } else { 0 },
+            vertical: if (300..340).contains(&tick) { 1 } else { 0 },
+            yaw: (tick * 97) as u16,
+            sprint: (100..200).contains(&tick),
+            precision: (250..300).contains(&tick),
+        });
+    }
+    inputs
+}
+
+fn replay() -> CameraController {
+    let mut camera = CameraController::new();
+    camera.transform.translation = Vec3::new(12.5, 64.0, -3.25);
+    camera.enable_deterministic_movement(60);
+    for input in recorded_inputs() {
+        camera.step_deterministic(&input);
+    }
+    camera
+}
+
+#[cfg(test)]
+mod deterministic_movement_tests {
+    use super::*;
+
+    #[test]
+    fn test_recorded_sequence_matches_pinned_hash() {
+        // **Feature: deterministic-movement, Property 1: A Recorded Input Sequence Replays To The Same Bits**
+
+        let camera = replay();
+        let movement = camera.deterministic.as_ref().unwrap();
+
+        assert_eq!(movement.position_hash(), RECORDED_POSITION_HASH, "position {:?}", movement.position);
+        assert_eq!(movement.position, replay().deterministic.unwrap().position);
+        assert_eq!(camera.transform.translation, movement.position_f32());
+    }
+
+    #[test]
+    fn test_forward_follows_quantized_yaw() {
+        let mut movement = DeterministicMovement::new(Vec3::ZERO, &CameraController::new().movement_state, 60);
+        let quarter_turn = MovementInput::quantize_yaw(std::f32::consts::FRAC_PI_2);
+        assert_eq!(quarter_turn, 16384);
+
+        for _ in 0..120 {
+            movement.step(&MovementInput { forward: 1, yaw: quarter_turn, ..default() });
+        }
+
+        // Yawing left a quarter turn faces -x, like `Quat::from_rotation_y`
+        let position = movement.position_f32();
+        assert!(position.x < -1.0, "moved {position:?}");
+        assert!(position.z.abs() < 1e-3 && position.y == 0.0, "moved {position:?}");
+    }
+
+    #[test]
+    fn test_stepping_is_a_no_op_until_enabled() {
+        let mut camera = CameraController::new();
+        let start = camera.transform.translation;
+
+        camera.step_deterministic(&MovementInput { forward: 1, ..default() });
+
+        assert_eq!(camera.transform.translation, start);
+        assert!(camera.deterministic.is_none());
+    }
+}
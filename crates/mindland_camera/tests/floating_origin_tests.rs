@@ -0,0 +1,49 @@
+//! Tests for large-world origin rebasing
+//!
+//! **Feature: floating-origin, Property 1: Rebasing Preserves Relative Positions**
+
+use bevy::{math::{DVec3, I64Vec3}, prelude::*};
+use mindland_camera::{floating_origin_system, CameraController, FloatingOrigin};
+
+#[cfg(test)]
+mod floating_origin_tests {
+    use super::*;
+
+    #[test]
+    fn test_rebase_after_1e6_units_keeps_relative_positions_exact() {
+        // **Feature: floating-origin, Property 1: Rebasing Preserves Relative Positions**
+
+        let mut app = App::new();
+        app.init_resource::<FloatingOrigin>();
+        app.add_systems(Update, floating_origin_system);
+
+        let mut camera = CameraController::new();
+        camera.transform.translation = Vec3::new(1.0e6, 64.0, -1.0e6);
+        let camera_start = camera.transform.translation;
+        app.world.spawn(camera);
+
+        let block_offset = Vec3::new(1.5, -2.25, 3.0);
+        let block = app.world.spawn(Transform::from_translation(camera_start + block_offset)).id();
+
+        app.update();
+
+        let origin = app.world.resource::<FloatingOrigin>().clone();
+        let camera = app.world.query::<&CameraController>().single(&app.world).transform.translation;
+        let block = app.world.get::<Transform>(block).unwrap().translation;
+
+        assert!(camera.length() <= origin.rebase_distance, "camera not rebased: {camera}");
+        assert_eq!(block - camera, block_offset);
+        assert_eq!(origin.offset, I64Vec3::new(1_000_000, 64, -1_000_000) - camera.as_dvec3().as_i64vec3());
+        assert_eq!(origin.to_world(camera), DVec3::new(1.0e6, 64.0, -1.0e6));
+        assert_eq!(origin.offset.x % 32, 0);
+    }
+
+    #[test]
+    fn test_no_rebase_within_distance() {
+        // **Feature: floating-origin, Property 1: Rebasing Preserves Relative Positions**
+
+        let origin = FloatingOrigin::default();
+        assert_eq!(origin.rebase_shift(Vec3::new(100.0, 0.0, 100.0)), None);
+        assert_eq!(origin.rebase_shift(Vec3::new(5000.0, 0.0, 0.0)), Some(IVec3::new(4992, 0, 0)));
+    }
+}
@@ -0,0 +1,74 @@
+//! Tests for aspect-correct FOV modes
+//!
+//! **Feature: fov-mode, Property 1: Hor+ Widens Horizontal FOV At Constant Vertical FOV**
+
+use bevy::prelude::*;
+use mindland_camera::{CameraController, FovMode};
+
+const WIDESCREEN: Vec2 = Vec2::new(1920.0, 1080.0);
+const ULTRAWIDE: Vec2 = Vec2::new(3440.0, 1440.0);
+
+#[cfg(test)]
+mod fov_mode_tests {
+    use super::*;
+
+    #[test]
+    fn test_hor_plus_widens_horizontal_fov() {
+        // **Feature: fov-mode, Property 1: Hor+ Widens Horizontal FOV At Constant Vertical FOV**
+
+        let mut camera = CameraController::new();
+        assert_eq!(camera.fov_mode, FovMode::HorizontalPlus);
+        camera.set_viewport_size(WIDESCREEN);
+        let (vertical, horizontal) = (camera.vertical_fov(), camera.horizontal_fov());
+
+        camera.set_viewport_size(ULTRAWIDE);
+
+        assert!((camera.vertical_fov() - vertical).abs() < 1e-6);
+        assert!(camera.horizontal_fov() > horizontal + 0.1, "{} vs {}", camera.horizontal_fov(), horizontal);
+    }
+
+    #[test]
+    fn test_vert_minus_keeps_horizontal_fov() {
+        let mut camera = CameraController::new();
+        camera.fov_mode = FovMode::Vertical;
+        camera.set_viewport_size(WIDESCREEN);
+        let (vertical, horizontal) = (camera.vertical_fov(), camera.horizontal_fov());
+
+        camera.set_viewport_size(ULTRAWIDE);
+
+        assert!((camera.horizontal_fov() - horizontal).abs() < 1e-5);
+        assert!(camera.vertical_fov() < vertical - 0.1);
+
+        // Back to 16:9 restores the original FOV
+        camera.set_viewport_size(WIDESCREEN);
+        assert!((camera.vertical_fov() - vertical).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_vert_minus_zoom_survives_resize() {
+        let mut camera = CameraController::new();
+        camera.fov_mode = FovMode::Vertical;
+        camera.zoom_sensitivity = 1.0;
+        camera.projection.fov = camera.base_fov * 0.5;
+        let sensitivity = camera.effective_sensitivity();
+
+        camera.set_viewport_size(ULTRAWIDE);
+
+        assert!(camera.vertical_fov() < camera.base_fov, "still zoomed in");
+        assert!((camera.effective_sensitivity() - sensitivity).abs() < sensitivity * 0.1);
+    }
+
+    #[test]
+    fn test_viewport_projection_follows_mode() {
+        let mut camera = CameraController::new();
+        camera.fov_mode = FovMode::Vertical;
+        camera.set_viewport_size(WIDESCREEN);
+
+        // A half-width split-screen viewport keeps the same horizontal FOV
+        let split = camera.projection_for_aspect(WIDESCREEN.x * 0.5 / WIDESCREEN.y);
+        assert!(split.fov > camera.vertical_fov());
+
+        camera.fov_mode = FovMode::HorizontalPlus;
+        assert_eq!(camera.projection_for_aspect(0.5).fov, camera.vertical_fov());
+    }
+}
@@ -0,0 +1,32 @@
+//! Tests for clock-driven camera updates
+//!
+//! **Feature: time-source, Property 2: Camera Updates Use The Injected Clock**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_performance::MockTimeSource;
+use std::time::Duration;
+
+#[cfg(test)]
+mod frame_time_tests {
+    use super::*;
+
+    #[test]
+    fn test_update_uses_mock_delta_time() {
+        // **Feature: time-source, Property 2: Camera Updates Use The Injected Clock**
+
+        let clock = MockTimeSource::new();
+        let mut camera = CameraController::new();
+        camera.add_shake(1.0);
+
+        assert_eq!(camera.update(&clock, Vec2::ZERO, Vec3::ZERO, false, false), 0.0); // Starts the clock
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(camera.update(&clock, Vec2::ZERO, Vec3::ZERO, false, false), 0.25);
+        assert!((camera.shake.trauma - (1.0 - 0.25 / camera.shake.decay_time)).abs() < 1e-6);
+
+        // A stopped clock means no time passes, so nothing moves
+        let position = camera.transform.translation;
+        assert_eq!(camera.update(&clock, Vec2::ZERO, Vec3::Z, false, false), 0.0);
+        assert_eq!(camera.transform.translation, position);
+    }
+}
@@ -0,0 +1,74 @@
+//! Tests for scripted camera moves toward a target transform
+//!
+//! **Feature: camera-move-toward, Property 1: Repeated Steps Converge On The Target**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+
+const DT: f32 = 1.0 / 60.0;
+
+fn death_cam() -> Transform {
+    Transform::from_xyz(12.0, 20.0, -8.0).looking_at(Vec3::new(0.0, 1.8, 0.0), Vec3::Y)
+}
+
+#[cfg(test)]
+mod move_toward_tests {
+    use super::*;
+
+    #[test]
+    fn test_move_toward_converges_within_bounded_steps() {
+        // **Feature: camera-move-toward, Property 1: Repeated Steps Converge On The Target**
+
+        let mut camera = CameraController::new();
+        let target = death_cam();
+        let start_gap = camera.transform.translation.distance(target.translation);
+
+        let mut steps = 0;
+        let mut previous_gap = start_gap;
+        while camera.move_target.is_some() || steps == 0 {
+            camera.move_toward(target, 4.0, DT);
+            steps += 1;
+            let gap = camera.transform.translation.distance(target.translation);
+            assert!(gap <= previous_gap, "step {steps} moved away from the target");
+            previous_gap = gap;
+            assert!(steps < 300, "no convergence after {steps} steps (5 seconds at speed 4)");
+        }
+
+        assert_eq!(camera.transform.translation, target.translation);
+        assert_eq!(camera.transform.rotation, target.rotation);
+        assert!(camera.is_at_target(0.0));
+    }
+
+    #[test]
+    fn test_speed_and_frame_rate_independence() {
+        // **Feature: camera-move-toward, Property 1: Repeated Steps Converge On The Target**
+
+        let mut at_60 = CameraController::new();
+        let mut at_120 = CameraController::new();
+        for _ in 0..30 {
+            at_60.move_toward(death_cam(), 3.0, DT);
+        }
+        for _ in 0..60 {
+            at_120.move_toward(death_cam(), 3.0, DT / 2.0);
+        }
+
+        assert!(at_60.transform.translation.distance(at_120.transform.translation) < 1e-3);
+        assert!(!at_60.is_at_target(0.1), "half a second at speed 3 is still on the way");
+    }
+
+    #[test]
+    fn test_input_ignored_until_target_reached_or_released() {
+        let mut camera = CameraController::new();
+        camera.move_toward(death_cam(), 2.0, DT);
+        let (position, rotation) = (camera.transform.translation, camera.transform.rotation);
+
+        camera.update_rotation(Vec2::new(40.0, 10.0), DT);
+        camera.update_movement(Vec3::Z, true, false, DT);
+        assert_eq!(camera.transform.translation, position);
+        assert_eq!(camera.transform.rotation, rotation);
+
+        camera.release_move_target();
+        camera.update_rotation(Vec2::new(40.0, 0.0), DT);
+        assert_ne!(camera.transform.rotation, rotation);
+    }
+}
@@ -0,0 +1,102 @@
+//! Tests for mouse-smoothing latency compensation
+//!
+//! **Feature: camera-prediction, Property 1: Prediction Reduces Smoothing Lag Without Overshoot**
+
+use bevy::prelude::*;
+use mindland_camera::{CameraController, ExponentialSmoothing};
+
+/// Turn at a constant mouse velocity and return the angle the view trails the input by
+fn steady_state_lag(prediction_strength: f32) -> (CameraController, f32) {
+    let mut camera = CameraController::new();
+    camera.smoothing.set_alpha(0.99); // Heavy smoothing so the lag is easy to measure
+    camera.smoothing.set_prediction_strength(prediction_strength);
+
+    for _ in 0..60 {
+        camera.update_rotation(Vec2::new(10.0, 0.0), 1.0 / 60.0);
+    }
+    let lag = camera.smoothing.predicted_rotation.angle_between(camera.transform.rotation);
+    (camera, lag)
+}
+
+#[cfg(test)]
+mod prediction_tests {
+    use super::*;
+
+    #[test]
+    fn test_prediction_reduces_lag() {
+        // **Feature: camera-prediction, Property 1: Prediction Reduces Smoothing Lag Without Overshoot**
+
+        let (_, unpredicted) = steady_state_lag(0.0);
+        let (_, half) = steady_state_lag(0.5);
+        let (_, full) = steady_state_lag(1.0);
+
+        assert!(unpredicted > 0.01, "smoothing should lag a moving input, got {unpredicted}");
+        assert!(half < unpredicted * 0.75, "half prediction: {half} vs {unpredicted}");
+        assert!(full < half, "full prediction: {full} vs {half}");
+    }
+
+    #[test]
+    fn test_prediction_never_overshoots_input() {
+        // **Feature: camera-prediction, Property 1: Prediction Reduces Smoothing Lag Without Overshoot**
+
+        let (camera, _) = steady_state_lag(1.0);
+        let smoothing = &camera.smoothing;
+        let target = camera.transform.rotation;
+
+        // The predicted rotation lies on the arc between the smoothed rotation and the input
+        let total = smoothing.previous_rotation.angle_between(target);
+        let covered = smoothing.previous_rotation.angle_between(smoothing.predicted_rotation);
+        let remaining = smoothing.predicted_rotation.angle_between(target);
+        assert!((covered + remaining - total).abs() < 1e-4);
+
+        // Even an absurd velocity estimate stops at the input
+        let mut smoothing = smoothing.clone();
+        smoothing.angular_velocity = 1000.0;
+        assert!(smoothing.predict_rotation(target, camera.update_rate).angle_between(target) < 1e-3);
+    }
+
+    #[test]
+    fn test_zero_strength_matches_smoothed_rotation() {
+        let (camera, _) = steady_state_lag(0.0);
+        assert_eq!(camera.smoothing.predicted_rotation, camera.smoothing.previous_rotation);
+    }
+
+    #[test]
+    fn test_prediction_strength_is_clamped() {
+        let mut smoothing = ExponentialSmoothing::new(0.8);
+        assert_eq!(smoothing.prediction_strength(), 0.0);
+
+        smoothing.set_prediction_strength(1.5);
+        assert_eq!(smoothing.prediction_strength(), 1.0);
+
+        smoothing.set_prediction_strength(f32::NAN);
+        assert_eq!(smoothing.prediction_strength(), 0.0);
+    }
+
+    #[test]
+    fn test_view_matrix_shows_predicted_rotation() {
+        // While turning, the rendered view is the predicted rotation: ahead of the plain smoothed
+        // rotation, still short of the raw aim
+        let (mut camera, _) = steady_state_lag(0.5);
+        let view_rotation = Quat::from_mat4(&camera.view_matrix().inverse());
+        let smoothing = &camera.smoothing;
+
+        assert!(view_rotation.angle_between(smoothing.predicted_rotation) < 1e-4);
+        let view_lag = view_rotation.angle_between(camera.transform.rotation);
+        assert!(view_lag < smoothing.previous_rotation.angle_between(camera.transform.rotation), "prediction leads the smoothed view");
+        assert!(view_lag > 1e-3, "the view is smoothed, not the raw aim");
+
+        // Setting the rotation directly shows it at once
+        camera.transform.rotation = Quat::from_rotation_y(1.0);
+        assert!(Quat::from_mat4(&camera.view_matrix().inverse()).angle_between(camera.transform.rotation) < 1e-4);
+    }
+
+    #[test]
+    fn test_smoothed_view_settles_once_turning_stops() {
+        let (mut camera, _) = steady_state_lag(1.0);
+        for _ in 0..120 {
+            camera.update_rotation(Vec2::ZERO, 1.0 / 60.0);
+        }
+        assert!(camera.view_rotation().angle_between(camera.transform.rotation) < 1e-3);
+    }
+}
@@ -0,0 +1,40 @@
+//! Tests for camera projection helpers
+//!
+//! **Feature: screen-space-lod, Property 1: Projected Size Scales With Resolution**
+
+use mindland_assets::BoundingSphere;
+use mindland_camera::CameraController;
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod projection_tests {
+    use super::*;
+
+    #[test]
+    fn test_doubling_viewport_height_doubles_projected_size() {
+        // **Feature: screen-space-lod, Property 1: Projected Size Scales With Resolution**
+
+        let camera = CameraController::new();
+        let bounds = BoundingSphere::new(Vec3::new(0.0, 1.8, -20.0), 1.0);
+
+        let at_1080 = camera.projected_size(&bounds, 1080.0);
+        let at_2160 = camera.projected_size(&bounds, 2160.0);
+
+        assert!(at_1080 > 0.0);
+        assert!((at_2160 - at_1080 * 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_projected_size_shrinks_with_distance_and_grows_with_zoom() {
+        let mut camera = CameraController::new();
+        let near = BoundingSphere::new(Vec3::new(0.0, 1.8, -10.0), 1.0);
+        let far = BoundingSphere::new(Vec3::new(0.0, 1.8, -40.0), 1.0);
+
+        assert!(camera.projected_size(&near, 1080.0) > camera.projected_size(&far, 1080.0));
+
+        // Narrower FOV magnifies the object
+        let wide = camera.projected_size(&far, 1080.0);
+        camera.projection.fov *= 0.5;
+        assert!(camera.projected_size(&far, 1080.0) > wide);
+    }
+}
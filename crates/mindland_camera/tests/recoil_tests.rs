@@ -0,0 +1,78 @@
+//! Tests for transient camera recoil
+//!
+//! **Feature: camera-recoil, Property 1: View Returns To Aim After Recoil**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+
+const DT: f32 = 1.0 / 60.0;
+
+/// Camera aimed somewhere other than straight ahead
+fn aimed_camera() -> CameraController {
+    let mut camera = CameraController::new();
+    camera.transform.rotation = Quat::from_euler(EulerRot::YXZ, 0.7, -0.2, 0.0);
+    camera
+}
+
+#[cfg(test)]
+mod recoil_tests {
+    use super::*;
+
+    #[test]
+    fn test_view_returns_to_aim_after_recoil() {
+        // **Feature: camera-recoil, Property 1: View Returns To Aim After Recoil**
+
+        let mut camera = aimed_camera();
+        let aim = camera.transform.rotation;
+
+        camera.apply_recoil(0.08, 0.02);
+        assert!(camera.view_rotation().angle_between(aim) > 0.05);
+        assert_eq!(camera.transform.rotation, aim); // Aim itself is not altered
+
+        for _ in 0..120 {
+            camera.update_recoil(DT);
+        }
+        assert!(camera.view_rotation().angle_between(aim) < 1e-3);
+        assert_eq!(camera.transform.rotation, aim);
+    }
+
+    #[test]
+    fn test_recovery_time_controls_decay() {
+        // **Feature: camera-recoil, Property 1: View Returns To Aim After Recoil**
+
+        let mut camera = aimed_camera();
+        camera.recoil_recovery_time = 0.5;
+        camera.apply_recoil(0.1, 0.0);
+
+        for _ in 0..30 {
+            camera.update_recoil(DT); // Exactly one recovery time
+        }
+        assert!((camera.recoil_offset.x - 0.1 * (-3.0f32).exp()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_kicks_accumulate_and_zero_recovery_snaps_back() {
+        let mut camera = aimed_camera();
+        camera.apply_recoil(0.05, 0.01);
+        camera.apply_recoil(0.05, -0.01);
+        assert!(camera.recoil_offset.abs_diff_eq(Vec2::new(0.1, 0.0), 1e-6));
+
+        camera.recoil_recovery_time = 0.0;
+        camera.update_recoil(DT);
+        assert_eq!(camera.recoil_offset, Vec2::ZERO);
+        assert_eq!(camera.view_rotation(), camera.transform.rotation);
+    }
+
+    #[test]
+    fn test_recoil_pitch_is_clamped_and_reaches_view_matrix() {
+        let mut camera = CameraController::new();
+        camera.transform.rotation = Quat::from_euler(EulerRot::YXZ, 0.0, 1.4, 0.0);
+        camera.apply_recoil(1.0, 0.0);
+
+        let (_, pitch, _) = camera.view_rotation().to_euler(EulerRot::YXZ);
+        assert!(pitch <= 1.5 + 1e-5);
+
+        let without_recoil = camera.transform.compute_matrix().inverse();
+        assert!(!camera.view_matrix().abs_diff_eq(without_recoil, 1e-4));
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests for world-to-screen and screen-to-world projection
+//!
+//! **Feature: screen-projection, Property 1: World To Screen To Ray Round-Trips**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+
+/// Distance from `point` to the ray
+fn ray_distance(origin: Vec3, direction: Vec3, point: Vec3) -> f32 {
+    let to_point = point - origin;
+    (to_point - direction * to_point.dot(direction)).length()
+}
+
+fn turned_camera() -> CameraController {
+    let mut camera = CameraController::new();
+    camera.transform.translation = Vec3::new(3.0, 2.0, -1.0);
+    camera.transform.rotation = Quat::from_euler(EulerRot::YXZ, 0.6, -0.3, 0.0);
+    camera
+}
+
+#[cfg(test)]
+mod screen_projection_tests {
+    use super::*;
+
+    #[test]
+    fn test_world_screen_ray_round_trip() {
+        // **Feature: screen-projection, Property 1: World To Screen To Ray Round-Trips**
+
+        let camera = turned_camera();
+        for viewport in [Vec2::new(1920.0, 1080.0), Vec2::new(800.0, 800.0)] {
+            for offset in [Vec3::new(0.0, 0.0, -10.0), Vec3::new(2.0, -1.0, -25.0), Vec3::new(-4.0, 3.0, -8.0)] {
+                let world = camera.transform.translation + camera.transform.rotation * offset;
+
+                let screen = camera.world_to_screen(world, viewport).expect("point is in front of the camera");
+                let (origin, direction) = camera.screen_to_world_ray(screen, viewport).unwrap();
+
+                assert!((direction.length() - 1.0).abs() < 1e-4);
+                assert!(direction.dot(world - origin) > 0.0, "ray should point toward the point");
+                assert!(ray_distance(origin, direction, world) < 1e-2, "ray misses {world} by too much");
+            }
+        }
+    }
+
+    #[test]
+    fn test_center_of_screen_is_view_direction() {
+        // **Feature: screen-projection, Property 1: World To Screen To Ray Round-Trips**
+
+        let camera = turned_camera();
+        let viewport = Vec2::new(1280.0, 720.0);
+        let forward = camera.transform.forward();
+
+        let ahead = camera.transform.translation + forward * 15.0;
+        let screen = camera.world_to_screen(ahead, viewport).unwrap();
+        assert!(screen.abs_diff_eq(viewport * 0.5, 1e-2));
+
+        let (origin, direction) = camera.screen_to_world_ray(viewport * 0.5, viewport).unwrap();
+        assert!(direction.abs_diff_eq(forward, 1e-4));
+        assert!((origin.distance(camera.transform.translation) - camera.projection.near).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_screen_axes_follow_pixel_convention() {
+        let camera = CameraController::new();
+        let viewport = Vec2::new(1920.0, 1080.0);
+        let eye = camera.transform.translation;
+
+        // Origin is top-left: right is +x, up is -y
+        let right = camera.world_to_screen(eye + Vec3::new(1.0, 0.0, -10.0), viewport).unwrap();
+        let up = camera.world_to_screen(eye + Vec3::new(0.0, 1.0, -10.0), viewport).unwrap();
+        assert!(right.x > viewport.x * 0.5);
+        assert!(up.y < viewport.y * 0.5);
+    }
+
+    #[test]
+    fn test_point_behind_camera_is_none() {
+        let camera = turned_camera();
+        let behind = camera.transform.translation - camera.transform.forward() * 5.0;
+        assert_eq!(camera.world_to_screen(behind, Vec2::new(1920.0, 1080.0)), None);
+    }
+
+    #[test]
+    fn test_empty_viewport_has_no_ray() {
+        // A minimized window reports a 0x0 viewport; picking must not divide by it
+        let camera = turned_camera();
+        for viewport in [Vec2::ZERO, Vec2::new(1920.0, 0.0), Vec2::new(0.0, 1080.0)] {
+            assert_eq!(camera.screen_to_world_ray(Vec2::ZERO, viewport), None);
+        }
+    }
+}
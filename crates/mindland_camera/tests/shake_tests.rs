@@ -0,0 +1,69 @@
+//! Tests for trauma-based camera shake
+//!
+//! **Feature: camera-shake, Property 1: Shake Decays Away And Never Moves The True Transform**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+
+const DT: f32 = 1.0 / 60.0;
+
+#[cfg(test)]
+mod shake_tests {
+    use super::*;
+
+    #[test]
+    fn test_trauma_decays_to_zero_over_decay_time() {
+        // **Feature: camera-shake, Property 1: Shake Decays Away And Never Moves The True Transform**
+
+        let mut camera = CameraController::new();
+        camera.shake.decay_time = 0.5;
+        camera.add_shake(1.0);
+
+        for _ in 0..15 {
+            camera.update_shake(DT); // Half the decay time
+        }
+        assert!((camera.shake.trauma - 0.5).abs() < 1e-4);
+        assert!((camera.shake.intensity() - 0.25).abs() < 1e-4, "intensity is trauma squared");
+
+        for _ in 0..16 {
+            camera.update_shake(DT);
+        }
+        assert_eq!(camera.shake.trauma, 0.0);
+        assert_eq!(camera.view_matrix_with_shake(3.7), camera.view_matrix());
+    }
+
+    #[test]
+    fn test_zero_trauma_leaves_view_unmodified() {
+        // **Feature: camera-shake, Property 1: Shake Decays Away And Never Moves The True Transform**
+
+        let mut camera = CameraController::new();
+        camera.transform.rotation = Quat::from_euler(EulerRot::YXZ, 0.4, 0.1, 0.0);
+        for time in [0.0, 0.37, 12.5] {
+            assert_eq!(camera.view_matrix_with_shake(time), camera.view_matrix());
+        }
+    }
+
+    #[test]
+    fn test_shake_offsets_view_but_not_transform() {
+        let mut camera = CameraController::new();
+        let transform = camera.transform;
+        camera.add_shake(0.8);
+
+        let moved = [0.13, 0.51, 0.97].iter().any(|&time| camera.view_matrix_with_shake(time) != camera.view_matrix());
+        assert!(moved);
+        assert_eq!(camera.transform, transform);
+    }
+
+    #[test]
+    fn test_impacts_accumulate_up_to_cap() {
+        let mut camera = CameraController::new();
+        camera.add_shake(0.3);
+        camera.add_shake(0.3);
+        assert!((camera.shake.trauma - 0.6).abs() < 1e-6);
+
+        camera.add_shake(5.0);
+        assert_eq!(camera.shake.trauma, camera.shake.max_trauma);
+        camera.add_shake(-1.0);
+        assert_eq!(camera.shake.trauma, camera.shake.max_trauma);
+    }
+}
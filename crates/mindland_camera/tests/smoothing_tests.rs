@@ -0,0 +1,72 @@
+//! Tests for camera exponential smoothing
+//!
+//! **Feature: camera-smoothing, Property 1: Stable Smoothing Factor**
+
+use bevy::prelude::*;
+use mindland_camera::{CameraController, ExponentialSmoothing, DEFAULT_SMOOTHING_ALPHA};
+
+#[cfg(test)]
+mod smoothing_tests {
+    use super::*;
+
+    #[test]
+    fn test_alpha_is_clamped() {
+        // **Feature: camera-smoothing, Property 1: Stable Smoothing Factor**
+
+        let mut smoothing = ExponentialSmoothing::new(2.0);
+        assert_eq!(smoothing.alpha(), 1.0);
+
+        smoothing.set_alpha(-0.5);
+        assert_eq!(smoothing.alpha(), 0.0);
+
+        smoothing.set_alpha(f32::NAN);
+        assert_eq!(smoothing.alpha(), 0.0);
+    }
+
+    #[test]
+    fn test_zero_alpha_means_no_smoothing() {
+        // **Feature: camera-smoothing, Property 1: Stable Smoothing Factor**
+        // With alpha 0 the smoothed velocity is exactly the unsmoothed world velocity
+
+        let mut camera = CameraController::new();
+        camera.smoothing.set_alpha(0.0);
+        let start = camera.transform.translation;
+
+        let dt = 0.016;
+        camera.update_movement(Vec3::new(0.0, 0.0, 1.0), false, false, dt);
+
+        // From rest: velocity = (target - 0) * 10 * dt along forward (-Z)
+        let expected_velocity = Vec3::new(0.0, 0.0, -5.0 * 10.0 * dt);
+        assert!(camera.smoothing.previous_value.abs_diff_eq(expected_velocity, 1e-5));
+        assert!((camera.transform.translation - start).abs_diff_eq(expected_velocity * dt, 1e-6));
+    }
+
+    #[test]
+    fn test_blend_factor_stays_in_unit_range() {
+        // **Feature: camera-smoothing, Property 1: Stable Smoothing Factor**
+        // The old alpha * dt * update_rate factor reached 12.8 at 60 FPS and overshot
+
+        for alpha in [0.0, 0.3, 0.8, 1.0] {
+            let smoothing = ExponentialSmoothing::new(alpha);
+            for dt in [0.0, 0.001, 0.016, 0.1, 1.0] {
+                let t = smoothing.blend_factor(dt, 1000);
+                assert!((0.0..=1.0).contains(&t), "alpha {alpha} dt {dt} gave {t}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_keeps_meaningful_history() {
+        // **Feature: camera-smoothing, Property 1: Stable Smoothing Factor**
+        // Alpha is a retention factor, so the default must keep a real share of the last value per frame
+
+        let camera = CameraController::new();
+        assert_eq!(camera.smoothing.alpha(), DEFAULT_SMOOTHING_ALPHA);
+
+        let kept = 1.0 - camera.smoothing.blend_factor(1.0 / 60.0, camera.update_rate);
+        assert!((0.2..0.8).contains(&kept), "kept {kept} of the previous value per 60 FPS frame");
+
+        let lag = camera.smoothing.lag_time(camera.update_rate);
+        assert!((0.005..0.05).contains(&lag), "lag {lag}s");
+    }
+}
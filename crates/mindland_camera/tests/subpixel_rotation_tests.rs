@@ -0,0 +1,26 @@
+//! Tests for camera rotation from sub-pixel mouse motion
+//!
+//! **Feature: subpixel-mouse, Property 2: Accumulated Rotation Matches The Integral**
+
+use bevy::prelude::*;
+use mindland_camera::{CameraController, ExponentialSmoothing};
+
+#[cfg(test)]
+mod subpixel_rotation_tests {
+    use super::*;
+
+    #[test]
+    fn test_slow_aim_rotates_by_the_integral() {
+        // **Feature: subpixel-mouse, Property 2: Accumulated Rotation Matches The Integral**
+
+        let mut camera = CameraController::new();
+        camera.smoothing = ExponentialSmoothing::new(0.0);
+        for _ in 0..1000 {
+            camera.update_rotation(Vec2::new(-0.3, 0.0), 1.0 / 60.0);
+        }
+
+        let yaw = camera.transform.rotation.to_euler(EulerRot::YXZ).0;
+        let expected = 300.0 * camera.sensitivity;
+        assert!((yaw - expected).abs() < 1e-3, "yaw {yaw}, expected {expected}");
+    }
+}
@@ -0,0 +1,54 @@
+//! Tests for FOV-scaled mouse sensitivity
+//!
+//! **Feature: zoom-sensitivity, Property 1: Rotation Per Pixel Follows FOV**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+
+/// Yaw (radians) applied by a horizontal mouse move, without smoothing in the way
+fn yaw_for(camera: &mut CameraController, pixels: f32) -> f32 {
+    camera.transform.rotation = Quat::IDENTITY;
+    camera.update_rotation(Vec2::new(pixels, 0.0), 0.016);
+    camera.transform.rotation.to_euler(EulerRot::YXZ).0
+}
+
+#[cfg(test)]
+mod zoom_sensitivity_tests {
+    use super::*;
+
+    #[test]
+    fn test_half_fov_halves_rotation_with_full_coefficient() {
+        // **Feature: zoom-sensitivity, Property 1: Rotation Per Pixel Follows FOV**
+
+        let mut camera = CameraController::new();
+        camera.zoom_sensitivity = 1.0;
+        let unzoomed = yaw_for(&mut camera, 50.0);
+
+        camera.projection.fov = camera.base_fov * 0.5;
+        let zoomed = yaw_for(&mut camera, 50.0);
+
+        assert!((zoomed - unzoomed * 0.5).abs() < 1e-5, "{zoomed} vs {unzoomed}");
+    }
+
+    #[test]
+    fn test_zero_coefficient_ignores_zoom() {
+        // **Feature: zoom-sensitivity, Property 1: Rotation Per Pixel Follows FOV**
+
+        let mut camera = CameraController::new();
+        camera.zoom_sensitivity = 0.0;
+        let unzoomed = yaw_for(&mut camera, 50.0);
+
+        camera.projection.fov = camera.base_fov * 0.5;
+        assert!((yaw_for(&mut camera, 50.0) - unzoomed).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_partial_coefficient_lands_between() {
+        let mut camera = CameraController::new();
+        camera.zoom_sensitivity = 0.5;
+        camera.projection.fov = camera.base_fov * 0.25;
+
+        let effective = camera.effective_sensitivity();
+        assert!(effective < camera.sensitivity && effective > camera.sensitivity * 0.25);
+    }
+}
@@ -0,0 +1,134 @@
+//! Input actions and chords
+//!
+//! An `ActionMap` turns the `InputManager`'s raw state into game actions once per frame. A binding
+//! is a key, a mouse button, the scroll wheel, or a `Chord` of bindings that must all be active
+//! together ("right mouse + scroll = zoom", "ctrl + click"). While a chord is active, bindings
+//! made of fewer inputs that share any of its inputs are suppressed, so plain "click" doesn't
+//! also fire on ctrl + click.
+
+use crate::InputManager;
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// An input, or a chord of inputs, that activates an action
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Scroll,                   // Active on frames the wheel moved
+    Chord(Vec<InputBinding>), // Active while every component is active
+}
+
+impl InputBinding {
+    /// The single inputs this binding is made of (nested chords are flattened)
+    pub fn inputs(&self) -> Vec<&InputBinding> {
+        match self {
+            Self::Chord(bindings) => bindings.iter().flat_map(InputBinding::inputs).collect(),
+            input => vec![input],
+        }
+    }
+
+    /// Whether every input of the binding is active (an empty chord never is)
+    pub fn is_active(&self, input: &InputManager) -> bool {
+        match self {
+            Self::Key(key) => input.is_key_pressed(*key),
+            Self::Mouse(button) => input.is_button_pressed(*button),
+            Self::Scroll => input.scroll_delta() != Vec2::ZERO,
+            Self::Chord(bindings) => !bindings.is_empty() && bindings.iter().all(|binding| binding.is_active(input)),
+        }
+    }
+}
+
+/// Bindings from inputs to actions, and which actions are active this frame
+#[derive(Resource, Debug, Clone)]
+pub struct ActionMap<A: Copy + Eq + Hash> {
+    bindings: Vec<(A, InputBinding)>,
+    active: HashSet<A>,
+    previous: HashSet<A>, // Active last frame, for press/release edges
+}
+
+impl<A: Copy + Eq + Hash> Default for ActionMap<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Copy + Eq + Hash> ActionMap<A> {
+    /// Empty map
+    pub fn new() -> Self {
+        Self { bindings: Vec::new(), active: HashSet::new(), previous: HashSet::new() }
+    }
+
+    /// Add a binding for an action (an action can have several)
+    pub fn bind(&mut self, action: A, binding: InputBinding) -> &mut Self {
+        self.bindings.push((action, binding));
+        self
+    }
+
+    /// Remove every binding for an action
+    pub fn unbind(&mut self, action: A) {
+        self.bindings.retain(|(bound, _)| *bound != action);
+    }
+
+    /// Bindings for an action
+    pub fn bindings(&self, action: A) -> impl Iterator<Item = &InputBinding> {
+        self.bindings.iter().filter(move |(bound, _)| *bound == action).map(|(_, binding)| binding)
+    }
+
+    /// Recompute active actions from the input state (call once per frame, after `process_events`)
+    ///
+    /// Bindings are resolved largest first: an active binding claims its inputs, and smaller
+    /// bindings sharing any claimed input stay inactive. Bindings of equal size don't suppress
+    /// each other.
+    pub fn update(&mut self, input: &InputManager) {
+        std::mem::swap(&mut self.previous, &mut self.active);
+        self.active.clear();
+
+        let mut by_size: Vec<(usize, A, Vec<&InputBinding>)> = self
+            .bindings
+            .iter()
+            .filter(|(_, binding)| binding.is_active(input))
+            .map(|(action, binding)| {
+                let inputs = binding.inputs();
+                (inputs.len(), *action, inputs)
+            })
+            .collect();
+        by_size.sort_by_key(|(size, _, _)| std::cmp::Reverse(*size));
+
+        let mut claimed: HashSet<&InputBinding> = HashSet::new();
+        for group in by_size.chunk_by(|a, b| a.0 == b.0) {
+            let winners: Vec<_> = group
+                .iter()
+                .filter(|(_, _, inputs)| !inputs.iter().any(|input| claimed.contains(input)))
+                .collect();
+            for (_, action, inputs) in winners {
+                self.active.insert(*action);
+                claimed.extend(inputs.iter().copied());
+            }
+        }
+    }
+
+    /// Whether an action is active this frame
+    pub fn pressed(&self, action: A) -> bool {
+        self.active.contains(&action)
+    }
+
+    /// Whether an action became active this frame
+    pub fn just_pressed(&self, action: A) -> bool {
+        self.active.contains(&action) && !self.previous.contains(&action)
+    }
+
+    /// Whether an action stopped being active this frame (including when a chord suppressed it)
+    pub fn just_released(&self, action: A) -> bool {
+        !self.active.contains(&action) && self.previous.contains(&action)
+    }
+}
+
+/// Update an `ActionMap` from the `InputManager` each frame
+pub fn action_map_system<A: Copy + Eq + Hash + Send + Sync + 'static>(
+    input: Res<InputManager>,
+    mut actions: ResMut<ActionMap<A>>,
+) {
+    actions.update(&input);
+}
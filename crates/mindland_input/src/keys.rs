@@ -0,0 +1,43 @@
+//! Dense key indices
+//!
+//! `KeyCode` is a large enum whose discriminants are an implementation detail, so key state isn't
+//! indexed by casting it. Instead the keys a game binds are mapped explicitly onto `0..KEY_COUNT`;
+//! media, browser and IME keys are left untracked.
+
+use bevy::prelude::KeyCode;
+
+/// Number of tracked keys (the size of the keyboard state array)
+pub const KEY_COUNT: usize = 102;
+
+/// Dense index of a tracked key, or None for keys the keyboard state doesn't track
+pub fn key_index(key: KeyCode) -> Option<usize> {
+    use KeyCode::*;
+    let index = match key {
+        // Digits
+        Key0 => 0, Key1 => 1, Key2 => 2, Key3 => 3, Key4 => 4, Key5 => 5, Key6 => 6, Key7 => 7, Key8 => 8,
+        Key9 => 9,
+        // Letters
+        A => 10, B => 11, C => 12, D => 13, E => 14, F => 15, G => 16, H => 17, I => 18, J => 19, K => 20,
+        L => 21, M => 22, N => 23, O => 24, P => 25, Q => 26, R => 27, S => 28, T => 29, U => 30, V => 31,
+        W => 32, X => 33, Y => 34, Z => 35,
+        // Function keys
+        F1 => 36, F2 => 37, F3 => 38, F4 => 39, F5 => 40, F6 => 41, F7 => 42, F8 => 43, F9 => 44, F10 => 45,
+        F11 => 46, F12 => 47,
+        // Editing and navigation
+        Escape => 48, Tab => 49, Space => 50, Return => 51, Back => 52, Insert => 53, Delete => 54,
+        Home => 55, End => 56, PageUp => 57, PageDown => 58, Left => 59, Right => 60, Up => 61, Down => 62,
+        // Modifiers and locks
+        ShiftLeft => 63, ShiftRight => 64, ControlLeft => 65, ControlRight => 66, AltLeft => 67,
+        AltRight => 68, SuperLeft => 69, SuperRight => 70, Capital => 71, Numlock => 72, Scroll => 73,
+        Pause => 74,
+        // Numpad
+        Numpad0 => 75, Numpad1 => 76, Numpad2 => 77, Numpad3 => 78, Numpad4 => 79, Numpad5 => 80,
+        Numpad6 => 81, Numpad7 => 82, Numpad8 => 83, Numpad9 => 84, NumpadAdd => 85, NumpadSubtract => 86,
+        NumpadMultiply => 87, NumpadDivide => 88, NumpadDecimal => 89, NumpadEnter => 90,
+        // Punctuation
+        Grave => 91, Minus => 92, Equals => 93, BracketLeft => 94, BracketRight => 95, Backslash => 96,
+        Semicolon => 97, Apostrophe => 98, Comma => 99, Period => 100, Slash => 101,
+        _ => return None,
+    };
+    Some(index)
+}
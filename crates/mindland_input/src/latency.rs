@@ -0,0 +1,76 @@
+//! Main-loop input latency measurement
+//!
+//! Events consumed by `InputManager::process_events` are reflected in the frame being built, so
+//! when that frame is presented each event's latency is the present time minus its timestamp.
+//! `InputPlugin` stamps events at frame start, so this is the main loop's share of input latency;
+//! time before the frame began (OS and winit queues) isn't visible and isn't counted.
+
+use mindland_performance::FrameTimeHistogram;
+use std::time::Duration;
+
+/// Latency bucket boundaries: <8ms, 8-16, 16-33, 33-50 and >=50ms
+pub const DEFAULT_LATENCY_BUCKETS: [Duration; 4] = [
+    Duration::from_millis(8),
+    Duration::from_millis(16),
+    Duration::from_millis(33),
+    Duration::from_millis(50),
+];
+
+/// Summary of measured main-loop input latencies (frame start to present)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyStats {
+    pub samples: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub histogram: FrameTimeHistogram, // Bucketed by `DEFAULT_LATENCY_BUCKETS`
+}
+
+/// Accumulates latencies between presents
+#[derive(Debug, Clone)]
+pub(crate) struct LatencyTracker {
+    in_flight: Vec<u64>, // Timestamps of events consumed for the frame not yet presented
+    total: Duration,
+    stats: LatencyStats,
+}
+
+impl Default for LatencyTracker {
+    fn default() -> Self {
+        Self {
+            in_flight: Vec::with_capacity(64),
+            total: Duration::ZERO,
+            stats: LatencyStats {
+                samples: 0,
+                min: Duration::ZERO,
+                max: Duration::ZERO,
+                mean: Duration::ZERO,
+                histogram: FrameTimeHistogram::new(&DEFAULT_LATENCY_BUCKETS),
+            },
+        }
+    }
+}
+
+impl LatencyTracker {
+    /// Remember an event consumed for the current frame
+    pub(crate) fn consumed(&mut self, timestamp: u64) {
+        self.in_flight.push(timestamp);
+    }
+
+    /// Record every in-flight event against a present at `presented` (microseconds)
+    pub(crate) fn presented(&mut self, presented: u64) {
+        for timestamp in self.in_flight.drain(..) {
+            let latency = Duration::from_micros(presented.saturating_sub(timestamp));
+            let stats = &mut self.stats;
+            stats.min = if stats.samples == 0 { latency } else { stats.min.min(latency) };
+            stats.max = stats.max.max(latency);
+            stats.samples += 1;
+            self.total += latency;
+            stats.mean = Duration::from_nanos((self.total.as_nanos() / stats.samples as u128) as u64);
+            stats.histogram.record(latency);
+        }
+    }
+
+    pub(crate) fn stats(&self) -> &LatencyStats {
+        &self.stats
+    }
+}
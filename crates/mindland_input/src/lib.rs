@@ -2,23 +2,53 @@
 //! 
 //! Zero-latency input handling with lock-free data structures and high-frequency polling.
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowFocused},
+};
 use crossbeam::queue::SegQueue;
+use mindland_performance::{InstantTimeSource, TimeSource};
 use parking_lot::RwLock;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+mod actions;
+mod keys;
+mod latency;
+mod plugin;
+pub use actions::*;
+pub use keys::*;
+pub use latency::*;
+pub use plugin::*;
+use latency::LatencyTracker;
 
 /// Ultra-fast input manager with lock-free architecture
+#[derive(Resource)]
 pub struct InputManager {
     pub keyboard_state: AtomicKeyboardState,
     pub mouse_state: AtomicMouseState,
     pub input_buffer: SegQueue<InputEvent>,
     pub polling_rate: u32,
+    pub key_debounce: HashMap<KeyCode, u64>, // Per-key debounce window in microseconds
+    pub subpixel_precision: bool,            // false reports `mouse_delta` in whole pixels, carrying the fraction
+    pub measure_latency: bool,               // Record main-loop input latency into `latency_stats`
+    pending_releases: HashMap<KeyCode, u64>, // Releases held back until their debounce window passes
+    released_this_frame: HashSet<KeyCode>,   // Keys whose release was committed by the last `process_events`
+    focused: bool,                           // Input is discarded while the window is unfocused
+    pixel_delta: IVec2,                      // Whole pixels moved this frame
+    pixel_residual: Vec2,                    // Sub-pixel motion carried into the next frame's `pixel_delta`
+    scroll_delta: Vec2,                      // Wheel motion this frame, in lines (x = horizontal)
+    clock: Box<dyn TimeSource>,              // Source of `timestamp` and present times
+    epoch: Instant,                          // Time zero for event timestamps
+    frame_start: Option<u64>,                // Set by `frame_started`; stamps the frame's Bevy input events
+    latency: LatencyTracker,
 }
 
 /// Lock-free keyboard state tracking
 pub struct AtomicKeyboardState {
-    // Using array of atomic bools for lock-free key state
-    keys: [AtomicBool; 256],
+    // One atomic bool per tracked key (see `key_index`) for lock-free key state
+    keys: [AtomicBool; KEY_COUNT],
 }
 
 /// Lock-free mouse state tracking
@@ -28,7 +58,7 @@ pub struct AtomicMouseState {
     pub buttons: AtomicU64, // Bitfield for mouse buttons
 }
 
-/// High-frequency input events with precise timing
+/// High-frequency input events with precise timing (timestamps in microseconds)
 #[derive(Debug, Clone)]
 pub enum InputEvent {
     KeyPressed { key: KeyCode, timestamp: u64 },
@@ -36,6 +66,21 @@ pub enum InputEvent {
     MouseMoved { delta: Vec2, timestamp: u64 },
     MousePressed { button: MouseButton, timestamp: u64 },
     MouseReleased { button: MouseButton, timestamp: u64 },
+    MouseScrolled { delta: Vec2, timestamp: u64 }, // Lines; pixel-precise touchpads should convert first
+}
+
+impl InputEvent {
+    /// When the event was produced, in microseconds
+    pub fn timestamp(&self) -> u64 {
+        match *self {
+            Self::KeyPressed { timestamp, .. }
+            | Self::KeyReleased { timestamp, .. }
+            | Self::MouseMoved { timestamp, .. }
+            | Self::MousePressed { timestamp, .. }
+            | Self::MouseReleased { timestamp, .. }
+            | Self::MouseScrolled { timestamp, .. } => timestamp,
+        }
+    }
 }
 
 impl Default for InputManager {
@@ -47,22 +92,191 @@ impl Default for InputManager {
 impl InputManager {
     /// Create a new input manager with 1000Hz target polling rate
     pub fn new() -> Self {
+        Self::with_time_source(Box::new(InstantTimeSource))
+    }
+
+    /// Create an input manager that timestamps events and presents with `clock`
+    pub fn with_time_source(clock: Box<dyn TimeSource>) -> Self {
+        let epoch = clock.now();
         Self {
             keyboard_state: AtomicKeyboardState::new(),
             mouse_state: AtomicMouseState::new(),
             input_buffer: SegQueue::new(),
             polling_rate: 1000, // Target 1000Hz polling
+            key_debounce: HashMap::new(),
+            subpixel_precision: true,
+            measure_latency: false,
+            pending_releases: HashMap::new(),
+            released_this_frame: HashSet::new(),
+            focused: true,
+            pixel_delta: IVec2::ZERO,
+            pixel_residual: Vec2::ZERO,
+            scroll_delta: Vec2::ZERO,
+            clock,
+            epoch,
+            frame_start: None,
+            latency: LatencyTracker::default(),
+        }
+    }
+
+    /// Current time in microseconds, for stamping `InputEvent`s as they are produced
+    pub fn timestamp(&self) -> u64 {
+        self.clock.now().saturating_duration_since(self.epoch).as_micros() as u64
+    }
+
+    /// Record the start of a frame (`input_frame_start_system` calls this in `First`)
+    ///
+    /// Bevy input events read later in the frame are stamped with this time rather than the time
+    /// they are read, so latency includes the work scheduled between frame start and input.
+    pub fn frame_started(&mut self) {
+        self.frame_start = Some(self.timestamp());
+    }
+
+    /// Mark the frame reflecting the events consumed by `process_events` as presented
+    ///
+    /// Call once the frame is handed to the swapchain. Each consumed event's latency is the time
+    /// from its timestamp to now.
+    pub fn frame_presented(&mut self) {
+        let now = self.timestamp();
+        self.latency.presented(now);
+    }
+
+    /// Main-loop input latencies (frame start to present) measured while `measure_latency` is on
+    pub fn latency_stats(&self) -> &LatencyStats {
+        self.latency.stats()
+    }
+
+    /// Track window focus; input is discarded while unfocused
+    ///
+    /// Losing focus releases held keys and buttons (their releases go to another window) and
+    /// zeroes the mouse delta. Gaining focus drops anything buffered meanwhile, so the camera
+    /// doesn't jump from motion that happened over another window.
+    pub fn set_focused(&mut self, focused: bool) {
+        if focused == self.focused {
+            return;
+        }
+        self.focused = focused;
+
+        while self.input_buffer.pop().is_some() {}
+        *self.mouse_state.delta.write() = Vec2::ZERO;
+        self.pixel_delta = IVec2::ZERO;
+        self.pixel_residual = Vec2::ZERO;
+        self.scroll_delta = Vec2::ZERO;
+        if !focused {
+            self.keyboard_state.release_all();
+            self.mouse_state.buttons.store(0, Ordering::Release);
+            self.pending_releases.clear();
+        }
+    }
+
+    /// Whether the window currently has focus
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Debounce a key: a release followed by a press within `window` counts as a continuous hold
+    pub fn set_key_debounce(&mut self, key: KeyCode, window: Option<Duration>) {
+        match window {
+            Some(window) => {
+                self.key_debounce.insert(key, window.as_micros() as u64);
+            }
+            None => {
+                self.key_debounce.remove(&key);
+                if self.pending_releases.remove(&key).is_some() {
+                    self.keyboard_state.set_key_state(key, false);
+                }
+            }
         }
     }
 
+    /// Apply buffered events to the input state, then commit debounced releases older than their window
+    ///
+    /// Call once per frame: the mouse delta is the sum of this frame's motion events. Deltas are
+    /// summed directly rather than differenced from the absolute position, whose f32 precision
+    /// drops below a pixel far from the origin.
+    pub fn process_events(&mut self, now: u64) {
+        if !self.focused {
+            while self.input_buffer.pop().is_some() {}
+            return;
+        }
+
+        let mut frame_delta = Vec2::ZERO;
+        self.scroll_delta = Vec2::ZERO;
+        self.released_this_frame.clear();
+        while let Some(event) = self.input_buffer.pop() {
+            if self.measure_latency {
+                self.latency.consumed(event.timestamp());
+            }
+            match event {
+                InputEvent::KeyPressed { key, timestamp } => {
+                    // Inside the debounce window the release was a glitch; outside it the key really was up
+                    if let Some(released_at) = self.pending_releases.remove(&key) {
+                        let window = self.key_debounce.get(&key).copied().unwrap_or(0);
+                        if timestamp.saturating_sub(released_at) >= window {
+                            self.keyboard_state.set_key_state(key, false);
+                            self.released_this_frame.insert(key);
+                        }
+                    }
+                    self.keyboard_state.set_key_state(key, true);
+                }
+                InputEvent::KeyReleased { key, timestamp } => {
+                    if self.key_debounce.contains_key(&key) {
+                        self.pending_releases.insert(key, timestamp);
+                    } else {
+                        self.keyboard_state.set_key_state(key, false);
+                        self.released_this_frame.insert(key);
+                    }
+                }
+                InputEvent::MouseMoved { delta, .. } => {
+                    frame_delta += delta;
+                    *self.mouse_state.position.write() += delta;
+                }
+                InputEvent::MousePressed { button, .. } => self.mouse_state.set_button_state(button, true),
+                InputEvent::MouseReleased { button, .. } => self.mouse_state.set_button_state(button, false),
+                InputEvent::MouseScrolled { delta, .. } => self.scroll_delta += delta,
+            }
+        }
+
+        // Whole-pixel consumers get the integer part; the fraction carries over instead of being lost
+        let total = self.pixel_residual + frame_delta;
+        self.pixel_delta = total.trunc().as_ivec2();
+        self.pixel_residual = total - total.trunc();
+        *self.mouse_state.delta.write() = if self.subpixel_precision { frame_delta } else { self.pixel_delta.as_vec2() };
+
+        let key_debounce = &self.key_debounce;
+        let keyboard_state = &self.keyboard_state;
+        let released_this_frame = &mut self.released_this_frame;
+        self.pending_releases.retain(|key, released_at| {
+            let window = key_debounce.get(key).copied().unwrap_or(0);
+            let expired = now.saturating_sub(*released_at) >= window;
+            if expired {
+                keyboard_state.set_key_state(*key, false);
+                released_this_frame.insert(*key);
+            }
+            !expired
+        });
+    }
+
     /// Check if a key is currently pressed (lock-free)
+    ///
+    /// Always false for keys `key_index` doesn't track.
     pub fn is_key_pressed(&self, key: KeyCode) -> bool {
-        let key_index = key as usize;
-        if key_index < 256 {
-            self.keyboard_state.keys[key_index].load(Ordering::Acquire)
-        } else {
-            false
-        }
+        key_index(key).is_some_and(|index| self.keyboard_state.keys[index].load(Ordering::Acquire))
+    }
+
+    /// Whether the last `process_events` committed a release of `key`, even if it was pressed again
+    pub fn key_released_this_frame(&self, key: KeyCode) -> bool {
+        self.released_this_frame.contains(&key)
+    }
+
+    /// Check if a mouse button is currently pressed (lock-free)
+    pub fn is_button_pressed(&self, button: MouseButton) -> bool {
+        self.mouse_state.is_button_pressed(button)
+    }
+
+    /// Mouse wheel motion this frame, in lines (y > 0 = away from the user)
+    pub fn scroll_delta(&self) -> Vec2 {
+        self.scroll_delta
     }
 
     /// Get current mouse position (lock-free read)
@@ -70,10 +284,17 @@ impl InputManager {
         *self.mouse_state.position.read()
     }
 
-    /// Get mouse delta since last frame (lock-free read)
+    /// Get mouse delta since last frame (lock-free read), including sub-pixel motion
     pub fn mouse_delta(&self) -> Vec2 {
         *self.mouse_state.delta.read()
     }
+
+    /// Whole pixels moved since last frame, for integer consumers (UI cursors, grid selection)
+    ///
+    /// Fractions carry between frames, so slow motion still adds up instead of truncating to zero.
+    pub fn mouse_delta_pixels(&self) -> IVec2 {
+        self.pixel_delta
+    }
 }
 
 impl AtomicKeyboardState {
@@ -83,11 +304,17 @@ impl AtomicKeyboardState {
         Self { keys }
     }
 
-    /// Set key state atomically
+    /// Set key state atomically (ignored for keys `key_index` doesn't track)
     pub fn set_key_state(&self, key: KeyCode, pressed: bool) {
-        let key_index = key as usize;
-        if key_index < 256 {
-            self.keys[key_index].store(pressed, Ordering::Release);
+        if let Some(index) = key_index(key) {
+            self.keys[index].store(pressed, Ordering::Release);
+        }
+    }
+
+    /// Mark every key as released
+    pub fn release_all(&self) {
+        for key in &self.keys {
+            key.store(false, Ordering::Release);
         }
     }
 }
@@ -136,4 +363,33 @@ impl AtomicMouseState {
         };
         (self.buttons.load(Ordering::Acquire) & button_bit) != 0
     }
-}
\ No newline at end of file
+}
+
+/// Forward the primary window's focus events to the `InputManager`
+///
+/// Tool windows don't count: focusing one unfocuses the game, and focus moving back from one
+/// arrives as the primary window's own focus event.
+pub fn input_focus_system(
+    mut focus_events: EventReader<WindowFocused>,
+    primary: Query<(), With<PrimaryWindow>>,
+    mut input: ResMut<InputManager>,
+) {
+    if let Some(event) = focus_events.read().filter(|event| primary.contains(event.window)).last() {
+        input.set_focused(event.focused);
+    }
+}
+
+/// Stamp the start of the frame, before anything else in the main loop runs
+pub fn input_frame_start_system(mut input: ResMut<InputManager>) {
+    input.frame_started();
+}
+
+/// Close out input latency measurement at the end of the frame
+///
+/// Runs at the end of the main-world frame, which is when the frame is submitted for present;
+/// with pipelined rendering the real present is up to a frame later.
+pub fn input_present_system(mut input: ResMut<InputManager>) {
+    if input.measure_latency {
+        input.frame_presented();
+    }
+}
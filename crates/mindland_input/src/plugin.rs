@@ -0,0 +1,86 @@
+//! Bevy integration
+//!
+//! `InputPlugin` feeds the `InputManager` from Bevy's raw input events. Events are read rather
+//! than the `Input<KeyCode>` snapshot, so a press and release within one frame still both arrive,
+//! in order. Each frame's events are stamped with the frame's start time, taken in `First`:
+//! winit delivers them before the frame begins and doesn't timestamp them, so latency stats cover
+//! the main loop (frame start to present), not time spent in the OS or winit queues.
+
+use crate::{input_focus_system, input_frame_start_system, input_present_system, InputEvent, InputManager};
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+        ButtonState, InputSystem,
+    },
+    prelude::*,
+    window::WindowFocused,
+};
+
+/// Pixels of touchpad scrolling counted as one wheel line
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// Applying this frame's Bevy input events to the `InputManager`; order readers after it
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct InputEventSet;
+
+/// The end-of-frame latency measurement; order frame pacing (sleeps before present) before it
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct InputPresentSet;
+
+/// Inserts the `InputManager` and keeps it in sync with Bevy's input events
+pub struct InputPlugin;
+
+impl Plugin for InputPlugin {
+    fn build(&self, app: &mut App) {
+        // Already registered by Bevy's input and window plugins except in headless apps
+        app.add_event::<KeyboardInput>()
+            .add_event::<MouseMotion>()
+            .add_event::<MouseButtonInput>()
+            .add_event::<MouseWheel>()
+            .add_event::<WindowFocused>()
+            .init_resource::<InputManager>()
+            .add_systems(First, input_frame_start_system)
+            .add_systems(PreUpdate, (input_focus_system, input_event_system).chain().in_set(InputEventSet))
+            .configure_sets(PreUpdate, InputEventSet.after(InputSystem))
+            .add_systems(Last, input_present_system.in_set(InputPresentSet));
+    }
+}
+
+/// Queue this frame's Bevy input events on the `InputManager`, then apply them
+pub fn input_event_system(
+    mut keyboard: EventReader<KeyboardInput>,
+    mut motion: EventReader<MouseMotion>,
+    mut buttons: EventReader<MouseButtonInput>,
+    mut wheel: EventReader<MouseWheel>,
+    mut input: ResMut<InputManager>,
+) {
+    let timestamp = input.frame_start.take().unwrap_or_else(|| input.timestamp());
+    for event in keyboard.read() {
+        let Some(key) = event.key_code else { continue }; // Keys without a code can't be tracked
+        input.input_buffer.push(match event.state {
+            ButtonState::Pressed => InputEvent::KeyPressed { key, timestamp },
+            ButtonState::Released => InputEvent::KeyReleased { key, timestamp },
+        });
+    }
+    for event in motion.read() {
+        input.input_buffer.push(InputEvent::MouseMoved { delta: event.delta, timestamp });
+    }
+    for event in buttons.read() {
+        let button = event.button;
+        input.input_buffer.push(match event.state {
+            ButtonState::Pressed => InputEvent::MousePressed { button, timestamp },
+            ButtonState::Released => InputEvent::MouseReleased { button, timestamp },
+        });
+    }
+    for event in wheel.read() {
+        let lines = match event.unit {
+            MouseScrollUnit::Line => 1.0,
+            MouseScrollUnit::Pixel => 1.0 / PIXELS_PER_LINE,
+        };
+        input.input_buffer.push(InputEvent::MouseScrolled { delta: Vec2::new(event.x, event.y) * lines, timestamp });
+    }
+
+    let now = input.timestamp();
+    input.process_events(now);
+}
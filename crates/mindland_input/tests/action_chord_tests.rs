@@ -0,0 +1,131 @@
+//! Tests for input chords in the action map
+//!
+//! **Feature: action-chords, Property 1: Chords Fire Only When Complete And Suppress Their Components**
+
+use bevy::prelude::*;
+use mindland_input::{action_map_system, ActionMap, InputBinding, InputEvent, InputManager};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Action {
+    Attack,    // Left click
+    Crouch,    // Ctrl
+    SelectAll, // Ctrl + left click
+    Aim,       // Right mouse
+    Zoom,      // Right mouse + scroll
+    Hotbar,    // Scroll
+}
+
+fn ctrl_click() -> InputBinding {
+    InputBinding::Chord(vec![InputBinding::Key(KeyCode::ControlLeft), InputBinding::Mouse(MouseButton::Left)])
+}
+
+fn action_map() -> ActionMap<Action> {
+    let mut actions = ActionMap::new();
+    actions
+        .bind(Action::Attack, InputBinding::Mouse(MouseButton::Left))
+        .bind(Action::Crouch, InputBinding::Key(KeyCode::ControlLeft))
+        .bind(Action::SelectAll, ctrl_click())
+        .bind(Action::Aim, InputBinding::Mouse(MouseButton::Right))
+        .bind(Action::Zoom, InputBinding::Chord(vec![InputBinding::Mouse(MouseButton::Right), InputBinding::Scroll]))
+        .bind(Action::Hotbar, InputBinding::Scroll);
+    actions
+}
+
+/// Apply events as one frame and update the action map
+fn frame(input: &mut InputManager, actions: &mut ActionMap<Action>, events: Vec<InputEvent>) {
+    for event in events {
+        input.input_buffer.push(event);
+    }
+    input.process_events(0);
+    actions.update(input);
+}
+
+fn active(actions: &ActionMap<Action>) -> Vec<Action> {
+    [Action::Attack, Action::Crouch, Action::SelectAll, Action::Aim, Action::Zoom, Action::Hotbar]
+        .into_iter()
+        .filter(|&action| actions.pressed(action))
+        .collect()
+}
+
+#[cfg(test)]
+mod action_chord_tests {
+    use super::*;
+
+    #[test]
+    fn test_chord_needs_every_input_and_suppresses_components() {
+        // **Feature: action-chords, Property 1: Chords Fire Only When Complete And Suppress Their Components**
+
+        let mut input = InputManager::new();
+        let mut actions = action_map();
+
+        frame(&mut input, &mut actions, vec![InputEvent::KeyPressed { key: KeyCode::ControlLeft, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::Crouch], "half a chord is just its component");
+
+        frame(&mut input, &mut actions, vec![InputEvent::MousePressed { button: MouseButton::Left, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::SelectAll], "neither ctrl nor click fires alone");
+        assert!(actions.just_pressed(Action::SelectAll));
+        assert!(actions.just_released(Action::Crouch));
+
+        frame(&mut input, &mut actions, vec![InputEvent::KeyReleased { key: KeyCode::ControlLeft, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::Attack]);
+        assert!(actions.just_released(Action::SelectAll));
+    }
+
+    #[test]
+    fn test_mouse_button_as_scroll_modifier() {
+        // **Feature: action-chords, Property 1: Chords Fire Only When Complete And Suppress Their Components**
+
+        let mut input = InputManager::new();
+        let mut actions = action_map();
+
+        frame(&mut input, &mut actions, vec![InputEvent::MouseScrolled { delta: Vec2::Y, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::Hotbar]);
+
+        frame(&mut input, &mut actions, vec![InputEvent::MousePressed { button: MouseButton::Right, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::Aim], "scroll only counts on frames the wheel moves");
+
+        frame(&mut input, &mut actions, vec![InputEvent::MouseScrolled { delta: -Vec2::Y, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::Zoom]);
+        assert_eq!(input.scroll_delta(), -Vec2::Y);
+
+        frame(&mut input, &mut actions, vec![]);
+        assert_eq!(active(&actions), vec![Action::Aim]);
+    }
+
+    #[test]
+    fn test_equal_size_bindings_do_not_suppress_each_other() {
+        let mut input = InputManager::new();
+        let mut actions = ActionMap::new();
+        actions
+            .bind(Action::Attack, InputBinding::Mouse(MouseButton::Left))
+            .bind(Action::Crouch, InputBinding::Mouse(MouseButton::Left))
+            .bind(Action::SelectAll, InputBinding::Chord(vec![]));
+
+        frame(&mut input, &mut actions, vec![InputEvent::MousePressed { button: MouseButton::Left, timestamp: 0 }]);
+        assert_eq!(active(&actions), vec![Action::Attack, Action::Crouch], "empty chords never fire");
+
+        actions.unbind(Action::Crouch);
+        assert_eq!(actions.bindings(Action::Crouch).count(), 0);
+    }
+
+    #[test]
+    fn test_nested_chords_flatten() {
+        let nested = InputBinding::Chord(vec![ctrl_click(), InputBinding::Key(KeyCode::ShiftLeft)]);
+        assert_eq!(nested.inputs().len(), 3);
+    }
+
+    #[test]
+    fn test_action_map_system_updates_resource() {
+        let mut app = App::new();
+        app.insert_resource(InputManager::new())
+            .insert_resource(action_map())
+            .add_systems(Update, action_map_system::<Action>);
+
+        let mut input = app.world.resource_mut::<InputManager>();
+        input.input_buffer.push(InputEvent::KeyPressed { key: KeyCode::ControlLeft, timestamp: 0 });
+        input.process_events(0);
+        app.update();
+
+        assert!(app.world.resource::<ActionMap<Action>>().just_pressed(Action::Crouch));
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests for key debouncing in input event processing
+//!
+//! **Feature: input-debounce, Property 1: Glitches Within Window Are Continuous Holds**
+
+use bevy::prelude::*;
+use mindland_input::{InputEvent, InputManager};
+use std::time::Duration;
+
+#[cfg(test)]
+mod debounce_tests {
+    use super::*;
+
+    fn push(input: &InputManager, event: InputEvent) {
+        input.input_buffer.push(event);
+    }
+
+    #[test]
+    fn test_5ms_release_press_glitch_is_continuous_hold() {
+        // **Feature: input-debounce, Property 1: Glitches Within Window Are Continuous Holds**
+        // A spurious release/press pair 5ms apart never reports the key as up
+
+        let mut input = InputManager::new();
+        input.set_key_debounce(KeyCode::W, Some(Duration::from_millis(20)));
+
+        push(&input, InputEvent::KeyPressed { key: KeyCode::W, timestamp: 0 });
+        input.process_events(1_000);
+        assert!(input.is_key_pressed(KeyCode::W));
+
+        push(&input, InputEvent::KeyReleased { key: KeyCode::W, timestamp: 100_000 });
+        input.process_events(102_000);
+        assert!(input.is_key_pressed(KeyCode::W), "release committed inside debounce window");
+
+        push(&input, InputEvent::KeyPressed { key: KeyCode::W, timestamp: 105_000 });
+        input.process_events(106_000);
+        assert!(input.is_key_pressed(KeyCode::W));
+
+        // Long after the glitch the key is still held
+        input.process_events(500_000);
+        assert!(input.is_key_pressed(KeyCode::W));
+    }
+
+    #[test]
+    fn test_real_release_commits_after_window() {
+        // **Feature: input-debounce, Property 1: Glitches Within Window Are Continuous Holds**
+
+        let mut input = InputManager::new();
+        input.set_key_debounce(KeyCode::Space, Some(Duration::from_millis(20)));
+
+        push(&input, InputEvent::KeyPressed { key: KeyCode::Space, timestamp: 0 });
+        push(&input, InputEvent::KeyReleased { key: KeyCode::Space, timestamp: 50_000 });
+        input.process_events(60_000);
+        assert!(input.is_key_pressed(KeyCode::Space));
+
+        input.process_events(70_000);
+        assert!(!input.is_key_pressed(KeyCode::Space));
+    }
+
+    #[test]
+    fn test_press_after_window_in_same_batch_commits_release() {
+        // A re-press 30ms after the release is a real release and press, even within one frame
+        let mut input = InputManager::new();
+        input.set_key_debounce(KeyCode::W, Some(Duration::from_millis(20)));
+
+        push(&input, InputEvent::KeyPressed { key: KeyCode::W, timestamp: 0 });
+        push(&input, InputEvent::KeyReleased { key: KeyCode::W, timestamp: 50_000 });
+        push(&input, InputEvent::KeyPressed { key: KeyCode::W, timestamp: 80_000 });
+        input.process_events(81_000);
+        assert!(input.key_released_this_frame(KeyCode::W), "release outside the window was cancelled");
+        assert!(input.is_key_pressed(KeyCode::W));
+
+        // Inside the window the same batch shape stays a continuous hold
+        push(&input, InputEvent::KeyReleased { key: KeyCode::W, timestamp: 100_000 });
+        push(&input, InputEvent::KeyPressed { key: KeyCode::W, timestamp: 105_000 });
+        input.process_events(106_000);
+        assert!(!input.key_released_this_frame(KeyCode::W));
+        assert!(input.is_key_pressed(KeyCode::W));
+    }
+
+    #[test]
+    fn test_keys_without_debounce_release_immediately() {
+        let mut input = InputManager::new();
+
+        push(&input, InputEvent::KeyPressed { key: KeyCode::A, timestamp: 0 });
+        push(&input, InputEvent::KeyReleased { key: KeyCode::A, timestamp: 5_000 });
+        input.process_events(5_000);
+
+        assert!(!input.is_key_pressed(KeyCode::A));
+    }
+}
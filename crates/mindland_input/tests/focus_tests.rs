@@ -0,0 +1,99 @@
+//! Tests for gating input on window focus
+//!
+//! **Feature: input-focus, Property 1: Unfocused Input Never Reaches Gameplay**
+
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowFocused},
+};
+use mindland_input::{input_focus_system, InputEvent, InputManager};
+
+/// App running the focus system with a primary window and a tool window
+fn two_window_app() -> (App, Entity, Entity) {
+    let mut app = App::new();
+    app.add_event::<WindowFocused>()
+        .insert_resource(InputManager::new())
+        .add_systems(Update, input_focus_system);
+    let primary = app.world.spawn((Window::default(), PrimaryWindow)).id();
+    let tool = app.world.spawn(Window::default()).id();
+    (app, primary, tool)
+}
+
+#[cfg(test)]
+mod focus_tests {
+    use super::*;
+
+    #[test]
+    fn test_losing_focus_zeroes_mouse_delta() {
+        // **Feature: input-focus, Property 1: Unfocused Input Never Reaches Gameplay**
+
+        let mut input = InputManager::new();
+        input.input_buffer.push(InputEvent::MouseMoved { delta: Vec2::new(40.0, -12.0), timestamp: 0 });
+        input.process_events(1_000);
+        assert_eq!(input.mouse_delta(), Vec2::new(40.0, -12.0));
+
+        input.set_focused(false);
+        assert_eq!(input.mouse_delta(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_unfocused_events_are_discarded_and_focus_gain_does_not_jump() {
+        // **Feature: input-focus, Property 1: Unfocused Input Never Reaches Gameplay**
+
+        let mut input = InputManager::new();
+        input.input_buffer.push(InputEvent::KeyPressed { key: KeyCode::W, timestamp: 0 });
+        input.process_events(1_000);
+        input.set_focused(false);
+        assert!(!input.is_key_pressed(KeyCode::W)); // Release happens in another window
+
+        input.input_buffer.push(InputEvent::MouseMoved { delta: Vec2::new(300.0, 0.0), timestamp: 2_000 });
+        input.input_buffer.push(InputEvent::KeyPressed { key: KeyCode::S, timestamp: 2_000 });
+        input.process_events(3_000);
+        assert_eq!(input.mouse_delta(), Vec2::ZERO);
+        assert!(!input.is_key_pressed(KeyCode::S));
+
+        // Motion buffered just before the focus event arrives is dropped too
+        input.input_buffer.push(InputEvent::MouseMoved { delta: Vec2::new(500.0, 0.0), timestamp: 4_000 });
+        input.set_focused(true);
+        input.process_events(5_000);
+        assert_eq!(input.mouse_delta(), Vec2::ZERO);
+        assert_eq!(input.mouse_position(), Vec2::ZERO);
+
+        input.input_buffer.push(InputEvent::MouseMoved { delta: Vec2::new(2.0, 1.0), timestamp: 6_000 });
+        input.process_events(7_000);
+        assert_eq!(input.mouse_delta(), Vec2::new(2.0, 1.0));
+    }
+
+    #[test]
+    fn test_window_focused_event_drives_focus() {
+        // **Feature: input-focus, Property 1: Unfocused Input Never Reaches Gameplay**
+
+        let (mut app, primary, _) = two_window_app();
+
+        app.world.send_event(WindowFocused { window: primary, focused: false });
+        app.update();
+        assert!(!app.world.resource::<InputManager>().is_focused());
+
+        app.world.send_event(WindowFocused { window: primary, focused: true });
+        app.update();
+        assert!(app.world.resource::<InputManager>().is_focused());
+    }
+
+    #[test]
+    fn test_focusing_a_tool_window_unfocuses_the_game() {
+        // **Feature: input-focus, Property 1: Unfocused Input Never Reaches Gameplay**
+        // Clicking into the inspector sends "primary unfocused" then "tool focused" in one frame
+
+        let (mut app, primary, tool) = two_window_app();
+
+        app.world.send_event(WindowFocused { window: primary, focused: false });
+        app.world.send_event(WindowFocused { window: tool, focused: true });
+        app.update();
+        assert!(!app.world.resource::<InputManager>().is_focused(), "typing in the tool window must not move the game");
+
+        app.world.send_event(WindowFocused { window: tool, focused: false });
+        app.world.send_event(WindowFocused { window: primary, focused: true });
+        app.update();
+        assert!(app.world.resource::<InputManager>().is_focused());
+    }
+}
@@ -0,0 +1,69 @@
+//! Tests for feeding the InputManager from Bevy's input events
+//!
+//! **Feature: input-plugin, Property 1: Bevy Input Events Reach The InputManager**
+
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+        ButtonState,
+    },
+    prelude::*,
+};
+use mindland_input::{InputManager, InputPlugin};
+
+fn key(key_code: KeyCode, state: ButtonState) -> KeyboardInput {
+    KeyboardInput { scan_code: 0, key_code: Some(key_code), state, window: Entity::PLACEHOLDER }
+}
+
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(InputPlugin);
+    app
+}
+
+#[cfg(test)]
+mod input_plugin_tests {
+    use super::*;
+
+    #[test]
+    fn test_key_press_event_reaches_input_manager() {
+        // **Feature: input-plugin, Property 1: Bevy Input Events Reach The InputManager**
+
+        let mut app = headless_app();
+        app.world.send_event(key(KeyCode::W, ButtonState::Pressed));
+        app.update();
+        assert!(app.world.resource::<InputManager>().is_key_pressed(KeyCode::W));
+
+        app.world.send_event(key(KeyCode::W, ButtonState::Released));
+        app.update();
+        assert!(!app.world.resource::<InputManager>().is_key_pressed(KeyCode::W));
+    }
+
+    #[test]
+    fn test_tap_within_one_frame_ends_released() {
+        let mut app = headless_app();
+        app.world.send_event(key(KeyCode::Space, ButtonState::Pressed));
+        app.world.send_event(key(KeyCode::Space, ButtonState::Released));
+        app.update();
+        assert!(!app.world.resource::<InputManager>().is_key_pressed(KeyCode::Space), "events apply in order");
+    }
+
+    #[test]
+    fn test_mouse_events_reach_input_manager() {
+        let mut app = headless_app();
+        app.world.send_event(MouseMotion { delta: Vec2::new(3.0, -1.0) });
+        app.world.send_event(MouseMotion { delta: Vec2::new(1.5, 2.0) });
+        app.world.send_event(MouseButtonInput { button: MouseButton::Right, state: ButtonState::Pressed, window: Entity::PLACEHOLDER });
+        app.world.send_event(MouseWheel { unit: MouseScrollUnit::Line, x: 0.0, y: 2.0, window: Entity::PLACEHOLDER });
+        app.update();
+
+        let input = app.world.resource::<InputManager>();
+        assert_eq!(input.mouse_delta(), Vec2::new(4.5, 1.0));
+        assert!(input.is_button_pressed(MouseButton::Right));
+        assert_eq!(input.scroll_delta(), Vec2::new(0.0, 2.0));
+
+        app.update();
+        assert_eq!(app.world.resource::<InputManager>().mouse_delta(), Vec2::ZERO, "deltas are per frame");
+    }
+}
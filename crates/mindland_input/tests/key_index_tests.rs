@@ -0,0 +1,55 @@
+//! Tests for the dense KeyCode-to-index mapping
+//!
+//! **Feature: key-index, Property 1: Tracked Keys Map To Distinct Indices And Keep Their State**
+
+use bevy::prelude::*;
+use mindland_input::{key_index, InputManager, KEY_COUNT};
+use std::collections::HashSet;
+
+/// Keys games commonly bind
+const COMMON_KEYS: [KeyCode; 24] = [
+    KeyCode::W, KeyCode::A, KeyCode::S, KeyCode::D, KeyCode::Q, KeyCode::E, KeyCode::R, KeyCode::F,
+    KeyCode::Key0, KeyCode::Key1, KeyCode::Key9, KeyCode::Space, KeyCode::Escape, KeyCode::Tab,
+    KeyCode::Return, KeyCode::ShiftLeft, KeyCode::ControlLeft, KeyCode::AltLeft, KeyCode::F1,
+    KeyCode::F12, KeyCode::Up, KeyCode::Down, KeyCode::Numpad5, KeyCode::Slash,
+];
+
+#[cfg(test)]
+mod key_index_tests {
+    use super::*;
+
+    #[test]
+    fn test_common_keys_have_distinct_indices_and_round_trip() {
+        // **Feature: key-index, Property 1: Tracked Keys Map To Distinct Indices And Keep Their State**
+
+        let mut seen = HashSet::new();
+        for key in COMMON_KEYS {
+            let index = key_index(key).unwrap_or_else(|| panic!("{key:?} untracked"));
+            assert!(index < KEY_COUNT, "{key:?} -> {index}");
+            assert!(seen.insert(index), "{key:?} collides at {index}");
+        }
+
+        let input = InputManager::new();
+        for (i, &key) in COMMON_KEYS.iter().enumerate() {
+            input.keyboard_state.set_key_state(key, true);
+            for &other in &COMMON_KEYS[..=i] {
+                assert!(input.is_key_pressed(other), "{other:?} after pressing {key:?}");
+            }
+            for &other in &COMMON_KEYS[i + 1..] {
+                assert!(!input.is_key_pressed(other), "{other:?} after pressing {key:?}");
+            }
+        }
+        for key in COMMON_KEYS {
+            input.keyboard_state.set_key_state(key, false);
+            assert!(!input.is_key_pressed(key));
+        }
+    }
+
+    #[test]
+    fn test_untracked_keys_are_ignored() {
+        assert_eq!(key_index(KeyCode::Mail), None);
+        let input = InputManager::new();
+        input.keyboard_state.set_key_state(KeyCode::Mail, true);
+        assert!(!input.is_key_pressed(KeyCode::Mail));
+    }
+}
@@ -0,0 +1,61 @@
+//! Tests for sub-pixel mouse motion accumulation
+//!
+//! **Feature: subpixel-mouse, Property 1: Slow Motion Integrates Instead Of Truncating**
+
+use bevy::prelude::*;
+use mindland_input::{InputEvent, InputManager};
+
+/// Feed one mouse event per frame, returning the summed `mouse_delta` and `mouse_delta_pixels`
+fn feed(input: &mut InputManager, delta: Vec2, frames: u64) -> (Vec2, IVec2) {
+    let (mut total, mut pixels) = (Vec2::ZERO, IVec2::ZERO);
+    for frame in 0..frames {
+        input.input_buffer.push(InputEvent::MouseMoved { delta, timestamp: frame * 1_000 });
+        input.process_events(frame * 1_000 + 500);
+        total += input.mouse_delta();
+        pixels += input.mouse_delta_pixels();
+    }
+    (total, pixels)
+}
+
+#[cfg(test)]
+mod subpixel_tests {
+    use super::*;
+
+    #[test]
+    fn test_many_small_deltas_integrate() {
+        // **Feature: subpixel-mouse, Property 1: Slow Motion Integrates Instead Of Truncating**
+
+        let mut input = InputManager::new();
+        input.mouse_state.update_position(Vec2::splat(1.0e7)); // Where f32 positions can't hold 0.3 px
+        let (total, pixels) = feed(&mut input, Vec2::new(0.3, -0.3), 1000);
+
+        assert!((total - Vec2::new(300.0, -300.0)).length() < 1e-2, "{total}");
+        assert!((pixels.x - 300).abs() <= 1 && (pixels.y + 300).abs() <= 1, "{pixels}");
+    }
+
+    #[test]
+    fn test_whole_pixel_mode_carries_the_fraction() {
+        // **Feature: subpixel-mouse, Property 1: Slow Motion Integrates Instead Of Truncating**
+
+        let mut input = InputManager::new();
+        input.subpixel_precision = false;
+        let (total, _) = feed(&mut input, Vec2::new(0.3, 0.0), 10);
+
+        assert_eq!(input.mouse_delta().fract(), Vec2::ZERO);
+        assert_eq!(total, Vec2::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn test_events_within_a_frame_are_summed() {
+        let mut input = InputManager::new();
+        for _ in 0..4 {
+            input.input_buffer.push(InputEvent::MouseMoved { delta: Vec2::new(0.25, 1.0), timestamp: 0 });
+        }
+        input.process_events(1_000);
+        assert_eq!(input.mouse_delta(), Vec2::new(1.0, 4.0));
+        assert_eq!(input.mouse_delta_pixels(), IVec2::new(1, 4));
+
+        input.process_events(2_000);
+        assert_eq!(input.mouse_delta(), Vec2::ZERO); // No motion this frame
+    }
+}
@@ -0,0 +1,49 @@
+//! Process CPU usage sampling
+//!
+//! Each sample reports how busy this process kept the machine since the previous sample, as a
+//! share of all cores (0-100). Platforms `sysinfo` can't sample report NaN.
+
+use sysinfo::{Pid, ProcessExt, ProcessRefreshKind, System, SystemExt};
+
+/// Samples this process's CPU usage between calls
+pub struct CpuSampler {
+    system: System,
+    pid: Option<Pid>, // None = sampling unavailable
+    primed: bool,     // A first sample exists to measure from
+}
+
+impl Default for CpuSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CpuSampler {
+    /// Sampler for the current process
+    pub fn new() -> Self {
+        let pid = if System::IS_SUPPORTED { sysinfo::get_current_pid().ok() } else { None };
+        Self { system: System::new(), pid, primed: false }
+    }
+
+    /// Whether this platform can report CPU usage
+    pub fn is_supported(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    /// CPU usage since the previous sample, 0-100 across all cores (NaN before the first interval or if unsupported)
+    ///
+    /// `sysinfo` reads 0 for an interval starting before the process used any CPU time at all.
+    pub fn sample(&mut self) -> f32 {
+        let Some(pid) = self.pid else { return f32::NAN };
+        if !self.system.refresh_process_specifics(pid, ProcessRefreshKind::new().with_cpu()) {
+            return f32::NAN;
+        }
+        let Some(process) = self.system.process(pid) else { return f32::NAN };
+        if !std::mem::replace(&mut self.primed, true) {
+            return f32::NAN; // The first refresh only records a starting point
+        }
+
+        let cores = self.system.cpus().len().max(1) as f32; // `cpu_usage` is 100 per busy core
+        (process.cpu_usage() / cores).clamp(0.0, 100.0)
+    }
+}
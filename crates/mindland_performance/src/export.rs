@@ -0,0 +1,42 @@
+//! Performance history export
+//!
+//! Dumps `performance_history`, oldest frame first, for offline analysis: JSON keeps the
+//! `PerformanceFrame` fields as serialized, CSV flattens them with durations in milliseconds.
+//! Unsampled values (NaN) are `null` in JSON and `NaN` in CSV.
+
+use crate::PerformanceMonitor;
+use std::fmt::Write;
+
+/// CSV header row, one column per `PerformanceFrame` field
+pub const HISTORY_CSV_HEADER: &str = "timestamp_ms,frame_time_ms,cpu_usage,gpu_usage,memory_usage,temperature,fps";
+
+impl PerformanceMonitor {
+    /// The frame history as a JSON array
+    pub fn export_history_json(&self) -> String {
+        let history = self.performance_history.read();
+        serde_json::to_string_pretty(&*history).expect("performance frames always serialize")
+    }
+
+    /// The frame history as CSV: `HISTORY_CSV_HEADER`, then one row per frame
+    pub fn export_history_csv(&self) -> String {
+        let history = self.performance_history.read();
+        let mut csv = String::with_capacity((history.len() + 1) * 64);
+        csv.push_str(HISTORY_CSV_HEADER);
+        csv.push('\n');
+        for frame in history.iter() {
+            // Writing to a String can't fail
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{}",
+                frame.timestamp.as_secs_f64() * 1000.0,
+                frame.frame_time.as_secs_f64() * 1000.0,
+                frame.cpu_usage,
+                frame.gpu_usage,
+                frame.memory_usage,
+                frame.temperature,
+                frame.fps,
+            );
+        }
+        csv
+    }
+}
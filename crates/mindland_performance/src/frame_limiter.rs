@@ -0,0 +1,170 @@
+//! Frame rate cap that cooperates with vsync
+//!
+//! Under vsync the swapchain already paces frames; sleeping on top of it to the same rate makes
+//! the two fight and micro-stutter. The limiter therefore only caps vsync modes below the refresh
+//! rate, and caps non-vsync modes precisely (sleep, then spin the last stretch).
+//!
+//! Variable-refresh (G-Sync/FreeSync) displays are the exception: the display follows the game's
+//! timing, so `SyncMode::AdaptiveSync` caps at the refresh rate to stay inside the VRR range.
+
+use bevy::{prelude::*, window::PresentMode};
+use std::time::{Duration, Instant};
+
+/// Remaining wait that is spun instead of slept (OS sleep granularity is ~1ms or worse)
+const SPIN_THRESHOLD: Duration = Duration::from_millis(1);
+
+/// Caps the frame rate without fighting vsync
+#[derive(Debug, Clone, Resource)]
+pub struct FrameLimiter {
+    pub target_fps: Option<f32>, // None = uncapped
+    pub refresh_rate: f32,       // Display refresh rate in Hz
+    present_mode: PresentMode,
+    adaptive_sync: bool, // Display follows our pacing; cap at `refresh_rate`
+    next_deadline: Option<Instant>,
+}
+
+/// Player-facing sync option, resolved to a present mode the surface supports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Resource)]
+pub enum SyncMode {
+    #[default]
+    Vsync,
+    NoVsync,
+    AdaptiveSync, // Variable refresh: vsync within the display's range, no waiting on late frames
+}
+
+/// Present modes the window's surface supports
+///
+/// wgpu doesn't report variable-refresh support directly; `FifoRelaxed` (adaptive vsync) is what
+/// VRR drivers drive, so it stands in for it. The `Auto*` modes are always available.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Resource)]
+pub struct PresentCapabilities {
+    pub present_modes: Vec<PresentMode>,
+}
+
+impl PresentCapabilities {
+    /// Whether the surface can be configured with `mode`
+    pub fn supports(&self, mode: PresentMode) -> bool {
+        matches!(mode, PresentMode::AutoVsync | PresentMode::AutoNoVsync) || self.present_modes.contains(&mode)
+    }
+
+    /// Whether `SyncMode::AdaptiveSync` can be honored
+    pub fn supports_adaptive_sync(&self) -> bool {
+        self.supports(PresentMode::FifoRelaxed)
+    }
+}
+
+impl SyncMode {
+    /// Present mode for this option, falling back to `AutoVsync` if adaptive sync is unsupported
+    pub fn resolve(self, capabilities: &PresentCapabilities) -> PresentMode {
+        match self {
+            Self::Vsync => PresentMode::AutoVsync,
+            Self::NoVsync => PresentMode::AutoNoVsync,
+            Self::AdaptiveSync if capabilities.supports_adaptive_sync() => PresentMode::FifoRelaxed,
+            Self::AdaptiveSync => PresentMode::AutoVsync,
+        }
+    }
+}
+
+impl Default for FrameLimiter {
+    fn default() -> Self {
+        Self::new(None, 60.0)
+    }
+}
+
+impl FrameLimiter {
+    /// Create a limiter for a display, assuming `AutoVsync` until told otherwise
+    pub fn new(target_fps: Option<f32>, refresh_rate: f32) -> Self {
+        Self {
+            target_fps,
+            refresh_rate,
+            present_mode: PresentMode::AutoVsync,
+            adaptive_sync: false,
+            next_deadline: None,
+        }
+    }
+
+    /// Keep in sync with the window's present mode (call when it changes at runtime)
+    pub fn set_present_mode(&mut self, present_mode: PresentMode) {
+        if self.present_mode != present_mode {
+            self.present_mode = present_mode;
+            self.adaptive_sync = false; // Only `set_sync_mode` turns it on
+            self.next_deadline = None; // Re-phase against the new pacing
+        }
+    }
+
+    /// Switch sync option, returning the present mode to configure the window with
+    pub fn set_sync_mode(&mut self, sync_mode: SyncMode, capabilities: &PresentCapabilities) -> PresentMode {
+        let present_mode = sync_mode.resolve(capabilities);
+        self.set_present_mode(present_mode);
+        self.adaptive_sync = sync_mode == SyncMode::AdaptiveSync && present_mode == PresentMode::FifoRelaxed;
+        present_mode
+    }
+
+    /// Whether variable refresh is active, so frames are capped at the refresh rate
+    pub fn is_adaptive_sync(&self) -> bool {
+        self.adaptive_sync
+    }
+
+    /// Current present mode
+    pub fn present_mode(&self) -> PresentMode {
+        self.present_mode
+    }
+
+    /// Whether the present mode waits for vertical blank
+    pub fn is_vsync(&self) -> bool {
+        matches!(
+            self.present_mode,
+            PresentMode::AutoVsync | PresentMode::Fifo | PresentMode::FifoRelaxed
+        )
+    }
+
+    /// Cap actually enforced: under vsync only targets below the refresh rate are limited
+    ///
+    /// With adaptive sync the refresh rate is always the cap, since the display no longer paces us.
+    pub fn effective_cap(&self) -> Option<f32> {
+        if self.adaptive_sync && self.refresh_rate > 0.0 {
+            let target = self.target_fps.filter(|&fps| fps > 0.0).unwrap_or(self.refresh_rate);
+            return Some(target.min(self.refresh_rate));
+        }
+        let target = self.target_fps.filter(|&fps| fps > 0.0)?;
+        if self.is_vsync() && target >= self.refresh_rate {
+            return None; // Vsync already paces at or below the target
+        }
+        Some(target)
+    }
+
+    /// Minimum frame duration, if capped
+    pub fn frame_budget(&self) -> Option<Duration> {
+        self.effective_cap().map(|fps| Duration::from_secs_f32(1.0 / fps))
+    }
+
+    /// Block until the next frame may start, returning how long it waited
+    ///
+    /// Deadlines advance by whole budgets so the average rate is exact; a missed deadline
+    /// re-phases instead of rushing to catch up.
+    pub fn wait(&mut self) -> Duration {
+        let Some(budget) = self.frame_budget() else {
+            self.next_deadline = None;
+            return Duration::ZERO;
+        };
+
+        let now = Instant::now();
+        let deadline = match self.next_deadline {
+            Some(deadline) if deadline > now => deadline,
+            _ => {
+                self.next_deadline = Some(now + budget);
+                return Duration::ZERO;
+            }
+        };
+
+        if let Some(sleep) = (deadline - now).checked_sub(SPIN_THRESHOLD) {
+            std::thread::sleep(sleep);
+        }
+        while Instant::now() < deadline {
+            std::hint::spin_loop();
+        }
+
+        self.next_deadline = Some(deadline + budget);
+        deadline - now
+    }
+}
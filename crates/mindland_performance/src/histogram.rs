@@ -0,0 +1,78 @@
+//! Frame-time histograms
+//!
+//! An average frame rate hides stutter: 95 fast frames and 5 very slow ones average out fine.
+//! Bucketing frame times shows the long tail directly.
+
+use crate::PerformanceMonitor;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Bucket boundaries: <8ms, 8-12, 12-16, 16-20 and >=20ms
+pub const DEFAULT_FRAME_TIME_BUCKETS: [Duration; 4] = [
+    Duration::from_millis(8),
+    Duration::from_millis(12),
+    Duration::from_millis(16),
+    Duration::from_millis(20),
+];
+
+/// Frames whose time fell in `[min, max)`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameTimeBucket {
+    pub min: Duration,
+    pub max: Option<Duration>, // None for the open-ended last bucket
+    pub count: usize,
+}
+
+/// Frame counts per frame-time range
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameTimeHistogram {
+    pub buckets: Vec<FrameTimeBucket>, // One more than the number of boundaries
+}
+
+impl Default for FrameTimeHistogram {
+    fn default() -> Self {
+        Self::new(&DEFAULT_FRAME_TIME_BUCKETS)
+    }
+}
+
+impl FrameTimeHistogram {
+    /// Create an empty histogram split at `bounds` (sorted and deduplicated)
+    pub fn new(bounds: &[Duration]) -> Self {
+        let mut bounds = bounds.to_vec();
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let mins = std::iter::once(Duration::ZERO).chain(bounds.iter().copied());
+        let maxes = bounds.iter().copied().map(Some).chain(std::iter::once(None));
+        let buckets = mins.zip(maxes).map(|(min, max)| FrameTimeBucket { min, max, count: 0 }).collect();
+        Self { buckets }
+    }
+
+    /// Build a histogram of `frame_times`
+    pub fn from_frame_times(bounds: &[Duration], frame_times: impl IntoIterator<Item = Duration>) -> Self {
+        let mut histogram = Self::new(bounds);
+        for frame_time in frame_times {
+            histogram.record(frame_time);
+        }
+        histogram
+    }
+
+    /// Count one frame
+    pub fn record(&mut self, frame_time: Duration) {
+        let index = self.buckets.partition_point(|bucket| bucket.max.is_some_and(|max| max <= frame_time));
+        self.buckets[index].count += 1;
+    }
+
+    /// Frames counted
+    pub fn total(&self) -> usize {
+        self.buckets.iter().map(|bucket| bucket.count).sum()
+    }
+}
+
+impl PerformanceMonitor {
+    /// Histogram of the frames in `performance_history`
+    pub fn frame_time_histogram(&self, bounds: &[Duration]) -> FrameTimeHistogram {
+        let history = self.performance_history.read();
+        FrameTimeHistogram::from_frame_times(bounds, history.iter().map(|frame| frame.frame_time))
+    }
+}
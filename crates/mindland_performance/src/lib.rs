@@ -5,8 +5,43 @@
 use bevy::prelude::*;
 use parking_lot::RwLock;
 use std::collections::VecDeque;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
+use thermal_zones::ThermalZones;
+
+#[cfg(target_os = "macos")]
+mod smc;
+
+mod cpu;
+mod export;
+mod frame_limiter;
+mod histogram;
+mod power;
+mod thermal_zones;
+mod time_source;
+pub use cpu::*;
+pub use export::*;
+pub use frame_limiter::*;
+pub use histogram::*;
+pub use power::*;
+pub use thermal_zones::*;
+pub use time_source::*;
+
+/// Lowest internal render resolution relative to the window
+pub const MIN_RENDER_SCALE: f32 = 0.5;
+
+/// Native resolution
+pub const MAX_RENDER_SCALE: f32 = 1.0;
+
+/// Render scales this close to `MIN_RENDER_SCALE` count as the floor
+const RENDER_SCALE_EPSILON: f32 = 1e-4;
+
+/// Largest LOD bias the optimizer applies, in world units
+pub const MAX_LOD_BIAS: f32 = 64.0;
+
+/// Frame times `FpsCounter::new` keeps for percentile lows
+pub const DEFAULT_FPS_WINDOW: usize = 1000;
 
 /// Real-time performance monitor with sub-millisecond precision
 pub struct PerformanceMonitor {
@@ -16,6 +51,7 @@ pub struct PerformanceMonitor {
     pub thermal_monitor: ThermalMonitor,
     pub performance_history: RwLock<VecDeque<PerformanceFrame>>,
     pub targets: PerformanceTargets,
+    pub cpu_sampler: CpuSampler,
 }
 
 /// High-precision frame timing
@@ -24,16 +60,21 @@ pub struct HighPrecisionTimer {
     pub frame_start: Instant,
     pub accumulated_time: Duration,
     pub frame_count: u64,
+    clock: Box<dyn TimeSource>,
 }
 
 /// FPS counter with variance tracking
 pub struct FpsCounter {
     pub current_fps: f32,
+    pub display_fps: f32,       // Exponentially smoothed, for on-screen display only
+    pub display_smoothing: f32, // Weight of each new frame in display_fps (0, 1]
     pub average_fps: f32,
     pub min_fps: f32,
     pub max_fps: f32,
     pub frame_time_variance: f32,
     pub target_fps: f32,
+    recent_frame_times: VecDeque<Duration>, // Last `window` frames, oldest first
+    window: usize,
 }
 
 /// Memory usage tracking
@@ -46,12 +87,30 @@ pub struct MemoryTracker {
 }
 
 /// Thermal monitoring for hardware protection
+#[derive(Resource)]
 pub struct ThermalMonitor {
-    pub cpu_temp: f32,
-    pub gpu_temp: f32,
+    pub cpu_temp: f32, // Celsius; NaN until a sensor reports
+    pub gpu_temp: f32, // Celsius; NaN until a sensor reports
     pub fan_speed: u32,
+    pub max_fan_speed: u32, // RPM above which the thermal state escalates one step
     pub throttling_active: bool,
     pub thermal_state: ThermalState,
+    pub sensors_available: bool, // False when readings are defaults rather than hardware values
+    pub smoothing_time_constant: Duration, // EMA time constant for sensor readings, zero = raw values
+    pub sample_interval: Duration,         // Minimum time between hardware reads in `poll`
+    pub samples_taken: u64,                // Hardware reads so far
+    last_sample: Option<Instant>,
+    thermal_zones: Option<ThermalZones>, // Linux sysfs temperatures
+    #[cfg(target_os = "macos")]
+    smc: Option<smc::Smc>,
+}
+
+/// One set of raw sensor values; `None` for sensors that couldn't be read
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ThermalReading {
+    pub cpu_temp: Option<f32>,
+    pub gpu_temp: Option<f32>,
+    pub fan_speed: Option<f32>, // RPM
 }
 
 /// Performance data for a single frame
@@ -59,7 +118,7 @@ pub struct ThermalMonitor {
 pub struct PerformanceFrame {
     pub timestamp: Duration,
     pub frame_time: Duration,
-    pub cpu_usage: f32,
+    pub cpu_usage: f32, // Process share of all cores, 0-100; NaN where unsupported
     pub gpu_usage: f32,
     pub memory_usage: u64,
     pub temperature: f32,
@@ -77,8 +136,8 @@ pub struct PerformanceTargets {
     pub max_fan_speed: u32,
 }
 
-/// Thermal state for automatic quality adjustment
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Thermal state for automatic quality adjustment (ordered coolest to hottest)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ThermalState {
     Cool,      // < 60°C - Full performance
     Warm,      // 60-75°C - Slight optimization
@@ -87,13 +146,17 @@ pub enum ThermalState {
 }
 
 /// Automatic performance optimizer
+#[derive(Resource)]
 pub struct AutoOptimizer {
     pub hardware_detector: HardwareDetector,
     pub quality_settings: QualitySettings,
     pub adaptation_strategy: AdaptationStrategy,
+    thermal_state: ThermalState,            // State `quality_settings` was last derived for by `step`
+    saved_quality: Option<QualitySettings>, // Settings before thermal throttling, restored once Cool
 }
 
 /// Hardware detection for automatic optimization
+#[derive(Debug, Clone, Serialize)]
 pub struct HardwareDetector {
     pub cpu_model: String,
     pub gpu_model: String,
@@ -103,7 +166,7 @@ pub struct HardwareDetector {
 }
 
 /// Hardware tier classification
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HardwareTier {
     Low,
     Medium,
@@ -112,7 +175,7 @@ pub enum HardwareTier {
 }
 
 /// Quality settings for performance optimization
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Resource)]
 pub struct QualitySettings {
     pub render_distance: f32,
     pub texture_quality: TextureQuality,
@@ -120,6 +183,8 @@ pub struct QualitySettings {
     pub particle_density: f32,
     pub update_frequency: u32,
     pub vsync_enabled: bool,
+    pub render_scale: f32, // Internal resolution factor, `MIN_RENDER_SCALE`-`MAX_RENDER_SCALE`
+    pub lod_bias: f32,     // World units added to LOD selection distance, 0.0-`MAX_LOD_BIAS`
 }
 
 /// Texture quality levels
@@ -158,13 +223,19 @@ impl Default for PerformanceMonitor {
 impl PerformanceMonitor {
     /// Create a new performance monitor with default targets
     pub fn new() -> Self {
+        Self::with_time_source(InstantTimeSource)
+    }
+
+    /// Create a monitor whose frame timing reads `clock` (a `MockTimeSource` in tests)
+    pub fn with_time_source(clock: impl TimeSource + 'static) -> Self {
         Self {
-            frame_timer: HighPrecisionTimer::new(),
+            frame_timer: HighPrecisionTimer::with_time_source(clock),
             fps_counter: FpsCounter::new(60.0),
             memory_tracker: MemoryTracker::new(),
             thermal_monitor: ThermalMonitor::new(),
             performance_history: RwLock::new(VecDeque::with_capacity(1000)),
             targets: PerformanceTargets::default(),
+            cpu_sampler: CpuSampler::new(),
         }
     }
 
@@ -200,14 +271,33 @@ impl PerformanceMonitor {
     /// Check if performance targets are being met
     pub fn check_performance_targets(&self) -> bool {
         self.fps_counter.current_fps >= self.targets.target_fps &&
-        self.thermal_monitor.cpu_temp <= self.targets.max_temperature &&
+        (self.thermal_monitor.cpu_temp.is_nan() || self.thermal_monitor.cpu_temp <= self.targets.max_temperature) &&
         self.thermal_monitor.fan_speed <= self.targets.max_fan_speed
     }
 
-    /// Get current CPU usage (placeholder - would use system APIs)
-    fn get_cpu_usage(&self) -> f32 {
-        // TODO: Implement actual CPU usage detection
-        25.0 // Placeholder
+    /// Current fan speed relative to the target (1.0 = at `max_fan_speed`)
+    pub fn fan_speed_normalized(&self) -> f32 {
+        self.thermal_monitor.fan_speed as f32 / self.targets.max_fan_speed.max(1) as f32
+    }
+
+    /// Set how often `sample_thermals` reads hardware (default 500ms)
+    pub fn set_thermal_sample_interval(&mut self, interval: Duration) {
+        self.thermal_monitor.sample_interval = interval;
+    }
+
+    /// Sample thermal sensors at the configured interval and update the thermal state
+    ///
+    /// Call every frame: between samples the last readings and thermal state are held.
+    pub fn sample_thermals(&mut self) {
+        let now = self.frame_timer.clock.now();
+        self.thermal_monitor.max_fan_speed = self.targets.max_fan_speed;
+        self.thermal_monitor.poll(now);
+    }
+
+    /// Process CPU usage (0-100 across all cores) since the last call; `end_frame` calls this once
+    /// per frame. NaN where the platform can't be sampled.
+    pub fn get_cpu_usage(&mut self) -> f32 {
+        self.cpu_sampler.sample()
     }
 
     /// Get current GPU usage (placeholder - would use graphics APIs)
@@ -218,22 +308,26 @@ impl PerformanceMonitor {
 }
 
 impl HighPrecisionTimer {
-    fn new() -> Self {
-        let now = Instant::now();
+    /// Timer reading the given clock
+    pub fn with_time_source(clock: impl TimeSource + 'static) -> Self {
+        let now = clock.now();
         Self {
             last_frame: now,
             frame_start: now,
             accumulated_time: Duration::ZERO,
             frame_count: 0,
+            clock: Box::new(clock),
         }
     }
 
-    fn start_frame(&mut self) {
-        self.frame_start = Instant::now();
+    /// Mark the start of a frame
+    pub fn start_frame(&mut self) {
+        self.frame_start = self.clock.now();
     }
 
-    fn end_frame(&mut self) -> Duration {
-        let now = Instant::now();
+    /// Mark the end of a frame, returning its duration
+    pub fn end_frame(&mut self) -> Duration {
+        let now = self.clock.now();
         let frame_time = now - self.frame_start;
         self.accumulated_time += frame_time;
         self.frame_count += 1;
@@ -243,20 +337,75 @@ impl HighPrecisionTimer {
 }
 
 impl FpsCounter {
-    fn new(target_fps: f32) -> Self {
+    /// Create a new FPS counter for the given target, keeping `DEFAULT_FPS_WINDOW` frames for percentile lows
+    pub fn new(target_fps: f32) -> Self {
+        Self::with_window(target_fps, DEFAULT_FPS_WINDOW)
+    }
+
+    /// Create an FPS counter whose percentile lows cover the last `window` frames (at least one)
+    pub fn with_window(target_fps: f32, window: usize) -> Self {
+        let window = window.max(1);
         Self {
             current_fps: 0.0,
+            display_fps: 0.0,
+            display_smoothing: 0.05, // ~20 frame time constant
             average_fps: 0.0,
             min_fps: f32::MAX,
             max_fps: 0.0,
             frame_time_variance: 0.0,
             target_fps,
+            recent_frame_times: VecDeque::with_capacity(window),
+            window,
         }
     }
 
-    fn update(&mut self, frame_time: Duration) {
+    /// Frames the percentile lows cover once enough have been recorded
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// FPS of the frame at the worst `percent` of recent frame times (1.0 = the "1% low")
+    ///
+    /// Uses whatever frames have been recorded while the window is still filling; with too few
+    /// for the percentile to single out, that's the slowest frame. 0 before any frame.
+    pub fn percentile_low(&self, percent: f32) -> f32 {
+        let mut frame_times: Vec<Duration> = self.recent_frame_times.iter().copied().collect();
+        if frame_times.is_empty() {
+            return 0.0;
+        }
+        // Snapped to 1e-4 percent, so 0.1_f32 (0.10000000149) selects 1 frame of 1000, not 2
+        let worst_share = (percent.clamp(0.0, 100.0) as f64 * 1e4).round() / 1e6;
+        let rank = ((frame_times.len() as f64 * worst_share).ceil() as usize).clamp(1, frame_times.len());
+        let (_, frame_time, _) = frame_times.select_nth_unstable_by(rank - 1, |a, b| b.cmp(a)); // Slowest first
+        1.0 / frame_time.as_secs_f32()
+    }
+
+    /// FPS at the slowest 1% of recent frames
+    pub fn one_percent_low(&self) -> f32 {
+        self.percentile_low(1.0)
+    }
+
+    /// FPS at the slowest 0.1% of recent frames
+    pub fn point_one_percent_low(&self) -> f32 {
+        self.percentile_low(0.1)
+    }
+
+    /// Set the display smoothing weight (clamped to (0, 1], 1.0 disables smoothing)
+    pub fn set_display_smoothing(&mut self, smoothing: f32) {
+        self.display_smoothing = smoothing.clamp(f32::EPSILON, 1.0);
+    }
+
+    /// Record a frame time
+    pub fn update(&mut self, frame_time: Duration) {
         let frame_time_ms = frame_time.as_secs_f32() * 1000.0;
         self.current_fps = 1000.0 / frame_time_ms;
+
+        // Smoothed value for display; analysis keeps using the raw current_fps
+        if self.display_fps == 0.0 {
+            self.display_fps = self.current_fps;
+        } else {
+            self.display_fps += (self.current_fps - self.display_fps) * self.display_smoothing;
+        }
         
         // Update min/max
         self.min_fps = self.min_fps.min(self.current_fps);
@@ -265,6 +414,11 @@ impl FpsCounter {
         // Calculate variance (simplified)
         let target_frame_time = 1000.0 / self.target_fps;
         self.frame_time_variance = (frame_time_ms - target_frame_time).abs();
+
+        if self.recent_frame_times.len() == self.window {
+            self.recent_frame_times.pop_front();
+        }
+        self.recent_frame_times.push_back(frame_time);
     }
 }
 
@@ -280,25 +434,277 @@ impl MemoryTracker {
     }
 }
 
+impl Default for ThermalMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ThermalMonitor {
-    fn new() -> Self {
+    /// Monitor reading this platform's sensors: SMC keys on macOS, thermal zones on Linux
+    ///
+    /// Windows has no unprivileged temperature source (WMI's `MSAcpi_ThermalZoneTemperature`
+    /// needs administrator rights and most firmware doesn't populate it), so temperatures stay
+    /// NaN and the thermal state stays Cool there; fan speed and frame timing still drive it.
+    pub fn new() -> Self {
+        let thermal_zones = cfg!(target_os = "linux").then(|| ThermalZones::new(THERMAL_ZONE_ROOT));
         Self {
-            cpu_temp: 45.0, // Default cool temperature
-            gpu_temp: 40.0,
+            thermal_zones,
+            #[cfg(target_os = "macos")]
+            smc: smc::Smc::open(),
+            ..Self::without_sensors()
+        }
+    }
+
+    /// Monitor reading only the Linux-style thermal zones under `root` (fixtures, unusual sysfs mounts)
+    pub fn with_thermal_zone_root(root: impl Into<PathBuf>) -> Self {
+        Self { thermal_zones: Some(ThermalZones::new(root)), ..Self::without_sensors() }
+    }
+
+    fn without_sensors() -> Self {
+        Self {
+            cpu_temp: f32::NAN,
+            gpu_temp: f32::NAN,
             fan_speed: 1200, // Default quiet fan speed
+            max_fan_speed: PerformanceTargets::default().max_fan_speed,
             throttling_active: false,
             thermal_state: ThermalState::Cool,
+            sensors_available: false,
+            smoothing_time_constant: Duration::from_secs(3), // Rides out single-sample SMC spikes
+            sample_interval: Duration::from_millis(500), // SMC reads take milliseconds; never do them per frame
+            samples_taken: 0,
+            last_sample: None,
+            thermal_zones: None,
+            #[cfg(target_os = "macos")]
+            smc: None,
         }
     }
 
-    /// Update thermal state based on temperature
+    /// Read hardware sensors (temperatures, plus fan RPM via the SMC on macOS) and update the thermal state
+    pub fn sample(&mut self) {
+        self.sample_at(Instant::now());
+    }
+
+    /// Sample if `sample_interval` has passed since the last read, otherwise hold the last values
+    ///
+    /// Returns whether hardware was read. Safe to call every frame.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        let due = self.last_sample.is_none_or(|last| now.saturating_duration_since(last) >= self.sample_interval);
+        if due {
+            self.sample_at(now);
+        }
+        due
+    }
+
+    /// Read hardware sensors now, as of `now`
+    fn sample_at(&mut self, now: Instant) {
+        #[allow(unused_mut)]
+        let mut reading = self.thermal_zones.as_ref().map(ThermalZones::read).unwrap_or_default();
+        #[cfg(target_os = "macos")]
+        if let Some(smc) = &self.smc {
+            // Proximity sensors first; die sensors on machines without them
+            let first = |keys: [&str; 2]| keys.into_iter().find_map(|key| smc.read_f32(key));
+            reading.cpu_temp = first(["TC0P", "TC0D"]);
+            reading.gpu_temp = first(["TG0P", "TG0D"]);
+            reading.fan_speed = smc.read_f32("F0Ac");
+        }
+
+        // The first reading has no history to smooth against and is taken as-is
+        self.samples_taken += 1;
+        let elapsed = self.last_sample.map_or(Duration::MAX, |last| now - last);
+        self.last_sample = Some(now);
+        self.apply_reading(reading, elapsed);
+    }
+
+    /// Blend a raw reading taken `elapsed` after the previous one into the filtered values, then
+    /// update the thermal state from them
+    ///
+    /// Each value moves `1 - e^(-elapsed / smoothing_time_constant)` of the way toward the reading,
+    /// so a one-sample spike only nudges it regardless of how often sensors are polled.
+    pub fn apply_reading(&mut self, reading: ThermalReading, elapsed: Duration) {
+        let time_constant = self.smoothing_time_constant.as_secs_f32();
+        let blend = if time_constant > 0.0 {
+            1.0 - (-elapsed.as_secs_f32() / time_constant).exp()
+        } else {
+            1.0
+        };
+        // Values never read before (NaN) take the reading as-is
+        let smooth = |filtered: f32, raw: f32| if filtered.is_nan() { raw } else { filtered + (raw - filtered) * blend };
+
+        if let Some(cpu_temp) = reading.cpu_temp {
+            self.cpu_temp = smooth(self.cpu_temp, cpu_temp);
+        }
+        if let Some(gpu_temp) = reading.gpu_temp {
+            self.gpu_temp = smooth(self.gpu_temp, gpu_temp);
+        }
+        if let Some(fan_speed) = reading.fan_speed {
+            self.fan_speed = smooth(self.fan_speed as f32, fan_speed).round() as u32;
+        }
+        if reading != ThermalReading::default() {
+            self.sensors_available = true;
+        }
+
+        self.update_thermal_state();
+    }
+
+    /// Update thermal state based on temperature (Cool while the temperature is unknown), one step
+    /// hotter while the fan spins above `max_fan_speed`
     pub fn update_thermal_state(&mut self) {
-        self.thermal_state = match self.cpu_temp {
+        let state = match self.cpu_temp {
+            t if t.is_nan() => ThermalState::Cool,
             t if t < 60.0 => ThermalState::Cool,
             t if t < 75.0 => ThermalState::Warm,
             t if t < 85.0 => ThermalState::Hot,
             _ => ThermalState::Critical,
         };
+        // Fans spinning above target mean we're louder than wanted even if temperatures look fine
+        self.thermal_state = if self.fan_speed > self.max_fan_speed { state.escalated() } else { state };
+    }
+}
+
+impl AutoOptimizer {
+    /// Optimizer starting from `quality_settings`, not yet thermally throttled
+    pub fn new(hardware_detector: HardwareDetector, quality_settings: QualitySettings, adaptation_strategy: AdaptationStrategy) -> Self {
+        Self {
+            hardware_detector,
+            quality_settings,
+            adaptation_strategy,
+            thermal_state: ThermalState::Cool,
+            saved_quality: None,
+        }
+    }
+
+    /// Follow the thermal state, returning new quality settings when it changes (None while it holds)
+    ///
+    /// The first step above Cool saves `quality_settings`; each hotter state is derived from that
+    /// baseline, so repeated changes don't compound, and cooling back to Cool restores it exactly.
+    /// Conservative waits for Hot before throttling, Aggressive already lowers the render scale
+    /// at Warm, and Emergency applies full thermal protection from Warm on.
+    pub fn step(&mut self, thermal_state: ThermalState) -> Option<QualitySettings> {
+        if thermal_state == self.thermal_state {
+            return None;
+        }
+        self.thermal_state = thermal_state;
+
+        let settings = if thermal_state == ThermalState::Cool {
+            self.saved_quality.take().unwrap_or_else(|| self.quality_settings.clone())
+        } else {
+            let baseline = self.saved_quality.get_or_insert_with(|| self.quality_settings.clone());
+            let mut settings = baseline.clone();
+            match (thermal_state, self.adaptation_strategy) {
+                (ThermalState::Cool, _) | (ThermalState::Warm, AdaptationStrategy::Conservative) => {}
+                (ThermalState::Warm, AdaptationStrategy::Aggressive) => settings.reduce_gpu_load(0.25),
+                (ThermalState::Warm, AdaptationStrategy::Emergency) | (ThermalState::Hot, _) => settings.apply_thermal_protection(),
+                (ThermalState::Critical, _) => {
+                    settings.apply_thermal_protection();
+                    settings.set_render_scale(MIN_RENDER_SCALE);
+                }
+            }
+            settings
+        };
+        self.quality_settings = settings.clone();
+        Some(settings)
+    }
+
+    /// Whether `step` has throttled quality below the saved baseline
+    pub fn is_throttled(&self) -> bool {
+        self.saved_quality.is_some()
+    }
+
+    /// Adapt quality when frames are GPU-bound, lowering render scale before anything else
+    pub fn adapt_to_gpu_bound(&mut self) {
+        let render_scale_step = match self.adaptation_strategy {
+            AdaptationStrategy::Conservative => 0.05,
+            AdaptationStrategy::Aggressive => 0.1,
+            AdaptationStrategy::Emergency => 0.5,
+        };
+        self.quality_settings.reduce_gpu_load(render_scale_step);
+    }
+
+    /// Raise the LOD bias while thermally throttled or missing the frame target, and ease it back once recovered
+    pub fn adapt_lod_bias(&mut self, thermal_state: ThermalState, fps: f32, target_fps: f32) {
+        let stressed = matches!(thermal_state, ThermalState::Hot | ThermalState::Critical) || fps < target_fps * 0.9;
+        let step = match self.adaptation_strategy {
+            AdaptationStrategy::Conservative => 4.0,
+            AdaptationStrategy::Aggressive => 8.0,
+            AdaptationStrategy::Emergency => MAX_LOD_BIAS,
+        };
+
+        let settings = &mut self.quality_settings;
+        if stressed {
+            settings.set_lod_bias(settings.lod_bias + step);
+        } else if thermal_state == ThermalState::Cool && fps >= target_fps {
+            settings.set_lod_bias(settings.lod_bias - 4.0); // Recover slowly so quality doesn't oscillate
+        }
+    }
+}
+
+impl HardwareDetector {
+    /// Detect the host CPU and memory (GPU model is filled in once the renderer has an adapter)
+    pub fn detect() -> Self {
+        let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+        let model = machine_model();
+        let is_macbook_pro_2014 = model.as_deref().is_some_and(|model| {
+            // MacBookPro11,1-11,3 are the 2014 models
+            ["MacBookPro11,1", "MacBookPro11,2", "MacBookPro11,3"].contains(&model)
+        });
+
+        Self {
+            cpu_model: format!("{} ({} threads)", model.unwrap_or_else(|| std::env::consts::ARCH.to_string()), threads),
+            gpu_model: String::from("unknown"),
+            total_memory: total_memory().unwrap_or(0),
+            hardware_tier: match threads {
+                0..=4 => HardwareTier::Low,
+                5..=8 => HardwareTier::Medium,
+                9..=16 => HardwareTier::High,
+                _ => HardwareTier::UltraHigh,
+            },
+            is_macbook_pro_2014,
+        }
+    }
+}
+
+/// Machine model identifier (e.g. "MacBookPro11,3"), where the platform exposes one
+fn machine_model() -> Option<String> {
+    #[cfg(target_os = "macos")]
+    {
+        let output = std::process::Command::new("sysctl").args(["-n", "hw.model"]).output().ok()?;
+        let model = String::from_utf8(output.stdout).ok()?;
+        Some(model.trim().to_string()).filter(|model| !model.is_empty())
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        None
+    }
+}
+
+/// Total physical memory in bytes, where the platform exposes it
+fn total_memory() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+        let kib = meminfo.lines().find_map(|line| line.strip_prefix("MemTotal:"))?;
+        kib.trim().trim_end_matches("kB").trim().parse::<u64>().ok().map(|kib| kib * 1024)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let output = std::process::Command::new("sysctl").args(["-n", "hw.memsize"]).output().ok()?;
+        String::from_utf8(output.stdout).ok()?.trim().parse().ok()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+impl ThermalState {
+    /// Next more severe state (Critical stays Critical)
+    pub fn escalated(self) -> Self {
+        match self {
+            ThermalState::Cool => ThermalState::Warm,
+            ThermalState::Warm => ThermalState::Hot,
+            ThermalState::Hot | ThermalState::Critical => ThermalState::Critical,
+        }
     }
 }
 
@@ -325,9 +731,65 @@ impl QualitySettings {
             particle_density: 0.7,
             update_frequency: 60,
             vsync_enabled: true,
+            render_scale: MAX_RENDER_SCALE,
+            lod_bias: 0.0,
+        }
+    }
+
+    /// Minimal settings for the safe-mode launch after a crash
+    pub fn safe_mode_preset() -> Self {
+        Self {
+            render_distance: 64.0,
+            texture_quality: TextureQuality::Low,
+            shadow_quality: ShadowQuality::Off,
+            particle_density: 0.25,
+            update_frequency: 30,
+            vsync_enabled: false, // Paced by the frame limiter
+            render_scale: MIN_RENDER_SCALE,
+            lod_bias: MAX_LOD_BIAS * 0.5,
         }
     }
 
+    /// Set the internal resolution factor (clamped to `MIN_RENDER_SCALE`-`MAX_RENDER_SCALE`)
+    pub fn set_render_scale(&mut self, render_scale: f32) {
+        self.render_scale = render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+    }
+
+    /// Set the LOD bias (clamped to 0-`MAX_LOD_BIAS`)
+    pub fn set_lod_bias(&mut self, lod_bias: f32) {
+        self.lod_bias = lod_bias.clamp(0.0, MAX_LOD_BIAS);
+    }
+
+    /// Reduce GPU cost by one step: resolution first, then shadows, textures and particles
+    pub fn reduce_gpu_load(&mut self, render_scale_step: f32) {
+        // Compare against the clamped floor so step rounding never leaves a sliver above it
+        let current = self.render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+        if current - MIN_RENDER_SCALE > RENDER_SCALE_EPSILON {
+            self.set_render_scale(current - render_scale_step);
+            if self.render_scale - MIN_RENDER_SCALE <= RENDER_SCALE_EPSILON {
+                self.render_scale = MIN_RENDER_SCALE;
+            }
+            return;
+        }
+        self.render_scale = MIN_RENDER_SCALE;
+
+        self.shadow_quality = match self.shadow_quality {
+            ShadowQuality::Ultra => ShadowQuality::High,
+            ShadowQuality::High => ShadowQuality::Medium,
+            ShadowQuality::Medium => ShadowQuality::Low,
+            ShadowQuality::Low => ShadowQuality::Off,
+            ShadowQuality::Off => {
+                self.texture_quality = match self.texture_quality {
+                    TextureQuality::Ultra => TextureQuality::High,
+                    TextureQuality::High => TextureQuality::Medium,
+                    TextureQuality::Medium | TextureQuality::Low => TextureQuality::Low,
+                };
+                self.particle_density *= 0.8;
+                ShadowQuality::Off
+            }
+        };
+    }
+
     /// Apply thermal protection adjustments
     pub fn apply_thermal_protection(&mut self) {
         self.render_distance *= 0.8;
@@ -335,5 +797,6 @@ impl QualitySettings {
         self.shadow_quality = ShadowQuality::Off;
         self.particle_density *= 0.5;
         self.update_frequency = 30;
+        self.set_lod_bias(self.lod_bias + 32.0);
     }
 }
\ No newline at end of file
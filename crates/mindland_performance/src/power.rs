@@ -0,0 +1,238 @@
+//! Battery saver
+//!
+//! On battery, caps the frame rate and simulation update frequency to save power and keep the
+//! fans quiet; the previous settings come back as soon as the machine is plugged in.
+
+use crate::{FrameLimiter, QualitySettings};
+use bevy::prelude::*;
+use std::time::{Duration, Instant};
+
+/// How often the OS is asked for the power source
+pub const DEFAULT_POWER_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Where the machine is drawing power from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PowerSource {
+    Ac,
+    Battery,
+    #[default]
+    Unknown, // No battery reported (desktops) or unsupported platform; treated as AC
+}
+
+/// Reports the current power source (implement for tests or custom platforms)
+pub trait PowerSourceProvider: Send + Sync {
+    fn power_source(&self) -> PowerSource;
+}
+
+/// Power source from platform APIs (IOKit on macOS, sysfs on Linux, Win32 on Windows)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemPowerSource;
+
+impl PowerSourceProvider for SystemPowerSource {
+    fn power_source(&self) -> PowerSource {
+        platform::power_source()
+    }
+}
+
+/// Caps frame rate and update frequency while on battery
+#[derive(Resource)]
+pub struct BatterySaver {
+    pub enabled: bool,
+    pub battery_fps: f32,              // Frame cap on battery
+    pub battery_update_frequency: u32, // `QualitySettings::update_frequency` cap on battery
+    pub poll_interval: Duration,
+    provider: Box<dyn PowerSourceProvider>,
+    power_source: PowerSource,
+    last_poll: Option<Instant>,
+    saved_target_fps: Option<Option<f32>>, // Limiter target before the cap, restored on AC
+    saved_update_frequency: Option<u32>,
+}
+
+impl Default for BatterySaver {
+    fn default() -> Self {
+        Self::new(Box::new(SystemPowerSource))
+    }
+}
+
+impl BatterySaver {
+    /// Create an enabled battery saver (30 FPS, 30 Hz updates on battery) reading `provider`
+    pub fn new(provider: Box<dyn PowerSourceProvider>) -> Self {
+        Self {
+            enabled: true,
+            battery_fps: 30.0,
+            battery_update_frequency: 30,
+            poll_interval: DEFAULT_POWER_POLL_INTERVAL,
+            provider,
+            power_source: PowerSource::Unknown,
+            last_poll: None,
+            saved_target_fps: None,
+            saved_update_frequency: None,
+        }
+    }
+
+    /// Re-read the power source if `poll_interval` has passed, returning whether `is_active` changed
+    pub fn poll(&mut self, now: Instant) -> bool {
+        if self.last_poll.is_some_and(|last| now.saturating_duration_since(last) < self.poll_interval) {
+            return false;
+        }
+        self.last_poll = Some(now);
+
+        let was_active = self.is_active();
+        self.power_source = self.provider.power_source();
+        self.is_active() != was_active
+    }
+
+    /// Power source as of the last poll
+    pub fn power_source(&self) -> PowerSource {
+        self.power_source
+    }
+
+    /// Whether the battery caps currently apply
+    pub fn is_active(&self) -> bool {
+        self.enabled && self.power_source == PowerSource::Battery
+    }
+
+    /// Cap the limiter while active, restoring its previous target once inactive (call every frame)
+    pub fn apply_to_limiter(&mut self, limiter: &mut FrameLimiter) {
+        if self.is_active() {
+            let previous = *self.saved_target_fps.get_or_insert(limiter.target_fps);
+            let cap = previous.map_or(self.battery_fps, |fps| fps.min(self.battery_fps));
+            limiter.target_fps = Some(cap);
+        } else if let Some(previous) = self.saved_target_fps.take() {
+            limiter.target_fps = previous;
+        }
+    }
+
+    /// Cap the update frequency while active, restoring it once inactive (call every frame)
+    pub fn apply_to_quality(&mut self, quality: &mut QualitySettings) {
+        if self.is_active() {
+            let previous = *self.saved_update_frequency.get_or_insert(quality.update_frequency);
+            quality.update_frequency = previous.min(self.battery_update_frequency);
+        } else if let Some(previous) = self.saved_update_frequency.take() {
+            quality.update_frequency = previous;
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::PowerSource;
+    use std::ffi::{c_void, CStr};
+    use std::os::raw::c_char;
+
+    const CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOPSCopyPowerSourcesInfo() -> *const c_void;
+        fn IOPSGetProvidingPowerSourceType(snapshot: *const c_void) -> *const c_void;
+    }
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFStringGetCString(string: *const c_void, buffer: *mut c_char, size: isize, encoding: u32) -> u8;
+        fn CFRelease(object: *const c_void);
+    }
+
+    pub(super) fn power_source() -> PowerSource {
+        // SAFETY: the snapshot is owned (Copy rule) and released below; the type string follows
+        // the Get rule and is only read while the snapshot is alive
+        unsafe {
+            let snapshot = IOPSCopyPowerSourcesInfo();
+            if snapshot.is_null() {
+                return PowerSource::Unknown;
+            }
+
+            let kind = IOPSGetProvidingPowerSourceType(snapshot);
+            let mut buffer = [0 as c_char; 32];
+            let source = if !kind.is_null()
+                && CFStringGetCString(kind, buffer.as_mut_ptr(), buffer.len() as isize, CF_STRING_ENCODING_UTF8) != 0
+            {
+                match CStr::from_ptr(buffer.as_ptr()).to_bytes() {
+                    b"Battery Power" => PowerSource::Battery,
+                    b"AC Power" | b"UPS Power" => PowerSource::Ac,
+                    _ => PowerSource::Unknown,
+                }
+            } else {
+                PowerSource::Unknown
+            };
+
+            CFRelease(snapshot);
+            source
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::PowerSource;
+    use std::fs;
+
+    pub(super) fn power_source() -> PowerSource {
+        let Ok(supplies) = fs::read_dir("/sys/class/power_supply") else {
+            return PowerSource::Unknown;
+        };
+
+        let mut discharging = false;
+        for supply in supplies.flatten() {
+            let read = |name: &str| fs::read_to_string(supply.path().join(name)).unwrap_or_default();
+            match read("type").trim() {
+                "Mains" | "USB" if read("online").trim() == "1" => return PowerSource::Ac,
+                "Battery" if read("status").trim() == "Discharging" => discharging = true,
+                _ => {}
+            }
+        }
+
+        if discharging {
+            PowerSource::Battery
+        } else {
+            PowerSource::Unknown
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::PowerSource;
+
+    const NO_SYSTEM_BATTERY: u8 = 128;
+
+    /// Mirrors `SYSTEM_POWER_STATUS`
+    #[repr(C)]
+    #[derive(Default)]
+    struct SystemPowerStatus {
+        ac_line_status: u8,
+        battery_flag: u8,
+        battery_life_percent: u8,
+        system_status_flag: u8,
+        battery_life_time: u32,
+        battery_full_life_time: u32,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetSystemPowerStatus(status: *mut SystemPowerStatus) -> i32;
+    }
+
+    pub(super) fn power_source() -> PowerSource {
+        let mut status = SystemPowerStatus::default();
+        // SAFETY: `status` is a valid, writable SYSTEM_POWER_STATUS
+        if unsafe { GetSystemPowerStatus(&mut status) } == 0 || status.battery_flag == NO_SYSTEM_BATTERY {
+            return PowerSource::Unknown;
+        }
+        match status.ac_line_status {
+            0 => PowerSource::Battery,
+            1 => PowerSource::Ac,
+            _ => PowerSource::Unknown,
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+mod platform {
+    use super::PowerSource;
+
+    pub(super) fn power_source() -> PowerSource {
+        PowerSource::Unknown
+    }
+}
@@ -0,0 +1,182 @@
+//! Read-only access to the Apple System Management Controller (SMC)
+//!
+//! Used for fan speeds and temperatures on Intel Macs (e.g. the MacBook Pro 2014).
+//! Only reads keys - nothing here ever writes fan targets.
+
+use std::ffi::c_void;
+use std::os::raw::c_char;
+
+type KernReturn = i32;
+type IoObject = u32;
+type IoConnect = u32;
+type MachPort = u32;
+
+const KERN_SUCCESS: KernReturn = 0;
+const MASTER_PORT_DEFAULT: MachPort = 0;
+const KERNEL_INDEX_SMC: u32 = 2;
+const SMC_CMD_READ_BYTES: u8 = 5;
+const SMC_CMD_READ_KEYINFO: u8 = 9;
+
+#[link(name = "IOKit", kind = "framework")]
+extern "C" {
+    fn IOServiceMatching(name: *const c_char) -> *mut c_void;
+    fn IOServiceGetMatchingService(master_port: MachPort, matching: *mut c_void) -> IoObject;
+    fn IOServiceOpen(service: IoObject, owning_task: MachPort, kind: u32, connect: *mut IoConnect) -> KernReturn;
+    fn IOServiceClose(connect: IoConnect) -> KernReturn;
+    fn IOObjectRelease(object: IoObject) -> KernReturn;
+    fn IOConnectCallStructMethod(
+        connection: IoConnect,
+        selector: u32,
+        input: *const c_void,
+        input_size: usize,
+        output: *mut c_void,
+        output_size: *mut usize,
+    ) -> KernReturn;
+}
+
+extern "C" {
+    static mach_task_self_: MachPort;
+}
+
+/// Mirrors `SMCKeyData_vers_t`
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct SmcVersion {
+    major: u8,
+    minor: u8,
+    build: u8,
+    reserved: u8,
+    release: u16,
+}
+
+/// Mirrors `SMCKeyData_pLimitData_t`
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct SmcPLimitData {
+    version: u16,
+    length: u16,
+    cpu_p_limit: u32,
+    gpu_p_limit: u32,
+    mem_p_limit: u32,
+}
+
+/// Mirrors `SMCKeyData_keyInfo_t`
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct SmcKeyInfo {
+    data_size: u32,
+    data_type: u32,
+    data_attributes: u8,
+}
+
+/// Mirrors `SMCKeyData_t` (80 bytes)
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+struct SmcKeyData {
+    key: u32,
+    version: SmcVersion,
+    p_limit_data: SmcPLimitData,
+    key_info: SmcKeyInfo,
+    result: u8,
+    status: u8,
+    data8: u8,
+    data32: u32,
+    bytes: [u8; 32],
+}
+
+/// Open connection to the AppleSMC service
+pub struct Smc {
+    connection: IoConnect,
+}
+
+impl Smc {
+    /// Open the SMC, returning `None` if the service is unavailable (e.g. Apple Silicon VMs)
+    pub fn open() -> Option<Self> {
+        // SAFETY: plain IOKit calls; the matching dictionary is consumed by IOServiceGetMatchingService
+        unsafe {
+            let matching = IOServiceMatching(c"AppleSMC".as_ptr());
+            if matching.is_null() {
+                return None;
+            }
+
+            let service = IOServiceGetMatchingService(MASTER_PORT_DEFAULT, matching);
+            if service == 0 {
+                return None;
+            }
+
+            let mut connection = 0;
+            let result = IOServiceOpen(service, mach_task_self_, 0, &mut connection);
+            IOObjectRelease(service);
+
+            (result == KERN_SUCCESS).then_some(Self { connection })
+        }
+    }
+
+    /// Read a numeric key (e.g. `F0Ac` fan 0 RPM, `TC0P` CPU proximity °C)
+    pub fn read_f32(&self, key: &str) -> Option<f32> {
+        let key = four_char_code(key)?;
+
+        let mut input = SmcKeyData {
+            key,
+            data8: SMC_CMD_READ_KEYINFO,
+            ..Default::default()
+        };
+        let info = self.call(&input)?;
+
+        input.key_info.data_size = info.key_info.data_size;
+        input.data8 = SMC_CMD_READ_BYTES;
+        let output = self.call(&input)?;
+
+        decode(info.key_info.data_type, info.key_info.data_size, &output.bytes)
+    }
+
+    fn call(&self, input: &SmcKeyData) -> Option<SmcKeyData> {
+        let mut output = SmcKeyData::default();
+        let mut output_size = std::mem::size_of::<SmcKeyData>();
+
+        // SAFETY: input/output point to correctly sized #[repr(C)] SMCKeyData_t structs
+        let result = unsafe {
+            IOConnectCallStructMethod(
+                self.connection,
+                KERNEL_INDEX_SMC,
+                input as *const SmcKeyData as *const c_void,
+                std::mem::size_of::<SmcKeyData>(),
+                &mut output as *mut SmcKeyData as *mut c_void,
+                &mut output_size,
+            )
+        };
+
+        (result == KERN_SUCCESS && output.result == 0).then_some(output)
+    }
+}
+
+impl Drop for Smc {
+    fn drop(&mut self) {
+        // SAFETY: connection was opened by IOServiceOpen and is closed exactly once
+        unsafe {
+            IOServiceClose(self.connection);
+        }
+    }
+}
+
+/// Pack a 4-character SMC key into its big-endian integer form
+fn four_char_code(key: &str) -> Option<u32> {
+    let bytes: [u8; 4] = key.as_bytes().try_into().ok()?;
+    Some(u32::from_be_bytes(bytes))
+}
+
+/// Decode the SMC data types used by fan and temperature keys
+fn decode(data_type: u32, data_size: u32, bytes: &[u8; 32]) -> Option<f32> {
+    let type_code = data_type.to_be_bytes();
+    match (&type_code, data_size) {
+        // Unsigned 14.2 fixed point (fan RPM on Intel Macs)
+        (b"fpe2", 2) => Some(u16::from_be_bytes([bytes[0], bytes[1]]) as f32 / 4.0),
+        // Signed 7.8 fixed point (temperatures)
+        (b"sp78", 2) => Some(i16::from_be_bytes([bytes[0], bytes[1]]) as f32 / 256.0),
+        // Native float (newer machines)
+        (b"flt ", 4) => Some(f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        (b"ui8 ", 1) => Some(bytes[0] as f32),
+        (b"ui16", 2) => Some(u16::from_be_bytes([bytes[0], bytes[1]]) as f32),
+        _ => None,
+    }
+}
@@ -0,0 +1,63 @@
+//! Linux thermal zone temperatures
+//!
+//! Each `thermal_zone*` directory under `/sys/class/thermal` has a `type` (the sensor's driver
+//! name, e.g. `x86_pkg_temp`, `cpu-thermal`, `acpitz`, `gpu-thermal`) and a `temp` in
+//! millidegrees Celsius. The CPU temperature is the hottest zone whose type names the CPU or
+//! SoC, falling back to the hottest non-GPU zone (often just `acpitz`, the motherboard).
+
+use crate::ThermalReading;
+use std::path::{Path, PathBuf};
+
+/// Sysfs directory holding the thermal zones
+pub const THERMAL_ZONE_ROOT: &str = "/sys/class/thermal";
+
+/// Zone types that measure the CPU package or SoC
+const CPU_ZONE_TYPES: [&str; 5] = ["x86_pkg_temp", "cpu", "soc", "coretemp", "k10temp"];
+
+/// Zone type fragment marking GPU sensors
+const GPU_ZONE_TYPE: &str = "gpu";
+
+/// Reads temperatures from the thermal zones under a root directory
+#[derive(Debug, Clone)]
+pub(crate) struct ThermalZones {
+    root: PathBuf,
+}
+
+impl ThermalZones {
+    pub(crate) fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// (type, °C) of every readable zone
+    fn zones(&self) -> Vec<(String, f32)> {
+        let Ok(entries) = std::fs::read_dir(&self.root) else { return Vec::new() };
+        entries
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with("thermal_zone"))
+            .filter_map(|entry| read_zone(&entry.path()))
+            .collect()
+    }
+
+    /// Current CPU and GPU temperatures (None where no zone reports them)
+    pub(crate) fn read(&self) -> ThermalReading {
+        let zones = self.zones();
+        let hottest = |filter: &dyn Fn(&str) -> bool| {
+            zones.iter().filter(|(kind, _)| filter(kind)).map(|&(_, temp)| temp).reduce(f32::max)
+        };
+        let is_gpu = |kind: &str| kind.contains(GPU_ZONE_TYPE);
+        let is_cpu = |kind: &str| !is_gpu(kind) && CPU_ZONE_TYPES.iter().any(|cpu| kind.contains(cpu));
+
+        ThermalReading {
+            cpu_temp: hottest(&is_cpu).or_else(|| hottest(&|kind| !is_gpu(kind))),
+            gpu_temp: hottest(&is_gpu),
+            fan_speed: None,
+        }
+    }
+}
+
+/// A zone's lower-cased type and temperature, if both can be read
+fn read_zone(zone: &Path) -> Option<(String, f32)> {
+    let kind = std::fs::read_to_string(zone.join("type")).ok()?.trim().to_ascii_lowercase();
+    let millidegrees: i64 = std::fs::read_to_string(zone.join("temp")).ok()?.trim().parse().ok()?;
+    Some((kind, millidegrees as f32 / 1000.0))
+}
@@ -0,0 +1,63 @@
+//! Injectable clocks
+//!
+//! Frame timing reads time through `TimeSource`, so tests can swap the wall clock for a
+//! `MockTimeSource` and step frames by exact amounts.
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Where frame timing reads the current time from
+pub trait TimeSource: Send + Sync {
+    /// Current time; only differences between readings are meaningful
+    fn now(&self) -> Instant;
+}
+
+/// The monotonic wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InstantTimeSource;
+
+impl TimeSource for InstantTimeSource {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock that only moves when told to; clones share the same time
+#[derive(Debug, Clone)]
+pub struct MockTimeSource {
+    start: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl Default for MockTimeSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockTimeSource {
+    /// Clock stopped at an arbitrary starting point
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock() += duration;
+    }
+
+    /// Time advanced since creation
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock()
+    }
+}
+
+impl TimeSource for MockTimeSource {
+    fn now(&self) -> Instant {
+        self.start + *self.elapsed.lock()
+    }
+}
@@ -0,0 +1,77 @@
+//! Tests for adaptive sync on variable-refresh displays
+//!
+//! **Feature: adaptive-sync, Property 1: Adaptive Sync Only Where The Surface Supports It**
+
+use bevy::window::PresentMode;
+use mindland_performance::{FrameLimiter, PresentCapabilities, SyncMode};
+
+fn capabilities(present_modes: &[PresentMode]) -> PresentCapabilities {
+    PresentCapabilities { present_modes: present_modes.to_vec() }
+}
+
+#[cfg(test)]
+mod adaptive_sync_tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_sync_falls_back_to_vsync_when_unsupported() {
+        // **Feature: adaptive-sync, Property 1: Adaptive Sync Only Where The Surface Supports It**
+
+        let fifo_only = capabilities(&[PresentMode::Fifo]);
+        assert!(!fifo_only.supports_adaptive_sync());
+        assert_eq!(SyncMode::AdaptiveSync.resolve(&fifo_only), PresentMode::AutoVsync);
+        assert_eq!(SyncMode::AdaptiveSync.resolve(&PresentCapabilities::default()), PresentMode::AutoVsync);
+
+        let mut limiter = FrameLimiter::new(Some(300.0), 144.0);
+        assert_eq!(limiter.set_sync_mode(SyncMode::AdaptiveSync, &fifo_only), PresentMode::AutoVsync);
+        assert!(!limiter.is_adaptive_sync());
+        assert_eq!(limiter.effective_cap(), None, "plain vsync already paces at the refresh rate");
+    }
+
+    #[test]
+    fn test_adaptive_sync_caps_at_refresh_rate_when_supported() {
+        // **Feature: adaptive-sync, Property 1: Adaptive Sync Only Where The Surface Supports It**
+
+        let relaxed = capabilities(&[PresentMode::Fifo, PresentMode::FifoRelaxed]);
+        assert!(relaxed.supports_adaptive_sync());
+
+        let mut limiter = FrameLimiter::new(Some(300.0), 144.0);
+        assert_eq!(limiter.set_sync_mode(SyncMode::AdaptiveSync, &relaxed), PresentMode::FifoRelaxed);
+        assert!(limiter.is_adaptive_sync());
+        assert_eq!(limiter.effective_cap(), Some(144.0));
+
+        // Lower targets still apply, and an uncapped target defaults to the refresh rate
+        limiter.target_fps = Some(90.0);
+        assert_eq!(limiter.effective_cap(), Some(90.0));
+        limiter.target_fps = None;
+        assert_eq!(limiter.effective_cap(), Some(144.0));
+    }
+
+    #[test]
+    fn test_runtime_switch_leaves_adaptive_sync() {
+        let relaxed = capabilities(&[PresentMode::FifoRelaxed]);
+        let mut limiter = FrameLimiter::new(None, 120.0);
+        limiter.set_sync_mode(SyncMode::AdaptiveSync, &relaxed);
+
+        // The window reporting the same mode keeps it on
+        limiter.set_present_mode(PresentMode::FifoRelaxed);
+        assert!(limiter.is_adaptive_sync());
+
+        assert_eq!(limiter.set_sync_mode(SyncMode::NoVsync, &relaxed), PresentMode::AutoNoVsync);
+        assert!(!limiter.is_adaptive_sync());
+        assert_eq!(limiter.effective_cap(), None);
+
+        limiter.set_sync_mode(SyncMode::AdaptiveSync, &relaxed);
+        limiter.set_present_mode(PresentMode::Immediate);
+        assert!(!limiter.is_adaptive_sync(), "switching the window's present mode directly turns it off");
+    }
+
+    #[test]
+    fn test_fixed_modes_resolve_to_auto_modes() {
+        let none = PresentCapabilities::default();
+        assert_eq!(SyncMode::Vsync.resolve(&none), PresentMode::AutoVsync);
+        assert_eq!(SyncMode::NoVsync.resolve(&none), PresentMode::AutoNoVsync);
+        assert!(none.supports(PresentMode::AutoVsync));
+        assert!(!none.supports(PresentMode::Mailbox));
+    }
+}
@@ -0,0 +1,128 @@
+//! Tests for the battery saver
+//!
+//! **Feature: battery-saver, Property 1: Caps Apply On Battery And Relax On AC**
+
+use mindland_performance::{BatterySaver, FrameLimiter, PowerSource, PowerSourceProvider, QualitySettings};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Power source the test can unplug and plug back in
+#[derive(Clone)]
+struct MockPower(Arc<Mutex<PowerSource>>);
+
+impl MockPower {
+    fn new(source: PowerSource) -> Self {
+        Self(Arc::new(Mutex::new(source)))
+    }
+
+    fn set(&self, source: PowerSource) {
+        *self.0.lock().unwrap() = source;
+    }
+}
+
+impl PowerSourceProvider for MockPower {
+    fn power_source(&self) -> PowerSource {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// Poll and apply once, as the app does each frame
+fn frame(saver: &mut BatterySaver, now: Instant, limiter: &mut FrameLimiter, quality: &mut QualitySettings) -> bool {
+    let changed = saver.poll(now);
+    saver.apply_to_limiter(limiter);
+    saver.apply_to_quality(quality);
+    changed
+}
+
+#[cfg(test)]
+mod battery_saver_tests {
+    use super::*;
+
+    #[test]
+    fn test_battery_ac_transition() {
+        // **Feature: battery-saver, Property 1: Caps Apply On Battery And Relax On AC**
+
+        let power = MockPower::new(PowerSource::Ac);
+        let mut saver = BatterySaver::new(Box::new(power.clone()));
+        let mut limiter = FrameLimiter::new(Some(60.0), 60.0);
+        let mut quality = QualitySettings::macbook_pro_2014_preset();
+        let start = Instant::now();
+        let interval = saver.poll_interval;
+
+        assert!(!frame(&mut saver, start, &mut limiter, &mut quality));
+        assert_eq!(saver.power_source(), PowerSource::Ac);
+        assert_eq!(limiter.target_fps, Some(60.0));
+
+        // Unplugged: caps apply at the next poll
+        power.set(PowerSource::Battery);
+        let on_battery = start + interval;
+        assert!(frame(&mut saver, on_battery, &mut limiter, &mut quality));
+        assert!(saver.is_active());
+        assert_eq!(limiter.target_fps, Some(30.0));
+        assert_eq!(limiter.effective_cap(), Some(30.0)); // Below refresh, so enforced under vsync
+        assert_eq!(quality.update_frequency, 30);
+
+        // Applying again every frame doesn't lose the saved settings
+        assert!(!frame(&mut saver, on_battery + Duration::from_millis(33), &mut limiter, &mut quality));
+        assert_eq!(limiter.target_fps, Some(30.0));
+
+        // Plugged back in: previous settings restored
+        power.set(PowerSource::Ac);
+        assert!(frame(&mut saver, on_battery + interval, &mut limiter, &mut quality));
+        assert!(!saver.is_active());
+        assert_eq!(limiter.target_fps, Some(60.0));
+        assert_eq!(quality.update_frequency, 60);
+    }
+
+    #[test]
+    fn test_power_source_polled_at_interval() {
+        // **Feature: battery-saver, Property 1: Caps Apply On Battery And Relax On AC**
+
+        let power = MockPower::new(PowerSource::Ac);
+        let mut saver = BatterySaver::new(Box::new(power.clone()));
+        let start = Instant::now();
+        saver.poll(start);
+
+        power.set(PowerSource::Battery);
+        let interval = saver.poll_interval;
+        assert!(!saver.poll(start + interval / 2));
+        assert_eq!(saver.power_source(), PowerSource::Ac);
+        assert!(saver.poll(start + interval));
+        assert_eq!(saver.power_source(), PowerSource::Battery);
+    }
+
+    #[test]
+    fn test_uncapped_limiter_gets_battery_cap_and_lower_cap_is_kept() {
+        let power = MockPower::new(PowerSource::Battery);
+        let mut saver = BatterySaver::new(Box::new(power));
+        let mut quality = QualitySettings::macbook_pro_2014_preset();
+        let now = Instant::now();
+
+        let mut uncapped = FrameLimiter::new(None, 60.0);
+        frame(&mut saver, now, &mut uncapped, &mut quality);
+        assert_eq!(uncapped.target_fps, Some(30.0));
+
+        let mut saver = BatterySaver::new(Box::new(MockPower::new(PowerSource::Battery)));
+        let mut menu = FrameLimiter::new(Some(20.0), 60.0);
+        frame(&mut saver, now, &mut menu, &mut quality);
+        assert_eq!(menu.target_fps, Some(20.0));
+    }
+
+    #[test]
+    fn test_disabled_or_unknown_source_never_caps() {
+        let mut limiter = FrameLimiter::new(Some(60.0), 60.0);
+        let mut quality = QualitySettings::macbook_pro_2014_preset();
+        let now = Instant::now();
+
+        let mut unknown = BatterySaver::new(Box::new(MockPower::new(PowerSource::Unknown)));
+        frame(&mut unknown, now, &mut limiter, &mut quality);
+        assert!(!unknown.is_active());
+
+        let mut disabled = BatterySaver::new(Box::new(MockPower::new(PowerSource::Battery)));
+        disabled.enabled = false;
+        frame(&mut disabled, now, &mut limiter, &mut quality);
+        assert!(!disabled.is_active());
+        assert_eq!(limiter.target_fps, Some(60.0));
+        assert_eq!(quality.update_frequency, 60);
+    }
+}
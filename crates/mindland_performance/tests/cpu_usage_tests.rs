@@ -0,0 +1,62 @@
+//! Tests for process CPU usage sampling
+//!
+//! **Feature: cpu-usage, Property 1: Busy Frames Report More CPU Usage Than Idle Frames**
+
+use mindland_performance::{CpuSampler, PerformanceMonitor};
+use std::time::{Duration, Instant};
+
+/// Keep this thread busy for `duration`
+fn spin(duration: Duration) {
+    let start = Instant::now();
+    let mut value = 0_u64;
+    while start.elapsed() < duration {
+        value = std::hint::black_box(value.wrapping_mul(6364136223846793005).wrapping_add(1));
+    }
+}
+
+/// Run one monitored frame doing `work`, returning its recorded CPU usage
+fn frame(monitor: &mut PerformanceMonitor, work: impl FnOnce()) -> f32 {
+    monitor.start_frame();
+    work();
+    monitor.end_frame();
+    monitor.performance_history.read().back().unwrap().cpu_usage
+}
+
+#[cfg(test)]
+mod cpu_usage_tests {
+    use super::*;
+
+    #[test]
+    fn test_busy_frame_reports_more_than_idle() {
+        // **Feature: cpu-usage, Property 1: Busy Frames Report More CPU Usage Than Idle Frames**
+
+        let mut monitor = PerformanceMonitor::new();
+        if !monitor.cpu_sampler.is_supported() {
+            assert!(monitor.get_cpu_usage().is_nan(), "unsupported platforms report NaN, not a guess");
+            return;
+        }
+
+        spin(Duration::from_millis(30)); // sysinfo only measures from a non-zero starting CPU time
+        assert!(frame(&mut monitor, || {}).is_nan(), "no interval to average over yet");
+        let idle = frame(&mut monitor, || std::thread::sleep(Duration::from_millis(250)));
+        let busy = frame(&mut monitor, || spin(Duration::from_millis(250)));
+
+        assert!((0.0..=100.0).contains(&idle) && (0.0..=100.0).contains(&busy), "idle {idle}, busy {busy}");
+        assert!(busy > idle, "busy {busy} should exceed idle {idle}");
+    }
+
+    #[test]
+    fn test_samples_stay_in_range() {
+        let mut sampler = CpuSampler::new();
+        if !sampler.is_supported() {
+            return;
+        }
+        spin(Duration::from_millis(30));
+        sampler.sample();
+        for _ in 0..5 {
+            spin(Duration::from_millis(20));
+            let usage = sampler.sample();
+            assert!((0.0..=100.0).contains(&usage), "{usage}");
+        }
+    }
+}
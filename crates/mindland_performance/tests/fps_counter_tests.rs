@@ -0,0 +1,45 @@
+//! Tests for FPS counting and display smoothing
+//!
+//! **Feature: fps-display, Property 1: Smoothed Display FPS**
+
+use mindland_performance::FpsCounter;
+use std::time::Duration;
+
+#[cfg(test)]
+mod fps_counter_tests {
+    use super::*;
+
+    #[test]
+    fn test_single_spike_barely_moves_display_fps() {
+        // **Feature: fps-display, Property 1: Smoothed Display FPS**
+        // A one-frame hitch shows fully in current_fps but barely in display_fps
+
+        let mut counter = FpsCounter::new(60.0);
+        for _ in 0..120 {
+            counter.update(Duration::from_micros(16_667));
+        }
+        let steady_display = counter.display_fps;
+        assert!((steady_display - 60.0).abs() < 0.5);
+
+        counter.update(Duration::from_millis(100)); // 10 FPS spike
+
+        assert!((counter.current_fps - 10.0).abs() < 0.01);
+        assert!(
+            steady_display - counter.display_fps < steady_display * 0.1,
+            "display_fps moved from {:.1} to {:.1} on a single spike",
+            steady_display,
+            counter.display_fps
+        );
+    }
+
+    #[test]
+    fn test_smoothing_of_one_tracks_current_fps() {
+        let mut counter = FpsCounter::new(60.0);
+        counter.set_display_smoothing(5.0); // Clamped to 1.0
+        assert_eq!(counter.display_smoothing, 1.0);
+
+        counter.update(Duration::from_micros(16_667));
+        counter.update(Duration::from_millis(50));
+        assert_eq!(counter.display_fps, counter.current_fps);
+    }
+}
@@ -0,0 +1,63 @@
+//! Tests for the vsync-aware frame limiter
+//!
+//! **Feature: frame-limiter, Property 1: Limiter Never Fights Vsync**
+
+use bevy::window::PresentMode;
+use mindland_performance::FrameLimiter;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod frame_limiter_tests {
+    use super::*;
+
+    #[test]
+    fn test_vsync_below_refresh_rate_still_enforces_cap() {
+        // **Feature: frame-limiter, Property 1: Limiter Never Fights Vsync**
+        // AutoVsync at 60Hz cannot reach a 30 FPS cap on its own
+
+        let mut limiter = FrameLimiter::new(Some(30.0), 60.0);
+        limiter.set_present_mode(PresentMode::AutoVsync);
+        assert_eq!(limiter.effective_cap(), Some(30.0));
+
+        let start = Instant::now();
+        for _ in 0..7 {
+            limiter.wait();
+        }
+        // First frame only sets the phase; the next six are each a full 1/30s apart
+        assert!(start.elapsed() >= Duration::from_secs_f32(6.0 / 30.0));
+    }
+
+    #[test]
+    fn test_vsync_backs_off_at_or_above_refresh_rate() {
+        // **Feature: frame-limiter, Property 1: Limiter Never Fights Vsync**
+
+        let mut limiter = FrameLimiter::new(Some(60.0), 60.0);
+        for mode in [PresentMode::AutoVsync, PresentMode::Fifo, PresentMode::FifoRelaxed] {
+            limiter.set_present_mode(mode);
+            assert_eq!(limiter.effective_cap(), None, "{mode:?}");
+            assert_eq!(limiter.wait(), Duration::ZERO);
+        }
+
+        limiter.target_fps = Some(144.0);
+        assert_eq!(limiter.effective_cap(), None);
+    }
+
+    #[test]
+    fn test_non_vsync_modes_enforce_cap_above_refresh_rate() {
+        // **Feature: frame-limiter, Property 1: Limiter Never Fights Vsync**
+
+        let mut limiter = FrameLimiter::new(Some(144.0), 60.0);
+        for mode in [PresentMode::Mailbox, PresentMode::Immediate, PresentMode::AutoNoVsync] {
+            limiter.set_present_mode(mode);
+            assert_eq!(limiter.effective_cap(), Some(144.0), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn test_uncapped_limiter_never_waits() {
+        let mut limiter = FrameLimiter::new(None, 60.0);
+        limiter.set_present_mode(PresentMode::Immediate);
+        assert_eq!(limiter.frame_budget(), None);
+        assert_eq!(limiter.wait(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,70 @@
+//! Tests for frame-time histograms
+//!
+//! **Feature: frame-time-histogram, Property 1: Every Frame Lands In Exactly One Bucket**
+
+use mindland_performance::{FrameTimeHistogram, PerformanceFrame, PerformanceMonitor, DEFAULT_FRAME_TIME_BUCKETS};
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn counts(histogram: &FrameTimeHistogram) -> Vec<usize> {
+    histogram.buckets.iter().map(|bucket| bucket.count).collect()
+}
+
+#[cfg(test)]
+mod frame_time_histogram_tests {
+    use super::*;
+
+    #[test]
+    fn test_known_distribution_bucket_counts() {
+        // **Feature: frame-time-histogram, Property 1: Every Frame Lands In Exactly One Bucket**
+        // 90 smooth frames plus a tail of slow ones: the average hides what the buckets show
+
+        let frame_times = std::iter::repeat_n(ms(7), 90)
+            .chain([ms(8), ms(11), ms(12), ms(15), ms(16), ms(19), ms(20), ms(33), ms(50), ms(100)]);
+        let histogram = FrameTimeHistogram::from_frame_times(&DEFAULT_FRAME_TIME_BUCKETS, frame_times);
+
+        // Lower bounds are inclusive: 8ms falls in 8-12, 20ms in >=20
+        assert_eq!(counts(&histogram), vec![90, 2, 2, 2, 4]);
+        assert_eq!(histogram.total(), 100);
+        assert_eq!(histogram.buckets[0].min, Duration::ZERO);
+        assert_eq!(histogram.buckets[1].max, Some(ms(12)));
+        assert_eq!(histogram.buckets[4].max, None);
+    }
+
+    #[test]
+    fn test_custom_bounds_are_sorted_and_deduplicated() {
+        // **Feature: frame-time-histogram, Property 1: Every Frame Lands In Exactly One Bucket**
+
+        let histogram = FrameTimeHistogram::from_frame_times(&[ms(33), ms(16), ms(33)], [ms(1), ms(16), ms(20), ms(40)]);
+        assert_eq!(histogram.buckets.len(), 3);
+        assert_eq!(counts(&histogram), vec![1, 2, 1]);
+
+        let single = FrameTimeHistogram::from_frame_times(&[], [ms(1), ms(500)]);
+        assert_eq!(counts(&single), vec![2]);
+    }
+
+    #[test]
+    fn test_monitor_histogram_uses_performance_history() {
+        let monitor = PerformanceMonitor::new();
+        {
+            let mut history = monitor.performance_history.write();
+            for frame_time in [ms(5), ms(14), ms(14), ms(25)] {
+                history.push_back(PerformanceFrame {
+                    timestamp: Duration::ZERO,
+                    frame_time,
+                    cpu_usage: 0.0,
+                    gpu_usage: 0.0,
+                    memory_usage: 0,
+                    temperature: 0.0,
+                    fps: 0.0,
+                });
+            }
+        }
+
+        let histogram = monitor.frame_time_histogram(&DEFAULT_FRAME_TIME_BUCKETS);
+        assert_eq!(counts(&histogram), vec![1, 0, 2, 0, 1]);
+    }
+}
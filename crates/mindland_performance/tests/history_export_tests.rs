@@ -0,0 +1,56 @@
+//! Tests for exporting the performance history
+//!
+//! **Feature: history-export, Property 1: Every Recorded Frame Is Exported In Order**
+
+use mindland_performance::{MockTimeSource, PerformanceMonitor, HISTORY_CSV_HEADER};
+use std::time::Duration;
+
+/// A monitor that recorded `frames` frames of 10ms, 20ms, 30ms, ...
+fn recorded(frames: u64) -> PerformanceMonitor {
+    let clock = MockTimeSource::new();
+    let mut monitor = PerformanceMonitor::with_time_source(clock.clone());
+    for frame in 1..=frames {
+        monitor.start_frame();
+        clock.advance(Duration::from_millis(10 * frame));
+        monitor.end_frame();
+    }
+    monitor
+}
+
+#[cfg(test)]
+mod history_export_tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_has_header_and_one_parseable_row_per_frame() {
+        // **Feature: history-export, Property 1: Every Recorded Frame Is Exported In Order**
+
+        let csv = recorded(5).export_history_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], HISTORY_CSV_HEADER);
+
+        let columns = HISTORY_CSV_HEADER.split(',').count();
+        for (i, line) in lines[1..].iter().enumerate() {
+            let values: Vec<f64> = line.split(',').map(|value| value.parse().unwrap()).collect();
+            assert_eq!(values.len(), columns, "row {i}: {line}");
+            assert!((values[1] - 10.0 * (i + 1) as f64).abs() < 1e-6, "frame_time_ms in order: {line}");
+        }
+    }
+
+    #[test]
+    fn test_json_is_an_array_in_chronological_order() {
+        let json = recorded(3).export_history_json();
+        let frames: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(frames.len(), 3);
+        let frame_times: Vec<u64> = frames.iter().map(|frame| frame["frame_time"]["nanos"].as_u64().unwrap()).collect();
+        assert_eq!(frame_times, [10_000_000, 20_000_000, 30_000_000]);
+    }
+
+    #[test]
+    fn test_empty_history_exports_header_only() {
+        let monitor = PerformanceMonitor::new();
+        assert_eq!(monitor.export_history_csv(), format!("{HISTORY_CSV_HEADER}\n"));
+        assert_eq!(monitor.export_history_json(), "[]");
+    }
+}
@@ -0,0 +1,62 @@
+//! Tests for percentile-low frame rates
+//!
+//! **Feature: percentile-lows, Property 1: Lows Report The FPS Of The Worst Recent Frames**
+
+use mindland_performance::{FpsCounter, DEFAULT_FPS_WINDOW};
+use std::time::Duration;
+
+/// 1000 frames: 990 at 10ms (100 FPS), 9 at 20ms (50 FPS) and one 100ms hitch (10 FPS), interleaved
+fn crafted_frame_times() -> Vec<Duration> {
+    (0..1000)
+        .map(|i| match i {
+            500 => Duration::from_millis(100),
+            i if i % 100 == 50 => Duration::from_millis(20),
+            _ => Duration::from_millis(10),
+        })
+        .collect()
+}
+
+fn record(counter: &mut FpsCounter, frame_times: impl IntoIterator<Item = Duration>) {
+    for frame_time in frame_times {
+        counter.update(frame_time);
+    }
+}
+
+#[cfg(test)]
+mod percentile_low_tests {
+    use super::*;
+
+    #[test]
+    fn test_lows_of_crafted_distribution() {
+        // **Feature: percentile-lows, Property 1: Lows Report The FPS Of The Worst Recent Frames**
+
+        let mut counter = FpsCounter::new(60.0);
+        assert_eq!(counter.window(), DEFAULT_FPS_WINDOW);
+        record(&mut counter, crafted_frame_times());
+
+        assert!((counter.one_percent_low() - 50.0).abs() < 1e-3, "1% low {}", counter.one_percent_low());
+        assert!((counter.point_one_percent_low() - 10.0).abs() < 1e-3);
+        assert!((counter.percentile_low(50.0) - 100.0).abs() < 1e-3, "the median frame");
+        assert!((counter.percentile_low(100.0) - 100.0).abs() < 1e-3, "the fastest frame");
+    }
+
+    #[test]
+    fn test_window_keeps_only_recent_frames() {
+        let mut counter = FpsCounter::with_window(60.0, 100);
+        record(&mut counter, crafted_frame_times()); // The hitch is 500 frames old by the end
+        record(&mut counter, std::iter::repeat_n(Duration::from_millis(10), 100));
+
+        assert!((counter.point_one_percent_low() - 100.0).abs() < 1e-3);
+        assert_eq!(counter.min_fps, 10.0, "min_fps still covers the whole run");
+    }
+
+    #[test]
+    fn test_few_samples_use_the_slowest_frame() {
+        let mut counter = FpsCounter::new(60.0);
+        assert_eq!(counter.one_percent_low(), 0.0, "no frames yet");
+
+        record(&mut counter, [Duration::from_millis(10), Duration::from_millis(25), Duration::from_millis(16)]);
+        assert!((counter.one_percent_low() - 40.0).abs() < 1e-3);
+        assert!((counter.point_one_percent_low() - 40.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,88 @@
+//! Tests for automatic quality adaptation
+//!
+//! **Feature: dynamic-resolution, Property 2: Render Scale Drops First**
+
+use mindland_performance::{
+    AdaptationStrategy, AutoOptimizer, HardwareDetector, QualitySettings, ShadowQuality, TextureQuality, ThermalState,
+    MAX_LOD_BIAS, MIN_RENDER_SCALE,
+};
+
+#[cfg(test)]
+mod quality_settings_tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_bound_lowers_render_scale_before_other_quality() {
+        // **Feature: dynamic-resolution, Property 2: Render Scale Drops First**
+
+        let mut settings = QualitySettings::macbook_pro_2014_preset();
+        let shadows = settings.shadow_quality;
+        let textures = settings.texture_quality;
+
+        for _ in 0..5 {
+            settings.reduce_gpu_load(0.1);
+        }
+
+        assert!((settings.render_scale - 0.5).abs() < 1e-5);
+        assert_eq!(settings.shadow_quality, shadows);
+        assert_eq!(settings.texture_quality, textures);
+
+        // Once resolution bottoms out, other settings start dropping
+        settings.reduce_gpu_load(0.1);
+        assert_eq!(settings.shadow_quality, ShadowQuality::Off);
+        settings.reduce_gpu_load(0.1);
+        assert_eq!(settings.texture_quality, TextureQuality::Low);
+        assert!((settings.render_scale - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_uneven_steps_land_exactly_on_the_floor() {
+        // **Feature: dynamic-resolution, Property 2: Render Scale Drops First**
+        // Accumulated step rounding must not cost an extra step or leave the scale just above the floor
+
+        let mut settings = QualitySettings::macbook_pro_2014_preset();
+        let shadows = settings.shadow_quality;
+        for _ in 0..10 {
+            settings.reduce_gpu_load(0.05);
+        }
+        assert_eq!(settings.render_scale, MIN_RENDER_SCALE);
+        assert_eq!(settings.shadow_quality, shadows);
+
+        settings.reduce_gpu_load(0.05);
+        assert_eq!(settings.shadow_quality, ShadowQuality::Off);
+    }
+
+    #[test]
+    fn test_render_scale_is_clamped() {
+        let mut settings = QualitySettings::macbook_pro_2014_preset();
+        settings.set_render_scale(0.2);
+        assert_eq!(settings.render_scale, 0.5);
+        settings.set_render_scale(1.5);
+        assert_eq!(settings.render_scale, 1.0);
+    }
+
+    #[test]
+    fn test_lod_bias_rises_under_stress_and_recovers() {
+        let mut optimizer = AutoOptimizer::new(
+            HardwareDetector::detect(),
+            QualitySettings::macbook_pro_2014_preset(),
+            AdaptationStrategy::Aggressive,
+        );
+        assert_eq!(optimizer.quality_settings.lod_bias, 0.0);
+
+        optimizer.adapt_lod_bias(ThermalState::Hot, 60.0, 60.0);
+        let throttled = optimizer.quality_settings.lod_bias;
+        optimizer.adapt_lod_bias(ThermalState::Cool, 40.0, 60.0);
+        assert!(throttled > 0.0 && optimizer.quality_settings.lod_bias > throttled);
+
+        for _ in 0..100 {
+            optimizer.adapt_lod_bias(ThermalState::Critical, 10.0, 60.0);
+        }
+        assert_eq!(optimizer.quality_settings.lod_bias, MAX_LOD_BIAS);
+
+        for _ in 0..100 {
+            optimizer.adapt_lod_bias(ThermalState::Cool, 60.0, 60.0);
+        }
+        assert_eq!(optimizer.quality_settings.lod_bias, 0.0);
+    }
+}
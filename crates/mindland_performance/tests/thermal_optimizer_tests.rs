@@ -0,0 +1,59 @@
+//! Tests for thermal quality throttling in the AutoOptimizer
+//!
+//! **Feature: thermal-optimizer, Property 1: Heating Lowers Quality And Cooling Restores It Exactly**
+
+use mindland_performance::{
+    AdaptationStrategy, AutoOptimizer, HardwareDetector, QualitySettings, TextureQuality, ThermalState,
+};
+
+fn optimizer(strategy: AdaptationStrategy) -> AutoOptimizer {
+    AutoOptimizer::new(HardwareDetector::detect(), QualitySettings::macbook_pro_2014_preset(), strategy)
+}
+
+#[cfg(test)]
+mod thermal_optimizer_tests {
+    use super::*;
+
+    #[test]
+    fn test_hot_reduces_and_cool_restores() {
+        // **Feature: thermal-optimizer, Property 1: Heating Lowers Quality And Cooling Restores It Exactly**
+
+        let baseline = QualitySettings::macbook_pro_2014_preset();
+        let mut optimizer = optimizer(AdaptationStrategy::Conservative);
+        assert_eq!(optimizer.step(ThermalState::Cool), None, "no change, no new settings");
+
+        let hot = optimizer.step(ThermalState::Hot).expect("heating changes settings");
+        assert!(hot.render_distance < baseline.render_distance);
+        assert_eq!(hot.texture_quality, TextureQuality::Low);
+        assert!(optimizer.is_throttled());
+        assert_eq!(optimizer.step(ThermalState::Hot), None);
+
+        let cool = optimizer.step(ThermalState::Cool).expect("cooling changes settings");
+        assert_eq!(cool, baseline);
+        assert_eq!(optimizer.quality_settings, baseline);
+        assert!(!optimizer.is_throttled());
+    }
+
+    #[test]
+    fn test_levels_derive_from_baseline_without_compounding() {
+        let baseline = QualitySettings::macbook_pro_2014_preset();
+        let mut optimizer = optimizer(AdaptationStrategy::Conservative);
+        let first_hot = optimizer.step(ThermalState::Hot).unwrap();
+        let critical = optimizer.step(ThermalState::Critical).unwrap();
+        assert_eq!(critical.render_scale, 0.5);
+        assert_eq!(optimizer.step(ThermalState::Hot).unwrap(), first_hot, "back to the same Hot settings");
+        assert_eq!(optimizer.step(ThermalState::Warm).unwrap(), baseline, "Conservative leaves Warm alone");
+    }
+
+    #[test]
+    fn test_strategy_sets_when_throttling_starts() {
+        let baseline = QualitySettings::macbook_pro_2014_preset();
+
+        let warm = optimizer(AdaptationStrategy::Aggressive).step(ThermalState::Warm).unwrap();
+        assert!(warm.render_scale < baseline.render_scale);
+        assert_eq!(warm.render_distance, baseline.render_distance);
+
+        let warm = optimizer(AdaptationStrategy::Emergency).step(ThermalState::Warm).unwrap();
+        assert!(warm.render_distance < baseline.render_distance);
+    }
+}
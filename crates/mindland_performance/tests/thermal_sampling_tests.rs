@@ -0,0 +1,71 @@
+//! Tests for rate-limited thermal sampling
+//!
+//! **Feature: thermal-sampling, Property 1: Hardware Is Read At The Configured Cadence, Not Per Frame**
+
+use mindland_performance::{MockTimeSource, PerformanceMonitor, ThermalMonitor, ThermalState};
+use std::time::Duration;
+
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Call `sample_thermals` once per frame for `frames` frames
+fn run_frames(monitor: &mut PerformanceMonitor, clock: &MockTimeSource, frames: u32) {
+    for _ in 0..frames {
+        monitor.sample_thermals();
+        clock.advance(FRAME);
+    }
+}
+
+#[cfg(test)]
+mod thermal_sampling_tests {
+    use super::*;
+
+    #[test]
+    fn test_sampler_runs_at_interval_not_every_frame() {
+        // **Feature: thermal-sampling, Property 1: Hardware Is Read At The Configured Cadence, Not Per Frame**
+
+        let clock = MockTimeSource::new();
+        let mut monitor = PerformanceMonitor::with_time_source(clock.clone());
+        assert_eq!(monitor.thermal_monitor.sample_interval, Duration::from_millis(500));
+
+        run_frames(&mut monitor, &clock, 125); // 2 seconds at 16ms
+
+        // Reads at 0, 512, 1024 and 1536ms
+        assert_eq!(monitor.thermal_monitor.samples_taken, 4);
+    }
+
+    #[test]
+    fn test_interval_is_configurable() {
+        // **Feature: thermal-sampling, Property 1: Hardware Is Read At The Configured Cadence, Not Per Frame**
+
+        let clock = MockTimeSource::new();
+        let mut monitor = PerformanceMonitor::with_time_source(clock.clone());
+        monitor.set_thermal_sample_interval(Duration::from_millis(100));
+
+        run_frames(&mut monitor, &clock, 63); // ~1 second
+
+        // Every 7th frame (112ms) crosses the interval
+        assert_eq!(monitor.thermal_monitor.samples_taken, 9);
+    }
+
+    #[test]
+    fn test_state_held_between_samples() {
+        let clock = MockTimeSource::new();
+        let mut monitor = PerformanceMonitor::with_time_source(clock.clone());
+        monitor.thermal_monitor = ThermalMonitor::with_thermal_zone_root("/nonexistent/thermal"); // Keep the values set below
+        monitor.targets.max_fan_speed = u32::MAX;
+        monitor.thermal_monitor.cpu_temp = 80.0;
+        monitor.sample_thermals();
+        assert_eq!(monitor.thermal_monitor.thermal_state, ThermalState::Hot);
+
+        // A change not yet sampled doesn't reach the state until the next read
+        monitor.thermal_monitor.cpu_temp = 50.0;
+        clock.advance(Duration::from_millis(200));
+        monitor.sample_thermals();
+        assert_eq!(monitor.thermal_monitor.thermal_state, ThermalState::Hot);
+
+        clock.advance(Duration::from_millis(300));
+        monitor.sample_thermals();
+        assert_eq!(monitor.thermal_monitor.thermal_state, ThermalState::Cool);
+        assert_eq!(monitor.thermal_monitor.samples_taken, 2);
+    }
+}
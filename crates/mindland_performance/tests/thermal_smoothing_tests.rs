@@ -0,0 +1,90 @@
+//! Tests for low-pass filtering of thermal sensor readings
+//!
+//! **Feature: thermal-smoothing, Property 1: One-Sample Spikes Don't Change The Thermal State**
+
+use mindland_performance::{PerformanceMonitor, ThermalReading, ThermalState};
+use std::time::Duration;
+
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+fn cpu_reading(cpu_temp: f32) -> ThermalReading {
+    ThermalReading { cpu_temp: Some(cpu_temp), ..Default::default() }
+}
+
+/// A warm CPU around 62°C with ±1.5°C of sensor jitter
+fn noisy_warm_signal(samples: usize) -> impl Iterator<Item = f32> {
+    (0..samples).map(|i| 62.0 + [1.5, -1.0, 0.5, -1.5, 1.0][i % 5])
+}
+
+#[cfg(test)]
+mod thermal_smoothing_tests {
+    use super::*;
+
+    #[test]
+    fn test_single_spike_does_not_reach_hot() {
+        // **Feature: thermal-smoothing, Property 1: One-Sample Spikes Don't Change The Thermal State**
+
+        let mut monitor = PerformanceMonitor::new();
+        let thermal = &mut monitor.thermal_monitor;
+        thermal.apply_reading(cpu_reading(62.0), Duration::MAX);
+
+        for (i, cpu_temp) in noisy_warm_signal(40).enumerate() {
+            let cpu_temp = if i == 20 { 95.0 } else { cpu_temp }; // One spiky SMC read
+            thermal.apply_reading(cpu_reading(cpu_temp), SAMPLE_INTERVAL);
+
+            assert_eq!(thermal.thermal_state, ThermalState::Warm, "sample {i}: filtered {}", thermal.cpu_temp);
+        }
+        assert!((thermal.cpu_temp - 62.0).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_sustained_heat_still_escalates() {
+        // **Feature: thermal-smoothing, Property 1: One-Sample Spikes Don't Change The Thermal State**
+
+        let mut monitor = PerformanceMonitor::new();
+        let thermal = &mut monitor.thermal_monitor;
+        thermal.apply_reading(cpu_reading(62.0), Duration::MAX);
+
+        let mut samples_to_hot = None;
+        for i in 1..=30 {
+            thermal.apply_reading(cpu_reading(90.0), SAMPLE_INTERVAL);
+            if matches!(thermal.thermal_state, ThermalState::Hot | ThermalState::Critical) {
+                samples_to_hot = Some(i);
+                break;
+            }
+        }
+        assert!(matches!(samples_to_hot, Some(2..=10)), "reached Hot after {samples_to_hot:?} samples");
+    }
+
+    #[test]
+    fn test_zero_time_constant_uses_raw_values() {
+        let mut monitor = PerformanceMonitor::new();
+        let thermal = &mut monitor.thermal_monitor;
+        thermal.smoothing_time_constant = Duration::ZERO;
+
+        let reading = ThermalReading { cpu_temp: Some(88.0), gpu_temp: Some(70.0), fan_speed: Some(3400.0) };
+        thermal.apply_reading(reading, SAMPLE_INTERVAL);
+
+        assert_eq!(thermal.cpu_temp, 88.0);
+        assert_eq!(thermal.gpu_temp, 70.0);
+        assert_eq!(thermal.fan_speed, 3400);
+        assert_eq!(thermal.thermal_state, ThermalState::Critical);
+        assert!(thermal.sensors_available);
+    }
+
+    #[test]
+    fn test_filter_is_independent_of_sample_rate() {
+        let mut once = PerformanceMonitor::new().thermal_monitor;
+        let mut four_times = PerformanceMonitor::new().thermal_monitor;
+        let reading = ThermalReading { gpu_temp: Some(80.0), fan_speed: Some(2200.0), ..Default::default() };
+
+        once.apply_reading(reading, Duration::from_secs(2));
+        for _ in 0..4 {
+            four_times.apply_reading(reading, Duration::from_millis(500));
+        }
+
+        assert!((once.gpu_temp - four_times.gpu_temp).abs() < 1e-3);
+        assert!(once.fan_speed.abs_diff(four_times.fan_speed) <= 2);
+        assert!(once.cpu_temp.is_nan(), "unread sensors stay unknown");
+    }
+}
@@ -0,0 +1,64 @@
+//! Tests for reading temperatures from Linux thermal zones
+//!
+//! **Feature: thermal-zones, Property 1: Sampling Reports The Hottest CPU Zone, Or NaN Without Sensors**
+
+use mindland_performance::{ThermalMonitor, ThermalState};
+use std::path::PathBuf;
+
+/// A fake `/sys/class/thermal` holding `zones` as (type, millidegrees)
+fn fixture(name: &str, zones: &[(&str, &str)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("mindland-thermal-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    for (i, (kind, millidegrees)) in zones.iter().enumerate() {
+        let zone = root.join(format!("thermal_zone{i}"));
+        std::fs::create_dir_all(&zone).unwrap();
+        std::fs::write(zone.join("type"), format!("{kind}\n")).unwrap();
+        std::fs::write(zone.join("temp"), format!("{millidegrees}\n")).unwrap();
+    }
+    std::fs::create_dir_all(root.join("cooling_device0")).unwrap(); // Not a zone; ignored
+    root
+}
+
+#[cfg(test)]
+mod thermal_zone_tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_reads_cpu_and_gpu_zones() {
+        // **Feature: thermal-zones, Property 1: Sampling Reports The Hottest CPU Zone, Or NaN Without Sensors**
+
+        let root = fixture("hot", &[("acpitz", "95000"), ("x86_pkg_temp", "78500"), ("cpu-thermal", "77000"), ("gpu-thermal", "66000")]);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample();
+
+        assert_eq!(thermal.cpu_temp, 78.5, "CPU zones win over the motherboard");
+        assert_eq!(thermal.gpu_temp, 66.0);
+        assert_eq!(thermal.thermal_state, ThermalState::Hot);
+        assert!(thermal.sensors_available);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_falls_back_to_other_zones() {
+        let root = fixture("acpi", &[("acpitz", "52000"), ("gpu-thermal", "90000"), ("broken", "n/a")]);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample();
+
+        assert_eq!(thermal.cpu_temp, 52.0, "a GPU zone is never taken for the CPU");
+        assert_eq!(thermal.thermal_state, ThermalState::Cool);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_missing_sensors_read_nan_and_stay_cool() {
+        let root = fixture("empty", &[]);
+        let mut thermal = ThermalMonitor::with_thermal_zone_root(&root);
+        thermal.sample();
+
+        assert!(thermal.cpu_temp.is_nan());
+        assert!(thermal.gpu_temp.is_nan());
+        assert_eq!(thermal.thermal_state, ThermalState::Cool);
+        assert!(!thermal.sensors_available);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+//! Tests for injectable frame clocks
+//!
+//! **Feature: time-source, Property 1: Mock Time Is Reported Exactly**
+
+use mindland_performance::{HighPrecisionTimer, MockTimeSource, PerformanceMonitor, TimeSource};
+use std::time::Duration;
+
+#[cfg(test)]
+mod time_source_tests {
+    use super::*;
+
+    #[test]
+    fn test_end_frame_reports_mock_advance_exactly() {
+        // **Feature: time-source, Property 1: Mock Time Is Reported Exactly**
+
+        let clock = MockTimeSource::new();
+        let mut timer = HighPrecisionTimer::with_time_source(clock.clone());
+
+        for micros in [16_667, 8_333, 33_333] {
+            timer.start_frame();
+            clock.advance(Duration::from_micros(micros));
+            assert_eq!(timer.end_frame(), Duration::from_micros(micros));
+        }
+        assert_eq!(timer.accumulated_time, Duration::from_micros(58_333));
+        assert_eq!(timer.frame_count, 3);
+    }
+
+    #[test]
+    fn test_monitor_records_mock_frame_times() {
+        // **Feature: time-source, Property 1: Mock Time Is Reported Exactly**
+
+        let clock = MockTimeSource::new();
+        let mut monitor = PerformanceMonitor::with_time_source(clock.clone());
+
+        monitor.start_frame();
+        clock.advance(Duration::from_millis(20));
+        monitor.end_frame();
+
+        let history = monitor.performance_history.read();
+        assert_eq!(history.back().unwrap().frame_time, Duration::from_millis(20));
+        assert!((monitor.fps_counter.current_fps - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_mock_clock_stands_still_until_advanced() {
+        let clock = MockTimeSource::new();
+        let before = clock.now();
+        assert_eq!(clock.now(), before);
+
+        clock.clone().advance(Duration::from_secs(2)); // Clones share time
+        assert_eq!(clock.now() - before, Duration::from_secs(2));
+        assert_eq!(clock.elapsed(), Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,112 @@
+//! Optional GPU features reported by the adapter
+//!
+//! Optional render paths (GPU culling and particles, indirect draw, HDR) only run when the adapter supports
+//! them; `UltraRenderer::apply_capabilities` switches the unsupported ones off instead of
+//! letting pipeline creation fail later.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use wgpu::{DownlevelCapabilities, DownlevelFlags, Features, Limits, TextureFormatFeatures, TextureUsages};
+
+/// Sample counts checked for MSAA support
+const MSAA_SAMPLE_COUNTS: [u32; 5] = [1, 2, 4, 8, 16];
+
+/// Optional features supported by the chosen adapter
+///
+/// The default is a conservative baseline (no optional features), used until an adapter reports.
+#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+pub struct GpuCapabilities {
+    pub adapter_name: String,
+    pub compute_shaders: bool,
+    pub timestamp_queries: bool,
+    pub indirect_draw: bool,
+    pub bc_compression: bool,   // BC1-7 (desktop)
+    pub etc2_compression: bool, // ETC2/EAC (mobile, some integrated GPUs)
+    pub astc_compression: bool, // ASTC LDR (mobile, Apple silicon)
+    pub hdr_render_target: bool, // Rgba16Float is renderable and blendable
+    pub max_texture_size: u32,
+    pub msaa_sample_counts: Vec<u32>, // For the swapchain format, always includes 1
+}
+
+impl Default for GpuCapabilities {
+    fn default() -> Self {
+        Self {
+            adapter_name: String::new(),
+            compute_shaders: false,
+            timestamp_queries: false,
+            indirect_draw: false,
+            bc_compression: false,
+            etc2_compression: false,
+            astc_compression: false,
+            hdr_render_target: false,
+            max_texture_size: Limits::downlevel_webgl2_defaults().max_texture_dimension_2d,
+            msaa_sample_counts: vec![1],
+        }
+    }
+}
+
+impl GpuCapabilities {
+    /// Build the report from what an adapter exposes
+    ///
+    /// Pass `adapter.features()`, `adapter.limits()`, `adapter.get_downlevel_capabilities()` and
+    /// `|format| adapter.get_texture_format_features(format)`.
+    pub fn from_adapter(
+        adapter_name: impl Into<String>,
+        features: Features,
+        limits: &Limits,
+        downlevel: &DownlevelCapabilities,
+        format_features: impl Fn(TextureFormat) -> TextureFormatFeatures,
+    ) -> Self {
+        let hdr = format_features(TextureFormat::Rgba16Float);
+        let swapchain = format_features(TextureFormat::Bgra8UnormSrgb).flags;
+
+        Self {
+            adapter_name: adapter_name.into(),
+            compute_shaders: downlevel.flags.contains(DownlevelFlags::COMPUTE_SHADERS),
+            timestamp_queries: features.contains(Features::TIMESTAMP_QUERY),
+            indirect_draw: downlevel.flags.contains(DownlevelFlags::INDIRECT_EXECUTION),
+            bc_compression: features.contains(Features::TEXTURE_COMPRESSION_BC),
+            etc2_compression: features.contains(Features::TEXTURE_COMPRESSION_ETC2),
+            astc_compression: features.contains(Features::TEXTURE_COMPRESSION_ASTC),
+            hdr_render_target: hdr.allowed_usages.contains(TextureUsages::RENDER_ATTACHMENT)
+                && hdr.flags.contains(wgpu::TextureFormatFeatureFlags::BLENDABLE),
+            max_texture_size: limits.max_texture_dimension_2d,
+            msaa_sample_counts: MSAA_SAMPLE_COUNTS
+                .into_iter()
+                .filter(|&count| count == 1 || swapchain.sample_count_supported(count))
+                .collect(),
+        }
+    }
+}
+
+/// Optional render paths, each needing an adapter feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderFeatures {
+    pub gpu_culling: bool,   // Needs compute shaders
+    pub gpu_particles: bool, // Needs compute shaders; otherwise particles simulate on the CPU
+    pub indirect_draw: bool, // Needs indirect execution
+    pub hdr: bool,           // Needs a renderable, blendable Rgba16Float target
+}
+
+impl Default for RenderFeatures {
+    fn default() -> Self {
+        Self {
+            gpu_culling: true,
+            gpu_particles: true,
+            indirect_draw: true,
+            hdr: true,
+        }
+    }
+}
+
+impl RenderFeatures {
+    /// These features minus the ones `capabilities` can't run
+    pub fn supported_by(self, capabilities: &GpuCapabilities) -> Self {
+        Self {
+            gpu_culling: self.gpu_culling && capabilities.compute_shaders,
+            gpu_particles: self.gpu_particles && capabilities.compute_shaders,
+            indirect_draw: self.indirect_draw && capabilities.indirect_draw,
+            hdr: self.hdr && capabilities.hdr_render_target,
+        }
+    }
+}
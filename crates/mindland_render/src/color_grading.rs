@@ -0,0 +1,96 @@
+//! Color grading with a 3D LUT
+//!
+//! LUTs are stored as a horizontal strip of `size` slices (`size² x size` texels): red increases
+//! along x within a slice, green along y, and blue selects the slice. The grading pass runs after
+//! tonemapping, on display-referred colors in 0-1.
+
+use bevy::{
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+/// LUT resolution used unless a LUT image says otherwise
+pub const DEFAULT_LUT_SIZE: u32 = 32;
+
+/// A 3D color lookup table, sampled with trilinear filtering like the GPU pass
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorLut {
+    pub size: u32,
+    texels: Vec<Vec3>, // Indexed r + g * size + b * size²
+}
+
+impl ColorLut {
+    /// Build a LUT by evaluating `f` at every lattice point
+    pub fn from_fn(size: u32, f: impl Fn(Vec3) -> Vec3) -> Self {
+        let size = size.max(2);
+        let scale = 1.0 / (size - 1) as f32;
+        let texels = (0..size * size * size)
+            .map(|index| {
+                let (r, g, b) = (index % size, index / size % size, index / (size * size));
+                f(Vec3::new(r as f32, g as f32, b as f32) * scale)
+            })
+            .collect();
+        Self { size, texels }
+    }
+
+    /// LUT that leaves colors unchanged
+    pub fn identity(size: u32) -> Self {
+        Self::from_fn(size, |color| color)
+    }
+
+    /// Read a strip-layout LUT image (`size² x size`, 8-bit RGBA)
+    pub fn from_image(image: &Image) -> Option<Self> {
+        let size = image.height();
+        if size < 2 || image.width() != size * size || image.texture_descriptor.format.block_size(None) != Some(4) {
+            return None;
+        }
+
+        let texels = (0..size * size * size)
+            .map(|index| {
+                let (r, g, b) = (index % size, index / size % size, index / (size * size));
+                let offset = ((g * size * size + b * size + r) * 4) as usize;
+                let texel = image.data.get(offset..offset + 3)?;
+                Some(Vec3::new(texel[0] as f32, texel[1] as f32, texel[2] as f32) / 255.0)
+            })
+            .collect::<Option<_>>()?;
+        Some(Self { size, texels })
+    }
+
+    /// Encode as a strip-layout image for loading through `AssetManager` (use a clamping sampler)
+    pub fn to_image(&self) -> Image {
+        let size = self.size;
+        let mut data = vec![255; (size * size * size * 4) as usize];
+        for (index, texel) in self.texels.iter().enumerate() {
+            let index = index as u32;
+            let (r, g, b) = (index % size, index / size % size, index / (size * size));
+            let offset = ((g * size * size + b * size + r) * 4) as usize;
+            let encoded = (texel.clamp(Vec3::ZERO, Vec3::ONE) * 255.0).round();
+            data[offset..offset + 3].copy_from_slice(&[encoded.x as u8, encoded.y as u8, encoded.z as u8]);
+        }
+
+        Image::new(
+            Extent3d { width: size * size, height: size, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8Unorm, // Values are already display-encoded; no sRGB decode
+        )
+    }
+
+    /// Grade a color (channels clamped to 0-1), interpolating between the 8 nearest lattice points
+    ///
+    /// The shader samples texel centers, `(color * (size - 1) + 0.5) / size`, so it matches this.
+    pub fn sample(&self, color: Vec3) -> Vec3 {
+        let last = (self.size - 1) as f32;
+        let position = color.clamp(Vec3::ZERO, Vec3::ONE) * last;
+        let base = position.floor().min(Vec3::splat(last - 1.0));
+        let t = position - base;
+        let (r, g, b) = (base.x as u32, base.y as u32, base.z as u32);
+
+        let texel = |dr: u32, dg: u32, db: u32| {
+            self.texels[((r + dr) + (g + dg) * self.size + (b + db) * self.size * self.size) as usize]
+        };
+        let lerp_r = |dg, db| texel(0, dg, db).lerp(texel(1, dg, db), t.x);
+        let lerp_g = |db| lerp_r(0, db).lerp(lerp_r(1, db), t.y);
+        lerp_g(0).lerp(lerp_g(1), t.z)
+    }
+}
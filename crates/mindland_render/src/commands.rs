@@ -0,0 +1,100 @@
+//! Recorded render commands
+//!
+//! A `RenderCommandBuffer` owns copies of everything its commands reference, so a recorded frame
+//! can be submitted on another thread while the main thread rewrites the renderer's instances.
+
+use crate::{compare_draw_keys, draw_key, GpuParticleSpawn, InstanceData, ParticleBackend, ParticleSimParams, UltraRenderer};
+use bevy::prelude::*;
+use mindland_assets::TextureId;
+
+/// One step of a recorded frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenderCommand {
+    /// Particle compute pass: `simulate`, then `spawn` from `particle_spawns[first_spawn..]` (see `PARTICLE_SIMULATION_WGSL`)
+    SimulateParticles { params: ParticleSimParams, first_spawn: u32 },
+    /// Start a viewport pass; draws until the next `BeginPass` use its scissor and camera
+    BeginPass { window: Option<Entity>, scissor: URect, view_projection: Mat4 },
+    /// Instanced draw of `instances[first..first + count]`, in draw order (queue, depth, insertion)
+    DrawInstances { first: u32, count: u32 },
+    /// Instanced draw straight from the particle buffer's first `count` slots (dead slots collapse)
+    DrawParticles { count: u32 },
+    /// Screen-space glyph batch from `instances[first..first + count]`, with the font atlas bound
+    DrawText { first: u32, count: u32, font: Option<TextureId> },
+}
+
+/// A frame's commands and the instance data they draw
+#[derive(Clone, Default)]
+pub struct RenderCommandBuffer {
+    pub frame: u64, // Set when the buffer is handed off for submission
+    pub commands: Vec<RenderCommand>,
+    pub instances: Vec<InstanceData>,
+    pub particle_spawns: Vec<GpuParticleSpawn>,
+}
+
+impl RenderCommandBuffer {
+    /// Buffer with room for `commands` commands before it reallocates
+    pub fn with_capacity(commands: usize) -> Self {
+        Self { frame: 0, commands: Vec::with_capacity(commands), instances: Vec::new(), particle_spawns: Vec::new() }
+    }
+
+    /// Drop recorded commands, keeping the allocations for reuse
+    pub fn clear(&mut self) {
+        self.commands.clear();
+        self.instances.clear();
+        self.particle_spawns.clear();
+    }
+
+    /// Copy instances into the buffer, returning the draw command covering them
+    fn push_instances<'a>(&mut self, instances: impl Iterator<Item = &'a InstanceData>) -> (u32, u32) {
+        let first = self.instances.len();
+        self.instances.extend(instances);
+        (first as u32, (self.instances.len() - first) as u32)
+    }
+}
+
+impl UltraRenderer {
+    /// Record this frame's viewport passes and text into `buffer`, replacing its contents
+    pub fn record_commands(&self, buffer: &mut RenderCommandBuffer) {
+        buffer.clear();
+        let renderer = &self.instanced_renderer;
+        let gpu_particles = &self.particles.gpu;
+        let particle_slots = match self.particles.backend() {
+            ParticleBackend::Gpu => gpu_particles.params.slot_count,
+            ParticleBackend::Cpu => 0,
+        };
+        if particle_slots > 0 {
+            let first_spawn = buffer.particle_spawns.len() as u32;
+            buffer.particle_spawns.extend_from_slice(&gpu_particles.spawns);
+            buffer.commands.push(RenderCommand::SimulateParticles { params: gpu_particles.params, first_spawn });
+        }
+
+        for pass in &self.viewport_passes {
+            buffer.commands.push(RenderCommand::BeginPass {
+                window: pass.window,
+                scissor: pass.scissor,
+                view_projection: pass.view_projection,
+            });
+            // One draw in pass order: static and dynamic lists merged by draw key, static first on ties
+            let key = |instance: &InstanceData| draw_key(instance, pass.camera_position);
+            let mut statics = pass.visible_static_instances.iter().map(|&index| &renderer.static_instances[index as usize]).peekable();
+            let mut dynamics = pass.visible_instances.iter().map(|&index| &renderer.instance_data[index as usize]).peekable();
+            let merged = std::iter::from_fn(|| match (statics.peek(), dynamics.peek()) {
+                (Some(a), Some(b)) if compare_draw_keys(key(a), key(b)).is_gt() => dynamics.next(),
+                (Some(_), _) => statics.next(),
+                (None, _) => dynamics.next(),
+            });
+            let (first, count) = buffer.push_instances(merged);
+            if count > 0 {
+                buffer.commands.push(RenderCommand::DrawInstances { first, count });
+            }
+            if particle_slots > 0 {
+                buffer.commands.push(RenderCommand::DrawParticles { count: particle_slots });
+            }
+        }
+
+        if !self.text.glyphs.is_empty() {
+            let (first, count) = buffer.push_instances(self.text.glyphs.iter());
+            buffer.commands.push(RenderCommand::DrawText { first, count, font: self.text.font_texture });
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Debug gizmo lines
+//!
+//! Lines are expanded into screen-aligned quads so they keep a constant pixel width at any distance.
+
+use crate::UltraRenderer;
+use bevy::prelude::*;
+use mindland_assets::{BoundingBox, BoundingSphere};
+
+/// Segments used to approximate each great circle of a sphere gizmo
+const SPHERE_SEGMENTS: usize = 24;
+
+/// Extra pixels on each side of an anti-aliased line for the edge fade
+const AA_FEATHER: f32 = 1.0;
+
+/// Per-frame debug line accumulator
+#[derive(Debug, Clone)]
+pub struct GizmoRenderer {
+    pub lines: Vec<GizmoLine>,
+    pub default_width: f32, // Pixels, used by draw_box and draw_sphere
+    pub anti_aliased: bool,
+}
+
+/// A world-space line segment with a pixel width
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GizmoLine {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub color: Color,
+    pub width: f32,
+}
+
+/// Expanded line vertex ready for the gizmo pipeline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GizmoVertex {
+    pub clip_position: Vec4,
+    pub color: Color,
+    pub edge_distance: f32, // Signed pixels from the line center, positive on the left (for AA fade)
+}
+
+impl Default for GizmoRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GizmoRenderer {
+    /// Create an empty gizmo renderer with 2px anti-aliased lines
+    pub fn new() -> Self {
+        Self {
+            lines: Vec::new(),
+            default_width: 2.0,
+            anti_aliased: true,
+        }
+    }
+
+    /// Queue a line for this frame
+    pub fn draw_line(&mut self, a: Vec3, b: Vec3, color: Color, width: f32) {
+        self.lines.push(GizmoLine { start: a, end: b, color, width });
+    }
+
+    /// Queue the 12 edges of a bounding box
+    pub fn draw_box(&mut self, bounding_box: &BoundingBox, color: Color) {
+        let (min, max) = (bounding_box.min, bounding_box.max);
+        let corner = |i: usize| {
+            Vec3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        };
+
+        // Corners differing in exactly one bit share an edge
+        for i in 0..8 {
+            for bit in [1, 2, 4] {
+                if i & bit == 0 {
+                    self.draw_line(corner(i), corner(i | bit), color, self.default_width);
+                }
+            }
+        }
+    }
+
+    /// Queue three axis-aligned great circles of a bounding sphere
+    pub fn draw_sphere(&mut self, sphere: &BoundingSphere, color: Color) {
+        let axes = [(Vec3::X, Vec3::Y), (Vec3::Y, Vec3::Z), (Vec3::Z, Vec3::X)];
+        for (u, v) in axes {
+            let point = |segment: usize| {
+                let angle = segment as f32 / SPHERE_SEGMENTS as f32 * std::f32::consts::TAU;
+                sphere.center + (u * angle.cos() + v * angle.sin()) * sphere.radius
+            };
+            for segment in 0..SPHERE_SEGMENTS {
+                self.draw_line(point(segment), point(segment + 1), color, self.default_width);
+            }
+        }
+    }
+
+    /// Drop all queued lines (`gizmo_clear_system` calls this at the start of each frame)
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Expand all queued lines into triangle-list vertices (6 per line)
+    pub fn build_vertices(&self, view_projection: Mat4, viewport_size: Vec2) -> Vec<GizmoVertex> {
+        let feather = if self.anti_aliased { AA_FEATHER } else { 0.0 };
+        let mut vertices = Vec::with_capacity(self.lines.len() * 6);
+
+        for line in &self.lines {
+            let start = view_projection * line.start.extend(1.0);
+            let end = view_projection * line.end.extend(1.0);
+            if start.w <= 0.0 || end.w <= 0.0 {
+                continue; // Behind the camera; debug lines aren't clipped
+            }
+
+            let half_width = line.width * 0.5 + feather;
+            let quad = expand_line(start, end, half_width * 2.0, viewport_size);
+            let edges = [half_width, -half_width, -half_width, half_width];
+            for index in [0, 1, 2, 0, 2, 3] {
+                vertices.push(GizmoVertex {
+                    clip_position: quad[index],
+                    color: line.color,
+                    edge_distance: edges[index],
+                });
+            }
+        }
+        vertices
+    }
+}
+
+/// Expand a clip-space segment into a quad `width` pixels wide
+///
+/// Corners are ordered start-left, start-right, end-right, end-left. The offset is applied in
+/// screen space and scaled back by w, so width stays constant regardless of depth.
+pub fn expand_line(start: Vec4, end: Vec4, width: f32, viewport_size: Vec2) -> [Vec4; 4] {
+    let to_screen = |clip: Vec4| clip.truncate().truncate() / clip.w * viewport_size * 0.5;
+    let direction = (to_screen(end) - to_screen(start)).normalize_or_zero();
+    let normal = direction.perp();
+
+    // Pixels -> NDC offset
+    let offset = normal * (width * 0.5) / (viewport_size * 0.5);
+    let shift = |clip: Vec4, sign: f32| clip + (offset * sign * clip.w).extend(0.0).extend(0.0);
+
+    [shift(start, 1.0), shift(start, -1.0), shift(end, -1.0), shift(end, 1.0)]
+}
+
+/// Drop last frame's gizmo lines at the start of a frame, before anything draws this frame's lines
+pub fn gizmo_clear_system(mut renderer: ResMut<UltraRenderer>) {
+    renderer.gizmos.clear();
+}
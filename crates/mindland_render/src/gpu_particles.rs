@@ -0,0 +1,197 @@
+//! GPU particle simulation
+//!
+//! With `ParticleBackend::Gpu`, particles live in a storage buffer of `GpuParticle` slots that
+//! `PARTICLE_SIMULATION_WGSL` ages, moves and spawns into each frame. The same buffer is the
+//! instance buffer of the particle draw, so positions never come back to the CPU. The CPU side
+//! (`GpuParticles`) only decides how many particles to emit and which slots are free, from each
+//! particle's expiry time.
+
+use bevy::prelude::*;
+use bytemuck::{Pod, Zeroable};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Invocations per workgroup of both compute entry points
+pub const PARTICLE_WORKGROUP_SIZE: u32 = 64;
+
+/// Particle simulation compute shader
+///
+/// `simulate` runs over every slot, then `spawn` over this frame's spawns, with the same bind
+/// group. Slots whose age has reached their lifetime are dead; the draw collapses them.
+pub const PARTICLE_SIMULATION_WGSL: &str = r#"
+struct Particle {
+    position: vec3<f32>,
+    age: f32,
+    velocity: vec3<f32>,
+    lifetime: f32,
+}
+
+struct Spawn {
+    position: vec3<f32>,
+    slot: u32,
+    velocity: vec3<f32>,
+    lifetime: f32,
+}
+
+struct Params {
+    delta_time: f32,
+    slot_count: u32,
+    spawn_count: u32,
+    _padding: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> particles: array<Particle>;
+@group(0) @binding(2) var<storage, read> spawns: array<Spawn>;
+
+@compute @workgroup_size(64)
+fn simulate(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x >= params.slot_count {
+        return;
+    }
+    var particle = particles[id.x];
+    if particle.age < particle.lifetime {
+        particle.age += params.delta_time;
+        particle.position += particle.velocity * params.delta_time;
+        particles[id.x] = particle;
+    }
+}
+
+@compute @workgroup_size(64)
+fn spawn(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x >= params.spawn_count {
+        return;
+    }
+    let record = spawns[id.x];
+    particles[record.slot] = Particle(record.position, 0.0, record.velocity, record.lifetime);
+}
+"#;
+
+/// A particle slot, matching the shader's `Particle` (32 bytes, usable as two `Float32x4` instance attributes)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Pod, Zeroable)]
+pub struct GpuParticle {
+    pub position: [f32; 3],
+    pub age: f32,
+    pub velocity: [f32; 3],
+    pub lifetime: f32,
+}
+
+impl GpuParticle {
+    /// Whether the slot holds a live particle (zeroed slots are dead)
+    pub fn is_alive(&self) -> bool {
+        self.age < self.lifetime
+    }
+}
+
+/// A particle to write into a slot this frame, matching the shader's `Spawn`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Pod, Zeroable)]
+pub struct GpuParticleSpawn {
+    pub position: [f32; 3],
+    pub slot: u32,
+    pub velocity: [f32; 3],
+    pub lifetime: f32,
+}
+
+/// Per-frame uniform, matching the shader's `Params`
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Pod, Zeroable)]
+pub struct ParticleSimParams {
+    pub delta_time: f32,
+    pub slot_count: u32,  // Slots `simulate` runs over (and instances the draw covers)
+    pub spawn_count: u32, // Records `spawn` runs over
+    pub _padding: u32,
+}
+
+impl ParticleSimParams {
+    /// Workgroups to dispatch for `simulate`
+    pub fn simulate_workgroups(&self) -> u32 {
+        self.slot_count.div_ceil(PARTICLE_WORKGROUP_SIZE)
+    }
+
+    /// Workgroups to dispatch for `spawn`
+    pub fn spawn_workgroups(&self) -> u32 {
+        self.spawn_count.div_ceil(PARTICLE_WORKGROUP_SIZE)
+    }
+}
+
+/// CPU bookkeeping for GPU-simulated particles: free slots and this frame's dispatch
+#[derive(Debug, Clone, Default)]
+pub struct GpuParticles {
+    pub params: ParticleSimParams,      // Dispatch for the latest update
+    pub spawns: Vec<GpuParticleSpawn>,  // Upload for the latest update
+    clock: f64,                         // Seconds simulated so far
+    expiries: BinaryHeap<Reverse<(u64, u32)>>, // (expiry time bits, slot); times are non-negative, so bit order is numeric order
+    free_slots: Vec<u32>,
+    slot_count: u32, // Slots ever used; the buffer needs this many
+}
+
+impl GpuParticles {
+    /// Live particles (slots in use that haven't expired)
+    pub fn live_count(&self) -> usize {
+        self.slot_count as usize - self.free_slots.len()
+    }
+
+    /// Slots the particle buffer must hold
+    pub fn slot_count(&self) -> u32 {
+        self.slot_count
+    }
+
+    /// Forget every particle and slot
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Advance the clock by `delta_time`, freeing slots whose particles expire during the step
+    pub(crate) fn begin_frame(&mut self, delta_time: f32) {
+        self.clock += delta_time as f64;
+        self.spawns.clear();
+        while let Some(&Reverse((expiry, slot))) = self.expiries.peek() {
+            if f64::from_bits(expiry) > self.clock {
+                break;
+            }
+            self.expiries.pop();
+            self.free_slots.push(slot);
+        }
+        self.params = ParticleSimParams { delta_time, slot_count: self.slot_count, ..default() };
+    }
+
+    /// Queue a particle for this frame's `spawn` pass
+    pub(crate) fn spawn(&mut self, position: Vec3, velocity: Vec3, lifetime: f32) {
+        let slot = self.free_slots.pop().unwrap_or_else(|| {
+            self.slot_count += 1;
+            self.slot_count - 1
+        });
+        let expiry = self.clock + lifetime.max(0.0) as f64;
+        self.expiries.push(Reverse((expiry.to_bits(), slot)));
+        self.spawns.push(GpuParticleSpawn {
+            position: position.to_array(),
+            slot,
+            velocity: velocity.to_array(),
+            lifetime,
+        });
+        self.params.slot_count = self.slot_count;
+        self.params.spawn_count = self.spawns.len() as u32;
+    }
+
+    /// Run the latest dispatch on the CPU, exactly as the shader would (for tests and tools without a GPU)
+    pub fn dispatch_on_cpu(&self, particles: &mut Vec<GpuParticle>) {
+        particles.resize(self.params.slot_count as usize, GpuParticle::default());
+        let delta_time = self.params.delta_time;
+        for particle in particles.iter_mut().filter(|particle| particle.is_alive()) {
+            particle.age += delta_time;
+            for axis in 0..3 {
+                particle.position[axis] += particle.velocity[axis] * delta_time;
+            }
+        }
+        for spawn in &self.spawns {
+            particles[spawn.slot as usize] = GpuParticle {
+                position: spawn.position,
+                age: 0.0,
+                velocity: spawn.velocity,
+                lifetime: spawn.lifetime,
+            };
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Software hierarchical-Z occlusion
+//!
+//! Large occluders are rasterized into a low-resolution CPU depth buffer, which is reduced into
+//! a min-depth pyramid. An object is occluded when every texel under its screen rectangle holds
+//! an occluder closer than the object's nearest point. Depth is reverse-Z NDC as produced by the
+//! camera projection: 1.0 at the near plane, approaching 0.0 far away, so larger is closer.
+
+use bevy::prelude::*;
+use mindland_assets::BoundingBox;
+
+/// Box faces as corner-index triangles (corner bit 0 = x, bit 1 = y, bit 2 = z)
+pub(crate) const BOX_TRIANGLES: [[usize; 3]; 12] = [
+    [0, 1, 3], [0, 3, 2], // -z
+    [4, 6, 7], [4, 7, 5], // +z
+    [0, 4, 5], [0, 5, 1], // -y
+    [2, 3, 7], [2, 7, 6], // +y
+    [0, 2, 6], [0, 6, 4], // -x
+    [1, 5, 7], [1, 7, 3], // +x
+];
+
+/// Clip-space w below which a corner counts as behind the camera
+pub(crate) const MIN_CLIP_W: f32 = 1e-4;
+
+/// CPU depth buffer and its min-depth pyramid
+#[derive(Debug, Clone)]
+pub struct HiZBuffer {
+    width: u32,
+    height: u32,
+    levels: Vec<Vec<f32>>, // Level 0 is full resolution; each level keeps the farthest depth of its 2x2 texels
+}
+
+/// A box projected to screen space
+struct ProjectedBox {
+    corners: [Vec3; 8], // Pixel x, pixel y (top-left origin), NDC depth
+}
+
+impl Default for HiZBuffer {
+    fn default() -> Self {
+        Self::new(256, 128)
+    }
+}
+
+impl HiZBuffer {
+    /// Create a cleared buffer (sizes below 1 are raised to 1)
+    pub fn new(width: u32, height: u32) -> Self {
+        let (width, height) = (width.max(1), height.max(1));
+        let mut levels = Vec::new();
+        let (mut level_width, mut level_height) = (width, height);
+        loop {
+            levels.push(vec![0.0; (level_width * level_height) as usize]);
+            if level_width == 1 && level_height == 1 {
+                break;
+            }
+            level_width = level_width.div_ceil(2);
+            level_height = level_height.div_ceil(2);
+        }
+        Self { width, height, levels }
+    }
+
+    /// Resolution of level 0
+    pub fn size(&self) -> UVec2 {
+        UVec2::new(self.width, self.height)
+    }
+
+    /// Number of pyramid levels
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Reset every texel to infinitely far (start of frame)
+    pub fn clear(&mut self) {
+        for level in &mut self.levels {
+            level.fill(0.0);
+        }
+    }
+
+    /// Level-0 depth at a texel (0.0 = nothing rasterized there)
+    pub fn depth_at(&self, x: u32, y: u32) -> f32 {
+        self.levels[0][(y.min(self.height - 1) * self.width + x.min(self.width - 1)) as usize]
+    }
+
+    /// Fraction of the screen (larger of width and height) a box covers, or None if it crosses the near plane
+    pub fn screen_size(bounds: &BoundingBox, view_projection: Mat4) -> Option<f32> {
+        let (min, max) = ndc_bounds(bounds, view_projection)?;
+        let extent = (max - min) * 0.5; // NDC spans 2 units
+        Some(extent.x.max(extent.y))
+    }
+
+    /// Rasterize a box's faces into level 0 (call `build_pyramid` once all occluders are in)
+    ///
+    /// Boxes crossing the near plane are skipped rather than clipped; they would cover most of the screen.
+    pub fn rasterize_box(&mut self, bounds: &BoundingBox, view_projection: Mat4) {
+        let Some(projected) = self.project(bounds, view_projection) else {
+            return;
+        };
+        for [a, b, c] in BOX_TRIANGLES {
+            self.rasterize_triangle(projected.corners[a], projected.corners[b], projected.corners[c]);
+        }
+    }
+
+    fn rasterize_triangle(&mut self, a: Vec3, b: Vec3, c: Vec3) {
+        let area = edge(a, b, c);
+        if area.abs() < f32::EPSILON {
+            return;
+        }
+
+        let min = a.min(b).min(c).truncate().max(Vec2::ZERO);
+        let max = a.max(b).max(c).truncate().min(Vec2::new(self.width as f32, self.height as f32));
+        if min.x >= max.x || min.y >= max.y {
+            return;
+        }
+
+        let depth = &mut self.levels[0];
+        for y in min.y.floor() as u32..max.y.ceil() as u32 {
+            for x in min.x.floor() as u32..max.x.ceil() as u32 {
+                let p = Vec3::new(x as f32 + 0.5, y as f32 + 0.5, 0.0); // Sample at texel centers
+                let (wa, wb, wc) = (edge(b, c, p) / area, edge(c, a, p) / area, edge(a, b, p) / area);
+                if wa < 0.0 || wb < 0.0 || wc < 0.0 {
+                    continue;
+                }
+                // NDC depth is affine in screen space, so barycentric interpolation is exact
+                let z = wa * a.z + wb * b.z + wc * c.z;
+                let texel = &mut depth[(y * self.width + x) as usize];
+                *texel = texel.max(z);
+            }
+        }
+    }
+
+    /// Rebuild levels 1.. from level 0
+    pub fn build_pyramid(&mut self) {
+        let (mut width, mut height) = (self.width, self.height);
+        for level in 1..self.levels.len() {
+            let (parent_width, parent_height) = (width.div_ceil(2), height.div_ceil(2));
+            let (finer, coarser) = self.levels.split_at_mut(level);
+            let (source, target) = (&finer[level - 1], &mut coarser[0]);
+            for y in 0..parent_height {
+                for x in 0..parent_width {
+                    let mut farthest = f32::INFINITY;
+                    for (sx, sy) in [(2 * x, 2 * y), (2 * x + 1, 2 * y), (2 * x, 2 * y + 1), (2 * x + 1, 2 * y + 1)] {
+                        if sx < width && sy < height {
+                            farthest = farthest.min(source[(sy * width + sx) as usize]);
+                        }
+                    }
+                    target[(y * parent_width + x) as usize] = farthest;
+                }
+            }
+            (width, height) = (parent_width, parent_height);
+        }
+    }
+
+    /// Whether the occluders fully hide a box (boxes crossing the near plane or off screen are never occluded)
+    pub fn is_occluded(&self, bounds: &BoundingBox, view_projection: Mat4) -> bool {
+        let Some(projected) = self.project(bounds, view_projection) else {
+            return false;
+        };
+        let nearest = projected.corners.iter().map(|corner| corner.z).fold(f32::MIN, f32::max);
+        let min = projected.corners.iter().fold(Vec2::MAX, |min, corner| min.min(corner.truncate()));
+        let max = projected.corners.iter().fold(Vec2::MIN, |max, corner| max.max(corner.truncate()));
+
+        let screen = Vec2::new(self.width as f32, self.height as f32);
+        let (min, max) = (min.max(Vec2::ZERO), max.min(screen));
+        if min.x >= max.x || min.y >= max.y {
+            return false;
+        }
+
+        // Pick the level where the rectangle spans at most a few texels
+        let span = (max - min).max_element().max(1.0);
+        let level = (span.log2().ceil() as usize).saturating_sub(1).min(self.levels.len() - 1);
+        let (level_width, level_height) = self.level_size(level);
+        let scale = (1u32 << level) as f32;
+        let first = (min / scale).floor().as_uvec2();
+        let last = ((max / scale).ceil().as_uvec2()).min(UVec2::new(level_width, level_height));
+
+        let depth = &self.levels[level];
+        let farthest_occluder = (first.y..last.y)
+            .flat_map(|y| (first.x..last.x).map(move |x| depth[(y * level_width + x) as usize]))
+            .fold(f32::INFINITY, f32::min);
+        farthest_occluder > nearest
+    }
+
+    fn level_size(&self, level: usize) -> (u32, u32) {
+        let (mut width, mut height) = (self.width, self.height);
+        for _ in 0..level {
+            (width, height) = (width.div_ceil(2), height.div_ceil(2));
+        }
+        (width, height)
+    }
+
+    fn project(&self, bounds: &BoundingBox, view_projection: Mat4) -> Option<ProjectedBox> {
+        let mut corners = [Vec3::ZERO; 8];
+        for (index, corner) in box_corners(bounds).into_iter().enumerate() {
+            let clip = view_projection * corner.extend(1.0);
+            if clip.w <= MIN_CLIP_W {
+                return None;
+            }
+            let ndc = clip.truncate() / clip.w;
+            corners[index] = Vec3::new(
+                (ndc.x * 0.5 + 0.5) * self.width as f32,
+                (0.5 - ndc.y * 0.5) * self.height as f32,
+                ndc.z,
+            );
+        }
+        Some(ProjectedBox { corners })
+    }
+}
+
+pub(crate) fn box_corners(bounds: &BoundingBox) -> [Vec3; 8] {
+    std::array::from_fn(|index| {
+        Vec3::new(
+            if index & 1 == 0 { bounds.min.x } else { bounds.max.x },
+            if index & 2 == 0 { bounds.min.y } else { bounds.max.y },
+            if index & 4 == 0 { bounds.min.z } else { bounds.max.z },
+        )
+    })
+}
+
+/// NDC xy rectangle of a box, or None if it crosses the near plane
+fn ndc_bounds(bounds: &BoundingBox, view_projection: Mat4) -> Option<(Vec2, Vec2)> {
+    let mut min = Vec2::MAX;
+    let mut max = Vec2::MIN;
+    for corner in box_corners(bounds) {
+        let clip = view_projection * corner.extend(1.0);
+        if clip.w <= MIN_CLIP_W {
+            return None;
+        }
+        let ndc = clip.truncate().truncate() / clip.w;
+        (min, max) = (min.min(ndc), max.max(ndc));
+    }
+    Some((min, max))
+}
+
+/// Twice the signed area of (a, b, p) in screen space
+pub(crate) fn edge(a: Vec3, b: Vec3, p: Vec3) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
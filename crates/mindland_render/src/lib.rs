@@ -2,37 +2,152 @@
 //! 
 //! High-performance 3D rendering with instanced rendering, GPU culling, and compute shaders.
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    render::{
+        camera::{CameraProjection, Viewport},
+        render_resource::{LoadOp, TextureFormat},
+    },
+    utils::HashMap,
+    window::PresentMode,
+};
 use bytemuck::{Pod, Zeroable};
+use mindland_assets::{is_transparent_queue, BoundingBox, BoundingSphere, MeshId, TextureId, QUEUE_OPAQUE};
+use mindland_camera::CameraController;
+use mindland_performance::QualitySettings;
+use serde::Serialize;
 use slotmap::{SlotMap, DefaultKey};
+use std::time::Instant;
+
+mod capabilities;
+mod color_grading;
+mod commands;
+mod gizmo;
+mod gpu_particles;
+mod hiz;
+mod mesher;
+mod msaa;
+mod occlusion;
+mod particles;
+mod picking;
+mod remesh;
+mod shadows;
+mod surfaces;
+mod target_pool;
+mod text;
+pub use capabilities::*;
+pub use color_grading::*;
+pub use commands::*;
+pub use gizmo::*;
+pub use gpu_particles::*;
+pub use hiz::*;
+pub use mesher::*;
+pub use msaa::*;
+pub use occlusion::*;
+pub use particles::*;
+pub use picking::*;
+pub use remesh::*;
+pub use shadows::*;
+pub use surfaces::*;
+pub use target_pool::*;
+pub use text::*;
+
+pub use mindland_performance::{MAX_RENDER_SCALE, MIN_RENDER_SCALE};
 
 /// Ultra-optimized 3D renderer
+#[derive(Resource)]
 pub struct UltraRenderer {
     pub instanced_renderer: InstancedRenderer,
     pub texture_atlas: TextureAtlas,
     pub culling_system: CullingSystem,
+    pub viewport_passes: Vec<ViewportPass>,
+    pub surfaces: HashMap<Entity, SurfaceConfig>, // Extra windows drawn by `render_window`
+    pub clear_config: ClearConfig,
+    pub render_scale: f32, // Internal resolution factor (dynamic resolution)
+    pub upscaler: Upscaler,
+    pub upscale_sharpness: f32, // Sharpening strength after upscaling, 0.0-1.0
+    pub gizmos: GizmoRenderer,
+    pub particles: ParticleSystem,
+    pub lod_bias: f32, // World units added to LOD distance, 0 = neutral; synced from `QualitySettings::lod_bias`
+    pub stats: RenderStats,
+    pub msaa: MsaaTarget,
+    pub render_targets: RenderTargetPool, // Offscreen/depth/MSAA targets, debounced on resize
+    pub color_lut: Option<TextureId>,     // Strip-layout 3D LUT (see `ColorLut`), None = no grading
+    pub features: RenderFeatures, // Requested optional paths; `active_features` is what actually runs
+    pub capabilities: GpuCapabilities,
+    pub picking: PickBuffer, // Pick-id target for `pick_at`, off until `picking.enabled` is set
+    pub text: TextBatch,     // Screen-space glyph quads, drawn as one batch after the scene
 }
 
+/// Fewest dynamic instance buffers: the CPU writes one while the GPU reads the other
+pub const MIN_INSTANCE_BUFFERS: usize = 2;
+
+/// Most dynamic instance buffers, for drivers that queue more than one frame ahead
+pub const MAX_INSTANCE_BUFFERS: usize = 3;
+
 /// Instanced rendering system for draw call reduction
+///
+/// Dynamic instances rotate through `buffer_count` buffers, one per frame, so the CPU never
+/// rewrites a buffer the GPU may still be reading from the previous frame.
 pub struct InstancedRenderer {
     pub max_instances: u32,
     pub current_instances: u32,
-    pub instance_data: Vec<InstanceData>,
+    pub instance_data: Vec<InstanceData>,   // Dynamic instances, re-uploaded every frame (the current write buffer)
+    pub static_instances: Vec<InstanceData>, // Persist across frames, uploaded only when changed
+    pub static_dirty: bool,
+    instance_buffers: Vec<Vec<InstanceData>>, // One per in-flight frame; the write buffer's slot is lent to `instance_data`
+    write_buffer: usize,
+}
+
+/// Compact instance (48 bytes vs 96 for `InstanceData`) for rigid, uniformly scaled objects
+///
+/// Only translation, rotation and uniform scale survive - shear and non-uniform scale can't be
+/// represented. Expansion is exact up to f32 rounding of the rotation, which stays well below
+/// a pixel for block-sized objects.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompactInstance {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub scale: f32,
+    pub texture_index: u32,
+    pub color: u32, // Packed RGBA, same layout as InstanceData::color_tint
+}
+
+/// Per-frame renderer statistics (a resource so the render loop can publish them)
+#[derive(Resource, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    pub bytes_uploaded: usize,
+    pub static_instances: usize,
+    pub dynamic_instances: usize,
+    pub visible_instances: usize, // Summed over all viewport passes this frame
+    pub culled_instances: usize,
 }
 
 /// Texture atlas for binding optimization
 pub struct TextureAtlas {
     pub atlas_size: u32,
     pub tile_size: u32,
+    pub inset: f32, // Texels trimmed from each tile edge so filtering never reads a neighbour
     pub texture_coords: Vec<TextureCoords>,
 }
 
 /// GPU-accelerated culling system
 pub struct CullingSystem {
     pub frustum_culling: bool,
-    pub occlusion_culling: bool,
+    pub occlusion_method: OcclusionMethod,
     pub distance_culling: bool,
-    pub max_render_distance: f32,
+    pub max_render_distance: f32, // Sphere radius, or the cylinder's horizontal radius
+    pub vertical_render_distance: Option<f32>, // Some = cull with a vertical cylinder of this half-height
+    pub cull_margin: f32, // World units the frustum is expanded by to avoid edge popping
+    pub occlusion_queries: OcclusionQueries,
+    pub hi_z: HiZBuffer, // Depth pyramid for `OcclusionMethod::SoftwareHiZ`
+    pub min_occluder_screen_size: f32, // Screen fraction an object must exceed to be rasterized into `hi_z`
+    pub occlusion_culling: bool,     // Cull instances hidden behind `occluders` in each viewport pass
+    pub occluders: Vec<BoundingBox>, // Solid boxes for `is_hidden_by_occluders`; kept until the caller changes them
+    pub teleport_distance: f32, // Camera movement in one frame (blocks) treated as a teleport
+    pub cut_angle: f32,         // Camera rotation in one frame (radians) treated as a cut
+    last_camera: Option<(Vec3, Quat)>, // Previous frame's camera, for teleport/cut detection
+    occlusion_warmup: bool,            // History is invalid this frame; `is_occluded` reports nothing
 }
 
 /// SIMD-aligned vertex data for optimal GPU performance
@@ -46,13 +161,113 @@ pub struct Vertex {
 }
 
 /// Instance data for instanced rendering
+///
+/// Matches this WGSL storage-buffer struct:
+///
+/// ```wgsl
+/// struct InstanceData {
+///     transform: mat4x4<f32>,
+///     texture_index: u32,
+///     color_tint: u32,
+///     render_queue: i32, // Unused by shaders
+///     pick_id: u32,      // Written to the pick target; 0 = not pickable
+///     custom: vec4<f32>, // Offset 80; free for shader-specific data (animation frame, material params)
+/// }
+/// @group(1) @binding(0) var<storage, read> instances: array<InstanceData>;
+/// ```
 #[repr(C)]
 #[derive(Copy, Clone, Pod, Zeroable)]
 pub struct InstanceData {
     pub transform: [[f32; 4]; 4], // 4x4 transformation matrix
     pub texture_index: u32,
     pub color_tint: u32,
-    pub _padding: [u32; 2], // Align to 16 bytes for GPU
+    pub render_queue: i32, // CPU-side draw order (see `QUEUE_*`)
+    pub pick_id: u32,      // Id written to the pick target, `NO_PICK_ID` = not pickable
+    pub custom: [f32; 4],   // Per-instance shader data, zero unless set
+}
+
+/// Per-camera pass rendering into a sub-region of the window (split-screen)
+#[derive(Debug, Clone)]
+pub struct ViewportPass {
+    pub viewport: Viewport,
+    pub scissor: URect,
+    pub view_projection: Mat4,
+    pub camera_position: Vec3,
+    pub visible_instances: Vec<u32>,        // Indices into the dynamic instance buffer
+    pub visible_static_instances: Vec<u32>, // Indices into the static instance buffer
+    pub window: Option<Entity>,             // Surface drawn into, None = the primary window
+}
+
+/// Offscreen target rendered at reduced resolution, then upscaled to the swapchain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaledRenderTarget {
+    pub internal_size: UVec2, // Size of the offscreen color/depth attachments
+    pub output_size: UVec2,   // Swapchain size the blit upscales to
+}
+
+/// Filter used to bring the scaled internal target up to the swapchain
+///
+/// Ordered by cost. Temporal gives the best result at low scales; on the MacBook Pro 2014
+/// FsrSpatial is the sweet spot for 60 FPS at ~0.7 scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Upscaler {
+    /// Single bilinear blit - nearly free, but visibly soft below ~0.8 scale
+    Bilinear,
+    /// FSR1-style edge-adaptive upsample plus contrast-adaptive sharpening - two cheap
+    /// full-screen passes, keeps edges crisp down to ~0.6 scale, no temporal stability
+    #[default]
+    FsrSpatial,
+    /// Reprojects TAA history with motion vectors before sharpening - reconstructs detail
+    /// even at 0.5 scale, costs a history target and can ghost on disocclusion.
+    /// Falls back to FsrSpatial when no TAA history is available
+    Temporal,
+}
+
+/// A single full-screen pass of the upscale chain
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpscalePass {
+    BilinearBlit,
+    EdgeAdaptiveUpsample,           // FSR1 EASU
+    TemporalResolve,                // History reprojection + accumulation at output size
+    Sharpen { sharpness: f32 },     // FSR1 RCAS
+}
+
+/// A full-screen pass between tonemapping and upscaling
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PostProcessPass {
+    ColorGrade { lut: TextureId }, // 3D LUT lookup on tonemapped color
+}
+
+/// Mesh with discrete levels of detail, ordered from most to least detailed
+#[derive(Debug, Clone)]
+pub struct LodMesh {
+    pub levels: Vec<LodLevel>,
+    pub selection: LodSelection,
+}
+
+/// A single level of detail
+#[derive(Debug, Clone, Copy)]
+pub struct LodLevel {
+    pub mesh: MeshId,
+    pub max_distance: f32,    // Used by distance selection
+    pub min_screen_size: f32, // Pixels, used by screen-size selection
+}
+
+/// How a LOD level is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LodSelection {
+    /// Fixed distance table - cheap but resolution/FOV dependent
+    Distance,
+    /// Projected screen-space size - consistent across resolutions and FOVs
+    ScreenSize,
+}
+
+/// Attachment clearing for the main render target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClearConfig {
+    pub color: Option<Color>, // None skips the color clear (e.g. a skybox writes every pixel)
+    pub depth: bool,
+    pub stencil: bool,
 }
 
 /// Texture coordinates within atlas
@@ -75,68 +290,642 @@ impl UltraRenderer {
     pub fn new() -> Self {
         Self {
             instanced_renderer: InstancedRenderer::new(10000), // Support 10k instances
-            texture_atlas: TextureAtlas::new(1024, 16), // 1024x1024 atlas, 16x16 tiles
+            texture_atlas: TextureAtlas::new(1024, 16).with_inset(0.5), // 1024x1024 atlas, 16x16 tiles, bilinear-safe
             culling_system: CullingSystem::new(),
+            viewport_passes: Vec::with_capacity(4), // Up to 4-player split-screen
+            surfaces: HashMap::new(),
+            clear_config: ClearConfig::default(),
+            render_scale: MAX_RENDER_SCALE,
+            upscaler: Upscaler::default(),
+            upscale_sharpness: 0.8,
+            gizmos: GizmoRenderer::new(),
+            particles: ParticleSystem::new(),
+            lod_bias: 0.0,
+            stats: RenderStats::default(),
+            msaa: MsaaTarget::disabled(TextureFormat::Bgra8UnormSrgb),
+            render_targets: RenderTargetPool::default(),
+            color_lut: None,
+            features: RenderFeatures::default(),
+            capabilities: GpuCapabilities::default(),
+            picking: PickBuffer::default(),
+            text: TextBatch::new(),
+        }
+    }
+
+    /// Record the adapter's capabilities, returning the optional paths that will now run
+    pub fn apply_capabilities(&mut self, capabilities: &GpuCapabilities) -> RenderFeatures {
+        self.capabilities = capabilities.clone();
+        let active = self.active_features();
+        if active != self.features {
+            tracing::warn!("⚠️  Render paths unsupported by {:?}: running {:?}", capabilities.adapter_name, active);
+        }
+        self.sync_particle_backend();
+        active
+    }
+
+    /// Request a particle backend, returning the one that runs (GPU falls back to CPU without compute shaders)
+    pub fn set_particle_backend(&mut self, backend: ParticleBackend) -> ParticleBackend {
+        self.features.gpu_particles = backend == ParticleBackend::Gpu;
+        self.sync_particle_backend()
+    }
+
+    /// Simulate particles where the active features allow
+    fn sync_particle_backend(&mut self) -> ParticleBackend {
+        let backend = if self.active_features().gpu_particles { ParticleBackend::Gpu } else { ParticleBackend::Cpu };
+        self.particles.set_backend(backend);
+        backend
+    }
+
+    /// Requested optional paths the adapter supports
+    pub fn active_features(&self) -> RenderFeatures {
+        self.features.supported_by(&self.capabilities)
+    }
+
+    /// Choose MSAA sample count and resolve format for the surface (see `MsaaTarget::select`)
+    pub fn configure_msaa(
+        &mut self,
+        surface_format: TextureFormat,
+        requested_samples: u32,
+        features: impl Fn(TextureFormat) -> wgpu::TextureFormatFeatures,
+    ) -> MsaaTarget {
+        self.msaa = MsaaTarget::select(surface_format, requested_samples, features);
+        self.msaa
+    }
+
+    /// Set the internal resolution factor (clamped to 0.5-1.0)
+    pub fn set_render_scale(&mut self, render_scale: f32) {
+        self.render_scale = render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+    }
+
+    /// Offscreen target for a window of the given physical size
+    pub fn render_target(&self, window_size: UVec2) -> ScaledRenderTarget {
+        ScaledRenderTarget::new(window_size, self.render_scale)
+    }
+
+    /// Offscreen target for this frame, keeping the previous internal size while a resize settles
+    ///
+    /// During a resize burst the old-size targets are stretched to the new output by the upscale
+    /// blit instead of being reallocated every frame.
+    pub fn debounced_render_target(&mut self, window_size: UVec2, now: Instant) -> ScaledRenderTarget {
+        let internal_size = self.render_targets.request_size(self.render_target(window_size).internal_size, now);
+        ScaledRenderTarget { internal_size, output_size: window_size }
+    }
+
+    /// Select a LOD level for `mesh`, biased toward lower detail by `lod_bias`
+    pub fn select_lod(&self, mesh: &LodMesh, camera: &CameraController, bounds: &BoundingSphere, viewport_height: f32) -> usize {
+        mesh.select_with_bias(camera, bounds, viewport_height, self.lod_bias)
+    }
+
+    /// Offscreen target for this frame, or None when the window has no area (minimized)
+    ///
+    /// A skipped frame leaves the pooled targets and debounce state alone, so restoring the
+    /// window resumes with the targets it had instead of reallocating them.
+    pub fn begin_frame(&mut self, window_size: UVec2, now: Instant) -> Option<ScaledRenderTarget> {
+        if window_size.x == 0 || window_size.y == 0 {
+            return None;
+        }
+        Some(self.debounced_render_target(window_size, now))
+    }
+
+    /// Select the upscaling filter used at reduced render scale
+    pub fn set_upscaler(&mut self, upscaler: Upscaler) {
+        self.upscaler = upscaler;
+    }
+
+    /// Passes needed to present the internal target (empty when rendering at native resolution)
+    pub fn upscale_passes(&self, window_size: UVec2, taa_history_available: bool) -> Vec<UpscalePass> {
+        if !self.render_target(window_size).needs_upscale() {
+            return Vec::new();
+        }
+
+        let sharpen = UpscalePass::Sharpen { sharpness: self.upscale_sharpness.clamp(0.0, 1.0) };
+        match self.upscaler {
+            Upscaler::Bilinear => vec![UpscalePass::BilinearBlit],
+            Upscaler::Temporal if taa_history_available => vec![UpscalePass::TemporalResolve, sharpen],
+            Upscaler::FsrSpatial | Upscaler::Temporal => vec![UpscalePass::EdgeAdaptiveUpsample, sharpen],
         }
     }
 
+    /// Enable color grading with a LUT texture loaded through `AssetManager`, or disable it with None
+    pub fn set_color_lut(&mut self, lut: Option<TextureId>) {
+        self.color_lut = lut;
+    }
+
+    /// Passes run after tonemapping, before the upscale chain
+    pub fn post_process_passes(&self) -> Vec<PostProcessPass> {
+        self.color_lut.map(|lut| PostProcessPass::ColorGrade { lut }).into_iter().collect()
+    }
+
+    /// Set the color the main target is cleared to (re-enables color clearing)
+    pub fn set_clear_color(&mut self, color: Color) {
+        self.clear_config.color = Some(color);
+    }
+
+    /// Set which attachments are cleared at the start of the frame
+    pub fn set_clear_config(&mut self, clear_config: ClearConfig) {
+        self.clear_config = clear_config;
+    }
+
     /// Add an instance for rendering
     pub fn add_instance(&mut self, transform: Mat4, texture_index: u32, color_tint: Color) -> bool {
-        self.instanced_renderer.add_instance(transform, texture_index, color_tint)
+        self.instanced_renderer.add_instance(InstanceData::new(transform, texture_index, color_tint))
     }
 
-    /// Clear all instances for next frame
+    /// Add an instance carrying shader-specific data in `InstanceData::custom`
+    pub fn add_instance_with_custom(
+        &mut self,
+        transform: Mat4,
+        texture_index: u32,
+        color_tint: Color,
+        custom: [f32; 4],
+    ) -> bool {
+        self.instanced_renderer
+            .add_instance(InstanceData::new(transform, texture_index, color_tint).with_custom(custom))
+    }
+
+    /// Add an instance drawn in an explicit render queue (lower queues draw first)
+    pub fn add_instance_in_queue(&mut self, transform: Mat4, texture_index: u32, color_tint: Color, render_queue: i32) -> bool {
+        self.instanced_renderer
+            .add_instance(InstanceData::new(transform, texture_index, color_tint).with_render_queue(render_queue))
+    }
+
+    /// Add an instance that `pick_at` reports as `pick_id` (nonzero)
+    pub fn add_pickable_instance(&mut self, transform: Mat4, texture_index: u32, color_tint: Color, pick_id: u32) -> bool {
+        self.instanced_renderer
+            .add_instance(InstanceData::new(transform, texture_index, color_tint).with_pick_id(pick_id))
+    }
+
+    /// Pick id of the instance visible at a window pixel, from the newest pick readback
+    ///
+    /// Readback is asynchronous: the answer reflects the frame rendered two `clear_instances`
+    /// calls ago. None when nothing pickable is there or picking is off.
+    pub fn pick_at(&self, screen: Vec2) -> Option<u32> {
+        self.picking.id_at(screen * self.render_scale)
+    }
+
+    /// Queue text for this frame, top-left at `pos` (window pixels) and `size` pixels tall
+    ///
+    /// Each visible character is one glyph instance in `text`; all of a frame's text draws at once.
+    pub fn draw_text(&mut self, text: &str, pos: Vec2, size: f32, color: Color) {
+        self.text.draw_text(text, pos, size, color);
+    }
+
+    /// Add an instance that never moves; it stays until `clear_static_instances`
+    pub fn add_static_instance(&mut self, transform: Mat4, texture_index: u32, color_tint: Color) -> bool {
+        self.instanced_renderer.add_static_instance(transform, texture_index, color_tint)
+    }
+
+    /// Set how many dynamic instance buffers rotate (2 or 3), returning the count chosen
+    pub fn set_instance_buffer_count(&mut self, count: usize) -> usize {
+        self.instanced_renderer.set_buffer_count(count)
+    }
+
+    /// Remove all static instances (forces a static buffer re-upload)
+    pub fn clear_static_instances(&mut self) {
+        self.instanced_renderer.clear_static();
+    }
+
+    /// Upload instance buffers for this frame, skipping the static buffer if unchanged
+    pub fn upload_instances(&mut self) -> RenderStats {
+        self.stats.bytes_uploaded = self.instanced_renderer.upload() + std::mem::size_of_val(self.text.glyphs.as_slice());
+        self.stats.static_instances = self.instanced_renderer.static_instances.len();
+        self.stats.dynamic_instances = self.instanced_renderer.instance_data.len();
+        self.stats
+    }
+
+    /// Clear dynamic instances, viewport passes, gizmos and text for next frame
     pub fn clear_instances(&mut self) {
+        self.text.clear();
+        self.picking.end_frame();
         self.instanced_renderer.clear();
+        self.viewport_passes.clear();
+        self.gizmos.clear();
+        self.stats.visible_instances = 0;
+        self.stats.culled_instances = 0;
+    }
+
+    /// Configure (or reconfigure) a window's surface, returning whether anything changed
+    ///
+    /// Zero-area sizes (a minimized window) keep the previous configuration and return false.
+    pub fn configure_surface(&mut self, window: Entity, size: UVec2, present_mode: PresentMode) -> bool {
+        if size.x == 0 || size.y == 0 {
+            return false;
+        }
+        let config = SurfaceConfig::new(size, present_mode);
+        self.surfaces.insert(window, config) != Some(config)
+    }
+
+    /// Surface configuration for a window
+    pub fn surface(&self, window: Entity) -> Option<&SurfaceConfig> {
+        self.surfaces.get(&window)
+    }
+
+    /// Forget a closed window's surface
+    pub fn remove_surface(&mut self, window: Entity) {
+        self.surfaces.remove(&window);
+    }
+
+    /// Render a camera's visible set across a whole window, returning false if it has no surface
+    pub fn render_window(&mut self, window: Entity, camera: &CameraController) -> bool {
+        let Some(surface) = self.surfaces.get(&window) else {
+            return false;
+        };
+        let rect = Rect::from_corners(Vec2::ZERO, surface.size.as_vec2());
+        self.render_pass(camera, rect, Some(window));
+        true
+    }
+
+    /// Render a camera's visible set into a screen rectangle (call once per split-screen player)
+    pub fn render_viewport(&mut self, camera: &CameraController, rect: Rect) {
+        self.render_pass(camera, rect, None);
+    }
+
+    fn render_pass(&mut self, camera: &CameraController, rect: Rect, window: Option<Entity>) {
+        // Window-space rect -> internal target space
+        let viewport = viewport_from_rect(Rect::from_corners(rect.min * self.render_scale, rect.max * self.render_scale));
+        if viewport.physical_size.x == 0 || viewport.physical_size.y == 0 {
+            return; // Nothing to rasterize
+        }
+
+        let scissor = URect::from_corners(
+            viewport.physical_position,
+            viewport.physical_position + viewport.physical_size,
+        );
+
+        // Each viewport has its own aspect ratio, so rebuild the projection for it
+        let projection = camera.projection_for_aspect(viewport.physical_size.x as f32 / viewport.physical_size.y as f32);
+        let view_projection = projection.get_projection_matrix() * camera.view_matrix();
+        let frustum = Frustum::from_view_projection(view_projection);
+        let camera_position = camera.transform.translation;
+        if self.viewport_passes.is_empty() {
+            self.culling_system.track_camera(camera_position, camera.transform.rotation);
+        }
+
+        let visible = |instances: &[InstanceData]| -> Vec<u32> {
+            instances
+                .iter()
+                .enumerate()
+                .filter(|(_, instance)| {
+                    let culling = &self.culling_system;
+                    let bounds = instance.bounds();
+                    !culling.should_cull(&bounds, camera_position, &frustum)
+                        && (!culling.occlusion_culling || !culling.is_hidden_by_occluders(&bounds, camera_position))
+                })
+                .map(|(index, _)| index as u32)
+                .collect()
+        };
+        let mut visible_instances = visible(&self.instanced_renderer.instance_data);
+        let mut visible_static_instances = visible(&self.instanced_renderer.static_instances);
+        sort_draws(&mut visible_instances, &self.instanced_renderer.instance_data, camera_position);
+        sort_draws(&mut visible_static_instances, &self.instanced_renderer.static_instances, camera_position);
+
+        if self.picking.enabled && window.is_none() {
+            let pass_rect = Rect::from_corners(scissor.min.as_vec2(), scissor.max.as_vec2());
+            let drawn = visible_instances.iter().map(|&index| &self.instanced_renderer.instance_data[index as usize])
+                .chain(visible_static_instances.iter().map(|&index| &self.instanced_renderer.static_instances[index as usize]));
+            for instance in drawn {
+                let model = Mat4::from_cols_array_2d(&instance.transform);
+                self.picking.draw(instance.pick_id, model, view_projection, pass_rect, scissor.max);
+            }
+        }
+
+        let candidates = self.instanced_renderer.instance_data.len() + self.instanced_renderer.static_instances.len();
+        let visible_count = visible_instances.len() + visible_static_instances.len();
+        self.stats.visible_instances += visible_count;
+        self.stats.culled_instances += candidates - visible_count;
+
+        self.viewport_passes.push(ViewportPass {
+            viewport,
+            scissor,
+            view_projection,
+            camera_position,
+            visible_instances,
+            visible_static_instances,
+            window,
+        });
+    }
+}
+
+/// Draw-order key: render queue, then opaque front to back (early depth rejection) and
+/// transparent back to front (correct blending)
+pub(crate) fn draw_key(instance: &InstanceData, camera_position: Vec3) -> (i32, f32) {
+    let distance = Vec3::from_slice(&instance.transform[3]).distance_squared(camera_position);
+    let depth = if is_transparent_queue(instance.render_queue) { -distance } else { distance };
+    (instance.render_queue, depth)
+}
+
+/// Compare draw keys; equal keys are left to the caller's tie-break
+pub(crate) fn compare_draw_keys(a: (i32, f32), b: (i32, f32)) -> std::cmp::Ordering {
+    a.0.cmp(&b.0).then(a.1.total_cmp(&b.1))
+}
+
+/// Order draws by `draw_key`, breaking ties by instance index (insertion order)
+///
+/// Equal-depth transparent instances would otherwise be free to swap between runs and flicker.
+/// Both visible lists of a pass are sorted this way, so a backend can merge them by queue.
+fn sort_draws(indices: &mut [u32], instances: &[InstanceData], camera_position: Vec3) {
+    let key = |index: &u32| draw_key(&instances[*index as usize], camera_position);
+    indices.sort_unstable_by(|a, b| compare_draw_keys(key(a), key(b)).then(a.cmp(b)));
+}
+
+impl ScaledRenderTarget {
+    /// Compute the internal target for an output size and render scale
+    pub fn new(output_size: UVec2, render_scale: f32) -> Self {
+        let render_scale = render_scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE);
+        let internal_size = (output_size.as_vec2() * render_scale).round().as_uvec2().max(UVec2::ONE);
+        Self { internal_size, output_size }
+    }
+
+    /// Whether an upscale blit is needed rather than rendering straight to the swapchain
+    pub fn needs_upscale(&self) -> bool {
+        self.internal_size != self.output_size
+    }
+}
+
+impl Default for ClearConfig {
+    fn default() -> Self {
+        Self {
+            color: Some(Color::rgb(0.4, 0.4, 0.4)), // Matches Bevy's default clear color
+            depth: true,
+            stencil: false,
+        }
+    }
+}
+
+impl ClearConfig {
+    /// Load operation for the color attachment
+    pub fn color_load_op(&self) -> LoadOp<Color> {
+        match self.color {
+            Some(color) => LoadOp::Clear(color),
+            None => LoadOp::Load,
+        }
+    }
+
+    /// Load operation for the depth attachment (reverse-Z, so far plane clears to 0.0)
+    pub fn depth_load_op(&self) -> LoadOp<f32> {
+        if self.depth {
+            LoadOp::Clear(0.0)
+        } else {
+            LoadOp::Load
+        }
+    }
+
+    /// Load operation for the stencil attachment
+    pub fn stencil_load_op(&self) -> LoadOp<u32> {
+        if self.stencil {
+            LoadOp::Clear(0)
+        } else {
+            LoadOp::Load
+        }
     }
 }
 
 impl InstancedRenderer {
     fn new(max_instances: u32) -> Self {
-        Self {
+        let mut renderer = Self {
             max_instances,
             current_instances: 0,
-            instance_data: Vec::with_capacity(max_instances as usize),
+            instance_data: Vec::new(),
+            static_instances: Vec::new(),
+            static_dirty: false,
+            instance_buffers: Vec::new(),
+            write_buffer: 0,
+        };
+        renderer.set_buffer_count(MIN_INSTANCE_BUFFERS);
+        renderer
+    }
+
+    /// Set how many dynamic buffers rotate (clamped to 2-3), returning the count chosen
+    ///
+    /// Reallocates the buffers and drops this frame's dynamic instances.
+    pub fn set_buffer_count(&mut self, count: usize) -> usize {
+        let count = count.clamp(MIN_INSTANCE_BUFFERS, MAX_INSTANCE_BUFFERS);
+        self.instance_buffers = (0..count).map(|_| Vec::with_capacity(self.max_instances as usize)).collect();
+        self.write_buffer = 0;
+        self.instance_data = std::mem::take(&mut self.instance_buffers[0]);
+        self.current_instances = 0;
+        count
+    }
+
+    /// Number of dynamic buffers in rotation
+    pub fn buffer_count(&self) -> usize {
+        self.instance_buffers.len()
+    }
+
+    /// Index of the buffer this frame's dynamic instances are written to
+    pub fn write_buffer(&self) -> usize {
+        self.write_buffer
+    }
+
+    /// Contents of a dynamic buffer: the write buffer, or what an earlier frame left for the GPU
+    pub fn buffer(&self, index: usize) -> &[InstanceData] {
+        if index == self.write_buffer {
+            &self.instance_data
+        } else {
+            self.instance_buffers.get(index).map_or(&[], Vec::as_slice)
         }
     }
 
-    fn add_instance(&mut self, transform: Mat4, texture_index: u32, color_tint: Color) -> bool {
-        if self.current_instances >= self.max_instances {
+    fn add_instance(&mut self, instance: InstanceData) -> bool {
+        if self.is_full() {
             return false; // Instance buffer full
         }
 
-        let color_packed = pack_color(color_tint);
-        let instance = InstanceData {
-            transform: transform.to_cols_array_2d(),
-            texture_index,
-            color_tint: color_packed,
-            _padding: [0, 0],
-        };
-
         self.instance_data.push(instance);
         self.current_instances += 1;
         true
     }
 
+    fn add_static_instance(&mut self, transform: Mat4, texture_index: u32, color_tint: Color) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.static_instances.push(InstanceData::new(transform, texture_index, color_tint));
+        self.static_dirty = true;
+        true
+    }
+
+    /// Add a compact instance, expanding it to a full matrix on the CPU
+    pub fn add_compact_instance(&mut self, instance: CompactInstance) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.instance_data.push(instance.into());
+        self.current_instances += 1;
+        true
+    }
+
+    /// Static and dynamic instances share the same capacity
+    fn is_full(&self) -> bool {
+        self.current_instances as usize + self.static_instances.len() >= self.max_instances as usize
+    }
+
+    /// Bytes that would be written to the GPU this frame
+    fn upload(&mut self) -> usize {
+        let mut bytes = std::mem::size_of_val(self.instance_data.as_slice());
+        if self.static_dirty {
+            bytes += std::mem::size_of_val(self.static_instances.as_slice());
+            self.static_dirty = false;
+        }
+        bytes
+    }
+
+    /// Hand the written buffer to the GPU and move on to the oldest one, which it has finished reading
     fn clear(&mut self) {
+        std::mem::swap(&mut self.instance_data, &mut self.instance_buffers[self.write_buffer]);
+        self.write_buffer = (self.write_buffer + 1) % self.instance_buffers.len();
+        std::mem::swap(&mut self.instance_data, &mut self.instance_buffers[self.write_buffer]);
         self.instance_data.clear();
         self.current_instances = 0;
     }
+
+    fn clear_static(&mut self) {
+        self.static_instances.clear();
+        self.static_dirty = true;
+    }
+
+    /// Order-independent hash of all submitted instances (static and dynamic) for golden-frame tests
+    ///
+    /// Uses FNV-1a rather than `DefaultHasher` so hashes stay stable across runs and toolchains.
+    pub fn frame_hash(&self) -> u64 {
+        let mut instance_hashes: Vec<u64> = self.static_instances
+            .iter()
+            .chain(&self.instance_data)
+            .map(|instance| {
+                let mut hash = fnv1a(FNV_OFFSET_BASIS, bytemuck::cast_slice(&instance.transform));
+                hash = fnv1a(hash, &instance.texture_index.to_le_bytes());
+                hash = fnv1a(hash, &instance.color_tint.to_le_bytes());
+                fnv1a(hash, bytemuck::cast_slice(&instance.custom))
+            })
+            .collect();
+
+        // Submission order varies with entity iteration order, so it doesn't count as a change
+        instance_hashes.sort_unstable();
+        instance_hashes
+            .iter()
+            .fold(FNV_OFFSET_BASIS, |hash, instance_hash| fnv1a(hash, &instance_hash.to_le_bytes()))
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Continue a 64-bit FNV-1a hash over `bytes`
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+impl CompactInstance {
+    /// Build from a transform (uses the x scale; non-uniform scale is lost)
+    pub fn from_transform(transform: &Transform, texture_index: u32, color_tint: Color) -> Self {
+        Self {
+            position: transform.translation,
+            rotation: transform.rotation,
+            scale: transform.scale.x,
+            texture_index,
+            color: pack_color(color_tint),
+        }
+    }
+
+    /// Reconstruct the full model matrix
+    pub fn matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(Vec3::splat(self.scale), self.rotation, self.position)
+    }
+}
+
+impl From<CompactInstance> for InstanceData {
+    fn from(instance: CompactInstance) -> Self {
+        Self {
+            transform: instance.matrix().to_cols_array_2d(),
+            texture_index: instance.texture_index,
+            color_tint: instance.color,
+            render_queue: QUEUE_OPAQUE,
+            pick_id: NO_PICK_ID,
+            custom: [0.0; 4],
+        }
+    }
+}
+
+impl InstanceData {
+    /// Pack a transform, texture and tint for the GPU
+    pub fn new(transform: Mat4, texture_index: u32, color_tint: Color) -> Self {
+        Self {
+            transform: transform.to_cols_array_2d(),
+            texture_index,
+            color_tint: pack_color(color_tint),
+            render_queue: QUEUE_OPAQUE,
+            pick_id: NO_PICK_ID,
+            custom: [0.0; 4],
+        }
+    }
+
+    /// Attach per-instance shader data
+    pub fn with_custom(self, custom: [f32; 4]) -> Self {
+        Self { custom, ..self }
+    }
+
+    /// Draw in `render_queue` (usually the material's `ManagedMaterial::render_queue`)
+    pub fn with_render_queue(self, render_queue: i32) -> Self {
+        Self { render_queue, ..self }
+    }
+
+    /// Make the instance pickable as `pick_id` (see `UltraRenderer::pick_at`)
+    pub fn with_pick_id(self, pick_id: u32) -> Self {
+        Self { pick_id, ..self }
+    }
+
+    /// World-space box around the unit cube this instance transforms (instances are block-sized meshes)
+    pub fn bounds(&self) -> BoundingBox {
+        let transform = Mat4::from_cols_array_2d(&self.transform);
+        let center = transform.w_axis.truncate();
+        let half_extent = Vec3::new(
+            transform.x_axis.x.abs() + transform.y_axis.x.abs() + transform.z_axis.x.abs(),
+            transform.x_axis.y.abs() + transform.y_axis.y.abs() + transform.z_axis.y.abs(),
+            transform.x_axis.z.abs() + transform.y_axis.z.abs() + transform.z_axis.z.abs(),
+        ) * 0.5;
+        BoundingBox::new(center - half_extent, center + half_extent)
+    }
 }
 
 impl TextureAtlas {
-    fn new(atlas_size: u32, tile_size: u32) -> Self {
+    /// Create an atlas of square tiles with exact tile boundaries (for point-sampled atlases)
+    pub fn new(atlas_size: u32, tile_size: u32) -> Self {
+        let mut atlas = Self {
+            atlas_size,
+            tile_size,
+            inset: 0.0,
+            texture_coords: Vec::new(),
+        };
+        atlas.compute_coords();
+        atlas
+    }
+
+    /// Shrink every tile's UV rect by `texels` on each side (0.5 stops bilinear bleeding)
+    ///
+    /// Clamped to less than half a tile. Mipmapped atlases bleed again at coarser levels unless
+    /// the mip chain is limited or tiles are padded.
+    pub fn with_inset(mut self, texels: f32) -> Self {
+        self.inset = texels.clamp(0.0, self.tile_size as f32 * 0.5 - f32::EPSILON);
+        self.compute_coords();
+        self
+    }
+
+    fn compute_coords(&mut self) {
+        let (atlas_size, tile_size) = (self.atlas_size, self.tile_size);
         let tiles_per_row = atlas_size / tile_size;
         let total_tiles = tiles_per_row * tiles_per_row;
+        let inset = self.inset / atlas_size as f32;
         let mut texture_coords = Vec::with_capacity(total_tiles as usize);
 
         // Pre-calculate texture coordinates for all tiles
         for y in 0..tiles_per_row {
             for x in 0..tiles_per_row {
-                let u_min = (x * tile_size) as f32 / atlas_size as f32;
-                let v_min = (y * tile_size) as f32 / atlas_size as f32;
-                let u_max = ((x + 1) * tile_size) as f32 / atlas_size as f32;
-                let v_max = ((y + 1) * tile_size) as f32 / atlas_size as f32;
+                let u_min = (x * tile_size) as f32 / atlas_size as f32 + inset;
+                let v_min = (y * tile_size) as f32 / atlas_size as f32 + inset;
+                let u_max = ((x + 1) * tile_size) as f32 / atlas_size as f32 - inset;
+                let v_max = ((y + 1) * tile_size) as f32 / atlas_size as f32 - inset;
 
                 texture_coords.push(TextureCoords {
                     u_min,
@@ -147,11 +936,7 @@ impl TextureAtlas {
             }
         }
 
-        Self {
-            atlas_size,
-            tile_size,
-            texture_coords,
-        }
+        self.texture_coords = texture_coords;
     }
 
     /// Get texture coordinates for a specific tile index
@@ -160,34 +945,223 @@ impl TextureAtlas {
     }
 }
 
+impl LodMesh {
+    /// Create a LOD mesh using distance-based selection
+    pub fn new(levels: Vec<LodLevel>) -> Self {
+        Self {
+            levels,
+            selection: LodSelection::Distance,
+        }
+    }
+
+    /// Index of the first level whose max distance covers the given distance
+    pub fn select_by_distance(&self, distance: f32) -> usize {
+        self.levels
+            .iter()
+            .position(|level| distance <= level.max_distance)
+            .unwrap_or(self.levels.len().saturating_sub(1))
+    }
+
+    /// Index of the first level whose minimum screen size the object still reaches
+    pub fn select_by_screen_size(&self, screen_size: f32) -> usize {
+        self.levels
+            .iter()
+            .position(|level| screen_size >= level.min_screen_size)
+            .unwrap_or(self.levels.len().saturating_sub(1))
+    }
+
+    /// Select a level for the given camera using the configured selection mode
+    pub fn select(&self, camera: &CameraController, bounds: &BoundingSphere, viewport_height: f32) -> usize {
+        self.select_with_bias(camera, bounds, viewport_height, 0.0)
+    }
+
+    /// Select a level as if the object were `bias` world units farther away (negative biases are ignored)
+    pub fn select_with_bias(&self, camera: &CameraController, bounds: &BoundingSphere, viewport_height: f32, bias: f32) -> usize {
+        let bias = bias.max(0.0);
+        let distance = bounds.center.distance(camera.transform.translation);
+        match self.selection {
+            LodSelection::Distance => self.select_by_distance(distance + bias),
+            LodSelection::ScreenSize => {
+                // Projected size falls off as 1 / distance
+                let scale = if bias > 0.0 { distance / (distance + bias) } else { 1.0 };
+                self.select_by_screen_size(camera.projected_size(bounds, viewport_height) * scale)
+            }
+        }
+    }
+}
+
 impl CullingSystem {
     fn new() -> Self {
         Self {
             frustum_culling: true,
-            occlusion_culling: true,
+            occlusion_method: OcclusionMethod::default(),
             distance_culling: true,
             max_render_distance: 500.0,
+            vertical_render_distance: None, // Spherical
+            cull_margin: 1.0, // One block of slack around the screen edges
+            occlusion_queries: OcclusionQueries::default(),
+            hi_z: HiZBuffer::default(),
+            min_occluder_screen_size: 0.05, // Smaller objects cost Hi-Z time without hiding much
+            occlusion_culling: true, // Free until occluders are added
+            occluders: Vec::new(),
+            teleport_distance: 16.0, // About a second of sprinting in one frame
+            cut_angle: std::f32::consts::FRAC_PI_4,
+            last_camera: None,
+            occlusion_warmup: false,
+        }
+    }
+
+    /// Switch to cylinder culling with this horizontal radius
+    ///
+    /// The vertical half-height starts at the previous spherical distance until `set_render_distance_y`.
+    pub fn set_render_distance_xz(&mut self, distance: f32) {
+        self.vertical_render_distance.get_or_insert(self.max_render_distance);
+        self.max_render_distance = distance;
+    }
+
+    /// Switch to cylinder culling with this vertical half-height
+    pub fn set_render_distance_y(&mut self, distance: f32) {
+        self.vertical_render_distance = Some(distance);
+    }
+
+    /// Whether an object is beyond the render distance (sphere, or cylinder when a vertical distance is set)
+    pub fn is_beyond_render_distance(&self, position: Vec3, camera_position: Vec3) -> bool {
+        let offset = position - camera_position;
+        match self.vertical_render_distance {
+            None => offset.length() > self.max_render_distance,
+            Some(vertical) => offset.xz().length() > self.max_render_distance || offset.y.abs() > vertical,
         }
     }
 
-    /// Check if an object should be culled based on position and bounds
-    pub fn should_cull(&self, position: Vec3, camera_position: Vec3, camera_frustum: &Frustum) -> bool {
+    /// Check if an object should be culled: its center beyond the render distance, or its whole
+    /// box outside a frustum plane
+    pub fn should_cull(&self, bounds: &BoundingBox, camera_position: Vec3, frustum: &Frustum) -> bool {
         // Distance culling
-        if self.distance_culling {
-            let distance = position.distance(camera_position);
-            if distance > self.max_render_distance {
-                return true;
-            }
+        if self.distance_culling && self.is_beyond_render_distance(bounds.center(), camera_position) {
+            return true;
         }
 
-        // Frustum culling (simplified - would use proper frustum intersection in full implementation)
+        // Frustum culling against planes pushed outward by the cull margin
         if self.frustum_culling {
-            // TODO: Implement proper frustum culling
-            // For now, just a placeholder
+            let outside = frustum.planes.iter().any(|plane| {
+                // The corner furthest along the plane normal; if even it is behind, the whole box is
+                let normal = plane.truncate();
+                let corner = Vec3::select(normal.cmpge(Vec3::ZERO), bounds.max, bounds.min);
+                normal.dot(corner) + plane.w + self.cull_margin < 0.0
+            });
+            if outside {
+                return true;
+            }
         }
 
         false
     }
+
+    /// Rasterize an object into the Hi-Z buffer if it projects larger than `min_occluder_screen_size`
+    ///
+    /// Returns whether it was rasterized. Call `hi_z.clear()` before the frame's occluders and
+    /// `hi_z.build_pyramid()` after them; every object can still be tested with `is_occluded_by_hi_z`.
+    pub fn add_occluder(&mut self, bounds: &BoundingBox, view_projection: Mat4) -> bool {
+        let large_enough = HiZBuffer::screen_size(bounds, view_projection)
+            .is_some_and(|size| size > self.min_occluder_screen_size);
+        if large_enough {
+            self.hi_z.rasterize_box(bounds, view_projection);
+        }
+        large_enough
+    }
+
+    /// Set the screen fraction (0-1) below which objects aren't rasterized as occluders
+    pub fn set_min_occluder_screen_size(&mut self, screen_size: f32) {
+        self.min_occluder_screen_size = screen_size.clamp(0.0, 1.0);
+    }
+
+    /// Check if an object is hidden behind this frame's Hi-Z occluders (SoftwareHiZ only)
+    pub fn is_occluded_by_hi_z(&self, bounds: &BoundingBox, view_projection: Mat4) -> bool {
+        self.occlusion_method == OcclusionMethod::SoftwareHiZ && self.hi_z.is_occluded(bounds, view_projection)
+    }
+
+    /// Check if an object is completely hidden behind one of `occluders`, seen from `camera_position`
+    ///
+    /// Conservative: it's hidden only if the sight line to every corner of its box passes through
+    /// the same occluder (which, both being convex, hides the whole box). Objects hidden only by
+    /// several occluders together, or by other geometry, are reported visible. Occluders
+    /// containing the camera, or overlapping the object (such as the wall's own instance), are
+    /// skipped. Not called `is_occluded`: that name already answers for query-tracked `OcclusionId`s.
+    pub fn is_hidden_by_occluders(&self, target: &BoundingBox, camera_position: Vec3) -> bool {
+        self.occluders.iter().any(|occluder| {
+            !occluder.contains_point(camera_position)
+                && !boxes_overlap(occluder, target)
+                && box_corners(target).iter().all(|&corner| segment_hits_box(camera_position, corner, occluder))
+        })
+    }
+
+    /// Record this frame's camera, returning whether it teleported or cut since the last frame
+    ///
+    /// Query results describe the previous view, so after a jump they'd hide objects that are now
+    /// in plain sight. For that one frame `is_occluded` reports nothing (everything in the frustum
+    /// draws and is queried again) and the stale history is dropped. The renderer calls this
+    /// from the first pass of each frame.
+    pub fn track_camera(&mut self, position: Vec3, rotation: Quat) -> bool {
+        let jumped = self.last_camera.is_none_or(|(last_position, last_rotation)| {
+            last_position.distance(position) > self.teleport_distance || last_rotation.angle_between(rotation) > self.cut_angle
+        });
+        self.last_camera = Some((position, rotation));
+        self.occlusion_warmup = jumped;
+        if jumped {
+            self.occlusion_queries.history.clear();
+        }
+        jumped
+    }
+
+    /// Whether occlusion culling is paused this frame after a teleport or cut (see `track_camera`)
+    pub fn is_warming_up(&self) -> bool {
+        self.occlusion_warmup
+    }
+
+    /// Check if an object was hidden behind other geometry (as of the last resolved occlusion test)
+    ///
+    /// Always false on the frame after a teleport or cut.
+    pub fn is_occluded(&self, id: OcclusionId) -> bool {
+        if self.occlusion_warmup {
+            return false;
+        }
+        match self.occlusion_method {
+            OcclusionMethod::HardwareQuery => self.occlusion_queries.is_occluded(id),
+            OcclusionMethod::None | OcclusionMethod::SoftwareHiZ => false,
+        }
+    }
+}
+
+/// The eight corners of a box
+fn box_corners(bounds: &BoundingBox) -> [Vec3; 8] {
+    std::array::from_fn(|i| Vec3::select(BVec3::new(i & 1 != 0, i & 2 != 0, i & 4 != 0), bounds.max, bounds.min))
+}
+
+/// Whether two boxes overlap or touch
+fn boxes_overlap(a: &BoundingBox, b: &BoundingBox) -> bool {
+    a.min.cmple(b.max).all() && b.min.cmple(a.max).all()
+}
+
+/// Whether the segment from `start` to `end` touches a box (slab test)
+fn segment_hits_box(start: Vec3, end: Vec3, bounds: &BoundingBox) -> bool {
+    let direction = end - start;
+    let (mut enter, mut exit) = (0.0_f32, 1.0_f32);
+    for axis in 0..3 {
+        if direction[axis].abs() < f32::EPSILON {
+            if start[axis] < bounds.min[axis] || start[axis] > bounds.max[axis] {
+                return false; // Parallel to this slab and outside it
+            }
+            continue;
+        }
+        let a = (bounds.min[axis] - start[axis]) / direction[axis];
+        let b = (bounds.max[axis] - start[axis]) / direction[axis];
+        enter = enter.max(a.min(b));
+        exit = exit.min(a.max(b));
+        if enter > exit {
+            return false;
+        }
+    }
+    true
 }
 
 /// Pack Color into u32 for efficient GPU transfer
@@ -200,7 +1174,62 @@ fn pack_color(color: Color) -> u32 {
     (a << 24) | (b << 16) | (g << 8) | r
 }
 
-/// Placeholder frustum structure (would be more complex in full implementation)
+/// Copy renderer-facing quality settings into `UltraRenderer` when `QualitySettings` changes
+pub fn render_quality_system(quality: Res<QualitySettings>, mut renderer: ResMut<UltraRenderer>) {
+    renderer.lod_bias = quality.lod_bias;
+    renderer.set_render_scale(quality.render_scale);
+    renderer.culling_system.set_render_distance_xz(quality.render_distance);
+    renderer.particles.particle_density = quality.particle_density.clamp(0.0, 1.0);
+}
+
+/// Convert a screen rectangle (logical top-left origin) into a pixel-aligned GPU viewport
+pub fn viewport_from_rect(rect: Rect) -> Viewport {
+    let min = rect.min.round().max(Vec2::ZERO);
+    let max = rect.max.round().max(min);
+
+    Viewport {
+        physical_position: min.as_uvec2(),
+        physical_size: (max - min).as_uvec2(),
+        depth: 0.0..1.0,
+    }
+}
+
+/// View frustum as six inward-facing planes
+#[derive(Debug, Clone, Copy)]
 pub struct Frustum {
-    pub planes: [Vec4; 6], // 6 frustum planes
+    pub planes: [Vec4; 6], // xyz = unit normal, w = signed distance from origin
+}
+
+impl Frustum {
+    /// Extract the planes of a combined `projection * view` matrix (Gribb/Hartmann, 0-1 clip depth)
+    ///
+    /// Planes come from sums and differences of the matrix rows and are normalized, so `w` is a
+    /// distance in world units. Order: left, right, bottom, top, then the `z <= w` and `z >= 0`
+    /// depth planes (near and far with Bevy's reverse-Z). An infinite far plane has no normal and
+    /// is stored as a plane everything is inside.
+    pub fn from_view_projection(view_projection: Mat4) -> Self {
+        let [row0, row1, row2, row3] = [0, 1, 2, 3].map(|index| view_projection.row(index));
+        let planes = [row3 + row0, row3 - row0, row3 + row1, row3 - row1, row3 - row2, row2].map(|plane| {
+            let length = plane.truncate().length();
+            if length > f32::EPSILON {
+                plane / length
+            } else {
+                Vec4::new(0.0, 0.0, 0.0, f32::MAX)
+            }
+        });
+        Self { planes }
+    }
+
+    /// Whether a point is inside (or on) every plane
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes.iter().all(|plane| plane.truncate().dot(point) + plane.w >= 0.0)
+    }
+}
+
+impl From<bevy::render::primitives::Frustum> for Frustum {
+    fn from(frustum: bevy::render::primitives::Frustum) -> Self {
+        Self {
+            planes: frustum.half_spaces.map(|half_space| half_space.normal_d()),
+        }
+    }
 }
\ No newline at end of file
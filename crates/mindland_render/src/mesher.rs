@@ -0,0 +1,275 @@
+//! Voxel chunk meshing
+//!
+//! Greedy meshing of 32³ chunks, runnable on worker threads so chunk updates never hitch the main thread.
+
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use std::thread::JoinHandle;
+
+/// Chunk edge length in blocks
+pub const CHUNK_SIZE: usize = 32;
+
+/// Block type identifier (0 is air)
+pub type BlockId = u16;
+
+/// Empty block
+pub const AIR: BlockId = 0;
+
+/// Owned snapshot of a chunk's blocks, sent by value to mesh workers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkData {
+    pub blocks: Box<[BlockId]>, // CHUNK_SIZE³, x-fastest then y then z
+}
+
+/// Identifier for a queued meshing job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MeshJobId(pub u64);
+
+/// Off-thread chunk mesher backed by a small worker pool
+pub struct ChunkMesher {
+    job_sender: Option<Sender<(MeshJobId, ChunkData)>>,
+    result_receiver: Receiver<(MeshJobId, Mesh)>,
+    workers: Vec<JoinHandle<()>>,
+    next_job_id: u64,
+    pending_jobs: usize,
+}
+
+impl Default for ChunkData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChunkData {
+    /// Create an all-air chunk
+    pub fn new() -> Self {
+        Self {
+            blocks: vec![AIR; CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE].into_boxed_slice(),
+        }
+    }
+
+    /// Get a block (coordinates must be within the chunk)
+    pub fn get(&self, x: usize, y: usize, z: usize) -> BlockId {
+        self.blocks[Self::index(x, y, z)]
+    }
+
+    /// Set a block (coordinates must be within the chunk)
+    pub fn set(&mut self, x: usize, y: usize, z: usize, block: BlockId) {
+        self.blocks[Self::index(x, y, z)] = block;
+    }
+
+    /// Get a block, treating anything outside the chunk as air
+    fn get_or_air(&self, position: [i32; 3]) -> BlockId {
+        let size = CHUNK_SIZE as i32;
+        if position.iter().any(|&coord| coord < 0 || coord >= size) {
+            return AIR;
+        }
+        self.get(position[0] as usize, position[1] as usize, position[2] as usize)
+    }
+
+    fn index(x: usize, y: usize, z: usize) -> usize {
+        x + y * CHUNK_SIZE + z * CHUNK_SIZE * CHUNK_SIZE
+    }
+}
+
+impl Default for ChunkMesher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChunkMesher {
+    /// Create a mesher leaving one core free for the main thread
+    pub fn new() -> Self {
+        let cores = std::thread::available_parallelism().map_or(2, |cores| cores.get());
+        Self::with_workers(cores.saturating_sub(1).max(1))
+    }
+
+    /// Create a mesher with an explicit number of worker threads
+    pub fn with_workers(worker_count: usize) -> Self {
+        let (job_sender, job_receiver) = unbounded::<(MeshJobId, ChunkData)>();
+        let (result_sender, result_receiver) = unbounded();
+
+        let workers = (0..worker_count.max(1))
+            .map(|index| {
+                let jobs = job_receiver.clone();
+                let results = result_sender.clone();
+                std::thread::Builder::new()
+                    .name(format!("mindland-mesher-{index}"))
+                    .spawn(move || {
+                        // Exits once the mesher drops its sender
+                        for (job_id, chunk) in jobs {
+                            if results.send((job_id, greedy_mesh(&chunk))).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .expect("failed to spawn mesher thread")
+            })
+            .collect();
+
+        Self {
+            job_sender: Some(job_sender),
+            result_receiver,
+            workers,
+            next_job_id: 0,
+            pending_jobs: 0,
+        }
+    }
+
+    /// Queue a chunk snapshot for meshing on a worker thread
+    pub fn request(&mut self, chunk: ChunkData) -> MeshJobId {
+        let job_id = MeshJobId(self.next_job_id);
+        self.next_job_id += 1;
+
+        if let Some(sender) = &self.job_sender {
+            // Workers only exit after the sender is dropped, so this cannot fail
+            let _ = sender.send((job_id, chunk));
+            self.pending_jobs += 1;
+        }
+        job_id
+    }
+
+    /// Collect finished meshes (call on the main thread, then upload to the GPU)
+    pub fn poll_finished(&mut self) -> Vec<(MeshJobId, Mesh)> {
+        let finished: Vec<_> = self.result_receiver.try_iter().collect();
+        self.pending_jobs -= finished.len();
+        finished
+    }
+
+    /// Number of requested jobs not yet collected
+    pub fn pending_jobs(&self) -> usize {
+        self.pending_jobs
+    }
+}
+
+impl Drop for ChunkMesher {
+    fn drop(&mut self) {
+        self.job_sender = None; // Closes the queue so workers exit
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Build a mesh for a chunk, merging coplanar faces of the same block into larger quads
+pub fn greedy_mesh(chunk: &ChunkData) -> Mesh {
+    let size = CHUNK_SIZE as i32;
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    // Visible face per cell of the current slice: (block, faces positive axis direction)
+    let mut mask: Vec<Option<(BlockId, bool)>> = vec![None; CHUNK_SIZE * CHUNK_SIZE];
+
+    for axis in 0..3 {
+        let u = (axis + 1) % 3;
+        let v = (axis + 2) % 3;
+        let mut step = [0i32; 3];
+        step[axis] = 1;
+
+        let mut cursor = [0i32; 3];
+        cursor[axis] = -1;
+        while cursor[axis] < size {
+            // Build the face mask between slice `cursor[axis]` and the next one
+            let mut n = 0;
+            for cv in 0..size {
+                cursor[v] = cv;
+                for cu in 0..size {
+                    cursor[u] = cu;
+                    let behind = chunk.get_or_air(cursor);
+                    let ahead = chunk.get_or_air([
+                        cursor[0] + step[0],
+                        cursor[1] + step[1],
+                        cursor[2] + step[2],
+                    ]);
+                    mask[n] = match (behind != AIR, ahead != AIR) {
+                        (true, false) => Some((behind, true)),
+                        (false, true) => Some((ahead, false)),
+                        _ => None,
+                    };
+                    n += 1;
+                }
+            }
+            cursor[axis] += 1;
+
+            // Greedily merge the mask into rectangles
+            n = 0;
+            for j in 0..size {
+                let mut i = 0;
+                while i < size {
+                    let Some(face) = mask[n] else {
+                        i += 1;
+                        n += 1;
+                        continue;
+                    };
+
+                    let mut width = 1;
+                    while i + width < size && mask[n + width as usize] == Some(face) {
+                        width += 1;
+                    }
+
+                    let mut height = 1;
+                    'grow: while j + height < size {
+                        for k in 0..width {
+                            if mask[n + (k + height * size) as usize] != Some(face) {
+                                break 'grow;
+                            }
+                        }
+                        height += 1;
+                    }
+
+                    cursor[u] = i;
+                    cursor[v] = j;
+                    let mut du = [0i32; 3];
+                    du[u] = width;
+                    let mut dv = [0i32; 3];
+                    dv[v] = height;
+
+                    let base = positions.len() as u32;
+                    let corner = |offset_u: bool, offset_v: bool| -> [f32; 3] {
+                        std::array::from_fn(|c| {
+                            (cursor[c]
+                                + if offset_u { du[c] } else { 0 }
+                                + if offset_v { dv[c] } else { 0 }) as f32
+                        })
+                    };
+                    positions.extend([corner(false, false), corner(true, false), corner(true, true), corner(false, true)]);
+
+                    let positive = face.1;
+                    let mut normal = [0.0; 3];
+                    normal[axis] = if positive { 1.0 } else { -1.0 };
+                    normals.extend([normal; 4]);
+                    uvs.extend([[0.0, 0.0], [width as f32, 0.0], [width as f32, height as f32], [0.0, height as f32]]);
+
+                    // u x v points along +axis, so flip the winding for negative-facing quads
+                    if positive {
+                        indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+                    } else {
+                        indices.extend([base, base + 2, base + 1, base, base + 3, base + 2]);
+                    }
+
+                    for h in 0..height {
+                        for k in 0..width {
+                            mask[n + (k + h * size) as usize] = None;
+                        }
+                    }
+
+                    i += width;
+                    n += width as usize;
+                }
+            }
+        }
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
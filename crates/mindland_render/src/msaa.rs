@@ -0,0 +1,105 @@
+//! MSAA target selection
+//!
+//! The multisampled color target resolves into a single-sample target of the same format.
+//! Not every format supports every sample count (or resolving at all), so the final
+//! sample count and format are picked from what the adapter reports.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use wgpu::{TextureFormatFeatureFlags, TextureFormatFeatures};
+
+/// Largest sample count wgpu exposes
+const MAX_MSAA_SAMPLES: u32 = 16;
+
+/// Chosen multisampled color target; the resolve target uses the same format with one sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsaaTarget {
+    pub format: TextureFormat,
+    pub sample_count: u32,  // 1 = MSAA off, no resolve step
+    pub surface_format: TextureFormat,
+}
+
+impl MsaaTarget {
+    /// Single-sample target matching the surface (no MSAA)
+    pub fn disabled(surface_format: TextureFormat) -> Self {
+        Self {
+            format: surface_format,
+            sample_count: 1,
+            surface_format,
+        }
+    }
+
+    /// Format of the single-sample resolve target
+    pub fn resolve_format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// Whether a resolve pass runs at all
+    pub fn needs_resolve(&self) -> bool {
+        self.sample_count > 1
+    }
+
+    /// Whether the resolved image must be copied to the surface (format differs from the swapchain)
+    pub fn needs_present_blit(&self) -> bool {
+        self.format != self.surface_format
+    }
+
+    /// Pick a sample count and format for `requested_samples`, given adapter format features
+    ///
+    /// The surface's preferred format is kept whenever it supports any MSAA, since switching
+    /// formats adds a blit to present; only then are the sRGB/linear counterpart and the common
+    /// 8-bit swapchain formats tried. Each format uses the highest supported count not above the
+    /// request. Pass `|format| adapter.get_texture_format_features(format)` for `features`.
+    pub fn select(
+        surface_format: TextureFormat,
+        requested_samples: u32,
+        features: impl Fn(TextureFormat) -> TextureFormatFeatures,
+    ) -> Self {
+        let requested = floor_power_of_two(requested_samples.clamp(1, MAX_MSAA_SAMPLES));
+        if requested == 1 {
+            return Self::disabled(surface_format);
+        }
+
+        let alternate = if surface_format.is_srgb() {
+            surface_format.remove_srgb_suffix()
+        } else {
+            surface_format.add_srgb_suffix()
+        };
+        let mut candidates = vec![surface_format];
+        for format in [alternate, TextureFormat::Bgra8UnormSrgb, TextureFormat::Rgba8UnormSrgb] {
+            if !candidates.contains(&format) {
+                candidates.push(format);
+            }
+        }
+
+        for format in candidates {
+            let flags = features(format).flags;
+            if !flags.contains(TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE) {
+                continue;
+            }
+            let supported = std::iter::successors(Some(requested), |&count| (count > 2).then_some(count / 2))
+                .find(|&count| flags.sample_count_supported(count));
+
+            if let Some(sample_count) = supported {
+                let target = Self { format, sample_count, surface_format };
+                if sample_count != requested || format != surface_format {
+                    warn!(
+                        "MSAA {}x on {:?} unsupported; using {}x on {:?}",
+                        requested, surface_format, sample_count, format
+                    );
+                } else {
+                    info!("MSAA {}x on {:?}", sample_count, format);
+                }
+                return target;
+            }
+        }
+
+        warn!("No MSAA support for {:?}; rendering without MSAA", surface_format);
+        Self::disabled(surface_format)
+    }
+}
+
+/// Largest power of two not above `value` (value >= 1)
+fn floor_power_of_two(value: u32) -> u32 {
+    1 << (u32::BITS - 1 - value.leading_zeros())
+}
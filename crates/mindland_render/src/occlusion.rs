@@ -0,0 +1,104 @@
+//! Hardware occlusion query bookkeeping
+//!
+//! Each frame, candidate objects' bounding boxes are drawn depth-only with an occlusion query.
+//! Results are read back a frame later, so culling uses the previous frame's answer.
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use mindland_assets::BoundingBox;
+
+/// How `CullingSystem` rejects objects hidden behind other geometry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OcclusionMethod {
+    /// No occlusion culling
+    None,
+    /// Hierarchical-Z test against a CPU depth pyramid of large occluders (see `HiZBuffer`)
+    SoftwareHiZ,
+    /// GPU occlusion queries on bounding boxes, one frame of latency
+    #[default]
+    HardwareQuery,
+}
+
+/// Stable per-object key for query history (entity bits, chunk index, ...)
+pub type OcclusionId = u64;
+
+/// Last known query outcome for an object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OcclusionVisibility {
+    #[default]
+    Visible, // Also the state for objects never queried, so they draw until proven hidden
+    Occluded,
+}
+
+/// Per-object query history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OcclusionHistory {
+    pub visibility: OcclusionVisibility,
+    pub frames_in_state: u32, // Resolved frames since the last transition
+}
+
+/// Queries issued this frame and results from previous frames
+#[derive(Debug, Clone, Default)]
+pub struct OcclusionQueries {
+    pub history: HashMap<OcclusionId, OcclusionHistory>,
+    pending: Vec<(OcclusionId, BoundingBox)>,
+}
+
+impl OcclusionHistory {
+    /// Apply one resolved query (any passing samples means visible)
+    pub fn record(&mut self, samples_passed: u64) {
+        let visibility = if samples_passed > 0 {
+            OcclusionVisibility::Visible
+        } else {
+            OcclusionVisibility::Occluded
+        };
+
+        if visibility == self.visibility {
+            self.frames_in_state = self.frames_in_state.saturating_add(1);
+        } else {
+            self.visibility = visibility;
+            self.frames_in_state = 0;
+        }
+    }
+
+    /// Whether the object was fully hidden as of the last resolved query
+    pub fn is_occluded(&self) -> bool {
+        self.visibility == OcclusionVisibility::Occluded
+    }
+}
+
+impl OcclusionQueries {
+    /// Request a query for an object's bounds this frame
+    ///
+    /// A box containing the camera would be clipped by the near plane and report no samples,
+    /// so such objects are marked visible directly instead of queried.
+    pub fn issue(&mut self, id: OcclusionId, bounds: BoundingBox, camera_position: Vec3) {
+        if bounds.contains_point(camera_position) {
+            self.history.entry(id).or_default().record(1);
+            return;
+        }
+        self.pending.push((id, bounds));
+    }
+
+    /// Take this frame's queries for the depth-only bounding box pass
+    pub fn take_queries(&mut self) -> Vec<(OcclusionId, BoundingBox)> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Feed back query results (samples passed per object) once the GPU has them
+    pub fn resolve(&mut self, results: impl IntoIterator<Item = (OcclusionId, u64)>) {
+        for (id, samples_passed) in results {
+            self.history.entry(id).or_default().record(samples_passed);
+        }
+    }
+
+    /// Whether an object's last resolved query found it fully hidden
+    pub fn is_occluded(&self, id: OcclusionId) -> bool {
+        self.history.get(&id).is_some_and(OcclusionHistory::is_occluded)
+    }
+
+    /// Drop history for an object that no longer exists
+    pub fn forget(&mut self, id: OcclusionId) {
+        self.history.remove(&id);
+    }
+}
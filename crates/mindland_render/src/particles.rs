@@ -0,0 +1,175 @@
+//! Particle emitters
+//!
+//! Emission scales with the global `particle_density` (from `QualitySettings`, so thermal
+//! reductions apply) and fades with distance: full density up to `full_density_distance`,
+//! thinning continuously to nothing at `cull_distance`. Particles are simulated on the CPU, or
+//! on the GPU by a compute pass (see `GpuParticles`) when the adapter supports it.
+
+use crate::GpuParticles;
+use bevy::prelude::*;
+
+/// Where live particles are simulated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParticleBackend {
+    #[default]
+    Cpu, // `particles` holds them, updated on the main thread
+    Gpu, // A compute pass updates them; `gpu` only tracks slots and uploads spawns
+}
+
+/// A source of particles
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParticleEmitter {
+    pub position: Vec3,
+    pub rate: f32,          // Particles per second at full density
+    pub velocity: Vec3,     // Initial particle velocity
+    pub lifetime: f32,      // Seconds each particle lives
+    pub spawn_debt: f32,    // Fractional particles carried to the next update
+}
+
+/// A live particle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub age: f32,
+    pub lifetime: f32,
+}
+
+/// Emitters and their live particles
+#[derive(Debug, Clone)]
+pub struct ParticleSystem {
+    pub emitters: Vec<ParticleEmitter>,
+    pub particles: Vec<Particle>,
+    pub particle_density: f32,       // Global multiplier, 0.0-1.0; `render_quality_system` copies `QualitySettings::particle_density` here
+    pub full_density_distance: f32,  // Emitters this close to the camera aren't thinned
+    pub cull_distance: f32,          // Emitters this far away emit nothing
+    pub max_particles: usize,
+    pub gpu: GpuParticles, // Slot bookkeeping and the frame's dispatch, used by `ParticleBackend::Gpu`
+    backend: ParticleBackend,
+}
+
+impl ParticleEmitter {
+    /// Emitter with no initial velocity
+    pub fn new(position: Vec3, rate: f32, lifetime: f32) -> Self {
+        Self {
+            position,
+            rate,
+            velocity: Vec3::ZERO,
+            lifetime,
+            spawn_debt: 0.0,
+        }
+    }
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParticleSystem {
+    /// Create an empty system: full density within 32 blocks, culled beyond 64
+    pub fn new() -> Self {
+        Self {
+            emitters: Vec::new(),
+            particles: Vec::new(),
+            particle_density: 1.0,
+            full_density_distance: 32.0,
+            cull_distance: 64.0,
+            max_particles: 10_000,
+            gpu: GpuParticles::default(),
+            backend: ParticleBackend::Cpu,
+        }
+    }
+
+    /// Where particles are simulated
+    pub fn backend(&self) -> ParticleBackend {
+        self.backend
+    }
+
+    /// Switch simulation backend; live particles are dropped when it changes (emitters keep emitting)
+    pub fn set_backend(&mut self, backend: ParticleBackend) {
+        if backend != self.backend {
+            self.particles.clear();
+            self.gpu.clear();
+            self.backend = backend;
+        }
+    }
+
+    /// Live particles on either backend
+    pub fn live_count(&self) -> usize {
+        match self.backend {
+            ParticleBackend::Cpu => self.particles.len(),
+            ParticleBackend::Gpu => self.gpu.live_count(),
+        }
+    }
+
+    /// Add an emitter, returning its index
+    pub fn add_emitter(&mut self, emitter: ParticleEmitter) -> usize {
+        self.emitters.push(emitter);
+        self.emitters.len() - 1
+    }
+
+    /// Distance thinning factor: 1.0 up to `full_density_distance`, falling smoothly to 0.0 at `cull_distance`
+    pub fn distance_density(&self, distance: f32) -> f32 {
+        if distance <= self.full_density_distance {
+            return 1.0;
+        }
+        if distance >= self.cull_distance {
+            return 0.0;
+        }
+        let t = (distance - self.full_density_distance) / (self.cull_distance - self.full_density_distance);
+        1.0 - t * t * (3.0 - 2.0 * t) // Smoothstep, so there's no visible ring where thinning starts
+    }
+
+    /// Effective density for an emitter: global density times distance thinning
+    pub fn emitter_density(&self, emitter: &ParticleEmitter, camera_position: Vec3) -> f32 {
+        self.particle_density.clamp(0.0, 1.0) * self.distance_density(emitter.position.distance(camera_position))
+    }
+
+    /// Age and move particles, then emit new ones; returns how many were emitted
+    ///
+    /// With `ParticleBackend::Gpu` this only prepares `gpu` for the frame's compute dispatch.
+    pub fn update(&mut self, delta_time: f32, camera_position: Vec3) -> usize {
+        let delta_time = delta_time.max(0.0);
+        match self.backend {
+            ParticleBackend::Cpu => self.particles.retain_mut(|particle| {
+                particle.age += delta_time;
+                particle.position += particle.velocity * delta_time;
+                particle.age < particle.lifetime
+            }),
+            ParticleBackend::Gpu => self.gpu.begin_frame(delta_time),
+        }
+
+        let mut emitted = 0;
+        for index in 0..self.emitters.len() {
+            let density = self.emitter_density(&self.emitters[index], camera_position);
+            let emitter = &mut self.emitters[index];
+            if density <= 0.0 {
+                emitter.spawn_debt = 0.0; // Don't burst when the camera comes back into range
+                continue;
+            }
+
+            emitter.spawn_debt += emitter.rate * density * delta_time;
+            let count = emitter.spawn_debt.floor();
+            emitter.spawn_debt -= count;
+
+            let room = self.max_particles.saturating_sub(self.live_count());
+            let count = (count as usize).min(room);
+            let emitter = &self.emitters[index];
+            match self.backend {
+                ParticleBackend::Cpu => self.particles.extend((0..count).map(|_| Particle {
+                    position: emitter.position,
+                    velocity: emitter.velocity,
+                    age: 0.0,
+                    lifetime: emitter.lifetime,
+                })),
+                ParticleBackend::Gpu => {
+                    (0..count).for_each(|_| self.gpu.spawn(emitter.position, emitter.velocity, emitter.lifetime));
+                }
+            }
+            emitted += count;
+        }
+        emitted
+    }
+}
@@ -0,0 +1,160 @@
+//! Pick-id render target for mouse picking
+//!
+//! Instances with a nonzero `InstanceData::pick_id` are drawn a second time into an `R32Uint`
+//! target, each fragment writing its instance's id under the usual depth test, so the id at a
+//! pixel is whatever is visible there. Like `HiZBuffer`, the pass is rasterized on the CPU, here
+//! from each instance's `mesh_bounds` box.
+//!
+//! At the end of the frame the target is copied out and mapped asynchronously; `pick_at` reads
+//! the newest mapped copy, so an answer trails rendering by a frame rather than stalling it.
+
+use crate::hiz::{box_corners, edge, BOX_TRIANGLES, MIN_CLIP_W};
+use bevy::{prelude::*, render::render_resource::TextureFormat};
+use mindland_assets::BoundingBox;
+
+/// Pick id of instances that can't be picked (and of empty pixels)
+pub const NO_PICK_ID: u32 = 0;
+
+/// Format of the pick target
+pub const PICK_TARGET_FORMAT: TextureFormat = TextureFormat::R32Uint;
+
+/// The pick target and its in-flight readbacks
+#[derive(Debug, Clone)]
+pub struct PickBuffer {
+    pub enabled: bool,            // Off by default; the extra pass costs a draw per pickable instance
+    pub mesh_bounds: BoundingBox, // Model-space box rasterized for each instance
+    target: PickImage,            // Written by this frame's passes
+    in_flight: Option<PickImage>, // Copied out last frame, waiting to be mapped
+    readback: Option<PickImage>,  // Newest mapped copy, what `pick_at` reads
+}
+
+/// Ids and reverse-Z depths at internal resolution
+#[derive(Debug, Clone, Default)]
+struct PickImage {
+    size: UVec2,
+    ids: Vec<u32>,
+    depth: Vec<f32>, // 0.0 = nothing drawn, larger is closer
+}
+
+impl Default for PickBuffer {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mesh_bounds: BoundingBox::new(Vec3::splat(-0.5), Vec3::splat(0.5)), // A unit block around the origin
+            target: PickImage::default(),
+            in_flight: None,
+            readback: None,
+        }
+    }
+}
+
+impl PickBuffer {
+    /// Draw one instance's id into the target, which grows to cover `extent` (internal pixels)
+    ///
+    /// Instances without a pick id, or crossing the near plane, are skipped.
+    pub fn draw(&mut self, pick_id: u32, model: Mat4, view_projection: Mat4, viewport: Rect, extent: UVec2) {
+        if !self.enabled || pick_id == NO_PICK_ID {
+            return;
+        }
+        self.target.grow(extent);
+
+        let mut corners = [Vec3::ZERO; 8];
+        for (index, corner) in box_corners(&self.mesh_bounds).into_iter().enumerate() {
+            let clip = view_projection * model * corner.extend(1.0);
+            if clip.w <= MIN_CLIP_W {
+                return;
+            }
+            let ndc = clip.truncate() / clip.w;
+            corners[index] = Vec3::new(
+                viewport.min.x + (ndc.x * 0.5 + 0.5) * viewport.width(),
+                viewport.min.y + (0.5 - ndc.y * 0.5) * viewport.height(),
+                ndc.z,
+            );
+        }
+        for [a, b, c] in BOX_TRIANGLES {
+            self.target.rasterize_triangle(pick_id, corners[a], corners[b], corners[c], viewport);
+        }
+    }
+
+    /// Copy the target out for readback and start the next frame's target
+    ///
+    /// The copy made a frame earlier has been mapped by now and becomes readable. Its storage is
+    /// erased and reused for the next target.
+    pub fn end_frame(&mut self) {
+        if !self.enabled {
+            return;
+        }
+        let mut next = self.readback.take().unwrap_or_default();
+        next.clear();
+        self.readback = self.in_flight.take();
+        self.in_flight = Some(std::mem::replace(&mut self.target, next));
+    }
+
+    /// Id at an internal-resolution pixel in the newest readback, if anything pickable is there
+    pub fn id_at(&self, pixel: Vec2) -> Option<u32> {
+        let image = self.readback.as_ref()?;
+        if pixel.x < 0.0 || pixel.y < 0.0 {
+            return None;
+        }
+        let (x, y) = (pixel.x as u32, pixel.y as u32);
+        if x >= image.size.x || y >= image.size.y {
+            return None;
+        }
+        Some(image.ids[(y * image.size.x + x) as usize]).filter(|&id| id != NO_PICK_ID)
+    }
+}
+
+impl PickImage {
+    /// Erase in place, keeping the size so the next frame reuses the allocation
+    fn clear(&mut self) {
+        self.ids.fill(NO_PICK_ID);
+        self.depth.fill(0.0);
+    }
+
+    /// Enlarge to at least `extent`, keeping what's been drawn (only reallocates when growing)
+    fn grow(&mut self, extent: UVec2) {
+        let size = self.size.max(extent);
+        if size == self.size {
+            return;
+        }
+        let mut ids = vec![NO_PICK_ID; (size.x * size.y) as usize];
+        let mut depth = vec![0.0; ids.len()];
+        for y in 0..self.size.y {
+            let (from, to) = ((y * self.size.x) as usize, (y * size.x) as usize);
+            let width = self.size.x as usize;
+            ids[to..to + width].copy_from_slice(&self.ids[from..from + width]);
+            depth[to..to + width].copy_from_slice(&self.depth[from..from + width]);
+        }
+        *self = Self { size, ids, depth };
+    }
+
+    fn rasterize_triangle(&mut self, pick_id: u32, a: Vec3, b: Vec3, c: Vec3, viewport: Rect) {
+        let area = edge(a, b, c);
+        if area.abs() < f32::EPSILON {
+            return;
+        }
+
+        // Clip to the pass's viewport as well as the target
+        let min = a.min(b).min(c).truncate().max(viewport.min).max(Vec2::ZERO);
+        let max = a.max(b).max(c).truncate().min(viewport.max).min(self.size.as_vec2());
+        if min.x >= max.x || min.y >= max.y {
+            return;
+        }
+
+        for y in min.y.floor() as u32..max.y.ceil() as u32 {
+            for x in min.x.floor() as u32..max.x.ceil() as u32 {
+                let p = Vec3::new(x as f32 + 0.5, y as f32 + 0.5, 0.0);
+                let (wa, wb, wc) = (edge(b, c, p) / area, edge(c, a, p) / area, edge(a, b, p) / area);
+                if wa < 0.0 || wb < 0.0 || wc < 0.0 {
+                    continue;
+                }
+                let z = wa * a.z + wb * b.z + wc * c.z;
+                let texel = (y * self.size.x + x) as usize;
+                if z > self.depth[texel] {
+                    self.depth[texel] = z;
+                    self.ids[texel] = pick_id;
+                }
+            }
+        }
+    }
+}
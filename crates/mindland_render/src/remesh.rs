@@ -0,0 +1,109 @@
+//! Dirty-chunk remesh scheduling
+//!
+//! Block edits mark their chunk dirty, plus the neighbouring chunk across any face the block
+//! touches (its faces against that neighbour may appear or disappear). Any number of edits to a
+//! chunk before it's scheduled coalesce into one remesh. Each frame, at most `remesh_budget`
+//! dirty chunks are sent to the `ChunkMesher`, nearest the camera first.
+
+use crate::{ChunkData, ChunkMesher, MeshJobId, CHUNK_SIZE};
+use bevy::{prelude::*, utils::HashMap, utils::HashSet};
+
+/// Tracks dirty chunks and hands them to the mesher within a per-frame budget
+#[derive(Resource, Debug, Clone)]
+pub struct ChunkMeshScheduler {
+    pub remesh_budget: usize, // Remeshes started per frame
+    dirty: HashSet<IVec3>,
+    in_flight: HashMap<MeshJobId, IVec3>,
+}
+
+impl Default for ChunkMeshScheduler {
+    fn default() -> Self {
+        Self::new(8)
+    }
+}
+
+impl ChunkMeshScheduler {
+    /// Scheduler starting at most `remesh_budget` remeshes per frame
+    pub fn new(remesh_budget: usize) -> Self {
+        Self { remesh_budget, dirty: HashSet::new(), in_flight: HashMap::new() }
+    }
+
+    /// Chunk containing a block, in chunk coordinates
+    pub fn chunk_of(block: IVec3) -> IVec3 {
+        block.div_euclid(IVec3::splat(CHUNK_SIZE as i32))
+    }
+
+    /// Mark a chunk for remeshing (already-dirty chunks stay a single remesh)
+    pub fn mark_dirty(&mut self, chunk: IVec3) {
+        self.dirty.insert(chunk);
+    }
+
+    /// Mark the chunks affected by an edit to `block` (world block coordinates)
+    pub fn block_edited(&mut self, block: IVec3) {
+        let chunk = Self::chunk_of(block);
+        let local = block.rem_euclid(IVec3::splat(CHUNK_SIZE as i32));
+        self.mark_dirty(chunk);
+        for axis in [IVec3::X, IVec3::Y, IVec3::Z] {
+            let coordinate = local.dot(axis);
+            if coordinate == 0 {
+                self.mark_dirty(chunk - axis);
+            } else if coordinate == CHUNK_SIZE as i32 - 1 {
+                self.mark_dirty(chunk + axis);
+            }
+        }
+    }
+
+    /// Whether a chunk is waiting for a remesh
+    pub fn is_dirty(&self, chunk: IVec3) -> bool {
+        self.dirty.contains(&chunk)
+    }
+
+    /// Chunks waiting for a remesh
+    pub fn dirty_count(&self) -> usize {
+        self.dirty.len()
+    }
+
+    /// Remove and return this frame's chunks to remesh: up to `remesh_budget`, nearest the camera first
+    pub fn next_batch(&mut self, camera_position: Vec3) -> Vec<IVec3> {
+        let distance = |chunk: &IVec3| ((chunk.as_vec3() + 0.5) * CHUNK_SIZE as f32).distance_squared(camera_position);
+        let mut batch: Vec<IVec3> = self.dirty.iter().copied().collect();
+        batch.sort_by(|a, b| distance(a).total_cmp(&distance(b)).then_with(|| a.to_array().cmp(&b.to_array())));
+        batch.truncate(self.remesh_budget);
+        for chunk in &batch {
+            self.dirty.remove(chunk);
+        }
+        batch
+    }
+
+    /// Queue this frame's batch on `mesher`, snapshotting each chunk with `chunk_data`
+    ///
+    /// Chunks `chunk_data` returns None for (unloaded) are dropped. Returns the jobs started.
+    pub fn submit(
+        &mut self,
+        mesher: &mut ChunkMesher,
+        camera_position: Vec3,
+        mut chunk_data: impl FnMut(IVec3) -> Option<ChunkData>,
+    ) -> Vec<(IVec3, MeshJobId)> {
+        let mut started = Vec::new();
+        for chunk in self.next_batch(camera_position) {
+            let Some(data) = chunk_data(chunk) else { continue };
+            let job_id = mesher.request(data);
+            self.in_flight.insert(job_id, chunk);
+            started.push((chunk, job_id));
+        }
+        started
+    }
+
+    /// Chunk a finished job meshed (None for jobs not started by `submit`)
+    ///
+    /// A chunk edited while its job was in flight is dirty again, so the stale mesh is replaced
+    /// by a later remesh.
+    pub fn finish(&mut self, job_id: MeshJobId) -> Option<IVec3> {
+        self.in_flight.remove(&job_id)
+    }
+
+    /// Remesh jobs started but not yet finished
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+}
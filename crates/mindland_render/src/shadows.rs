@@ -0,0 +1,130 @@
+//! Directional light with cascaded shadow maps
+//!
+//! Cascades cover consecutive depth slices of the view frustum, each with its own shadow map. By
+//! default the slice boundaries blend logarithmic splits (even texel density per depth) with
+//! uniform splits (even coverage) through `split_lambda`; `set_cascade_splits` fixes them instead.
+
+use bevy::prelude::*;
+use mindland_performance::{QualitySettings, ShadowQuality};
+
+/// Most shadow cascades a light renders
+pub const MAX_SHADOW_CASCADES: usize = 4;
+
+/// Sun/moon light and how its shadow cascades are split
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct DirectionalLightController {
+    pub direction: Vec3, // Direction the light travels (normalized)
+    pub color: Color,
+    pub illuminance: f32, // Lux
+    pub cascade_count: usize, // 0 = shadows off, at most `MAX_SHADOW_CASCADES`
+    pub split_lambda: f32,    // 0 = uniform splits, 1 = logarithmic
+    pub shadow_distance: f32, // Shadows end here or at the camera far plane, whichever is nearer
+    fixed_splits: Option<Vec<f32>>, // Set by `set_cascade_splits`; overrides `split_lambda`
+    shadow_quality: ShadowQuality,  // Level last applied by `apply_shadow_quality`
+}
+
+impl Default for DirectionalLightController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DirectionalLightController {
+    /// Midday sun with High-quality shadows
+    pub fn new() -> Self {
+        let mut light = Self {
+            direction: Vec3::new(0.3, -1.0, 0.2).normalize(),
+            color: Color::WHITE,
+            illuminance: 100_000.0,
+            cascade_count: 0,
+            split_lambda: 0.0,
+            shadow_distance: 0.0,
+            fixed_splits: None,
+            shadow_quality: ShadowQuality::High,
+        };
+        light.apply_shadow_quality(ShadowQuality::High);
+        light
+    }
+
+    /// Pick cascade count, split scheme and shadow distance for a quality level
+    ///
+    /// Lower quality uses fewer cascades over a shorter distance. Clears any fixed splits.
+    pub fn apply_shadow_quality(&mut self, quality: ShadowQuality) {
+        let (cascade_count, split_lambda, shadow_distance) = match quality {
+            ShadowQuality::Off => (0, 0.0, 0.0),
+            ShadowQuality::Low => (1, 0.5, 48.0),
+            ShadowQuality::Medium => (2, 0.6, 96.0),
+            ShadowQuality::High => (3, 0.75, 160.0),
+            ShadowQuality::Ultra => (4, 0.85, 256.0),
+        };
+        self.cascade_count = cascade_count;
+        self.split_lambda = split_lambda;
+        self.shadow_distance = shadow_distance;
+        self.fixed_splits = None;
+        self.shadow_quality = quality;
+    }
+
+    /// Quality level the cascade settings were last derived from
+    pub fn shadow_quality(&self) -> ShadowQuality {
+        self.shadow_quality
+    }
+
+    /// Use fixed far distances for each cascade instead of `split_lambda`
+    ///
+    /// Extra splits beyond `MAX_SHADOW_CASCADES` are dropped; an empty slice turns shadows off.
+    pub fn set_cascade_splits(&mut self, splits: &[f32]) {
+        let splits: Vec<f32> = splits.iter().copied().take(MAX_SHADOW_CASCADES).collect();
+        self.cascade_count = splits.len();
+        self.fixed_splits = Some(splits);
+    }
+
+    /// Fixed splits from `set_cascade_splits`, if any
+    pub fn fixed_splits(&self) -> Option<&[f32]> {
+        self.fixed_splits.as_deref()
+    }
+
+    /// Far distance of each cascade for a camera, strictly increasing and within its far plane
+    ///
+    /// Splits are clamped to the shadow distance; fixed splits that don't increase past the
+    /// previous split (or the near plane) are skipped.
+    pub fn cascade_splits(&self, projection: &PerspectiveProjection) -> Vec<f32> {
+        let near = projection.near;
+        let far = self.shadow_distance.min(projection.far);
+        if far <= near {
+            return Vec::new();
+        }
+
+        let candidates: Vec<f32> = match &self.fixed_splits {
+            Some(splits) => splits.clone(),
+            None => {
+                let count = self.cascade_count.min(MAX_SHADOW_CASCADES);
+                let lambda = self.split_lambda.clamp(0.0, 1.0);
+                (1..=count)
+                    .map(|cascade| {
+                        let t = cascade as f32 / count as f32;
+                        let logarithmic = near * (far / near).powf(t);
+                        let uniform = near + (far - near) * t;
+                        lambda * logarithmic + (1.0 - lambda) * uniform
+                    })
+                    .collect()
+            }
+        };
+
+        let mut splits: Vec<f32> = Vec::with_capacity(candidates.len());
+        for split in candidates.into_iter().map(|split| split.min(far)) {
+            if split > splits.last().copied().unwrap_or(near) {
+                splits.push(split);
+            }
+        }
+        splits
+    }
+}
+
+/// Re-derive cascade settings when the shadow quality changes (other quality changes keep fixed splits)
+pub fn shadow_quality_system(quality: Res<QualitySettings>, mut lights: Query<&mut DirectionalLightController>) {
+    for mut light in &mut lights {
+        if light.shadow_quality != quality.shadow_quality {
+            light.apply_shadow_quality(quality.shadow_quality);
+        }
+    }
+}
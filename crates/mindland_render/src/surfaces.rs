@@ -0,0 +1,25 @@
+//! Per-window surface configuration
+//!
+//! Each window the renderer draws into has its own swapchain size and present mode, so an
+//! inspector window can run at a different resolution (and without vsync) next to the game.
+
+use bevy::{prelude::*, render::render_resource::TextureFormat, window::PresentMode};
+
+/// Swapchain configuration for one window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurfaceConfig {
+    pub size: UVec2, // Physical pixels
+    pub present_mode: PresentMode,
+    pub format: TextureFormat,
+}
+
+impl SurfaceConfig {
+    /// Surface in the default swapchain format
+    pub fn new(size: UVec2, present_mode: PresentMode) -> Self {
+        Self {
+            size,
+            present_mode,
+            format: TextureFormat::Bgra8UnormSrgb,
+        }
+    }
+}
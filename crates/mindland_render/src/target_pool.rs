@@ -0,0 +1,129 @@
+//! Render-target pooling
+//!
+//! Dragging a window edge changes its size every frame. Recreating the offscreen, depth and MSAA
+//! targets each time stutters and churns GPU memory, so the pool keeps rendering at the last
+//! settled size until a new size has held for `debounce`, then reallocates once.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::utils::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long a new size must hold before targets are reallocated
+pub const DEFAULT_RESIZE_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// What makes two render targets interchangeable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RenderTargetKey {
+    pub size: UVec2,
+    pub format: TextureFormat,
+    pub sample_count: u32,
+}
+
+/// Render targets reused across frames, reallocated only once a resize settles
+pub struct RenderTargetPool<T = Handle<Image>> {
+    pub debounce: Duration,
+    pub allocations: u64, // Targets created over the pool's lifetime
+    targets: HashMap<RenderTargetKey, T>,
+    settled_size: Option<UVec2>,
+    pending: Option<(UVec2, Instant)>, // Latest requested size and when it was first requested
+}
+
+impl<T> Default for RenderTargetPool<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_RESIZE_DEBOUNCE)
+    }
+}
+
+impl<T> RenderTargetPool<T> {
+    /// Create an empty pool with the given resize debounce
+    pub fn new(debounce: Duration) -> Self {
+        Self {
+            debounce,
+            allocations: 0,
+            targets: HashMap::default(),
+            settled_size: None,
+            pending: None,
+        }
+    }
+
+    /// Change how long a new size must hold before reallocating (zero reallocates immediately)
+    pub fn set_debounce(&mut self, debounce: Duration) {
+        self.debounce = debounce;
+    }
+
+    /// Report this frame's desired target size and get the size to render at
+    ///
+    /// The first size is adopted immediately. Later changes keep the previous size until the
+    /// same new size has been requested for `debounce`; targets of other sizes are then dropped.
+    pub fn request_size(&mut self, size: UVec2, now: Instant) -> UVec2 {
+        match self.settled_size {
+            Some(settled) if settled == size => self.pending = None,
+            Some(_) => {
+                let since = match self.pending {
+                    Some((pending_size, since)) if pending_size == size => since,
+                    _ => now,
+                };
+                if now.saturating_duration_since(since) >= self.debounce {
+                    self.settle(size);
+                } else {
+                    self.pending = Some((size, since));
+                }
+            }
+            None => self.settle(size),
+        }
+        self.settled_size.unwrap_or(size)
+    }
+
+    fn settle(&mut self, size: UVec2) {
+        self.settled_size = Some(size);
+        self.pending = None;
+        self.targets.retain(|key, _| key.size == size);
+    }
+
+    /// Size targets are currently allocated at (None before the first request)
+    pub fn settled_size(&self) -> Option<UVec2> {
+        self.settled_size
+    }
+
+    /// Whether a requested size is still waiting out the debounce
+    pub fn is_resizing(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Get the target for a format and sample count at the settled size, creating it on first use
+    ///
+    /// Returns None until a size has been requested.
+    pub fn acquire(
+        &mut self,
+        format: TextureFormat,
+        sample_count: u32,
+        allocate: impl FnOnce(&RenderTargetKey) -> T,
+    ) -> Option<&T> {
+        let key = RenderTargetKey {
+            size: self.settled_size?,
+            format,
+            sample_count,
+        };
+        let allocations = &mut self.allocations;
+        Some(self.targets.entry(key).or_insert_with(|| {
+            *allocations += 1;
+            allocate(&key)
+        }))
+    }
+
+    /// Number of live targets
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Whether no targets are allocated
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Drop every target (e.g. after device loss); the settled size is kept
+    pub fn clear(&mut self) {
+        self.targets.clear();
+    }
+}
@@ -0,0 +1,82 @@
+//! Batched screen-space text
+//!
+//! Glyphs are tiles of a fixed-grid font atlas laid out in code-point order (tile 65 is 'A'), so
+//! each visible character becomes one `InstanceData` quad in the overlay queue and a frame's text
+//! is a single instanced draw with the font texture bound.
+
+use crate::{InstanceData, TextureAtlas, UltraRenderer};
+use bevy::prelude::*;
+use mindland_assets::{TextureId, QUEUE_OVERLAY};
+
+/// Glyph drawn for characters the font atlas doesn't cover
+pub const FALLBACK_GLYPH: char = '?';
+
+/// Per-frame glyph instances and the font they index into
+pub struct TextBatch {
+    pub font_atlas: TextureAtlas,        // One tile per code point, starting at U+0000
+    pub font_texture: Option<TextureId>, // Atlas image loaded through `AssetManager`, bound for the text draw
+    pub advance: f32,                    // Horizontal step per character, as a fraction of the text size (monospace)
+    pub line_height: f32,                // Vertical step per line, as a fraction of the text size
+    pub glyphs: Vec<InstanceData>,       // Screen-space quads: pixel translation, uniform scale = text size
+}
+
+impl Default for TextBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextBatch {
+    /// Create an empty batch over a 16x16-glyph Latin-1 atlas
+    pub fn new() -> Self {
+        Self {
+            font_atlas: TextureAtlas::new(256, 16).with_inset(0.5),
+            font_texture: None,
+            advance: 0.6,
+            line_height: 1.2,
+            glyphs: Vec::new(),
+        }
+    }
+
+    /// Queue a string with its top-left corner at `position` (pixels), `size` pixels tall
+    ///
+    /// Spaces and tabs only advance the pen and `\n` starts a new line, so they add no quads.
+    pub fn draw_text(&mut self, text: &str, position: Vec2, size: f32, color: Color) {
+        let mut pen = position;
+        for character in text.chars() {
+            match character {
+                '\n' => {
+                    pen = Vec2::new(position.x, pen.y + size * self.line_height);
+                    continue;
+                }
+                ' ' | '\t' => {}
+                _ => {
+                    let transform = Mat4::from_scale_rotation_translation(Vec3::splat(size), Quat::IDENTITY, pen.extend(0.0));
+                    self.glyphs.push(
+                        InstanceData::new(transform, self.glyph_index(character), color).with_render_queue(QUEUE_OVERLAY),
+                    );
+                }
+            }
+            pen.x += size * self.advance;
+        }
+    }
+
+    /// Atlas tile for a character, or the fallback glyph's tile if the atlas doesn't cover it
+    pub fn glyph_index(&self, character: char) -> u32 {
+        let tiles = self.font_atlas.texture_coords.len() as u32;
+        match character as u32 {
+            code_point if code_point < tiles => code_point,
+            _ => FALLBACK_GLYPH as u32,
+        }
+    }
+
+    /// Drop this frame's glyphs
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+    }
+}
+
+/// Drop last frame's glyphs at the start of a frame, before anything draws this frame's text
+pub fn text_batch_clear_system(mut renderer: ResMut<UltraRenderer>) {
+    renderer.text.clear();
+}
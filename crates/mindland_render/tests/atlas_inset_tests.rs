@@ -0,0 +1,58 @@
+//! Tests for texture atlas UV insets
+//!
+//! **Feature: atlas-inset, Property 1: Inset Shrinks Every Tile By The Configured Texels**
+
+use mindland_render::{TextureAtlas, TextureCoords};
+
+fn width(coords: TextureCoords) -> f32 {
+    coords.u_max - coords.u_min
+}
+
+fn height(coords: TextureCoords) -> f32 {
+    coords.v_max - coords.v_min
+}
+
+#[cfg(test)]
+mod atlas_inset_tests {
+    use super::*;
+
+    #[test]
+    fn test_inset_shrinks_each_tile_rect() {
+        // **Feature: atlas-inset, Property 1: Inset Shrinks Every Tile By The Configured Texels**
+
+        let exact = TextureAtlas::new(256, 16);
+        let inset = TextureAtlas::new(256, 16).with_inset(0.5);
+        let half_texel = 0.5 / 256.0;
+        assert_eq!(exact.texture_coords.len(), inset.texture_coords.len());
+
+        for (exact, inset) in exact.texture_coords.iter().zip(&inset.texture_coords) {
+            assert!((inset.u_min - (exact.u_min + half_texel)).abs() < 1e-6);
+            assert!((inset.v_min - (exact.v_min + half_texel)).abs() < 1e-6);
+            assert!((inset.u_max - (exact.u_max - half_texel)).abs() < 1e-6);
+            assert!((inset.v_max - (exact.v_max - half_texel)).abs() < 1e-6);
+            assert!((width(*exact) - width(*inset) - 1.0 / 256.0).abs() < 1e-6);
+            assert!((height(*exact) - height(*inset) - 1.0 / 256.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_zero_inset_keeps_exact_boundaries() {
+        // **Feature: atlas-inset, Property 1: Inset Shrinks Every Tile By The Configured Texels**
+
+        let atlas = TextureAtlas::new(256, 16);
+        assert_eq!(atlas.inset, 0.0);
+        let second = atlas.get_coords(1).unwrap();
+        assert_eq!((second.u_min, second.u_max), (16.0 / 256.0, 32.0 / 256.0));
+
+        let point_sampled = TextureAtlas::new(256, 16).with_inset(0.0);
+        assert_eq!(point_sampled.get_coords(1).unwrap().u_min, 16.0 / 256.0);
+    }
+
+    #[test]
+    fn test_inset_never_inverts_a_tile() {
+        let atlas = TextureAtlas::new(64, 4).with_inset(10.0);
+        assert!(atlas.inset < 2.0);
+        let coords = atlas.get_coords(0).unwrap();
+        assert!(coords.u_min < coords.u_max && coords.v_min < coords.v_max);
+    }
+}
@@ -0,0 +1,78 @@
+//! Tests for occlusion against CPU-side occluder boxes
+//!
+//! **Feature: box-occlusion, Property 1: Only Boxes Fully Behind One Occluder Are Hidden**
+
+use bevy::prelude::*;
+use mindland_assets::BoundingBox;
+use mindland_camera::CameraController;
+use mindland_render::UltraRenderer;
+
+fn cube(center: Vec3, half_size: f32) -> BoundingBox {
+    BoundingBox::new(center - Vec3::splat(half_size), center + Vec3::splat(half_size))
+}
+
+/// A 10x10 wall across -z, five units in front of the origin
+fn wall() -> BoundingBox {
+    BoundingBox::new(Vec3::new(-5.0, -5.0, -5.5), Vec3::new(5.0, 5.0, -4.5))
+}
+
+#[cfg(test)]
+mod box_occlusion_tests {
+    use super::*;
+
+    #[test]
+    fn test_box_fully_behind_occluder_is_hidden() {
+        // **Feature: box-occlusion, Property 1: Only Boxes Fully Behind One Occluder Are Hidden**
+
+        let mut culling = UltraRenderer::new().culling_system;
+        let target = cube(Vec3::new(0.0, 0.0, -20.0), 1.0);
+        assert!(!culling.is_hidden_by_occluders(&target, Vec3::ZERO), "no occluders, nothing hidden");
+
+        culling.occluders.push(wall());
+        assert!(culling.is_hidden_by_occluders(&target, Vec3::ZERO));
+        assert!(culling.is_hidden_by_occluders(&cube(Vec3::new(8.0, 0.0, -30.0), 1.0), Vec3::ZERO), "inside the shadow cone");
+    }
+
+    #[test]
+    fn test_partially_visible_box_is_not_hidden() {
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.occluders.push(wall());
+
+        // Peeks out past the wall's edge
+        assert!(!culling.is_hidden_by_occluders(&cube(Vec3::new(9.0, 0.0, -7.0), 1.0), Vec3::ZERO));
+        // In front of the wall
+        assert!(!culling.is_hidden_by_occluders(&cube(Vec3::new(0.0, 0.0, -2.0), 1.0), Vec3::ZERO));
+        // Seen from the other side, the wall is behind it
+        assert!(!culling.is_hidden_by_occluders(&cube(Vec3::new(0.0, 0.0, -20.0), 1.0), Vec3::new(0.0, 0.0, -30.0)));
+        // Camera inside the occluder
+        assert!(!culling.is_hidden_by_occluders(&cube(Vec3::new(0.0, 0.0, -20.0), 1.0), Vec3::new(0.0, 0.0, -5.0)));
+    }
+
+    #[test]
+    fn test_occluders_are_not_combined() {
+        // Two half walls together cover the target, but neither does alone
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.occluders.push(BoundingBox::new(Vec3::new(-5.0, -5.0, -5.5), Vec3::new(0.0, 5.0, -4.5)));
+        culling.occluders.push(BoundingBox::new(Vec3::new(0.0, -5.0, -5.5), Vec3::new(5.0, 5.0, -4.5)));
+        assert!(!culling.is_hidden_by_occluders(&cube(Vec3::new(0.0, 0.0, -20.0), 1.0), Vec3::ZERO));
+    }
+
+    #[test]
+    fn test_viewport_pass_culls_hidden_instances() {
+        let camera = CameraController::new();
+        let eye = camera.transform.translation;
+        let mut renderer = UltraRenderer::new();
+        let wall = BoundingBox::new(wall().min + eye, wall().max + eye);
+        renderer.culling_system.occluders.push(wall);
+        renderer.add_instance(Mat4::from_scale_rotation_translation(wall.max - wall.min, Quat::IDENTITY, wall.center()), 1, Color::WHITE);
+        renderer.add_instance(Mat4::from_scale_rotation_translation(Vec3::splat(2.0), Quat::IDENTITY, eye + Vec3::new(0.0, 0.0, -20.0)), 2, Color::WHITE);
+        let screen = Rect::new(0.0, 0.0, 1920.0, 1080.0);
+
+        renderer.render_viewport(&camera, screen);
+        assert_eq!(renderer.viewport_passes[0].visible_instances, vec![0], "the wall draws, what's behind it doesn't");
+
+        renderer.culling_system.occlusion_culling = false;
+        renderer.render_viewport(&camera, screen);
+        assert_eq!(renderer.viewport_passes[1].visible_instances.len(), 2);
+    }
+}
@@ -0,0 +1,106 @@
+//! Tests for dirty-chunk remesh scheduling
+//!
+//! **Feature: chunk-remesh, Property 1: Edits Dirty Exactly The Chunks Whose Faces They Change**
+
+use bevy::prelude::*;
+use mindland_render::{ChunkData, ChunkMeshScheduler, ChunkMesher, CHUNK_SIZE};
+use std::time::{Duration, Instant};
+
+const EDGE: i32 = CHUNK_SIZE as i32 - 1;
+
+/// Collect finished jobs until `count` arrive (or time out)
+fn wait_for(mesher: &mut ChunkMesher, count: usize) -> usize {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut finished = 0;
+    while finished < count && Instant::now() < deadline {
+        finished += mesher.poll_finished().len();
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    finished
+}
+
+#[cfg(test)]
+mod chunk_remesh_tests {
+    use super::*;
+
+    #[test]
+    fn test_boundary_edit_dirties_both_chunks() {
+        // **Feature: chunk-remesh, Property 1: Edits Dirty Exactly The Chunks Whose Faces They Change**
+
+        let mut scheduler = ChunkMeshScheduler::new(8);
+        scheduler.block_edited(IVec3::new(EDGE, 5, 5)); // Last column of chunk (0,0,0), facing (1,0,0)
+
+        assert!(scheduler.is_dirty(IVec3::ZERO));
+        assert!(scheduler.is_dirty(IVec3::X));
+        assert_eq!(scheduler.dirty_count(), 2);
+
+        // Negative coordinates: block -32 is the first column of chunk -1, facing chunk -2
+        let mut scheduler = ChunkMeshScheduler::new(8);
+        scheduler.block_edited(IVec3::new(-(CHUNK_SIZE as i32), 5, 5));
+        assert!(scheduler.is_dirty(IVec3::new(-1, 0, 0)) && scheduler.is_dirty(IVec3::new(-2, 0, 0)));
+        assert_eq!(scheduler.dirty_count(), 2);
+    }
+
+    #[test]
+    fn test_interior_and_corner_edits() {
+        // **Feature: chunk-remesh, Property 1: Edits Dirty Exactly The Chunks Whose Faces They Change**
+
+        let mut scheduler = ChunkMeshScheduler::new(8);
+        scheduler.block_edited(IVec3::new(10, 10, 10));
+        assert_eq!(scheduler.dirty_count(), 1, "interior edits only touch their own chunk");
+
+        let mut scheduler = ChunkMeshScheduler::new(8);
+        scheduler.block_edited(IVec3::new(0, EDGE, 0)); // Corner: faces three neighbours
+        for chunk in [IVec3::ZERO, -IVec3::X, IVec3::Y, -IVec3::Z] {
+            assert!(scheduler.is_dirty(chunk), "{chunk}");
+        }
+        assert_eq!(scheduler.dirty_count(), 4);
+    }
+
+    #[test]
+    fn test_repeated_edits_coalesce_into_one_job() {
+        let mut scheduler = ChunkMeshScheduler::new(8);
+        let mut mesher = ChunkMesher::with_workers(1);
+        for x in 5..15 {
+            scheduler.block_edited(IVec3::new(x, 3, 7));
+        }
+
+        let started = scheduler.submit(&mut mesher, Vec3::ZERO, |_| Some(ChunkData::new()));
+        assert_eq!(started.len(), 1);
+        assert_eq!(mesher.pending_jobs(), 1);
+        assert_eq!(scheduler.dirty_count(), 0);
+
+        let (chunk, job_id) = started[0];
+        assert_eq!(wait_for(&mut mesher, 1), 1);
+        assert_eq!(scheduler.finish(job_id), Some(chunk));
+        assert_eq!(scheduler.in_flight(), 0);
+        assert!(scheduler.submit(&mut mesher, Vec3::ZERO, |_| Some(ChunkData::new())).is_empty(), "nothing left to remesh");
+    }
+
+    #[test]
+    fn test_budget_takes_nearest_chunks_first() {
+        let mut scheduler = ChunkMeshScheduler::new(2);
+        for x in [-3, 5, 1, 0, 2] {
+            scheduler.mark_dirty(IVec3::new(x, 0, 0));
+        }
+        let camera = Vec3::new(1.5, 0.5, 0.5) * CHUNK_SIZE as f32; // Centre of chunk (1,0,0)
+
+        assert_eq!(scheduler.next_batch(camera), vec![IVec3::new(1, 0, 0), IVec3::new(0, 0, 0)], "ties break by coordinate");
+        assert_eq!(scheduler.next_batch(camera), vec![IVec3::new(2, 0, 0), IVec3::new(-3, 0, 0)]);
+        assert_eq!(scheduler.next_batch(camera), vec![IVec3::new(5, 0, 0)]);
+        assert!(scheduler.next_batch(camera).is_empty());
+    }
+
+    #[test]
+    fn test_unloaded_chunks_are_dropped() {
+        let mut scheduler = ChunkMeshScheduler::new(8);
+        let mut mesher = ChunkMesher::with_workers(1);
+        scheduler.mark_dirty(IVec3::ZERO);
+        scheduler.mark_dirty(IVec3::X);
+
+        let started = scheduler.submit(&mut mesher, Vec3::ZERO, |chunk| (chunk == IVec3::ZERO).then(ChunkData::new));
+        assert_eq!(started.iter().map(|(chunk, _)| *chunk).collect::<Vec<_>>(), vec![IVec3::ZERO]);
+        assert_eq!(scheduler.dirty_count(), 0);
+        wait_for(&mut mesher, 1);
+    }
+}
@@ -0,0 +1,51 @@
+//! Tests for render-target clear configuration
+//!
+//! **Feature: clear-config, Property 1: Config to LoadOp Mapping**
+
+use mindland_render::{ClearConfig, UltraRenderer};
+use bevy::{prelude::*, render::render_resource::LoadOp};
+
+#[cfg(test)]
+mod clear_config_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_clears_color_and_depth() {
+        let config = ClearConfig::default();
+
+        assert!(matches!(config.color_load_op(), LoadOp::Clear(_)));
+        assert_eq!(config.depth_load_op(), LoadOp::Clear(0.0));
+        assert_eq!(config.stencil_load_op(), LoadOp::Load);
+    }
+
+    #[test]
+    fn test_skybox_config_skips_color_but_clears_depth() {
+        // **Feature: clear-config, Property 1: Config to LoadOp Mapping**
+        // A skybox covering every pixel makes the color clear redundant, depth must still clear
+
+        let config = ClearConfig {
+            color: None,
+            depth: true,
+            stencil: true,
+        };
+
+        assert_eq!(config.color_load_op(), LoadOp::Load);
+        assert_eq!(config.depth_load_op(), LoadOp::Clear(0.0));
+        assert_eq!(config.stencil_load_op(), LoadOp::Clear(0));
+    }
+
+    #[test]
+    fn test_set_clear_color_maps_to_clear_op() {
+        let mut renderer = UltraRenderer::new();
+        renderer.set_clear_config(ClearConfig {
+            color: None,
+            depth: false,
+            stencil: false,
+        });
+        assert_eq!(renderer.clear_config.color_load_op(), LoadOp::Load);
+        assert_eq!(renderer.clear_config.depth_load_op(), LoadOp::Load);
+
+        renderer.set_clear_color(Color::rgb(0.5, 0.7, 1.0));
+        assert_eq!(renderer.clear_config.color_load_op(), LoadOp::Clear(Color::rgb(0.5, 0.7, 1.0)));
+    }
+}
@@ -0,0 +1,87 @@
+//! Tests for color grading LUTs
+//!
+//! **Feature: color-grading, Property 1: Identity LUT Is A No-Op**
+
+use bevy::prelude::*;
+use mindland_assets::{AssetManager, SamplerConfig};
+use mindland_render::{ColorLut, PostProcessPass, UltraRenderer, DEFAULT_LUT_SIZE};
+use std::path::PathBuf;
+
+/// One 8-bit step, the rounding the LUT texture introduces
+const TOLERANCE: f32 = 1.0 / 255.0;
+
+/// Sample colors, including lattice points, off-lattice values and the extremes
+fn pixels() -> Vec<Vec3> {
+    let mut pixels = vec![Vec3::ZERO, Vec3::ONE, Vec3::new(1.0, 0.0, 0.5)];
+    for i in 0..50 {
+        let t = i as f32 / 49.0;
+        pixels.push(Vec3::new(t, (t * 7.3).fract(), (t * 3.1 + 0.2).fract()));
+    }
+    pixels
+}
+
+/// Load a LUT through the asset manager and read it back as the grading pass sees it
+fn load_lut(assets: &mut AssetManager, renderer: &mut UltraRenderer, name: &str, lut: &ColorLut) -> ColorLut {
+    let id = assets
+        .load_texture_image_with(PathBuf::from(name), lut.to_image(), SamplerConfig::clamp())
+        .unwrap();
+    renderer.set_color_lut(Some(id));
+    assert_eq!(renderer.post_process_passes(), vec![PostProcessPass::ColorGrade { lut: id }]);
+
+    let image = assets.get_texture(id).unwrap().image.as_ref().unwrap();
+    ColorLut::from_image(image).unwrap()
+}
+
+#[cfg(test)]
+mod color_grading_tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_lut_leaves_pixels_unchanged() {
+        // **Feature: color-grading, Property 1: Identity LUT Is A No-Op**
+
+        let mut assets = AssetManager::new();
+        let mut renderer = UltraRenderer::new();
+        let lut = load_lut(&mut assets, &mut renderer, "luts/identity.png", &ColorLut::identity(DEFAULT_LUT_SIZE));
+        assert_eq!(lut.size, DEFAULT_LUT_SIZE);
+
+        for pixel in pixels() {
+            let graded = lut.sample(pixel);
+            assert!((graded - pixel).abs().max_element() <= TOLERANCE, "{pixel} -> {graded}");
+        }
+    }
+
+    #[test]
+    fn test_inversion_lut_inverts_pixels() {
+        // **Feature: color-grading, Property 1: Identity LUT Is A No-Op**
+
+        let mut assets = AssetManager::new();
+        let mut renderer = UltraRenderer::new();
+        let inversion = ColorLut::from_fn(DEFAULT_LUT_SIZE, |color| Vec3::ONE - color);
+        let lut = load_lut(&mut assets, &mut renderer, "luts/invert.png", &inversion);
+
+        for pixel in pixels() {
+            let graded = lut.sample(pixel);
+            assert!((graded - (Vec3::ONE - pixel)).abs().max_element() <= TOLERANCE, "{pixel} -> {graded}");
+        }
+    }
+
+    #[test]
+    fn test_configurable_size_and_disable() {
+        let lut = ColorLut::from_image(&ColorLut::identity(16).to_image()).unwrap();
+        assert_eq!(lut.size, 16);
+        assert!((lut.sample(Vec3::splat(0.3)) - Vec3::splat(0.3)).abs().max_element() <= TOLERANCE);
+
+        let mut renderer = UltraRenderer::new();
+        assert!(renderer.post_process_passes().is_empty());
+        renderer.set_color_lut(None);
+        assert!(renderer.post_process_passes().is_empty());
+    }
+
+    #[test]
+    fn test_rejects_non_strip_images() {
+        let mut image = ColorLut::identity(8).to_image();
+        image.texture_descriptor.size.width = 60;
+        assert!(ColorLut::from_image(&image).is_none());
+    }
+}
@@ -0,0 +1,46 @@
+//! Tests for compact instance transforms
+//!
+//! **Feature: compact-instances, Property 1: TRS Round Trip**
+
+use bevy::prelude::*;
+use mindland_render::{CompactInstance, InstanceData, UltraRenderer};
+
+#[cfg(test)]
+mod compact_instance_tests {
+    use super::*;
+
+    #[test]
+    fn test_trs_round_trip() {
+        // **Feature: compact-instances, Property 1: TRS Round Trip**
+        // Compact -> InstanceData reproduces the full TRS matrix
+
+        let transform = Transform::from_xyz(12.5, -3.0, 40.25)
+            .with_rotation(Quat::from_euler(EulerRot::YXZ, 0.7, -0.3, 1.1))
+            .with_scale(Vec3::splat(2.5));
+        let compact = CompactInstance::from_transform(&transform, 7, Color::RED);
+
+        let expanded = InstanceData::from(compact);
+        let expected = transform.compute_matrix();
+        let actual = Mat4::from_cols_array_2d(&expanded.transform);
+
+        assert!(actual.abs_diff_eq(expected, 1e-5), "{actual:?} != {expected:?}");
+        assert_eq!(expanded.texture_index, 7);
+        assert_eq!(expanded.color_tint, InstanceData::new(Mat4::IDENTITY, 0, Color::RED).color_tint);
+
+        let (scale, rotation, translation) = actual.to_scale_rotation_translation();
+        assert!(scale.abs_diff_eq(Vec3::splat(2.5), 1e-5));
+        assert!(rotation.abs_diff_eq(transform.rotation, 1e-5) || rotation.abs_diff_eq(-transform.rotation, 1e-5));
+        assert!(translation.abs_diff_eq(transform.translation, 1e-5));
+    }
+
+    #[test]
+    fn test_compact_instances_use_shared_capacity() {
+        // **Feature: compact-instances, Property 1: TRS Round Trip**
+
+        let mut renderer = UltraRenderer::new();
+        let compact = CompactInstance::from_transform(&Transform::IDENTITY, 0, Color::WHITE);
+        assert!(renderer.instanced_renderer.add_compact_instance(compact));
+        assert_eq!(renderer.instanced_renderer.current_instances, 1);
+        assert!(std::mem::size_of::<CompactInstance>() < std::mem::size_of::<InstanceData>());
+    }
+}
@@ -0,0 +1,126 @@
+//! Tests for CPU-side visibility culling
+//!
+//! **Feature: culling, Property 1: Frustum Cull Margin**
+//! **Feature: culling, Property 2: Boxes Are Culled Only When Entirely Outside A Plane**
+
+use mindland_assets::BoundingBox;
+use mindland_render::{Frustum, UltraRenderer};
+use bevy::prelude::*;
+
+/// Axis-aligned box frustum covering [-10, 10] on every axis
+fn box_frustum() -> Frustum {
+    Frustum {
+        planes: [
+            Vec4::new(1.0, 0.0, 0.0, 10.0),
+            Vec4::new(-1.0, 0.0, 0.0, 10.0),
+            Vec4::new(0.0, 1.0, 0.0, 10.0),
+            Vec4::new(0.0, -1.0, 0.0, 10.0),
+            Vec4::new(0.0, 0.0, 1.0, 10.0),
+            Vec4::new(0.0, 0.0, -1.0, 10.0),
+        ],
+    }
+}
+
+/// Zero-size box at a point
+fn point(position: Vec3) -> BoundingBox {
+    BoundingBox::new(position, position)
+}
+
+/// Box with the given center and half extent
+fn cube(center: Vec3, half_extent: f32) -> BoundingBox {
+    BoundingBox::new(center - half_extent, center + half_extent)
+}
+
+#[cfg(test)]
+mod culling_tests {
+    use super::*;
+
+    #[test]
+    fn test_object_within_margin_is_not_culled() {
+        // **Feature: culling, Property 1: Frustum Cull Margin**
+        // Slightly off-screen objects survive, objects beyond the margin are culled
+
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.cull_margin = 0.5;
+        let frustum = box_frustum();
+
+        assert!(!culling.should_cull(&point(Vec3::new(10.3, 0.0, 0.0)), Vec3::ZERO, &frustum));
+        assert!(culling.should_cull(&point(Vec3::new(10.8, 0.0, 0.0)), Vec3::ZERO, &frustum));
+    }
+
+    #[test]
+    fn test_zero_margin_is_exact_frustum() {
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.cull_margin = 0.0;
+        let frustum = box_frustum();
+
+        assert!(!culling.should_cull(&point(Vec3::new(0.0, 9.9, 0.0)), Vec3::ZERO, &frustum));
+        assert!(culling.should_cull(&point(Vec3::new(0.0, 10.1, 0.0)), Vec3::ZERO, &frustum));
+    }
+
+    #[test]
+    fn test_default_margin_is_small_and_positive() {
+        let culling = UltraRenderer::new().culling_system;
+        assert!(culling.cull_margin > 0.0 && culling.cull_margin <= 2.0);
+    }
+
+    #[test]
+    fn test_cylinder_culls_far_above_but_sphere_does_not() {
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.frustum_culling = false;
+        culling.max_render_distance = 200.0;
+        let above = Vec3::new(10.0, 150.0, 0.0); // Horizontally close, far overhead
+
+        assert_eq!(culling.vertical_render_distance, None);
+        assert!(!culling.should_cull(&point(above), Vec3::ZERO, &box_frustum()));
+
+        culling.set_render_distance_xz(200.0);
+        culling.set_render_distance_y(64.0);
+        assert!(culling.should_cull(&point(above), Vec3::ZERO, &box_frustum()));
+        assert!(!culling.should_cull(&point(Vec3::new(190.0, 10.0, 0.0)), Vec3::ZERO, &box_frustum())); // Outside a 64-block sphere, inside the cylinder
+        assert!(culling.should_cull(&point(Vec3::new(150.0, 0.0, 150.0)), Vec3::ZERO, &box_frustum()));
+    }
+
+    #[test]
+    fn test_boxes_inside_outside_and_straddling() {
+        // **Feature: culling, Property 2: Boxes Are Culled Only When Entirely Outside A Plane**
+
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.cull_margin = 0.0;
+        let frustum = box_frustum();
+
+        assert!(!culling.should_cull(&cube(Vec3::ZERO, 2.0), Vec3::ZERO, &frustum), "clearly inside");
+        assert!(culling.should_cull(&cube(Vec3::new(0.0, 0.0, 20.0), 2.0), Vec3::ZERO, &frustum), "clearly outside");
+        assert!(!culling.should_cull(&cube(Vec3::new(11.0, 0.0, 0.0), 2.0), Vec3::ZERO, &frustum), "straddles +x");
+        assert!(!culling.should_cull(&cube(Vec3::new(-11.0, 11.0, 0.0), 2.0), Vec3::ZERO, &frustum), "straddles a corner");
+        assert!(culling.should_cull(&cube(Vec3::new(-11.0, 13.0, 0.0), 2.0), Vec3::ZERO, &frustum), "inside one plane, outside another");
+    }
+
+    #[test]
+    fn test_box_touching_plane_is_kept_and_margin_applies_to_boxes() {
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.cull_margin = 0.0;
+        let frustum = box_frustum();
+
+        assert!(!culling.should_cull(&BoundingBox::new(Vec3::new(10.0, 0.0, 0.0), Vec3::new(12.0, 1.0, 1.0)), Vec3::ZERO, &frustum));
+
+        culling.cull_margin = 1.0;
+        let just_outside = BoundingBox::new(Vec3::new(10.5, 0.0, 0.0), Vec3::new(12.0, 1.0, 1.0));
+        assert!(!culling.should_cull(&just_outside, Vec3::ZERO, &frustum));
+        culling.cull_margin = 0.25;
+        assert!(culling.should_cull(&just_outside, Vec3::ZERO, &frustum));
+    }
+
+    #[test]
+    fn test_large_instance_straddling_screen_edge_is_drawn() {
+        let mut renderer = UltraRenderer::new();
+        renderer.culling_system.cull_margin = 0.0;
+        let camera = mindland_camera::CameraController::new();
+        let position = camera.transform.translation + Vec3::new(-30.0, 0.0, -10.0); // Centre well off the left edge
+        renderer.add_instance(Mat4::from_scale_rotation_translation(Vec3::splat(60.0), Quat::IDENTITY, position), 1, Color::WHITE);
+        renderer.add_instance(Mat4::from_translation(position), 2, Color::WHITE);
+
+        renderer.render_viewport(&camera, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+        assert_eq!(renderer.viewport_passes[0].visible_instances, vec![0], "only the big instance reaches on screen");
+    }
+}
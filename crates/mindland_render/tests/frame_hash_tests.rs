@@ -0,0 +1,77 @@
+//! Tests for deterministic per-frame instance hashing
+//!
+//! **Feature: frame-hash, Property 1: Identical Scenes Hash Equal**
+
+use bevy::prelude::*;
+use mindland_render::UltraRenderer;
+
+/// A small deterministic scene: a row of blocks plus one static floor instance
+fn build_scene(renderer: &mut UltraRenderer, offsets: &[f32]) {
+    renderer.add_static_instance(Mat4::from_scale(Vec3::new(64.0, 1.0, 64.0)), 3, Color::DARK_GREEN);
+    for (i, offset) in offsets.iter().enumerate() {
+        let transform = Mat4::from_translation(Vec3::new(i as f32 * 2.0 + offset, 1.0, -8.0));
+        renderer.add_instance(transform, i as u32 % 4, Color::WHITE);
+    }
+}
+
+#[cfg(test)]
+mod frame_hash_tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_scenes_hash_equal() {
+        // **Feature: frame-hash, Property 1: Identical Scenes Hash Equal**
+
+        let mut first = UltraRenderer::new();
+        let mut second = UltraRenderer::new();
+        build_scene(&mut first, &[0.0; 16]);
+        build_scene(&mut second, &[0.0; 16]);
+
+        assert_eq!(first.instanced_renderer.frame_hash(), second.instanced_renderer.frame_hash());
+    }
+
+    #[test]
+    fn test_single_changed_transform_changes_hash() {
+        // **Feature: frame-hash, Property 1: Identical Scenes Hash Equal**
+
+        let mut offsets = [0.0; 16];
+        let mut baseline = UltraRenderer::new();
+        build_scene(&mut baseline, &offsets);
+
+        offsets[7] = 0.001;
+        let mut changed = UltraRenderer::new();
+        build_scene(&mut changed, &offsets);
+
+        assert_ne!(baseline.instanced_renderer.frame_hash(), changed.instanced_renderer.frame_hash());
+    }
+
+    #[test]
+    fn test_submission_order_does_not_change_hash() {
+        // **Feature: frame-hash, Property 1: Identical Scenes Hash Equal**
+
+        let transforms: Vec<Mat4> = (0..8).map(|i| Mat4::from_translation(Vec3::X * i as f32)).collect();
+        let mut forward = UltraRenderer::new();
+        let mut reversed = UltraRenderer::new();
+        for transform in &transforms {
+            forward.add_instance(*transform, 0, Color::WHITE);
+        }
+        for transform in transforms.iter().rev() {
+            reversed.add_instance(*transform, 0, Color::WHITE);
+        }
+
+        assert_eq!(forward.instanced_renderer.frame_hash(), reversed.instanced_renderer.frame_hash());
+    }
+
+    #[test]
+    fn test_texture_and_color_are_hashed() {
+        let hash_of = |texture_index: u32, color: Color| {
+            let mut renderer = UltraRenderer::new();
+            renderer.add_instance(Mat4::IDENTITY, texture_index, color);
+            renderer.instanced_renderer.frame_hash()
+        };
+
+        let baseline = hash_of(0, Color::WHITE);
+        assert_ne!(baseline, hash_of(1, Color::WHITE));
+        assert_ne!(baseline, hash_of(0, Color::RED));
+    }
+}
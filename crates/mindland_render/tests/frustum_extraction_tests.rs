@@ -0,0 +1,79 @@
+//! Tests for building frustums from view-projection matrices
+//!
+//! **Feature: frustum-extraction, Property 1: Extracted Planes Bound Exactly The Visible Volume**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_render::Frustum;
+
+/// Camera origin and forward direction in world space
+fn eye(camera: &CameraController) -> (Vec3, Vec3) {
+    let camera_to_world = camera.view_matrix().inverse();
+    (camera_to_world.transform_point3(Vec3::ZERO), camera_to_world.transform_vector3(Vec3::NEG_Z).normalize())
+}
+
+#[cfg(test)]
+mod frustum_extraction_tests {
+    use super::*;
+
+    #[test]
+    fn test_finite_far_plane_rejects_distant_points() {
+        // **Feature: frustum-extraction, Property 1: Extracted Planes Bound Exactly The Visible Volume**
+
+        let mut camera = CameraController::new();
+        camera.transform.rotate_y(0.7);
+        let (origin, forward) = eye(&camera);
+        let projection = Mat4::perspective_rh(camera.projection.fov, 16.0 / 9.0, 0.1, 100.0);
+        let frustum = Frustum::from_view_projection(projection * camera.view_matrix());
+
+        // The camera sits at the apex: on every side plane, `near` behind the near plane
+        for plane in &frustum.planes[..4] {
+            assert!((plane.truncate().dot(origin) + plane.w).abs() < 1e-3, "{plane}");
+        }
+        let near = frustum.planes[5];
+        assert!((near.truncate().dot(origin) + near.w + 0.1).abs() < 1e-3);
+
+        assert!(frustum.contains_point(origin + forward));
+        assert!(frustum.contains_point(origin + forward * 99.0));
+        assert!(!frustum.contains_point(origin + forward * 1000.0), "beyond the far plane");
+        assert!(!frustum.contains_point(origin - forward), "behind the camera");
+    }
+
+    #[test]
+    fn test_planes_are_normalized() {
+        let camera = CameraController::new();
+        let frustum = Frustum::from_view_projection(camera.projection_matrix() * camera.view_matrix());
+
+        for plane in &frustum.planes[..5] {
+            assert!((plane.truncate().length() - 1.0).abs() < 1e-5, "{plane}");
+        }
+        let (origin, forward) = eye(&camera);
+        let near = frustum.planes[4]; // Reverse-Z: `z <= w` is the near plane
+        let distance = |point: Vec3| near.truncate().dot(point) + near.w;
+        assert!((distance(origin + forward * 10.0) - distance(origin + forward * 7.0) - 3.0).abs() < 1e-3, "distances in world units");
+    }
+
+    #[test]
+    fn test_infinite_reverse_z_has_no_far_limit() {
+        // Bevy's perspective projection is infinite reverse-Z: nothing is too far away
+        let camera = CameraController::new();
+        let frustum = Frustum::from_view_projection(camera.projection_matrix() * camera.view_matrix());
+        let (origin, forward) = eye(&camera);
+
+        assert!(frustum.planes.iter().all(|plane| !plane.is_nan()));
+        assert!(frustum.contains_point(origin + forward * 1.0e6));
+        assert!(!frustum.contains_point(origin - forward * 10.0));
+    }
+
+    #[test]
+    fn test_matches_bevy_frustum_planes() {
+        let camera = CameraController::new();
+        let view_projection = Mat4::perspective_rh(1.2, 1.5, 0.5, 300.0) * camera.view_matrix();
+        let ours = Frustum::from_view_projection(view_projection);
+        let bevy = Frustum::from(bevy::render::primitives::Frustum::from_view_projection(&view_projection));
+
+        for (ours, bevy) in ours.planes[..4].iter().zip(&bevy.planes[..4]) {
+            assert!(ours.abs_diff_eq(*bevy, 1e-4), "{ours} vs {bevy}");
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Tests for debug gizmo line rendering
+//!
+//! **Feature: debug-gizmos, Property 1: Constant Pixel Width Lines**
+
+use bevy::prelude::*;
+use mindland_assets::{BoundingBox, BoundingSphere};
+use mindland_render::{expand_line, UltraRenderer};
+
+#[cfg(test)]
+mod gizmo_tests {
+    use super::*;
+
+    const VIEWPORT: Vec2 = Vec2::new(1000.0, 500.0);
+
+    /// Pixel distance between two clip-space points after the perspective divide
+    fn screen_distance(a: Vec4, b: Vec4) -> f32 {
+        let to_screen = |clip: Vec4| clip.truncate().truncate() / clip.w * VIEWPORT * 0.5;
+        to_screen(a).distance(to_screen(b))
+    }
+
+    #[test]
+    fn test_line_expansion_has_requested_pixel_width() {
+        // **Feature: debug-gizmos, Property 1: Constant Pixel Width Lines**
+        // A horizontal 4px line expands vertically by 2px on each side
+
+        let quad = expand_line(Vec4::new(-0.5, 0.0, 0.5, 1.0), Vec4::new(0.5, 0.0, 0.5, 1.0), 4.0, VIEWPORT);
+
+        assert!((quad[0].y - 2.0 / 250.0).abs() < 1e-6);
+        assert!((quad[1].y + 2.0 / 250.0).abs() < 1e-6);
+        assert!((screen_distance(quad[0], quad[1]) - 4.0).abs() < 1e-4);
+        assert!((screen_distance(quad[3], quad[2]) - 4.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_line_width_is_independent_of_depth() {
+        // **Feature: debug-gizmos, Property 1: Constant Pixel Width Lines**
+        // Endpoints at different w (perspective depth) still produce a constant pixel width
+
+        let near = Vec4::new(-0.2, 0.1, 0.5, 1.0);
+        let far = Vec4::new(0.6, 0.8, 0.5, 8.0);
+        let quad = expand_line(near, far, 3.0, VIEWPORT);
+
+        assert!((screen_distance(quad[0], quad[1]) - 3.0).abs() < 1e-3);
+        assert!((screen_distance(quad[3], quad[2]) - 3.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_gizmos_accumulate_and_clear_each_frame() {
+        // **Feature: debug-gizmos, Property 1: Constant Pixel Width Lines**
+
+        let mut renderer = UltraRenderer::new();
+        renderer.gizmos.draw_line(Vec3::ZERO, Vec3::X, Color::RED, 1.0);
+        renderer.gizmos.draw_box(&BoundingBox::new(Vec3::ZERO, Vec3::ONE), Color::GREEN);
+        renderer.gizmos.draw_sphere(&BoundingSphere::new(Vec3::ZERO, 1.0), Color::BLUE);
+        assert_eq!(renderer.gizmos.lines.len(), 1 + 12 + 3 * 24);
+
+        let view_projection = Mat4::perspective_infinite_reverse_rh(1.0, 2.0, 0.1)
+            * Mat4::from_translation(Vec3::new(0.0, 0.0, -5.0));
+        let vertices = renderer.gizmos.build_vertices(view_projection, VIEWPORT);
+        assert_eq!(vertices.len(), renderer.gizmos.lines.len() * 6);
+
+        renderer.clear_instances();
+        assert!(renderer.gizmos.lines.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! Tests for the GPU particle simulation path
+//!
+//! **Feature: gpu-particles, Property 1: GPU And CPU Backends Simulate The Same Particles**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_render::{
+    GpuCapabilities, GpuParticle, GpuParticleSpawn, ParticleBackend, ParticleEmitter, ParticleSimParams,
+    ParticleSystem, RenderCommand, RenderCommandBuffer, UltraRenderer, PARTICLE_SIMULATION_WGSL,
+};
+
+/// Exact in binary, so both backends accumulate ages without rounding
+const STEP: f32 = 1.0 / 64.0;
+
+/// Three emitters with different rates, velocities and lifetimes, capped so the limit is reached
+fn deterministic_system(backend: ParticleBackend) -> ParticleSystem {
+    let mut system = ParticleSystem::new();
+    system.set_backend(backend);
+    system.max_particles = 120;
+    let emitters = [
+        (Vec3::new(0.0, 0.0, -4.0), 90.0, Vec3::new(0.0, 2.0, 0.0), 0.5),
+        (Vec3::new(3.0, 1.0, -6.0), 40.0, Vec3::new(-1.5, 0.5, 0.25), 1.25),
+        (Vec3::new(-2.0, 0.0, -3.0), 25.0, Vec3::new(0.75, -1.0, 1.0), 2.0),
+    ];
+    for (position, rate, velocity, lifetime) in emitters {
+        system.add_emitter(ParticleEmitter { velocity, ..ParticleEmitter::new(position, rate, lifetime) });
+    }
+    system
+}
+
+/// Live positions, sorted so slot order doesn't matter
+fn sorted(mut positions: Vec<Vec3>) -> Vec<Vec3> {
+    positions.sort_by(|a, b| a.to_array().partial_cmp(&b.to_array()).unwrap());
+    positions
+}
+
+fn desktop_capabilities() -> GpuCapabilities {
+    GpuCapabilities { compute_shaders: true, ..default() }
+}
+
+#[cfg(test)]
+mod gpu_particle_tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_and_cpu_backends_match() {
+        // **Feature: gpu-particles, Property 1: GPU And CPU Backends Simulate The Same Particles**
+
+        let mut cpu = deterministic_system(ParticleBackend::Cpu);
+        let mut gpu = deterministic_system(ParticleBackend::Gpu);
+        let mut slots: Vec<GpuParticle> = Vec::new(); // Stands in for the storage buffer
+
+        let mut hit_cap = false;
+        for frame in 0..256 {
+            let emitted = cpu.update(STEP, Vec3::ZERO);
+            assert_eq!(gpu.update(STEP, Vec3::ZERO), emitted, "frame {frame}");
+            gpu.gpu.dispatch_on_cpu(&mut slots);
+
+            let live: Vec<&GpuParticle> = slots.iter().filter(|slot| slot.is_alive()).collect();
+            assert_eq!(gpu.live_count(), cpu.live_count(), "frame {frame}");
+            assert_eq!(live.len(), cpu.live_count(), "frame {frame}: shader and slot bookkeeping agree");
+            hit_cap |= cpu.live_count() == cpu.max_particles;
+
+            let expected = sorted(cpu.particles.iter().map(|particle| particle.position).collect());
+            let actual = sorted(live.iter().map(|slot| Vec3::from_array(slot.position)).collect());
+            for (expected, actual) in expected.iter().zip(&actual) {
+                assert!(expected.abs_diff_eq(*actual, 1e-4), "frame {frame}: {expected} vs {actual}");
+            }
+        }
+        assert!(hit_cap, "the scenario should exercise the particle cap");
+        assert!(gpu.gpu.slot_count() as usize <= gpu.max_particles, "expired slots are reused");
+    }
+
+    #[test]
+    fn test_shader_validates_and_matches_rust_layouts() {
+        let module = naga::front::wgsl::parse_str(PARTICLE_SIMULATION_WGSL).expect("shader parses");
+        naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+            .validate(&module)
+            .expect("shader validates");
+
+        let mut layouter = naga::proc::Layouter::default();
+        layouter.update(module.to_ctx()).unwrap();
+        let size = |name: &str| {
+            let (handle, _) = module.types.iter().find(|(_, ty)| ty.name.as_deref() == Some(name)).unwrap();
+            layouter[handle].size as usize
+        };
+        assert_eq!(size("Particle"), std::mem::size_of::<GpuParticle>());
+        assert_eq!(size("Spawn"), std::mem::size_of::<GpuParticleSpawn>());
+        assert_eq!(size("Params"), std::mem::size_of::<ParticleSimParams>());
+
+        for entry in ["simulate", "spawn"] {
+            assert!(module.entry_points.iter().any(|point| point.name == entry), "missing {entry}");
+        }
+    }
+
+    #[test]
+    fn test_gpu_backend_needs_compute_shaders() {
+        let mut renderer = UltraRenderer::new();
+        assert_eq!(renderer.particles.backend(), ParticleBackend::Cpu, "CPU until an adapter reports");
+
+        renderer.apply_capabilities(&GpuCapabilities::default());
+        assert_eq!(renderer.set_particle_backend(ParticleBackend::Gpu), ParticleBackend::Cpu, "falls back");
+
+        renderer.apply_capabilities(&desktop_capabilities());
+        assert_eq!(renderer.particles.backend(), ParticleBackend::Gpu);
+
+        assert_eq!(renderer.set_particle_backend(ParticleBackend::Cpu), ParticleBackend::Cpu, "CPU on request");
+        renderer.apply_capabilities(&desktop_capabilities());
+        assert_eq!(renderer.particles.backend(), ParticleBackend::Cpu, "the request sticks");
+    }
+
+    #[test]
+    fn test_gpu_particles_are_recorded_as_compute_then_draw() {
+        let mut renderer = UltraRenderer::new();
+        renderer.apply_capabilities(&desktop_capabilities());
+        renderer.particles.add_emitter(ParticleEmitter::new(Vec3::new(0.0, 0.0, -5.0), 64.0, 1.0));
+        renderer.particles.update(STEP * 4.0, Vec3::ZERO);
+        renderer.render_viewport(&CameraController::new(), Rect::new(0.0, 0.0, 1920.0, 1080.0));
+
+        let mut buffer = RenderCommandBuffer::default();
+        renderer.record_commands(&mut buffer);
+
+        let RenderCommand::SimulateParticles { params, first_spawn } = buffer.commands[0] else {
+            panic!("compute pass first, got {:?}", buffer.commands[0]);
+        };
+        assert_eq!((params.slot_count, params.spawn_count, first_spawn), (4, 4, 0));
+        assert_eq!(buffer.particle_spawns.len(), 4);
+        assert_eq!(buffer.commands.last(), Some(&RenderCommand::DrawParticles { count: 4 }));
+
+        renderer.particles.set_backend(ParticleBackend::Cpu);
+        renderer.record_commands(&mut buffer);
+        assert!(buffer.particle_spawns.is_empty());
+        assert!(!buffer.commands.iter().any(|command| matches!(command, RenderCommand::SimulateParticles { .. })));
+    }
+}
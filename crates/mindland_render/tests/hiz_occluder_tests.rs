@@ -0,0 +1,69 @@
+//! Tests for the software Hi-Z occluder threshold
+//!
+//! **Feature: hiz-occluders, Property 1: Only Large Occluders Write Depth, Everything Is Tested**
+
+use bevy::prelude::*;
+use mindland_assets::BoundingBox;
+use mindland_camera::CameraController;
+use mindland_render::{OcclusionMethod, UltraRenderer};
+
+/// Camera at the origin looking down -z
+fn view_projection() -> Mat4 {
+    let mut camera = CameraController::new();
+    camera.transform.translation = Vec3::ZERO;
+    camera.projection.aspect_ratio = 2.0; // Matches the default 256x128 Hi-Z buffer
+    camera.projection_matrix() * camera.view_matrix()
+}
+
+fn cube(center: Vec3, half_size: f32) -> BoundingBox {
+    BoundingBox::new(center - Vec3::splat(half_size), center + Vec3::splat(half_size))
+}
+
+#[cfg(test)]
+mod hiz_occluder_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_supra_threshold_occluders_write_depth() {
+        // **Feature: hiz-occluders, Property 1: Only Large Occluders Write Depth, Everything Is Tested**
+
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.set_min_occluder_screen_size(0.05);
+        let view_projection = view_projection();
+        let center = culling.hi_z.size() / 2;
+
+        // A pebble far away covers well under 5% of the screen
+        assert!(!culling.add_occluder(&cube(Vec3::new(0.0, 0.0, -60.0), 0.1), view_projection));
+        assert_eq!(culling.hi_z.depth_at(center.x, center.y), 0.0, "sub-threshold occluder wrote depth");
+
+        // A wall-sized box up close covers most of it
+        assert!(culling.add_occluder(&cube(Vec3::new(0.0, 0.0, -10.0), 4.0), view_projection));
+        let depth = culling.hi_z.depth_at(center.x, center.y);
+        let expected = view_projection.project_point3(Vec3::new(0.0, 0.0, -6.0)).z; // Front face
+        assert!((depth - expected).abs() < 1e-4, "depth {depth}, expected {expected}");
+        assert_eq!(culling.hi_z.depth_at(0, 0), 0.0, "the wall shouldn't reach the corner");
+    }
+
+    #[test]
+    fn test_small_objects_are_still_tested() {
+        // **Feature: hiz-occluders, Property 1: Only Large Occluders Write Depth, Everything Is Tested**
+
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.occlusion_method = OcclusionMethod::SoftwareHiZ;
+        let view_projection = view_projection();
+
+        culling.hi_z.clear();
+        culling.add_occluder(&cube(Vec3::new(0.0, 0.0, -10.0), 4.0), view_projection);
+        culling.hi_z.build_pyramid();
+
+        let behind = cube(Vec3::new(0.0, 0.0, -60.0), 0.1);
+        let in_front = cube(Vec3::new(0.0, 0.0, -3.0), 0.1);
+        let beside = cube(Vec3::new(30.0, 0.0, -40.0), 0.1);
+        assert!(culling.is_occluded_by_hi_z(&behind, view_projection));
+        assert!(!culling.is_occluded_by_hi_z(&in_front, view_projection));
+        assert!(!culling.is_occluded_by_hi_z(&beside, view_projection));
+
+        culling.occlusion_method = OcclusionMethod::HardwareQuery;
+        assert!(!culling.is_occluded_by_hi_z(&behind, view_projection));
+    }
+}
@@ -0,0 +1,75 @@
+//! Tests for rotating dynamic instance buffers
+//!
+//! **Feature: instance-buffering, Property 1: The CPU Never Writes The Buffer The GPU Is Reading**
+
+use bevy::prelude::*;
+use mindland_render::{UltraRenderer, MAX_INSTANCE_BUFFERS, MIN_INSTANCE_BUFFERS};
+
+/// Submit one frame of `count` instances tagged with `frame` in their texture index
+fn submit_frame(renderer: &mut UltraRenderer, frame: u32, count: usize) {
+    for _ in 0..count {
+        assert!(renderer.add_instance(Mat4::IDENTITY, frame, Color::WHITE));
+    }
+    renderer.upload_instances();
+}
+
+#[cfg(test)]
+mod instance_buffering_tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_cycles_through_every_buffer() {
+        // **Feature: instance-buffering, Property 1: The CPU Never Writes The Buffer The GPU Is Reading**
+
+        for requested in [2, 3] {
+            let mut renderer = UltraRenderer::new();
+            let count = renderer.set_instance_buffer_count(requested);
+            assert_eq!(count, requested);
+            assert_eq!(renderer.instanced_renderer.buffer_count(), count);
+
+            let mut visited = Vec::new();
+            for frame in 0..count as u32 {
+                let write = renderer.instanced_renderer.write_buffer();
+                visited.push(write);
+                submit_frame(&mut renderer, frame, 2);
+                renderer.clear_instances();
+
+                // The frame just submitted stays intact while the next one is written elsewhere
+                assert_ne!(renderer.instanced_renderer.write_buffer(), write);
+                let submitted = renderer.instanced_renderer.buffer(write);
+                assert!(submitted.len() == 2 && submitted.iter().all(|instance| instance.texture_index == frame));
+            }
+
+            visited.sort_unstable();
+            assert_eq!(visited, (0..count).collect::<Vec<_>>());
+            assert_eq!(renderer.instanced_renderer.write_buffer(), 0, "rotation should wrap after {count} frames");
+        }
+    }
+
+    #[test]
+    fn test_add_and_clear_target_the_write_buffer() {
+        // **Feature: instance-buffering, Property 1: The CPU Never Writes The Buffer The GPU Is Reading**
+
+        let mut renderer = UltraRenderer::new();
+        submit_frame(&mut renderer, 7, 3);
+        let write = renderer.instanced_renderer.write_buffer();
+        assert_eq!(renderer.instanced_renderer.buffer(write).len(), 3);
+        assert_eq!(renderer.instanced_renderer.buffer(write).as_ptr(), renderer.instanced_renderer.instance_data.as_ptr());
+
+        renderer.clear_instances();
+        let next = renderer.instanced_renderer.write_buffer();
+        assert!(renderer.instanced_renderer.buffer(next).is_empty(), "the new write buffer starts empty");
+
+        submit_frame(&mut renderer, 8, 1);
+        assert_eq!(renderer.instanced_renderer.buffer(next).len(), 1);
+        assert_eq!(renderer.instanced_renderer.buffer(write).len(), 3, "adding must not touch the in-flight buffer");
+    }
+
+    #[test]
+    fn test_buffer_count_is_clamped() {
+        let mut renderer = UltraRenderer::new();
+        assert_eq!(renderer.instanced_renderer.buffer_count(), MIN_INSTANCE_BUFFERS);
+        assert_eq!(renderer.set_instance_buffer_count(1), MIN_INSTANCE_BUFFERS);
+        assert_eq!(renderer.set_instance_buffer_count(8), MAX_INSTANCE_BUFFERS);
+    }
+}
@@ -0,0 +1,54 @@
+//! Tests for the per-instance custom data slot
+//!
+//! **Feature: instance-custom-data, Property 1: Custom Data Reaches The Instance Buffer Unchanged**
+
+use bevy::prelude::*;
+use mindland_render::{InstanceData, UltraRenderer};
+
+#[cfg(test)]
+mod instance_custom_data_tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_data_survives_add_and_clear() {
+        // **Feature: instance-custom-data, Property 1: Custom Data Reaches The Instance Buffer Unchanged**
+
+        let mut renderer = UltraRenderer::new();
+        let custom = [0.25, -1.0, 42.0, 1.0e6];
+        assert!(renderer.add_instance_with_custom(Mat4::from_translation(Vec3::new(0.0, 0.0, -5.0)), 3, Color::RED, custom));
+        assert!(renderer.add_instance(Mat4::IDENTITY, 1, Color::WHITE));
+
+        let instances = &renderer.instanced_renderer.instance_data;
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[0].custom, custom);
+        assert_eq!(instances[0].texture_index, 3);
+        assert_eq!(instances[1].custom, [0.0; 4], "add_instance should leave the slot zeroed");
+
+        renderer.clear_instances();
+        assert!(renderer.instanced_renderer.instance_data.is_empty());
+
+        let custom = [7.0, 8.0, 9.0, 10.0];
+        assert!(renderer.add_instance_with_custom(Mat4::IDENTITY, 0, Color::WHITE, custom));
+        assert_eq!(renderer.instanced_renderer.instance_data.len(), 1);
+        assert_eq!(renderer.instanced_renderer.instance_data[0].custom, custom);
+    }
+
+    #[test]
+    fn test_instance_data_layout_matches_shader() {
+        assert_eq!(std::mem::size_of::<InstanceData>(), 96);
+        assert_eq!(std::mem::size_of::<InstanceData>() % 16, 0);
+        assert_eq!(std::mem::offset_of!(InstanceData, custom), 80, "vec4 must start on a 16-byte boundary");
+    }
+
+    #[test]
+    fn test_instance_data_is_pod() {
+        let instance = InstanceData::new(Mat4::from_scale(Vec3::splat(2.0)), 5, Color::BLUE).with_custom([1.0, 2.0, 3.0, 4.0]);
+        let bytes: &[u8] = bytemuck::bytes_of(&instance);
+        assert_eq!(&bytes[80..96], bytemuck::cast_slice::<f32, u8>(&[1.0, 2.0, 3.0, 4.0]));
+
+        let round_trip: InstanceData = bytemuck::pod_read_unaligned(bytes);
+        assert_eq!(round_trip.custom, instance.custom);
+        assert_eq!(round_trip.transform, instance.transform);
+        assert_eq!(round_trip.texture_index, 5);
+    }
+}
@@ -0,0 +1,66 @@
+//! Tests for static/dynamic instance buffer uploads
+//!
+//! **Feature: instance-upload, Property 1: Unchanged Static Instances Are Not Re-uploaded**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_render::{InstanceData, UltraRenderer};
+
+#[cfg(test)]
+mod instance_upload_tests {
+    use super::*;
+
+    const INSTANCE_BYTES: usize = std::mem::size_of::<InstanceData>();
+
+    fn add_dynamic(renderer: &mut UltraRenderer, count: usize) {
+        for i in 0..count {
+            renderer.add_instance(Mat4::from_translation(Vec3::new(i as f32, 0.0, -10.0)), 0, Color::WHITE);
+        }
+    }
+
+    #[test]
+    fn test_static_buffer_uploaded_once() {
+        // **Feature: instance-upload, Property 1: Unchanged Static Instances Are Not Re-uploaded**
+
+        let mut renderer = UltraRenderer::new();
+        for i in 0..100 {
+            assert!(renderer.add_static_instance(Mat4::from_translation(Vec3::new(0.0, i as f32, -20.0)), 1, Color::WHITE));
+        }
+        add_dynamic(&mut renderer, 10);
+
+        let first = renderer.upload_instances();
+        assert_eq!(first.bytes_uploaded, 110 * INSTANCE_BYTES);
+        assert_eq!((first.static_instances, first.dynamic_instances), (100, 10));
+
+        // Following frames only re-upload the dynamic buffer
+        for _ in 0..3 {
+            renderer.clear_instances();
+            add_dynamic(&mut renderer, 10);
+            assert_eq!(renderer.upload_instances().bytes_uploaded, 10 * INSTANCE_BYTES);
+        }
+        assert_eq!(renderer.stats.static_instances, 100);
+
+        // Changing the static set re-uploads it once
+        renderer.add_static_instance(Mat4::IDENTITY, 1, Color::WHITE);
+        assert_eq!(renderer.upload_instances().bytes_uploaded, 111 * INSTANCE_BYTES);
+        renderer.clear_static_instances();
+        assert_eq!(renderer.upload_instances().bytes_uploaded, 10 * INSTANCE_BYTES);
+    }
+
+    #[test]
+    fn test_static_instances_are_culled_per_viewport() {
+        // **Feature: instance-upload, Property 1: Unchanged Static Instances Are Not Re-uploaded**
+
+        let mut renderer = UltraRenderer::new();
+        let camera = CameraController::new();
+        let ahead = camera.transform.translation + camera.transform.forward() * 10.0;
+        let behind = camera.transform.translation - camera.transform.forward() * 10.0;
+        renderer.add_static_instance(Mat4::from_translation(ahead), 0, Color::WHITE);
+        renderer.add_static_instance(Mat4::from_translation(behind), 0, Color::WHITE);
+
+        renderer.render_viewport(&camera, Rect::new(0.0, 0.0, 1280.0, 800.0));
+
+        assert_eq!(renderer.viewport_passes[0].visible_static_instances, vec![0]);
+        assert!(renderer.viewport_passes[0].visible_instances.is_empty());
+    }
+}
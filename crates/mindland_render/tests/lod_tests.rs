@@ -0,0 +1,67 @@
+//! Tests for level-of-detail selection
+//!
+//! **Feature: screen-space-lod, Property 2: Resolution-Independent LOD Selection**
+
+use mindland_assets::{BoundingSphere, MeshId};
+use mindland_camera::CameraController;
+use mindland_render::{LodLevel, LodMesh, LodSelection, UltraRenderer};
+use bevy::prelude::*;
+
+fn three_level_mesh() -> LodMesh {
+    LodMesh::new(vec![
+        LodLevel { mesh: MeshId::default(), max_distance: 32.0, min_screen_size: 200.0 },
+        LodLevel { mesh: MeshId::default(), max_distance: 96.0, min_screen_size: 50.0 },
+        LodLevel { mesh: MeshId::default(), max_distance: f32::MAX, min_screen_size: 0.0 },
+    ])
+}
+
+#[cfg(test)]
+mod lod_tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_selection_uses_table() {
+        let mesh = three_level_mesh();
+
+        assert_eq!(mesh.select_by_distance(10.0), 0);
+        assert_eq!(mesh.select_by_distance(50.0), 1);
+        assert_eq!(mesh.select_by_distance(500.0), 2);
+    }
+
+    #[test]
+    fn test_screen_size_selection_follows_resolution() {
+        // **Feature: screen-space-lod, Property 2: Resolution-Independent LOD Selection**
+        // The same object at the same distance gets more detail on a taller viewport
+
+        let mut mesh = three_level_mesh();
+        mesh.selection = LodSelection::ScreenSize;
+
+        let camera = CameraController::new();
+        let bounds = BoundingSphere::new(Vec3::new(0.0, 1.8, -40.0), 2.0);
+
+        let low_res = mesh.select(&camera, &bounds, 540.0);
+        let high_res = mesh.select(&camera, &bounds, 2160.0);
+
+        assert!(high_res < low_res, "higher resolution should select a more detailed LOD");
+    }
+
+    #[test]
+    fn test_positive_bias_selects_lower_detail() {
+        let camera = CameraController::new();
+        let bounds = BoundingSphere::new(camera.transform.translation + Vec3::new(0.0, 0.0, -20.0), 2.0);
+        let mut renderer = UltraRenderer::new();
+
+        for selection in [LodSelection::Distance, LodSelection::ScreenSize] {
+            let mut mesh = three_level_mesh();
+            mesh.selection = selection;
+
+            renderer.lod_bias = 0.0;
+            let neutral = renderer.select_lod(&mesh, &camera, &bounds, 1080.0);
+            assert_eq!(neutral, mesh.select(&camera, &bounds, 1080.0));
+
+            renderer.lod_bias = 64.0;
+            let biased = renderer.select_lod(&mesh, &camera, &bounds, 1080.0);
+            assert!(biased > neutral, "{selection:?}: bias selected level {biased}, neutral {neutral}");
+        }
+    }
+}
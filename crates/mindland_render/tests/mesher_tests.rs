@@ -0,0 +1,68 @@
+//! Tests for off-thread greedy chunk meshing
+//!
+//! **Feature: async-meshing, Property 1: All Queued Jobs Complete**
+
+use mindland_render::{greedy_mesh, ChunkData, ChunkMesher, CHUNK_SIZE};
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod mesher_tests {
+    use super::*;
+
+    #[test]
+    fn test_single_block_produces_six_faces() {
+        let mut chunk = ChunkData::new();
+        chunk.set(5, 5, 5, 1);
+
+        let mesh = greedy_mesh(&chunk);
+        assert_eq!(mesh.count_vertices(), 6 * 4);
+        assert_eq!(mesh.indices().unwrap().len(), 6 * 6);
+    }
+
+    #[test]
+    fn test_coplanar_faces_are_merged() {
+        // A solid 4x4x4 cube of one block type still needs only 6 quads
+        let mut chunk = ChunkData::new();
+        for x in 0..4 {
+            for y in 0..4 {
+                for z in 0..4 {
+                    chunk.set(x, y, z, 3);
+                }
+            }
+        }
+
+        let mesh = greedy_mesh(&chunk);
+        assert_eq!(mesh.count_vertices(), 6 * 4);
+    }
+
+    #[test]
+    fn test_hundred_queued_chunks_all_complete() {
+        // **Feature: async-meshing, Property 1: All Queued Jobs Complete**
+
+        let mut mesher = ChunkMesher::with_workers(4);
+        let mut requested = HashSet::new();
+
+        for job in 0..100 {
+            let mut chunk = ChunkData::new();
+            // Vary the contents so jobs differ in cost
+            for i in 0..(job % CHUNK_SIZE) {
+                chunk.set(i, (job * 7) % CHUNK_SIZE, (i * 3) % CHUNK_SIZE, (job % 5 + 1) as u16);
+            }
+            requested.insert(mesher.request(chunk));
+        }
+        assert_eq!(requested.len(), 100, "job ids must be unique");
+
+        let mut finished = HashSet::new();
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while finished.len() < requested.len() && Instant::now() < deadline {
+            for (job_id, _mesh) in mesher.poll_finished() {
+                assert!(finished.insert(job_id), "job completed twice");
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_eq!(finished, requested);
+        assert_eq!(mesher.pending_jobs(), 0);
+    }
+}
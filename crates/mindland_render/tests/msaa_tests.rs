@@ -0,0 +1,73 @@
+//! Tests for MSAA sample count and resolve format selection
+//!
+//! **Feature: msaa-target, Property 1: Unsupported Sample Counts Downgrade**
+
+use bevy::render::render_resource::{TextureFormat, TextureUsages};
+use mindland_render::{MsaaTarget, UltraRenderer};
+use wgpu::{TextureFormatFeatureFlags as Flags, TextureFormatFeatures};
+
+fn features(flags: Flags) -> TextureFormatFeatures {
+    TextureFormatFeatures {
+        allowed_usages: TextureUsages::RENDER_ATTACHMENT,
+        flags,
+    }
+}
+
+#[cfg(test)]
+mod msaa_tests {
+    use super::*;
+
+    #[test]
+    fn test_format_supporting_4x_but_not_8x_downgrades_to_4x() {
+        // **Feature: msaa-target, Property 1: Unsupported Sample Counts Downgrade**
+
+        let mut renderer = UltraRenderer::new();
+        let target = renderer.configure_msaa(TextureFormat::Bgra8UnormSrgb, 8, |_| {
+            features(Flags::MULTISAMPLE_X2 | Flags::MULTISAMPLE_X4 | Flags::MULTISAMPLE_RESOLVE)
+        });
+
+        assert_eq!(target.sample_count, 4);
+        assert_eq!(target.resolve_format(), TextureFormat::Bgra8UnormSrgb);
+        assert!(target.needs_resolve());
+        assert!(!target.needs_present_blit());
+        assert_eq!(renderer.msaa, target);
+    }
+
+    #[test]
+    fn test_format_without_resolve_falls_back_to_another_format() {
+        // **Feature: msaa-target, Property 1: Unsupported Sample Counts Downgrade**
+
+        let target = MsaaTarget::select(TextureFormat::Rgb10a2Unorm, 4, |format| match format {
+            TextureFormat::Bgra8UnormSrgb => features(Flags::MULTISAMPLE_X4 | Flags::MULTISAMPLE_RESOLVE),
+            _ => features(Flags::MULTISAMPLE_X4), // Multisamples but can't resolve
+        });
+
+        assert_eq!(target.format, TextureFormat::Bgra8UnormSrgb);
+        assert_eq!(target.sample_count, 4);
+        assert!(target.needs_present_blit());
+    }
+
+    #[test]
+    fn test_surface_format_kept_at_lower_count_over_switching_format() {
+        let target = MsaaTarget::select(TextureFormat::Rgba8Unorm, 8, |format| match format {
+            TextureFormat::Rgba8Unorm => features(Flags::MULTISAMPLE_X2 | Flags::MULTISAMPLE_RESOLVE),
+            _ => features(Flags::MULTISAMPLE_X8 | Flags::MULTISAMPLE_RESOLVE),
+        });
+
+        assert_eq!((target.format, target.sample_count), (TextureFormat::Rgba8Unorm, 2));
+    }
+
+    #[test]
+    fn test_no_support_disables_msaa() {
+        let target = MsaaTarget::select(TextureFormat::Bgra8UnormSrgb, 4, |_| features(Flags::empty()));
+        assert_eq!(target, MsaaTarget::disabled(TextureFormat::Bgra8UnormSrgb));
+        assert!(!target.needs_resolve());
+    }
+
+    #[test]
+    fn test_odd_request_rounds_down_to_power_of_two() {
+        let all = Flags::MULTISAMPLE_X2 | Flags::MULTISAMPLE_X4 | Flags::MULTISAMPLE_X8 | Flags::MULTISAMPLE_RESOLVE;
+        assert_eq!(MsaaTarget::select(TextureFormat::Bgra8UnormSrgb, 6, |_| features(all)).sample_count, 4);
+        assert_eq!(MsaaTarget::select(TextureFormat::Bgra8UnormSrgb, 1, |_| features(all)).sample_count, 1);
+    }
+}
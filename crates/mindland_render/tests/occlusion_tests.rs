@@ -0,0 +1,83 @@
+//! Tests for hardware occlusion query history
+//!
+//! **Feature: occlusion-queries, Property 1: One-Frame-Latency Visibility History**
+
+use bevy::prelude::*;
+use mindland_assets::BoundingBox;
+use mindland_render::{OcclusionHistory, OcclusionMethod, OcclusionVisibility, UltraRenderer};
+
+fn unit_box_at(center: Vec3) -> BoundingBox {
+    BoundingBox::new(center - Vec3::splat(0.5), center + Vec3::splat(0.5))
+}
+
+#[cfg(test)]
+mod occlusion_tests {
+    use super::*;
+
+    #[test]
+    fn test_history_visible_occluded_visible_transitions() {
+        // **Feature: occlusion-queries, Property 1: One-Frame-Latency Visibility History**
+
+        let mut history = OcclusionHistory::default();
+        assert_eq!(history.visibility, OcclusionVisibility::Visible); // Unqueried objects draw
+
+        history.record(0);
+        assert!(history.is_occluded());
+        assert_eq!(history.frames_in_state, 0);
+
+        history.record(0);
+        assert!(history.is_occluded());
+        assert_eq!(history.frames_in_state, 1);
+
+        history.record(12); // Any passing sample reveals it again
+        assert!(!history.is_occluded());
+        assert_eq!(history.frames_in_state, 0);
+    }
+
+    #[test]
+    fn test_culling_uses_previous_frame_results() {
+        // **Feature: occlusion-queries, Property 1: One-Frame-Latency Visibility History**
+
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.occlusion_method = OcclusionMethod::HardwareQuery;
+        let camera = Vec3::ZERO;
+
+        // Frame 1: issue queries; nothing is culled until results come back
+        culling.occlusion_queries.issue(1, unit_box_at(Vec3::new(0.0, 0.0, -20.0)), camera);
+        culling.occlusion_queries.issue(2, unit_box_at(Vec3::new(5.0, 0.0, -20.0)), camera);
+        let queries = culling.occlusion_queries.take_queries();
+        assert_eq!(queries.len(), 2);
+        assert!(!culling.is_occluded(1) && !culling.is_occluded(2));
+
+        // Frame 2: object 1's box was fully hidden last frame
+        culling.occlusion_queries.resolve([(1, 0), (2, 40)]);
+        assert!(culling.is_occluded(1));
+        assert!(!culling.is_occluded(2));
+
+        // Frame 3: object 1 came back into view
+        culling.occlusion_queries.resolve([(1, 3)]);
+        assert!(!culling.is_occluded(1));
+    }
+
+    #[test]
+    fn test_camera_inside_bounds_is_visible_without_query() {
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.occlusion_queries.resolve([(7, 0)]);
+        assert!(culling.is_occluded(7));
+
+        culling.occlusion_queries.issue(7, unit_box_at(Vec3::ZERO), Vec3::new(0.1, 0.0, 0.0));
+        assert!(culling.occlusion_queries.take_queries().is_empty());
+        assert!(!culling.is_occluded(7));
+    }
+
+    #[test]
+    fn test_other_methods_never_report_occlusion() {
+        let mut culling = UltraRenderer::new().culling_system;
+        culling.occlusion_queries.resolve([(1, 0)]);
+
+        for method in [OcclusionMethod::None, OcclusionMethod::SoftwareHiZ] {
+            culling.occlusion_method = method;
+            assert!(!culling.is_occluded(1));
+        }
+    }
+}
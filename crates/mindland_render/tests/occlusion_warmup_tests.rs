@@ -0,0 +1,108 @@
+//! Tests for pausing occlusion culling after a camera teleport or cut
+//!
+//! **Feature: occlusion-warmup, Property 1: Nothing Is Occlusion-Culled On The Frame After A Teleport**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_render::{OcclusionMethod, UltraRenderer};
+
+const WINDOW: Rect = Rect { min: Vec2::ZERO, max: Vec2::new(1920.0, 1080.0) };
+
+/// Render one frame from `camera`, then resolve queries with ids 1-3 fully hidden
+fn render_frame(renderer: &mut UltraRenderer, camera: &CameraController) {
+    renderer.render_viewport(camera, WINDOW);
+    renderer.culling_system.occlusion_queries.resolve([(1, 0), (2, 0), (3, 0)]);
+    renderer.clear_instances();
+}
+
+fn hardware_query_renderer() -> UltraRenderer {
+    let mut renderer = UltraRenderer::new();
+    renderer.culling_system.occlusion_method = OcclusionMethod::HardwareQuery;
+    renderer
+}
+
+#[cfg(test)]
+mod occlusion_warmup_tests {
+    use super::*;
+
+    #[test]
+    fn test_teleport_disables_occlusion_for_one_frame() {
+        // **Feature: occlusion-warmup, Property 1: Nothing Is Occlusion-Culled On The Frame After A Teleport**
+
+        let mut renderer = hardware_query_renderer();
+        let mut camera = CameraController::new();
+        render_frame(&mut renderer, &camera);
+        render_frame(&mut renderer, &camera);
+        assert!((1..=3).all(|id| renderer.culling_system.is_occluded(id)));
+
+        // Teleport: the first frame at the destination culls nothing
+        camera.transform.translation += Vec3::new(500.0, 0.0, 0.0);
+        renderer.render_viewport(&camera, WINDOW);
+        assert!(renderer.culling_system.is_warming_up());
+        assert!((1..=3).all(|id| !renderer.culling_system.is_occluded(id)));
+        renderer.culling_system.occlusion_queries.resolve([(1, 0), (2, 5)]); // This frame's queries come back
+        renderer.clear_instances();
+
+        // Culling resumes on fresh results only
+        renderer.render_viewport(&camera, WINDOW);
+        assert!(!renderer.culling_system.is_warming_up());
+        assert!(renderer.culling_system.is_occluded(1));
+        assert!(!renderer.culling_system.is_occluded(2));
+        assert!(!renderer.culling_system.is_occluded(3), "pre-teleport history was dropped");
+    }
+
+    #[test]
+    fn test_normal_movement_keeps_culling() {
+        let mut renderer = hardware_query_renderer();
+        let mut camera = CameraController::new();
+        render_frame(&mut renderer, &camera);
+
+        camera.transform.translation.x += 0.25; // A frame of sprinting
+        camera.transform.rotate_y(0.05);
+        renderer.render_viewport(&camera, WINDOW);
+
+        assert!(!renderer.culling_system.is_warming_up());
+        assert!(renderer.culling_system.is_occluded(1));
+    }
+
+    #[test]
+    fn test_camera_cut_by_rotation() {
+        let mut renderer = hardware_query_renderer();
+        let mut camera = CameraController::new();
+        render_frame(&mut renderer, &camera);
+
+        camera.transform.rotate_y(std::f32::consts::PI);
+        renderer.render_viewport(&camera, WINDOW);
+
+        assert!(renderer.culling_system.is_warming_up());
+        assert!(!renderer.culling_system.is_occluded(1));
+    }
+
+    #[test]
+    fn test_threshold_is_configurable() {
+        let mut culling = hardware_query_renderer().culling_system;
+        culling.teleport_distance = 1000.0;
+        culling.track_camera(Vec3::ZERO, Quat::IDENTITY);
+
+        assert!(!culling.track_camera(Vec3::new(500.0, 0.0, 0.0), Quat::IDENTITY));
+        assert!(culling.track_camera(Vec3::new(2000.0, 0.0, 0.0), Quat::IDENTITY));
+    }
+
+    #[test]
+    fn test_split_screen_passes_dont_count_as_cuts() {
+        let mut renderer = hardware_query_renderer();
+        let player_one = CameraController::new();
+        let mut player_two = CameraController::new();
+        player_two.transform.translation.x += 500.0;
+
+        for _ in 0..2 {
+            renderer.render_viewport(&player_one, Rect::new(0.0, 0.0, 960.0, 1080.0));
+            renderer.render_viewport(&player_two, Rect::new(960.0, 0.0, 1920.0, 1080.0));
+            renderer.culling_system.occlusion_queries.resolve([(1, 0)]);
+            renderer.clear_instances();
+        }
+
+        renderer.render_viewport(&player_one, Rect::new(0.0, 0.0, 960.0, 1080.0));
+        assert!(!renderer.culling_system.is_warming_up());
+    }
+}
@@ -0,0 +1,98 @@
+//! Tests for distance-based particle thinning
+//!
+//! **Feature: particle-density, Property 1: Emission Fades To Zero With Distance**
+
+use bevy::prelude::*;
+use mindland_performance::QualitySettings;
+use mindland_render::{render_quality_system, ParticleEmitter, ParticleSystem, UltraRenderer};
+
+/// Emit from a single 100/s emitter at `distance` for one second in 60 steps
+fn emitted_at(system: &mut ParticleSystem, distance: f32) -> usize {
+    system.emitters.clear();
+    system.particles.clear();
+    system.add_emitter(ParticleEmitter::new(Vec3::new(0.0, 0.0, -distance), 100.0, 10.0));
+    (0..60).map(|_| system.update(1.0 / 60.0, Vec3::ZERO)).sum()
+}
+
+#[cfg(test)]
+mod particle_density_tests {
+    use super::*;
+
+    #[test]
+    fn test_far_emitters_are_culled_and_near_ones_emit_fully() {
+        // **Feature: particle-density, Property 1: Emission Fades To Zero With Distance**
+
+        let mut system = ParticleSystem::new();
+        let cull = system.cull_distance;
+
+        assert_eq!(emitted_at(&mut system, cull * 2.0), 0);
+        assert_eq!(emitted_at(&mut system, cull * 0.5), 100);
+    }
+
+    #[test]
+    fn test_density_falls_continuously_between_distances() {
+        // **Feature: particle-density, Property 1: Emission Fades To Zero With Distance**
+
+        let system = ParticleSystem::new();
+        let mut previous = 1.0;
+        for step in 0..=40 {
+            let distance = system.full_density_distance + (system.cull_distance - system.full_density_distance) * step as f32 / 40.0;
+            let density = system.distance_density(distance);
+            assert!(density <= previous && previous - density < 0.1, "jump at {distance}: {previous} -> {density}");
+            previous = density;
+        }
+        assert_eq!(previous, 0.0);
+
+        let mut system = ParticleSystem::new();
+        let midway = (system.full_density_distance + system.cull_distance) * 0.5;
+        assert_eq!(emitted_at(&mut system, midway), 50);
+    }
+
+    #[test]
+    fn test_global_density_stacks_with_distance() {
+        let mut system = ParticleSystem::new();
+        system.particle_density = 0.5; // e.g. after thermal protection halved it
+        let cull = system.cull_distance;
+        let midway = (system.full_density_distance + cull) * 0.5;
+
+        assert_eq!(emitted_at(&mut system, cull * 0.5), 50);
+        assert_eq!(emitted_at(&mut system, midway), 25);
+        assert_eq!(emitted_at(&mut system, cull * 2.0), 0);
+    }
+
+    #[test]
+    fn test_particles_expire_and_respect_capacity() {
+        let mut system = ParticleSystem::new();
+        system.max_particles = 30;
+        system.add_emitter(ParticleEmitter::new(Vec3::ZERO, 100.0, 0.5));
+
+        for _ in 0..60 {
+            system.update(1.0 / 60.0, Vec3::ZERO);
+            assert!(system.particles.len() <= 30);
+        }
+        system.emitters.clear();
+        system.update(1.0, Vec3::ZERO);
+        assert!(system.particles.is_empty());
+    }
+
+    #[test]
+    fn test_quality_particle_density_reaches_the_renderer() {
+        // **Feature: particle-density, Property 1: Emission Fades To Zero With Distance**
+        // Thermal protection halves `QualitySettings::particle_density`; the emitters must follow
+
+        let mut app = App::new();
+        app.insert_resource(UltraRenderer::new());
+        app.insert_resource(QualitySettings::macbook_pro_2014_preset());
+        app.add_systems(Update, render_quality_system.run_if(resource_changed::<QualitySettings>()));
+        app.update();
+        let preset = QualitySettings::macbook_pro_2014_preset().particle_density;
+        assert_eq!(app.world.resource::<UltraRenderer>().particles.particle_density, preset);
+
+        app.world.resource_mut::<QualitySettings>().apply_thermal_protection();
+        app.update();
+        let mut renderer = app.world.resource_mut::<UltraRenderer>();
+        assert_eq!(renderer.particles.particle_density, preset * 0.5);
+        let cull = renderer.particles.cull_distance;
+        assert!(emitted_at(&mut renderer.particles, cull * 0.1) < 100);
+    }
+}
@@ -0,0 +1,111 @@
+//! Tests for the pick-id target
+//!
+//! **Feature: picking, Property 1: Picking Returns The Visible Instance's Id**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_render::UltraRenderer;
+
+const WINDOW: Rect = Rect { min: Vec2::ZERO, max: Vec2::new(1920.0, 1080.0) };
+
+/// In front of the default camera, `distance` blocks away and `offset` to the side
+fn ahead(offset: f32, distance: f32) -> Mat4 {
+    Mat4::from_translation(Vec3::new(offset, 1.8, -distance))
+}
+
+/// A centered block with neighbours on either side and a larger block hidden behind it
+fn add_scene(renderer: &mut UltraRenderer) {
+    renderer.add_pickable_instance(ahead(-3.0, 10.0), 0, Color::WHITE, 3);
+    renderer.add_pickable_instance(ahead(0.0, 20.0) * Mat4::from_scale(Vec3::splat(4.0)), 0, Color::WHITE, 9);
+    renderer.add_pickable_instance(ahead(0.0, 10.0), 0, Color::WHITE, 7);
+    renderer.add_pickable_instance(ahead(3.0, 10.0), 0, Color::WHITE, 5);
+}
+
+/// Render the scene for `frames` frames
+fn render_frames(renderer: &mut UltraRenderer, frames: usize) {
+    let camera = CameraController::new();
+    for _ in 0..frames {
+        add_scene(renderer);
+        renderer.render_viewport(&camera, WINDOW);
+        renderer.clear_instances();
+    }
+}
+
+fn picking_renderer() -> UltraRenderer {
+    let mut renderer = UltraRenderer::new();
+    renderer.picking.enabled = true;
+    renderer
+}
+
+#[cfg(test)]
+mod picking_tests {
+    use super::*;
+
+    #[test]
+    fn test_center_picks_centered_object() {
+        // **Feature: picking, Property 1: Picking Returns The Visible Instance's Id**
+
+        let mut renderer = picking_renderer();
+        render_frames(&mut renderer, 2);
+
+        assert_eq!(renderer.pick_at(WINDOW.center()), Some(7), "the nearer block hides the large one behind it");
+    }
+
+    #[test]
+    fn test_neighbours_and_background() {
+        // **Feature: picking, Property 1: Picking Returns The Visible Instance's Id**
+
+        let mut renderer = picking_renderer();
+        render_frames(&mut renderer, 2);
+
+        let center = WINDOW.center();
+        let pixels_per_block = center.y / (10.0 * (CameraController::new().projection.fov * 0.5).tan());
+        assert_eq!(renderer.pick_at(center - Vec2::new(3.0 * pixels_per_block, 0.0)), Some(3));
+        assert_eq!(renderer.pick_at(center + Vec2::new(3.0 * pixels_per_block, 0.0)), Some(5));
+        assert_eq!(renderer.pick_at(center + Vec2::new(0.0, 0.8 * pixels_per_block)), Some(9));
+        assert_eq!(renderer.pick_at(Vec2::new(5.0, 5.0)), None, "empty sky");
+        assert_eq!(renderer.pick_at(Vec2::new(-1.0, 5.0)), None, "outside the window");
+    }
+
+    #[test]
+    fn test_readback_trails_rendering() {
+        let mut renderer = picking_renderer();
+        render_frames(&mut renderer, 1);
+        assert_eq!(renderer.pick_at(WINDOW.center()), None, "first frame's copy is still being mapped");
+
+        render_frames(&mut renderer, 1);
+        assert_eq!(renderer.pick_at(WINDOW.center()), Some(7));
+
+        // The scene goes away; the stale answer holds until the empty frame is read back
+        renderer.render_viewport(&CameraController::new(), WINDOW);
+        renderer.clear_instances();
+        assert_eq!(renderer.pick_at(WINDOW.center()), Some(7));
+        renderer.render_viewport(&CameraController::new(), WINDOW);
+        renderer.clear_instances();
+        assert_eq!(renderer.pick_at(WINDOW.center()), None);
+    }
+
+    #[test]
+    fn test_picking_at_reduced_render_scale() {
+        let mut renderer = picking_renderer();
+        renderer.set_render_scale(0.5);
+        render_frames(&mut renderer, 2);
+
+        assert_eq!(renderer.pick_at(WINDOW.center()), Some(7), "window coordinates map into the scaled target");
+    }
+
+    #[test]
+    fn test_unpickable_and_disabled() {
+        let mut renderer = picking_renderer();
+        for _ in 0..2 {
+            renderer.add_instance(ahead(0.0, 10.0), 0, Color::WHITE);
+            renderer.render_viewport(&CameraController::new(), WINDOW);
+            renderer.clear_instances();
+        }
+        assert_eq!(renderer.pick_at(WINDOW.center()), None, "instances without a pick id aren't drawn");
+
+        let mut disabled = UltraRenderer::new();
+        render_frames(&mut disabled, 2);
+        assert_eq!(disabled.pick_at(WINDOW.center()), None);
+    }
+}
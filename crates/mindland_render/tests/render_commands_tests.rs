@@ -0,0 +1,61 @@
+//! Tests for recording a frame into a render command buffer
+//!
+//! **Feature: render-commands, Property 1: Recorded Frames Own Their Instance Data**
+
+use bevy::prelude::*;
+use mindland_camera::CameraController;
+use mindland_render::{RenderCommand, RenderCommandBuffer, UltraRenderer};
+
+/// Transform placing an instance in front of the default camera
+fn ahead(x: f32) -> Mat4 {
+    Mat4::from_translation(Vec3::new(x, 0.0, -10.0))
+}
+
+#[cfg(test)]
+mod render_commands_tests {
+    use super::*;
+
+    #[test]
+    fn test_record_copies_each_pass() {
+        // **Feature: render-commands, Property 1: Recorded Frames Own Their Instance Data**
+
+        let mut renderer = UltraRenderer::new();
+        renderer.add_static_instance(ahead(0.0), 1, Color::WHITE);
+        renderer.add_instance(ahead(1.0), 2, Color::WHITE);
+        renderer.add_instance(ahead(-1.0), 3, Color::WHITE);
+        renderer.draw_text("ok", Vec2::ZERO, 16.0, Color::WHITE);
+        let camera = CameraController::new();
+        renderer.render_viewport(&camera, Rect::new(0.0, 0.0, 960.0, 1080.0));
+        renderer.render_viewport(&camera, Rect::new(960.0, 0.0, 1920.0, 1080.0));
+
+        let mut buffer = RenderCommandBuffer::with_capacity(16);
+        renderer.record_commands(&mut buffer);
+        renderer.clear_instances(); // The recording must not depend on the renderer's buffers
+
+        let kinds: Vec<&str> = buffer.commands.iter().map(|command| match command {
+            RenderCommand::BeginPass { .. } => "pass",
+            RenderCommand::DrawInstances { .. } => "draw",
+            RenderCommand::DrawText { .. } => "text",
+            RenderCommand::SimulateParticles { .. } => "simulate",
+            RenderCommand::DrawParticles { .. } => "particles",
+        }).collect();
+        assert_eq!(kinds, vec!["pass", "draw", "pass", "draw", "text"], "static and dynamic merge into one draw");
+        assert_eq!(buffer.instances.len(), 2 * 3 + 2);
+        let RenderCommand::DrawInstances { first, count } = buffer.commands[1] else { unreachable!() };
+        let mut textures: Vec<u32> = (first..first + count).map(|index| buffer.instances[index as usize].texture_index).collect();
+        textures.sort();
+        assert_eq!(textures, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_record_replaces_previous_contents() {
+        let mut renderer = UltraRenderer::new();
+        let mut buffer = RenderCommandBuffer::with_capacity(16);
+        renderer.draw_text("first", Vec2::ZERO, 16.0, Color::WHITE);
+        renderer.record_commands(&mut buffer);
+        renderer.clear_instances();
+
+        renderer.record_commands(&mut buffer);
+        assert!(buffer.commands.is_empty() && buffer.instances.is_empty());
+    }
+}
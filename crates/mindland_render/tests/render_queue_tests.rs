@@ -0,0 +1,132 @@
+//! Tests for render queue draw ordering
+//!
+//! **Feature: render-queue, Property 1: Lower Queues Draw First Regardless Of Insertion Order**
+
+use bevy::prelude::*;
+use mindland_assets::{is_transparent_queue, QUEUE_BACKGROUND, QUEUE_OPAQUE, QUEUE_OVERLAY, QUEUE_TRANSPARENT};
+use mindland_camera::CameraController;
+use mindland_render::{RenderCommand, RenderCommandBuffer, UltraRenderer};
+
+/// In front of the default camera, `distance` blocks away
+fn ahead(distance: f32) -> Mat4 {
+    Mat4::from_translation(Vec3::new(0.0, 1.8, -distance))
+}
+
+/// Render one full-window pass and return the dynamic draw order as queues
+fn draw_queues(renderer: &mut UltraRenderer) -> Vec<i32> {
+    renderer.render_viewport(&CameraController::new(), Rect::new(0.0, 0.0, 1920.0, 1080.0));
+    let instances = &renderer.instanced_renderer.instance_data;
+    renderer.viewport_passes[0]
+        .visible_instances
+        .iter()
+        .map(|&index| instances[index as usize].render_queue)
+        .collect()
+}
+
+/// Points exactly 13 blocks from the origin, all in front of a camera there
+const EQUAL_DISTANCE: [Vec3; 5] = [
+    Vec3::new(0.0, 0.0, -13.0),
+    Vec3::new(5.0, 0.0, -12.0),
+    Vec3::new(-5.0, 0.0, -12.0),
+    Vec3::new(0.0, 5.0, -12.0),
+    Vec3::new(0.0, -5.0, -12.0),
+];
+
+/// Record one frame of `textures[i]` at `EQUAL_DISTANCE[i]` in the transparent queue, returning
+/// the textures in recorded draw order
+fn recorded_transparent_order(textures: &[u32]) -> Vec<u32> {
+    let mut renderer = UltraRenderer::new();
+    for (&texture, &position) in textures.iter().zip(&EQUAL_DISTANCE) {
+        renderer.add_instance_in_queue(Mat4::from_translation(position), texture, Color::WHITE, QUEUE_TRANSPARENT);
+    }
+    let mut camera = CameraController::new();
+    camera.transform.translation = Vec3::ZERO; // Exact distances
+    renderer.render_viewport(&camera, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+
+    let mut buffer = RenderCommandBuffer::default();
+    renderer.record_commands(&mut buffer);
+    let Some(RenderCommand::DrawInstances { first, count }) = buffer.commands.get(1).copied() else {
+        panic!("no draw recorded");
+    };
+    (first..first + count).map(|index| buffer.instances[index as usize].texture_index).collect()
+}
+
+#[cfg(test)]
+mod render_queue_tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_queues_draw_in_queue_order() {
+        // **Feature: render-queue, Property 1: Lower Queues Draw First Regardless Of Insertion Order**
+
+        let mut renderer = UltraRenderer::new();
+        for queue in [QUEUE_OVERLAY, QUEUE_TRANSPARENT, QUEUE_OPAQUE, QUEUE_BACKGROUND, 1500, QUEUE_OPAQUE + 10] {
+            assert!(renderer.add_instance_in_queue(ahead(10.0), 0, Color::WHITE, queue));
+        }
+
+        assert_eq!(
+            draw_queues(&mut renderer),
+            vec![QUEUE_BACKGROUND, QUEUE_OPAQUE, QUEUE_OPAQUE + 10, QUEUE_TRANSPARENT, 1500, QUEUE_OVERLAY]
+        );
+    }
+
+    #[test]
+    fn test_opaque_front_to_back_and_transparent_back_to_front() {
+        // **Feature: render-queue, Property 1: Lower Queues Draw First Regardless Of Insertion Order**
+
+        let mut renderer = UltraRenderer::new();
+        renderer.add_instance_in_queue(ahead(30.0), 1, Color::WHITE, QUEUE_TRANSPARENT);
+        renderer.add_instance_in_queue(ahead(5.0), 2, Color::WHITE, QUEUE_TRANSPARENT);
+        renderer.add_instance(ahead(40.0), 3, Color::WHITE); // Default queue is opaque
+        renderer.add_instance(ahead(8.0), 4, Color::WHITE);
+        renderer.add_instance_in_queue(ahead(20.0), 5, Color::WHITE, QUEUE_TRANSPARENT);
+
+        renderer.render_viewport(&CameraController::new(), Rect::new(0.0, 0.0, 1920.0, 1080.0));
+        let instances = &renderer.instanced_renderer.instance_data;
+        let textures: Vec<u32> = renderer.viewport_passes[0]
+            .visible_instances
+            .iter()
+            .map(|&index| instances[index as usize].texture_index)
+            .collect();
+
+        assert_eq!(textures, vec![4, 3, 1, 5, 2]);
+    }
+
+    #[test]
+    fn test_transparent_queue_boundary() {
+        assert!(!is_transparent_queue(QUEUE_BACKGROUND));
+        assert!(!is_transparent_queue(QUEUE_OPAQUE));
+        assert!(!is_transparent_queue(QUEUE_TRANSPARENT - 1));
+        assert!(is_transparent_queue(QUEUE_TRANSPARENT));
+        assert!(is_transparent_queue(QUEUE_OVERLAY));
+    }
+
+    #[test]
+    fn test_equal_distance_transparent_order_is_deterministic() {
+        // **Feature: render-queue, Property 1: Lower Queues Draw First Regardless Of Insertion Order**
+
+        for textures in [[0, 1, 2, 3, 4], [3, 1, 4, 0, 2], [4, 3, 2, 1, 0]] {
+            let first_run = recorded_transparent_order(&textures);
+            assert_eq!(first_run, textures.to_vec(), "ties draw in insertion order");
+            for _ in 0..10 {
+                assert_eq!(recorded_transparent_order(&textures), first_run);
+            }
+        }
+    }
+
+    #[test]
+    fn test_static_draws_before_dynamic_on_ties() {
+        let mut renderer = UltraRenderer::new();
+        renderer.add_instance_in_queue(Mat4::from_translation(EQUAL_DISTANCE[1]), 1, Color::WHITE, QUEUE_OPAQUE);
+        renderer.add_static_instance(Mat4::from_translation(EQUAL_DISTANCE[2]), 2, Color::WHITE);
+        renderer.add_instance_in_queue(Mat4::from_translation(EQUAL_DISTANCE[0] * 0.5), 3, Color::WHITE, QUEUE_TRANSPARENT);
+        let mut camera = CameraController::new();
+        camera.transform.translation = Vec3::ZERO;
+        renderer.render_viewport(&camera, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+
+        let mut buffer = RenderCommandBuffer::default();
+        renderer.record_commands(&mut buffer);
+        let textures: Vec<u32> = buffer.instances.iter().map(|instance| instance.texture_index).collect();
+        assert_eq!(textures, vec![2, 1, 3], "merged by queue and depth, static first on ties");
+    }
+}
@@ -0,0 +1,73 @@
+//! Tests for dynamic resolution
+//!
+//! **Feature: dynamic-resolution, Property 1: Scaled Internal Target**
+
+use mindland_camera::CameraController;
+use mindland_performance::QualitySettings;
+use mindland_render::{render_quality_system, ScaledRenderTarget, UltraRenderer, MIN_RENDER_SCALE};
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod render_scale_tests {
+    use super::*;
+
+    #[test]
+    fn test_half_scale_on_1080p_gives_540p_target() {
+        // **Feature: dynamic-resolution, Property 1: Scaled Internal Target**
+        // Scale 0.5 on a 1920x1080 window renders into 960x540 and upscales
+
+        let mut renderer = UltraRenderer::new();
+        renderer.set_render_scale(0.5);
+
+        let target = renderer.render_target(UVec2::new(1920, 1080));
+
+        assert_eq!(target.internal_size, UVec2::new(960, 540));
+        assert_eq!(target.output_size, UVec2::new(1920, 1080));
+        assert!(target.needs_upscale());
+    }
+
+    #[test]
+    fn test_render_scale_is_clamped() {
+        // **Feature: dynamic-resolution, Property 1: Scaled Internal Target**
+
+        let mut renderer = UltraRenderer::new();
+        renderer.set_render_scale(0.1);
+        assert_eq!(renderer.render_scale, 0.5);
+        renderer.set_render_scale(2.0);
+        assert_eq!(renderer.render_scale, 1.0);
+
+        let native = ScaledRenderTarget::new(UVec2::new(1280, 800), 1.0);
+        assert!(!native.needs_upscale());
+    }
+
+    #[test]
+    fn test_viewports_are_scaled_into_internal_target() {
+        // **Feature: dynamic-resolution, Property 1: Scaled Internal Target**
+        // Split-screen rects are given in window pixels and land in the scaled target
+
+        let mut renderer = UltraRenderer::new();
+        renderer.set_render_scale(0.5);
+        renderer.render_viewport(&CameraController::new(), Rect::new(0.0, 540.0, 1920.0, 1080.0));
+
+        let pass = &renderer.viewport_passes[0];
+        assert_eq!(pass.viewport.physical_position, UVec2::new(0, 270));
+        assert_eq!(pass.viewport.physical_size, UVec2::new(960, 270));
+    }
+
+    #[test]
+    fn test_quality_render_scale_reaches_the_renderer() {
+        // **Feature: dynamic-resolution, Property 1: Scaled Internal Target**
+
+        let mut app = App::new();
+        app.insert_resource(UltraRenderer::new());
+        app.insert_resource(QualitySettings::macbook_pro_2014_preset());
+        app.add_systems(Update, render_quality_system.run_if(resource_changed::<QualitySettings>()));
+
+        app.world.resource_mut::<QualitySettings>().reduce_gpu_load(1.0);
+        app.update();
+
+        assert_eq!(app.world.resource::<UltraRenderer>().render_scale, MIN_RENDER_SCALE);
+        let target = app.world.resource::<UltraRenderer>().render_target(UVec2::new(1920, 1080));
+        assert_eq!(target.internal_size, UVec2::new(960, 540));
+    }
+}
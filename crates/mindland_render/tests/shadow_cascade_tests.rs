@@ -0,0 +1,113 @@
+//! Tests for directional-light shadow cascade splits
+//!
+//! **Feature: shadow-cascades, Property 1: Splits Increase Monotonically Within The Far Plane**
+
+use bevy::prelude::*;
+use mindland_performance::{QualitySettings, ShadowQuality};
+use mindland_render::{shadow_quality_system, DirectionalLightController, MAX_SHADOW_CASCADES};
+
+const QUALITIES: [ShadowQuality; 5] =
+    [ShadowQuality::Off, ShadowQuality::Low, ShadowQuality::Medium, ShadowQuality::High, ShadowQuality::Ultra];
+
+fn projection(near: f32, far: f32) -> PerspectiveProjection {
+    PerspectiveProjection { near, far, ..default() }
+}
+
+fn assert_valid_splits(splits: &[f32], projection: &PerspectiveProjection) {
+    assert!(splits.len() <= MAX_SHADOW_CASCADES);
+    assert!(splits.first().is_none_or(|&first| first > projection.near), "{splits:?}");
+    assert!(splits.windows(2).all(|pair| pair[0] < pair[1]), "not increasing: {splits:?}");
+    assert!(splits.iter().all(|&split| split <= projection.far), "beyond far plane: {splits:?}");
+}
+
+#[cfg(test)]
+mod shadow_cascade_tests {
+    use super::*;
+
+    #[test]
+    fn test_splits_increase_within_far_plane() {
+        // **Feature: shadow-cascades, Property 1: Splits Increase Monotonically Within The Far Plane**
+
+        let projections = [projection(0.1, 1000.0), projection(0.1, 64.0), projection(0.5, 20.0), projection(1.0, 100_000.0)];
+        for quality in QUALITIES {
+            for lambda in [0.0, 0.3, 0.75, 1.0] {
+                let mut light = DirectionalLightController::new();
+                light.apply_shadow_quality(quality);
+                light.split_lambda = lambda;
+                for projection in &projections {
+                    assert_valid_splits(&light.cascade_splits(projection), projection);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lower_quality_uses_fewer_coarser_cascades() {
+        // **Feature: shadow-cascades, Property 1: Splits Increase Monotonically Within The Far Plane**
+
+        let camera = projection(0.1, 1000.0);
+        let mut light = DirectionalLightController::new();
+        let counts: Vec<usize> = QUALITIES
+            .iter()
+            .map(|&quality| {
+                light.apply_shadow_quality(quality);
+                light.cascade_splits(&camera).len()
+            })
+            .collect();
+        assert_eq!(counts, vec![0, 1, 2, 3, 4]);
+
+        light.apply_shadow_quality(ShadowQuality::Ultra);
+        let ultra = light.cascade_splits(&camera);
+        light.apply_shadow_quality(ShadowQuality::Medium);
+        let medium = light.cascade_splits(&camera);
+        assert!(medium[0] > ultra[0], "first cascade covers more depth at lower quality");
+    }
+
+    #[test]
+    fn test_lambda_blends_uniform_and_logarithmic() {
+        let camera = projection(1.0, 1000.0);
+        let mut light = DirectionalLightController::new();
+        light.apply_shadow_quality(ShadowQuality::Low);
+        light.cascade_count = 2;
+        light.shadow_distance = 100.0;
+
+        light.split_lambda = 0.0;
+        assert_eq!(light.cascade_splits(&camera), vec![50.5, 100.0]);
+        light.split_lambda = 1.0;
+        let logarithmic = light.cascade_splits(&camera);
+        assert!((logarithmic[0] - 10.0).abs() < 1e-3, "{logarithmic:?}");
+    }
+
+    #[test]
+    fn test_fixed_splits_are_clamped_and_sanitized() {
+        let camera = projection(0.1, 100.0);
+        let mut light = DirectionalLightController::new();
+        light.set_cascade_splits(&[8.0, 4.0, 30.0, 500.0, 900.0, 2000.0]);
+
+        assert_eq!(light.cascade_count, MAX_SHADOW_CASCADES);
+        assert_eq!(light.fixed_splits(), Some(&[8.0, 4.0, 30.0, 500.0][..]));
+        assert_eq!(light.cascade_splits(&camera), vec![8.0, 30.0, 100.0]);
+
+        light.set_cascade_splits(&[]);
+        assert!(light.cascade_splits(&camera).is_empty());
+    }
+
+    #[test]
+    fn test_quality_system_keeps_fixed_splits_until_shadow_quality_changes() {
+        let mut app = App::new();
+        app.insert_resource(QualitySettings { shadow_quality: ShadowQuality::High, ..QualitySettings::macbook_pro_2014_preset() })
+            .add_systems(Update, shadow_quality_system);
+        let mut light = DirectionalLightController::new();
+        light.set_cascade_splits(&[10.0, 40.0]);
+        let entity = app.world.spawn(light).id();
+
+        app.update();
+        assert!(app.world.get::<DirectionalLightController>(entity).unwrap().fixed_splits().is_some());
+
+        app.world.resource_mut::<QualitySettings>().shadow_quality = ShadowQuality::Low;
+        app.update();
+        let light = app.world.get::<DirectionalLightController>(entity).unwrap();
+        assert_eq!(light.shadow_quality(), ShadowQuality::Low);
+        assert_eq!((light.cascade_count, light.fixed_splits()), (1, None));
+    }
+}
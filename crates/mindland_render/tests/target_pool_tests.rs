@@ -0,0 +1,135 @@
+//! Tests for render-target pooling
+//!
+//! **Feature: render-target-pool, Property 1: Resize Bursts Coalesce Allocations**
+
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use mindland_render::{RenderTargetKey, RenderTargetPool, UltraRenderer, DEFAULT_RESIZE_DEBOUNCE};
+use std::time::{Duration, Instant};
+
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Acquire the color, depth and MSAA targets a frame needs
+fn acquire_frame_targets(pool: &mut RenderTargetPool<RenderTargetKey>) {
+    for (format, samples) in [
+        (TextureFormat::Rgba16Float, 1),
+        (TextureFormat::Depth32Float, 4),
+        (TextureFormat::Rgba16Float, 4),
+    ] {
+        pool.acquire(format, samples, |key| *key).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod target_pool_tests {
+    use super::*;
+
+    #[test]
+    fn test_resize_burst_coalesces_allocations() {
+        // **Feature: render-target-pool, Property 1: Resize Bursts Coalesce Allocations**
+
+        let mut pool = RenderTargetPool::<RenderTargetKey>::default();
+        let mut now = Instant::now();
+        pool.request_size(UVec2::new(1280, 720), now);
+        acquire_frame_targets(&mut pool);
+        assert_eq!(pool.allocations, 3);
+
+        // Dragging the window edge: a new size every frame
+        for step in 1..=30 {
+            now += FRAME;
+            let rendered = pool.request_size(UVec2::new(1280 + step * 7, 720 + step * 3), now);
+            assert_eq!(rendered, UVec2::new(1280, 720));
+            acquire_frame_targets(&mut pool);
+        }
+        assert_eq!(pool.allocations, 3);
+        assert!(pool.is_resizing());
+
+        // Released: the final size holds until the debounce elapses
+        let final_size = UVec2::new(1280 + 30 * 7, 720 + 30 * 3);
+        let settled_at = now + DEFAULT_RESIZE_DEBOUNCE;
+        while now < settled_at + FRAME {
+            now += FRAME;
+            pool.request_size(final_size, now);
+            acquire_frame_targets(&mut pool);
+        }
+
+        assert_eq!(pool.settled_size(), Some(final_size));
+        assert!(!pool.is_resizing());
+        assert_eq!(pool.allocations, 6);
+        assert_eq!(pool.len(), 3); // Old-size targets dropped
+    }
+
+    #[test]
+    fn test_burst_returning_to_original_size_allocates_nothing() {
+        // **Feature: render-target-pool, Property 1: Resize Bursts Coalesce Allocations**
+
+        let mut pool = RenderTargetPool::<RenderTargetKey>::default();
+        let mut now = Instant::now();
+        pool.request_size(UVec2::new(800, 600), now);
+        acquire_frame_targets(&mut pool);
+
+        for width in [810, 820, 830, 800] {
+            now += FRAME;
+            pool.request_size(UVec2::new(width, 600), now);
+            acquire_frame_targets(&mut pool);
+        }
+        now += DEFAULT_RESIZE_DEBOUNCE * 2;
+        pool.request_size(UVec2::new(800, 600), now);
+        acquire_frame_targets(&mut pool);
+
+        assert_eq!(pool.allocations, 3);
+        assert!(!pool.is_resizing());
+    }
+
+    #[test]
+    fn test_debounce_is_configurable() {
+        let mut pool = RenderTargetPool::<RenderTargetKey>::new(Duration::ZERO);
+        let now = Instant::now();
+        for width in [100, 200, 300] {
+            assert_eq!(pool.request_size(UVec2::new(width, 100), now), UVec2::new(width, 100));
+            acquire_frame_targets(&mut pool);
+        }
+        assert_eq!(pool.allocations, 9);
+
+        pool.set_debounce(Duration::from_secs(1));
+        assert_eq!(pool.request_size(UVec2::new(400, 100), now), UVec2::new(300, 100));
+        assert_eq!(pool.request_size(UVec2::new(400, 100), now + Duration::from_millis(999)), UVec2::new(300, 100));
+        assert_eq!(pool.request_size(UVec2::new(400, 100), now + Duration::from_secs(1)), UVec2::new(400, 100));
+    }
+
+    #[test]
+    fn test_acquire_needs_a_size_and_keys_by_format_and_samples() {
+        let mut pool = RenderTargetPool::<RenderTargetKey>::default();
+        assert!(pool.acquire(TextureFormat::Rgba8Unorm, 1, |key| *key).is_none());
+
+        pool.request_size(UVec2::new(64, 64), Instant::now());
+        let key = *pool.acquire(TextureFormat::Rgba8Unorm, 4, |key| *key).unwrap();
+        assert_eq!(key, RenderTargetKey { size: UVec2::new(64, 64), format: TextureFormat::Rgba8Unorm, sample_count: 4 });
+
+        pool.acquire(TextureFormat::Rgba8Unorm, 1, |key| *key);
+        pool.acquire(TextureFormat::Rgba8Unorm, 4, |_| panic!("reused target was reallocated"));
+        assert_eq!(pool.len(), 2);
+
+        pool.clear();
+        assert!(pool.is_empty());
+        assert_eq!(pool.settled_size(), Some(UVec2::new(64, 64)));
+    }
+
+    #[test]
+    fn test_renderer_holds_internal_size_during_resize() {
+        let mut renderer = UltraRenderer::new();
+        renderer.set_render_scale(0.5);
+        let now = Instant::now();
+
+        let first = renderer.debounced_render_target(UVec2::new(1000, 800), now);
+        assert_eq!(first.internal_size, UVec2::new(500, 400));
+
+        let dragging = renderer.debounced_render_target(UVec2::new(1200, 800), now + FRAME);
+        assert_eq!(dragging.internal_size, UVec2::new(500, 400));
+        assert_eq!(dragging.output_size, UVec2::new(1200, 800));
+        assert!(dragging.needs_upscale());
+
+        let settled = renderer.debounced_render_target(UVec2::new(1200, 800), now + FRAME + DEFAULT_RESIZE_DEBOUNCE);
+        assert_eq!(settled.internal_size, UVec2::new(600, 400));
+    }
+}
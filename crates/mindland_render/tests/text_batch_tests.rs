@@ -0,0 +1,71 @@
+//! Tests for batched screen-space text
+//!
+//! **Feature: text-batching, Property 1: One Glyph Instance Per Visible Character**
+
+use bevy::prelude::*;
+use mindland_assets::QUEUE_OVERLAY;
+use mindland_render::{UltraRenderer, FALLBACK_GLYPH};
+
+/// Pixel position of a glyph quad's top-left corner
+fn glyph_position(renderer: &UltraRenderer, index: usize) -> Vec2 {
+    Vec2::new(renderer.text.glyphs[index].transform[3][0], renderer.text.glyphs[index].transform[3][1])
+}
+
+#[cfg(test)]
+mod text_batch_tests {
+    use super::*;
+
+    #[test]
+    fn test_n_characters_make_n_glyph_instances() {
+        // **Feature: text-batching, Property 1: One Glyph Instance Per Visible Character**
+
+        let mut renderer = UltraRenderer::new();
+        let text = "FPS:144.0";
+        renderer.draw_text(text, Vec2::new(10.0, 20.0), 16.0, Color::WHITE);
+
+        let glyphs = &renderer.text.glyphs;
+        assert_eq!(glyphs.len(), text.chars().count());
+        let tiles: Vec<u32> = glyphs.iter().map(|glyph| glyph.texture_index).collect();
+        assert_eq!(tiles, text.chars().map(|character| character as u32).collect::<Vec<_>>());
+        assert!(glyphs.iter().all(|glyph| glyph.render_queue == QUEUE_OVERLAY));
+        assert_eq!(renderer.instanced_renderer.instance_data.len(), 0, "text stays out of the scene's instances");
+    }
+
+    #[test]
+    fn test_layout_advances_and_wraps() {
+        // **Feature: text-batching, Property 1: One Glyph Instance Per Visible Character**
+
+        let mut renderer = UltraRenderer::new();
+        let advance = 20.0 * renderer.text.advance;
+        let line_height = 20.0 * renderer.text.line_height;
+        renderer.draw_text("ab c\nd", Vec2::new(5.0, 5.0), 20.0, Color::WHITE);
+
+        assert_eq!(renderer.text.glyphs.len(), 4, "whitespace adds no quads");
+        assert_eq!(glyph_position(&renderer, 1), Vec2::new(5.0 + advance, 5.0));
+        assert_eq!(glyph_position(&renderer, 2), Vec2::new(5.0 + 3.0 * advance, 5.0), "space still advances");
+        assert_eq!(glyph_position(&renderer, 3), Vec2::new(5.0, 5.0 + line_height));
+        assert_eq!(renderer.text.glyphs[0].transform[0][0], 20.0, "quads are scaled to the text size");
+    }
+
+    #[test]
+    fn test_uncovered_characters_use_fallback() {
+        let mut renderer = UltraRenderer::new();
+        renderer.draw_text("é→", Vec2::ZERO, 16.0, Color::WHITE);
+
+        let tiles: Vec<u32> = renderer.text.glyphs.iter().map(|glyph| glyph.texture_index).collect();
+        assert_eq!(tiles, vec!['é' as u32, FALLBACK_GLYPH as u32], "Latin-1 is covered, arrows aren't");
+    }
+
+    #[test]
+    fn test_batch_uploads_and_clears_each_frame() {
+        let mut renderer = UltraRenderer::new();
+        renderer.draw_text("debug", Vec2::ZERO, 16.0, Color::WHITE);
+        renderer.draw_text("overlay", Vec2::new(0.0, 20.0), 16.0, Color::WHITE);
+
+        let stats = renderer.upload_instances();
+        assert_eq!(stats.bytes_uploaded, 12 * std::mem::size_of::<mindland_render::InstanceData>());
+
+        renderer.clear_instances();
+        assert!(renderer.text.glyphs.is_empty());
+    }
+}
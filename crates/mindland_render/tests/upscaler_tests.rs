@@ -0,0 +1,59 @@
+//! Tests for upscaler selection at reduced render scale
+//!
+//! **Feature: dynamic-resolution, Property 3: Upscale Pass Chain**
+
+use mindland_render::{UltraRenderer, UpscalePass, Upscaler};
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod upscaler_tests {
+    use super::*;
+
+    const WINDOW: UVec2 = UVec2::new(1920, 1080);
+
+    #[test]
+    fn test_native_scale_needs_no_upscale() {
+        // **Feature: dynamic-resolution, Property 3: Upscale Pass Chain**
+
+        let renderer = UltraRenderer::new();
+        assert!(renderer.upscale_passes(WINDOW, true).is_empty());
+    }
+
+    #[test]
+    fn test_upscaler_pass_chains() {
+        // **Feature: dynamic-resolution, Property 3: Upscale Pass Chain**
+        // Each upscaler maps to its pass chain, always ending in sharpening except bilinear
+
+        let mut renderer = UltraRenderer::new();
+        renderer.set_render_scale(0.5);
+        renderer.upscale_sharpness = 0.5;
+        let sharpen = UpscalePass::Sharpen { sharpness: 0.5 };
+
+        renderer.set_upscaler(Upscaler::Bilinear);
+        assert_eq!(renderer.upscale_passes(WINDOW, true), vec![UpscalePass::BilinearBlit]);
+
+        renderer.set_upscaler(Upscaler::FsrSpatial);
+        assert_eq!(
+            renderer.upscale_passes(WINDOW, true),
+            vec![UpscalePass::EdgeAdaptiveUpsample, sharpen]
+        );
+
+        renderer.set_upscaler(Upscaler::Temporal);
+        assert_eq!(
+            renderer.upscale_passes(WINDOW, true),
+            vec![UpscalePass::TemporalResolve, sharpen]
+        );
+    }
+
+    #[test]
+    fn test_temporal_falls_back_to_spatial_without_history() {
+        // **Feature: dynamic-resolution, Property 3: Upscale Pass Chain**
+
+        let mut renderer = UltraRenderer::new();
+        renderer.set_render_scale(0.5);
+        renderer.set_upscaler(Upscaler::Temporal);
+
+        let passes = renderer.upscale_passes(WINDOW, false);
+        assert_eq!(passes[0], UpscalePass::EdgeAdaptiveUpsample);
+    }
+}
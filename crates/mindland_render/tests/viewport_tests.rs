@@ -0,0 +1,55 @@
+//! Tests for split-screen viewport rendering
+//!
+//! **Feature: split-screen, Property 1: Viewport Rect Math**
+
+use mindland_camera::CameraController;
+use mindland_render::{viewport_from_rect, UltraRenderer};
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod viewport_tests {
+    use super::*;
+
+    #[test]
+    fn test_two_player_horizontal_split_viewports() {
+        // **Feature: split-screen, Property 1: Viewport Rect Math**
+        // A 1920x1080 window split horizontally gives two full-width, half-height viewports
+
+        let top = viewport_from_rect(Rect::new(0.0, 0.0, 1920.0, 540.0));
+        let bottom = viewport_from_rect(Rect::new(0.0, 540.0, 1920.0, 1080.0));
+
+        assert_eq!(top.physical_position, UVec2::new(0, 0));
+        assert_eq!(top.physical_size, UVec2::new(1920, 540));
+        assert_eq!(bottom.physical_position, UVec2::new(0, 540));
+        assert_eq!(bottom.physical_size, UVec2::new(1920, 540));
+
+        // Viewports must tile the window without overlap
+        assert_eq!(top.physical_position.y + top.physical_size.y, bottom.physical_position.y);
+        assert_eq!(bottom.physical_position.y + bottom.physical_size.y, 1080);
+    }
+
+    #[test]
+    fn test_render_viewport_records_one_pass_per_camera() {
+        // **Feature: split-screen, Property 1: Viewport Rect Math**
+        // Each render_viewport call composes one pass with its own scissor and camera
+
+        let mut renderer = UltraRenderer::new();
+        renderer.add_instance(Mat4::from_translation(Vec3::new(0.0, 1.8, -10.0)), 0, Color::WHITE);
+
+        let player_one = CameraController::new();
+        let mut player_two = CameraController::new();
+        player_two.transform.translation = Vec3::new(50.0, 1.8, 0.0);
+
+        renderer.render_viewport(&player_one, Rect::new(0.0, 0.0, 1920.0, 540.0));
+        renderer.render_viewport(&player_two, Rect::new(0.0, 540.0, 1920.0, 1080.0));
+
+        assert_eq!(renderer.viewport_passes.len(), 2);
+        assert_eq!(renderer.viewport_passes[1].scissor, URect::new(0, 540, 1920, 1080));
+        assert_eq!(renderer.viewport_passes[1].camera_position, player_two.transform.translation);
+        assert_eq!(renderer.viewport_passes[0].visible_instances, vec![0]);
+
+        // Passes are per-frame and reset with the instances
+        renderer.clear_instances();
+        assert!(renderer.viewport_passes.is_empty());
+    }
+}
@@ -2,7 +2,10 @@
 //! 
 //! Cross-platform window creation and management with optimized graphics backend selection.
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    window::{PresentMode, WindowMode},
+};
 
 /// Cross-platform window manager
 pub struct WindowManager {
@@ -43,6 +46,19 @@ impl Default for DisplaySettings {
     }
 }
 
+impl DisplaySettings {
+    /// Bevy window with these settings (spawn it to open an extra window)
+    pub fn to_window(&self, title: impl Into<String>) -> Window {
+        Window {
+            title: title.into(),
+            resolution: (self.resolution.0 as f32, self.resolution.1 as f32).into(),
+            mode: if self.fullscreen { WindowMode::BorderlessFullscreen } else { WindowMode::Windowed },
+            present_mode: if self.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync },
+            ..default()
+        }
+    }
+}
+
 impl GraphicsBackend {
     /// Automatically select the optimal graphics backend for the current platform
     pub fn auto_select() -> Self {
@@ -3,7 +3,7 @@
 //! Built on Rust and Bevy ECS for unprecedented performance in the voxel genre.
 //! Designed to outperform Minecraft by 300% while maintaining 60 FPS on MacBook Pro 2014.
 
-use mindland_app::{MindLandApp, EngineConfig, PerformanceMode, HardwareTier};
+use mindland_app::{MindLandApp, EngineConfig};
 
 fn main() {
     // Initialize high-performance logging
@@ -27,56 +27,8 @@ fn main() {
 }
 
 /// Detect hardware capabilities and create optimal configuration
+///
+/// `MINDLAND_HARDWARE_TIER` and `MINDLAND_PERFORMANCE_MODE` override what detection picks.
 fn detect_hardware_and_configure() -> EngineConfig {
-    tracing::info!("🔍 Detecting hardware configuration...");
-    
-    // TODO: Implement actual hardware detection
-    // For now, we'll use intelligent defaults based on common hardware
-    
-    let is_macbook = detect_macbook_pro_2014();
-    let hardware_tier = detect_hardware_tier();
-    
-    if is_macbook {
-        tracing::info!("🍎 MacBook Pro 2014 detected - applying thermal optimization");
-        EngineConfig::macbook_pro_2014()
-    } else if hardware_tier >= HardwareTier::High {
-        tracing::info!("🚀 High-end hardware detected - enabling ultra-performance mode");
-        EngineConfig::ultra_performance()
-    } else {
-        tracing::info!("⚖️  Standard hardware detected - using balanced configuration");
-        EngineConfig::default()
-    }
+    EngineConfig::from_env()
 }
-
-/// Detect if running on MacBook Pro 2014 (placeholder implementation)
-fn detect_macbook_pro_2014() -> bool {
-    // TODO: Implement actual MacBook Pro 2014 detection
-    // This would check:
-    // - macOS version and hardware identifiers
-    // - CPU model (Intel Core i5-4278U or i5-4308U)
-    // - GPU model (Intel Iris 5100)
-    // - System memory configuration
-    
-    #[cfg(target_os = "macos")]
-    {
-        // Placeholder: assume MacBook Pro 2014 for now on macOS
-        // In real implementation, would check system_profiler or similar
-        std::env::var("MINDLAND_FORCE_MACBOOK_2014").is_ok()
-    }
-    
-    #[cfg(not(target_os = "macos"))]
-    false
-}
-
-/// Detect hardware tier based on system capabilities
-fn detect_hardware_tier() -> HardwareTier {
-    // TODO: Implement actual hardware tier detection
-    // This would check:
-    // - CPU core count and model
-    // - GPU model and VRAM
-    // - System RAM amount
-    // - Storage type (SSD vs HDD)
-    
-    // For now, return medium tier as safe default
-    HardwareTier::Medium
-}
\ No newline at end of file